@@ -6,50 +6,132 @@ use std::io::{
 
 use clap::Parser;
 
+use crate::cli::changelog_range::run_changelog_range;
 use crate::cli::check::run_check_command;
 use crate::cli::flow::run_flow_pipeline;
+use crate::cli::json_schema::run_json_schema;
 use crate::cli::llm_help::display_llm_help;
 use crate::cli::parser::{
     Cli,
     Commands,
+    cli_manifest,
 };
 use crate::cli::render::run_render;
-use crate::cli::version::run_version_pipeline;
+use crate::cli::report::run_report;
+use crate::cli::selftest::run_selftest;
+use crate::cli::sort::{
+    run_max,
+    run_min,
+    run_sort,
+};
+use crate::cli::utils::config_defaults::apply_config_defaults;
+use crate::cli::utils::env_file::apply_env_file;
+use crate::cli::utils::output_formatter::OutputFormatter;
+use crate::cli::validate_tag::run_validate_tag;
+use crate::cli::vars::run_vars;
+use crate::cli::vcs_data::run_vcs_data;
+use crate::cli::version::{
+    run_version_pipeline,
+    run_version_watch,
+};
 
 pub fn run_with_args<W: Write>(
     args: Vec<String>,
     mut writer: W,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let cli = Cli::try_parse_from(args)?;
+    // Precedence: CLI > --env-file > config files
+    let cli = Cli::try_parse_from(apply_config_defaults(apply_env_file(args)?)?)?;
 
     crate::logging::init_logging(cli.verbose);
 
     tracing::debug!("Zerv started with args: {:?}", cli);
 
+    // Handle --dump-cli flag (json is the only format)
+    if cli.dump_cli.is_some() {
+        let manifest = serde_json::to_string_pretty(&cli_manifest())?;
+        writeln!(writer, "{manifest}")?;
+        return Ok(());
+    }
+
     // Handle --llm-help flag
     if cli.llm_help {
         display_llm_help(&mut writer)?;
         return Ok(());
     }
 
-    // Extract stdin content once at the beginning
-    let stdin_content = extract_stdin_once()?;
+    // Extract stdin content once, for the commands that read it
+    let stdin_content = if cli.command.as_ref().is_some_and(reads_stdin) {
+        extract_stdin_once()?
+    } else {
+        None
+    };
 
     match cli.command {
+        Some(Commands::Version(version_args)) if version_args.main.watch => {
+            run_version_watch(*version_args, &mut writer)?;
+        }
         Some(Commands::Version(version_args)) => {
+            let terminator = OutputFormatter::terminator(&version_args.output);
+            let output_config = version_args.output.clone();
             let output = run_version_pipeline(*version_args, stdin_content.as_deref())?;
-            writeln!(writer, "{output}")?;
+            OutputFormatter::publish_github_output(&output_config, &output)?;
+            write!(writer, "{output}{terminator}")?;
         }
         Some(Commands::Flow(flow_args)) => {
+            let terminator = OutputFormatter::terminator(&flow_args.output);
+            let output_config = flow_args.output.clone();
             let output = run_flow_pipeline(*flow_args, stdin_content.as_deref())?;
-            writeln!(writer, "{output}")?;
+            OutputFormatter::publish_github_output(&output_config, &output)?;
+            write!(writer, "{output}{terminator}")?;
         }
         Some(Commands::Check(check_args)) => {
             let output = run_check_command(check_args)?;
             writeln!(writer, "{output}")?;
         }
         Some(Commands::Render(render_args)) => {
+            let terminator = OutputFormatter::terminator(&render_args.output);
+            let output_config = render_args.output.clone();
             let output = run_render(*render_args)?;
+            OutputFormatter::publish_github_output(&output_config, &output)?;
+            write!(writer, "{output}{terminator}")?;
+        }
+        Some(Commands::Sort(sort_args)) => {
+            let output = run_sort(sort_args, stdin_content.as_deref())?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::Max(reduce_args)) => {
+            let output = run_max(reduce_args, stdin_content.as_deref())?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::Min(reduce_args)) => {
+            let output = run_min(reduce_args, stdin_content.as_deref())?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::Vars(vars_args)) => {
+            writeln!(writer, "{}", run_vars(vars_args))?;
+        }
+        Some(Commands::ChangelogRange(changelog_args)) => {
+            let output = run_changelog_range(*changelog_args)?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::JsonSchema(json_schema_args)) => {
+            let output = run_json_schema(json_schema_args)?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::ValidateTag(validate_tag_args)) => {
+            let output = run_validate_tag(*validate_tag_args)?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::VcsData(vcs_data_args)) => {
+            let output = run_vcs_data(*vcs_data_args)?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::Report(report_args)) => {
+            let output = run_report(*report_args)?;
+            writeln!(writer, "{output}")?;
+        }
+        Some(Commands::Selftest(selftest_args)) => {
+            let output = run_selftest(selftest_args)?;
             writeln!(writer, "{output}")?;
         }
         None => {
@@ -60,7 +142,19 @@ pub fn run_with_args<W: Write>(
     Ok(())
 }
 
-/// Extract stdin content once, regardless of command
+/// Whether the command may take its input from stdin
+fn reads_stdin(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Version(_)
+            | Commands::Flow(_)
+            | Commands::Sort(_)
+            | Commands::Max(_)
+            | Commands::Min(_)
+    )
+}
+
+/// Extract stdin content once for the commands that read it
 /// Returns Ok(Some(String)) if stdin is available, Ok(None) otherwise
 fn extract_stdin_once() -> Result<Option<String>, Box<dyn std::error::Error>> {
     // Check if stdin is being piped
@@ -110,4 +204,19 @@ mod tests {
         // and std::process::exit, so we just ensure it compiles and can be called
         let _test_compile = run; // Ensures function exists and compiles
     }
+
+    #[rstest::rstest]
+    #[case::newline("newline", b"1.2.3\n")]
+    #[case::none("none", b"1.2.3")]
+    #[case::null("null", b"1.2.3\0")]
+    fn test_render_writes_output_terminator(#[case] terminator: &str, #[case] expected: &[u8]) {
+        let args = ["zerv", "render", "1.2.3", "--output-terminator", terminator]
+            .map(String::from)
+            .to_vec();
+        let mut written = Vec::new();
+
+        run_with_args(args, &mut written).unwrap();
+
+        assert_eq!(written, expected);
+    }
 }
@@ -0,0 +1,94 @@
+use clap::Parser;
+use serde::Serialize;
+
+use crate::cli::common::args::{
+    InputConfig,
+    OutputConfig,
+};
+use crate::cli::utils::output_formatter::OutputFormatter;
+use crate::cli::version::{
+    VersionArgs,
+    prepare_git_only_args,
+    process_git_source,
+};
+use crate::error::ZervError;
+use crate::schema::schema_preset_names;
+use crate::utils::constants::{
+    commit_hash_prefix,
+    formats,
+};
+
+#[derive(Parser, Debug, Default)]
+pub struct ChangelogRangeArgs {
+    /// Input configuration (same as version; the source must be git)
+    #[command(flatten)]
+    pub input: InputConfig,
+
+    /// Format of the inferred next version
+    #[arg(
+        long = "output-format",
+        default_value = formats::SEMVER,
+        value_parser = [formats::SEMVER, formats::PEP440],
+        help = "Format of next_version: 'semver' (default) or 'pep440'"
+    )]
+    pub output_format: String,
+}
+
+/// Commit range since the last version tag and the version it leads to
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ChangelogRange {
+    /// Last version tag (start of the range, exclusive)
+    pub from: Option<String>,
+    /// HEAD commit hash (end of the range, inclusive)
+    pub to: Option<String>,
+    /// Version inferred from the conventional commits in the range
+    pub next_version: String,
+}
+
+/// Print `<last-tag>..HEAD` and the next version inferred from conventional commits as JSON
+pub fn run_changelog_range(args: ChangelogRangeArgs) -> Result<String, ZervError> {
+    let range = changelog_range(args)?;
+    serde_json::to_string_pretty(&range)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to serialize changelog range: {e}")))
+}
+
+fn changelog_range(args: ChangelogRangeArgs) -> Result<ChangelogRange, ZervError> {
+    let mut version_args = VersionArgs {
+        input: args.input,
+        output: OutputConfig {
+            output_format: args.output_format,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    version_args.main.schema = Some(schema_preset_names::STANDARD_BASE.to_string());
+    version_args.bumps.conventional_commits = true;
+    let work_dir = prepare_git_only_args("changelog-range", &mut version_args)?;
+
+    // One git scan serves the range and the classified commits behind next_version
+    let draft = process_git_source(&work_dir, &version_args)?;
+    let from = draft.vars.last_tag_version.clone();
+    let hash_prefix = version_args
+        .input
+        .commit_prefix
+        .as_deref()
+        .unwrap_or(commit_hash_prefix::DEFAULT);
+    let to = draft
+        .vars
+        .bumped_commit_hash
+        .as_deref()
+        .map(|hash| hash.strip_prefix(hash_prefix).unwrap_or(hash).to_string());
+    let zerv = draft.to_zerv(&version_args)?;
+    let next_version = OutputFormatter::format_with_scope(
+        &zerv,
+        &version_args.output,
+        version_args.input.scope_prefix.as_deref(),
+        version_args.input.commit_prefix.as_deref(),
+    )?;
+
+    Ok(ChangelogRange {
+        from,
+        to,
+        next_version,
+    })
+}
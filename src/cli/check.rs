@@ -1,25 +1,86 @@
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::path::Path;
 use std::str::FromStr;
 
 use clap::Parser;
 
+use crate::cli::version::{
+    VersionArgs,
+    branch_version_line,
+    compare_with_branch_line,
+    compile_branch_version_regex,
+    detect_git_vcs_data,
+};
 use crate::error::ZervError;
 use crate::utils::constants::{
     SUPPORTED_FORMAT_NAMES,
+    branch_version,
     format_names,
     formats,
+    manifest_sources,
 };
+use crate::utils::manifest::read_manifest_version;
+use crate::vcs::find_vcs_root;
+use crate::vcs::git_utils::GitUtils;
 use crate::version::pep440::PEP440;
 use crate::version::semver::SemVer;
+use crate::version::{
+    VersionObject,
+    Zerv,
+};
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct CheckArgs {
     /// Version string to validate
-    pub version: String,
+    #[arg(required_unless_present = "branch_consistency")]
+    pub version: Option<String>,
 
     /// Format to validate against
     #[arg(short, long)]
     pub format: Option<String>,
+
+    /// Manifest whose version must match the checked version
+    #[arg(
+        long,
+        value_name = "MANIFEST",
+        value_parser = [manifest_sources::CARGO, manifest_sources::NPM, manifest_sources::AUTO],
+        help = "Fail if the 'version' in Cargo.toml ('cargo'), package.json ('npm') or whichever exists ('auto') at the repository root differs from VERSION"
+    )]
+    pub manifest: Option<String>,
+
+    /// Require the version to be a pre-release
+    #[arg(
+        long,
+        visible_alias = "prerelease-only",
+        conflicts_with = "is_release",
+        help = "Fail unless VERSION has an alpha, beta or rc pre-release (1.2.3-rc.1, 1.2.3rc1)"
+    )]
+    pub is_prerelease: bool,
+
+    /// Require the version to be a release
+    #[arg(
+        long,
+        visible_alias = "release-only",
+        help = "Fail if VERSION has an alpha, beta or rc pre-release; post and dev releases count as releases"
+    )]
+    pub is_release: bool,
+
+    /// Compare the version line the branch names with the latest tag
+    #[arg(
+        long,
+        help = "Fail if the current branch names a version line (e.g. release/1.4) that the latest version tag is ahead of (e.g. v2.0.0), which usually means the branch was cut from the wrong commit. VERSION is optional with this flag"
+    )]
+    pub branch_consistency: bool,
+
+    /// Regex reading the version line from the branch name
+    #[arg(
+        long,
+        value_name = "RE",
+        requires = "branch_consistency",
+        help = "Regex with named groups major, minor and/or patch matched against the branch name for --branch-consistency (default: ^release/v?(?P<major>\\d+)(?:\\.(?P<minor>\\d+))?(?:\\.(?P<patch>\\d+))?)"
+    )]
+    pub branch_version_regex: Option<String>,
 }
 
 fn format_validation<T: Display>(original: &str, parsed: &T, format_name: &str) -> String {
@@ -31,68 +92,71 @@ fn format_validation<T: Display>(original: &str, parsed: &T, format_name: &str)
 }
 
 pub fn run_check_command(args: CheckArgs) -> Result<String, ZervError> {
+    let mut sections = Vec::new();
+    if let Some(ref version) = args.version {
+        sections.push(check_version(&args, version)?);
+    }
+    if args.branch_consistency {
+        let pattern = args
+            .branch_version_regex
+            .as_deref()
+            .unwrap_or(branch_version::DEFAULT_REGEX);
+        sections.push(check_branch_consistency(
+            &std::env::current_dir()?,
+            pattern,
+        )?);
+    }
+    Ok(sections.join("\n"))
+}
+
+/// Validate VERSION and run the checks that apply to it
+fn check_version(args: &CheckArgs, version: &str) -> Result<String, ZervError> {
     let mut output = String::new();
 
     match args.format.as_deref() {
         Some(formats::PEP440) => {
-            let parsed = PEP440::from_str(&args.version).map_err(|_| {
+            let parsed = PEP440::from_str(version).map_err(|_| {
                 ZervError::InvalidVersion(format!(
                     "{} - Invalid {} format",
-                    args.version,
+                    version,
                     format_names::PEP440
                 ))
             })?;
-            output.push_str(&format!("Version: {}\n", args.version));
-            output.push_str(&format_validation(
-                &args.version,
-                &parsed,
-                format_names::PEP440,
-            ));
+            output.push_str(&format!("Version: {}\n", version));
+            output.push_str(&format_validation(version, &parsed, format_names::PEP440));
         }
         Some(formats::SEMVER) => {
-            let parsed = SemVer::from_str(&args.version).map_err(|_| {
+            let parsed = SemVer::from_str(version).map_err(|_| {
                 ZervError::InvalidVersion(format!(
                     "{} - Invalid {} format",
-                    args.version,
+                    version,
                     format_names::SEMVER
                 ))
             })?;
-            output.push_str(&format!("Version: {}\n", args.version));
-            output.push_str(&format_validation(
-                &args.version,
-                &parsed,
-                format_names::SEMVER,
-            ));
+            output.push_str(&format!("Version: {}\n", version));
+            output.push_str(&format_validation(version, &parsed, format_names::SEMVER));
         }
         None => {
             // Auto-detect format
-            let pep440_result = PEP440::from_str(&args.version);
-            let semver_result = SemVer::from_str(&args.version);
+            let pep440_result = PEP440::from_str(version);
+            let semver_result = SemVer::from_str(version);
 
             if pep440_result.is_err() && semver_result.is_err() {
                 return Err(ZervError::InvalidVersion(format!(
                     "{} - Invalid for all supported formats ({})",
-                    args.version,
+                    version,
                     SUPPORTED_FORMAT_NAMES.join(", ")
                 )));
             }
 
-            output.push_str(&format!("Version: {}\n", args.version));
+            output.push_str(&format!("Version: {}\n", version));
 
             if let Ok(ref parsed) = pep440_result {
-                output.push_str(&format_validation(
-                    &args.version,
-                    parsed,
-                    format_names::PEP440,
-                ));
+                output.push_str(&format_validation(version, parsed, format_names::PEP440));
                 output.push('\n');
             }
             if let Ok(ref parsed) = semver_result {
-                output.push_str(&format_validation(
-                    &args.version,
-                    parsed,
-                    format_names::SEMVER,
-                ));
+                output.push_str(&format_validation(version, parsed, format_names::SEMVER));
                 output.push('\n');
             }
         }
@@ -106,9 +170,137 @@ pub fn run_check_command(args: CheckArgs) -> Result<String, ZervError> {
 
     // Remove trailing newline if present
     output = output.trim_end().to_string();
+
+    if args.is_prerelease || args.is_release {
+        output.push('\n');
+        output.push_str(&check_prerelease(
+            version,
+            args.format.as_deref().unwrap_or(formats::AUTO),
+            args.is_prerelease,
+        )?);
+    }
+
+    if let Some(ref manifest) = args.manifest {
+        let manifest_dir =
+            find_vcs_root(&std::env::current_dir()?).or_else(|_| std::env::current_dir())?;
+        output.push('\n');
+        output.push_str(&check_manifest_version(
+            &manifest_dir,
+            manifest,
+            version,
+            args.format.as_deref().unwrap_or(formats::AUTO),
+        )?);
+    }
+
     Ok(output)
 }
 
+/// Compare the version line the current branch names with the latest version tag
+fn check_branch_consistency(work_dir: &Path, pattern: &str) -> Result<String, ZervError> {
+    let regex = compile_branch_version_regex(pattern)?;
+    let version_args = VersionArgs::default();
+    let (_, vcs_data) = detect_git_vcs_data(work_dir, &version_args)?;
+
+    let Some(branch) = vcs_data.current_branch else {
+        return Ok("✓ No branch checked out, nothing to compare".to_string());
+    };
+    let Some(line) = branch_version_line(&regex, &branch)? else {
+        return Ok(format!("✓ Branch {branch} does not name a version line"));
+    };
+    let Some(tag) = vcs_data.tag_version else {
+        return Ok(format!("✓ No version tag yet for branch {branch}"));
+    };
+    let tag_zerv = Zerv::from(VersionObject::parse_with_prefix(
+        &tag,
+        formats::AUTO,
+        &version_args.input.tag_prefix(),
+    )?);
+    compare_branch_line(&branch, line, &tag, &tag_zerv)
+}
+
+/// A tag on the branch's line or an older one (the line is not released yet) is consistent;
+/// a tag ahead of the line is not
+fn compare_branch_line(
+    branch: &str,
+    line: [Option<u64>; 3],
+    tag: &str,
+    tag_zerv: &Zerv,
+) -> Result<String, ZervError> {
+    let tag_line = [
+        tag_zerv.vars.major,
+        tag_zerv.vars.minor,
+        tag_zerv.vars.patch,
+    ];
+    let ordering = compare_with_branch_line(&line, tag_line);
+    let line_name = line
+        .iter()
+        .flatten()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".");
+
+    match ordering {
+        Ordering::Equal => Ok(format!(
+            "✓ Tag {tag} is on the {line_name} line of branch {branch}"
+        )),
+        Ordering::Less => Ok(format!(
+            "✓ Branch {branch} starts the {line_name} line after tag {tag}"
+        )),
+        Ordering::Greater => Err(ZervError::InvalidVersion(format!(
+            "Tag {tag} is ahead of the {line_name} line that branch {branch} names; \
+             was the branch cut from the right commit?"
+        ))),
+    }
+}
+
+/// Check whether `version` has a pre-release, failing unless it matches `expect_prerelease`
+fn check_prerelease(
+    version: &str,
+    format: &str,
+    expect_prerelease: bool,
+) -> Result<String, ZervError> {
+    let zerv = Zerv::from(VersionObject::parse_with_format(version, format)?);
+    match (zerv.vars.pre_release.is_some(), expect_prerelease) {
+        (true, true) => Ok("✓ Is a pre-release".to_string()),
+        (false, false) => Ok("✓ Is a release".to_string()),
+        (true, false) => Err(ZervError::InvalidVersion(format!(
+            "{version} is a pre-release"
+        ))),
+        (false, true) => Err(ZervError::InvalidVersion(format!(
+            "{version} is not a pre-release"
+        ))),
+    }
+}
+
+/// Compare the manifest version against `version`, failing on drift
+fn check_manifest_version(
+    manifest_dir: &Path,
+    manifest: &str,
+    version: &str,
+    format: &str,
+) -> Result<String, ZervError> {
+    let manifest_version = read_manifest_version(manifest_dir, manifest)?;
+    if versions_match(&manifest_version, version, format) {
+        Ok(format!("✓ Manifest version matches: {manifest_version}"))
+    } else {
+        Err(ZervError::InvalidVersion(format!(
+            "Manifest version {manifest_version} does not match {version}"
+        )))
+    }
+}
+
+fn versions_match(manifest_version: &str, version: &str, format: &str) -> bool {
+    match (
+        VersionObject::parse_with_format(manifest_version, format),
+        VersionObject::parse_with_format(version, format),
+    ) {
+        (Ok(a), Ok(b)) => GitUtils::compare_version_objects(&a, &b)
+            .map(|ordering| ordering == Ordering::Equal)
+            .unwrap_or(false),
+        _ => manifest_version == version,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -119,7 +311,7 @@ mod tests {
     fn test_check_args_defaults() {
         use clap::Parser;
         let args = CheckArgs::try_parse_from(["zerv", "1.2.3"]).unwrap();
-        assert_eq!(args.version, "1.2.3");
+        assert_eq!(args.version.as_deref(), Some("1.2.3"));
         assert!(args.format.is_none());
     }
 
@@ -129,8 +321,9 @@ mod tests {
     #[case("1.2.3", None)]
     fn test_run_check_command_success(#[case] version: &str, #[case] format: Option<&str>) {
         let args = CheckArgs {
-            version: version.to_string(),
+            version: Some(version.to_string()),
             format: format.map(|s| s.to_string()),
+            ..Default::default()
         };
         let result = run_check_command(args);
         assert!(result.is_ok());
@@ -139,8 +332,9 @@ mod tests {
     #[test]
     fn test_run_check_command_invalid_version() {
         let args = CheckArgs {
-            version: "invalid".to_string(),
+            version: Some("invalid".to_string()),
             format: None,
+            ..Default::default()
         };
         let result = run_check_command(args);
         assert!(matches!(result, Err(ZervError::InvalidVersion(_))));
@@ -149,10 +343,128 @@ mod tests {
     #[test]
     fn test_run_check_command_unknown_format_error_type() {
         let args = CheckArgs {
-            version: "1.2.3".to_string(),
+            version: Some("1.2.3".to_string()),
             format: Some("unknown".to_string()),
+            ..Default::default()
         };
         let result = run_check_command(args);
         assert!(matches!(result, Err(ZervError::UnknownFormat(_))));
     }
+
+    #[rstest]
+    #[case::prerelease_is_prerelease("1.2.3-rc.1", true, true)]
+    #[case::release_is_prerelease("1.2.3", true, false)]
+    #[case::prerelease_is_release("1.2.3-rc.1", false, false)]
+    #[case::release_is_release("1.2.3", false, true)]
+    #[case::pep440_prerelease("1.2.3a1", true, true)]
+    #[case::pep440_post_is_release("1.2.3.post1", false, true)]
+    fn test_run_check_command_prerelease(
+        #[case] version: &str,
+        #[case] is_prerelease: bool,
+        #[case] expected_ok: bool,
+    ) {
+        let args = CheckArgs {
+            version: Some(version.to_string()),
+            is_prerelease,
+            is_release: !is_prerelease,
+            ..Default::default()
+        };
+        let result = run_check_command(args);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::InvalidVersion(_))));
+        }
+    }
+
+    mod manifest {
+        use super::*;
+        use crate::test_utils::TestDir;
+
+        fn cargo_dir(version: &str) -> TestDir {
+            let dir = TestDir::new().expect("should create test dir");
+            dir.create_file(
+                "Cargo.toml",
+                &format!("[package]\nname = \"demo\"\nversion = \"{version}\"\n"),
+            )
+            .expect("should write manifest");
+            dir
+        }
+
+        #[rstest]
+        #[case::exact("1.2.3", "1.2.3", formats::AUTO)]
+        #[case::v_prefixed("1.2.3", "v1.2.3", formats::AUTO)]
+        #[case::pep440_normalized("1.0.0-alpha.1", "1.0.0a1", formats::PEP440)]
+        fn test_check_manifest_version_matches(
+            #[case] manifest_version: &str,
+            #[case] version: &str,
+            #[case] format: &str,
+        ) {
+            let dir = cargo_dir(manifest_version);
+            let output =
+                check_manifest_version(dir.path(), manifest_sources::CARGO, version, format)
+                    .unwrap();
+            assert!(output.contains(manifest_version));
+        }
+
+        #[test]
+        fn test_check_manifest_version_drift() {
+            let dir = cargo_dir("1.2.2");
+            let error =
+                check_manifest_version(dir.path(), manifest_sources::CARGO, "1.2.3", formats::AUTO)
+                    .unwrap_err();
+            assert_eq!(
+                error,
+                ZervError::InvalidVersion(
+                    "Manifest version 1.2.2 does not match 1.2.3".to_string()
+                )
+            );
+        }
+    }
+
+    mod branch_consistency {
+        use super::*;
+
+        fn tag_zerv(tag: &str) -> Zerv {
+            Zerv::from(VersionObject::parse_with_format(tag, formats::AUTO).unwrap())
+        }
+
+        #[rstest]
+        #[case::same_line([Some(1), Some(4), None], "1.4.2", "is on the 1.4 line")]
+        #[case::major_only([Some(2), None, None], "2.7.0", "is on the 2 line")]
+        #[case::not_released_yet([Some(1), Some(4), None], "1.3.2", "starts the 1.4 line")]
+        fn test_compare_branch_line_consistent(
+            #[case] line: [Option<u64>; 3],
+            #[case] tag: &str,
+            #[case] expected: &str,
+        ) {
+            let output = compare_branch_line("release/x", line, tag, &tag_zerv(tag)).unwrap();
+            assert!(output.contains(expected), "{output}");
+        }
+
+        #[rstest]
+        #[case::newer_major([Some(1), Some(4), None], "2.0.0")]
+        #[case::newer_minor([Some(1), Some(4), None], "1.5.0")]
+        #[case::newer_patch([Some(1), Some(4), Some(1)], "1.4.3")]
+        fn test_compare_branch_line_inconsistent(
+            #[case] line: [Option<u64>; 3],
+            #[case] tag: &str,
+        ) {
+            let error = compare_branch_line("release/1.4", line, tag, &tag_zerv(tag)).unwrap_err();
+            assert!(matches!(error, ZervError::InvalidVersion(ref message)
+                if message.contains(&format!("Tag {tag} is ahead of"))));
+        }
+
+        #[test]
+        fn test_check_args_version_optional_with_branch_consistency() {
+            use clap::Parser;
+            let args = CheckArgs::try_parse_from(["zerv", "--branch-consistency"]).unwrap();
+            assert!(args.version.is_none());
+            assert!(CheckArgs::try_parse_from(["zerv"]).is_err());
+            assert!(
+                CheckArgs::try_parse_from(["zerv", "1.0.0", "--branch-version-regex", "x"])
+                    .is_err()
+            );
+        }
+    }
 }
@@ -3,9 +3,16 @@ use clap::Parser;
 use rstest::rstest;
 
 use crate::utils::constants::{
+    abbrev_modes,
+    ci_providers,
+    equal_tag_preferences,
     formats,
+    manifest_sources,
     sources,
+    tag_scope,
+    tags_reachable_modes,
 };
+use crate::version::TagPrefix;
 
 /// Reusable input configuration for version data
 #[derive(Parser, Debug, Clone)]
@@ -15,7 +22,7 @@ pub struct InputConfig {
     // ============================================================================
     /// Input source for version data
     #[arg(short = 's', long = "source", value_parser = [sources::GIT, sources::STDIN, sources::NONE],
-          help = "Input source: 'git' (extract from repository), 'stdin' (read Zerv RON format), or 'none' (no source, use overrides only)")]
+          help = "Input source: 'git' (extract from repository), 'stdin' (read Zerv RON format), or 'none' (no source, use overrides only; requires --tag-version, --major/--minor/--patch or --core)")]
     pub source: Option<String>,
 
     /// Input format for version string parsing
@@ -26,9 +33,196 @@ pub struct InputConfig {
     /// Working directory (default: current directory)
     #[arg(short = 'C', long = "directory", value_name = "DIR")]
     pub directory: Option<String>,
+
+    /// Repository root used as-is, without searching parent directories
+    #[arg(
+        long = "repo",
+        value_name = "PATH",
+        help = "Version the git repository rooted at PATH, without searching parent directories for .git. Use it for repositories vendored inside another one, where the upward search could pick the parent (requires --source git)"
+    )]
+    pub repo: Option<String>,
+
+    /// Restrict version derivation to commits touching this path (monorepo scoping)
+    #[arg(
+        long = "path-filter",
+        value_name = "DIR",
+        help = "Only count commits and tags whose history touches DIR (relative to the working directory)"
+    )]
+    pub path_filter: Option<String>,
+
+    /// Literal prefix stripped from tags before parsing
+    #[arg(
+        long = "input-prefix",
+        value_name = "PREFIX",
+        help = "Strip PREFIX from tags before parsing; tags without it are ignored (e.g. 'release-')"
+    )]
+    pub input_prefix: Option<String>,
+
+    /// Disable implicit 'v' prefix stripping
+    #[arg(
+        long = "literal-v",
+        help = "Treat a leading 'v' in tags as part of the version instead of stripping it (default: 'v1.2.3' parses as 1.2.3)"
+    )]
+    pub literal_v: bool,
+
+    /// Monorepo scope selecting '<SCOPE>-' tags and re-attached to the output
+    #[arg(
+        long = "scope-prefix",
+        value_name = "SCOPE",
+        help = "Only use tags scoped as '<SCOPE>-<version>' (e.g. 'frontend-v1.2.3') and prefix the rendered version with '<SCOPE>-' in any output format"
+    )]
+    pub scope_prefix: Option<String>,
+
+    /// Manifest that provides the base version when no tag is found
+    #[arg(
+        long = "base-from-manifest",
+        value_name = "MANIFEST",
+        value_parser = [manifest_sources::CARGO, manifest_sources::NPM, manifest_sources::AUTO],
+        help = "Use the 'version' from Cargo.toml ('cargo'), package.json ('npm') or whichever exists ('auto') at the repository root as the base version when no tag is found"
+    )]
+    pub base_from_manifest: Option<String>,
+
+    /// Always take the base version from the manifest, even when a tag is found
+    #[arg(
+        long = "prefer-manifest",
+        help = "Use the --base-from-manifest version even when a version tag is found"
+    )]
+    pub prefer_manifest: bool,
+
+    /// Version of the first release when the repository has no tags yet
+    #[arg(
+        long = "first-tag",
+        value_name = "VERSION",
+        help = "When no version tag exists, treat VERSION as the release being cut: emitted verbatim while HEAD is the root commit (distance 0), and as an 'alpha.<distance>' pre-release of VERSION on later commits"
+    )]
+    pub first_tag: Option<String>,
+
+    /// Error out instead of falling back when no version tag is found
+    #[arg(
+        long = "fail-if-untagged",
+        help = "Fail when no version tag is reachable instead of falling back (--first-tag, --base-from-manifest, 0.0.0 for an empty repository), so release pipelines never build an unintended version. --tag-version satisfies the requirement (requires --source git)"
+    )]
+    pub fail_if_untagged: bool,
+
+    /// Commits to add to the detected distance (e.g. history lost in an import)
+    #[arg(
+        long = "distance-offset",
+        value_name = "N",
+        value_parser = clap::value_parser!(u32),
+        help = "Always add N to the distance detected from git, e.g. to account for commits that predate a history import (SVN, etc.). Unlike --distance, which replaces the distance, this is added to it"
+    )]
+    pub distance_offset: Option<u32>,
+
+    /// How the short commit hash is abbreviated
+    #[arg(
+        long = "abbrev",
+        value_name = "MODE",
+        value_parser = abbrev_modes::VALID_MODES.to_vec(),
+        help = "Short commit hash length: 'fixed' (default, first 7 hex chars) or 'auto' (let git pick the shortest unique abbreviation, like 'git rev-parse --short')"
+    )]
+    pub abbrev: Option<String>,
+
+    /// Forbid any network access
+    #[arg(
+        long = "no-network",
+        help = "Guarantee that zerv never touches the network: any git operation that would contact a remote (fetch, ls-remote, ...) fails up front. zerv only reads local history today, so this is a safety contract for sandboxed builds"
+    )]
+    pub no_network: bool,
+
+    /// Bound on how many tagged commits are searched for a version tag
+    #[arg(
+        long = "max-tags",
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Only consider tags on the N most recently committed tagged commits, to bound tag enumeration on repositories with many tags. An older tag with a higher version outside that window is missed"
+    )]
+    pub max_tags: Option<u32>,
+
+    /// Skip the tag timestamp lookup
+    #[arg(
+        long = "no-tag-timestamp",
+        help = "Don't look up the date of the version tag, saving a git call. last_timestamp is left unset; CalVer and other timestamp patterns keep using the commit date"
+    )]
+    pub no_tag_timestamp: bool,
+
+    /// Which of several tags with the same version on one commit is used
+    #[arg(
+        long = "equal-tag-preference",
+        value_name = "PREFERENCE",
+        value_parser = equal_tag_preferences::VALID_PREFERENCES.to_vec(),
+        help = "Which tag to use when one commit has several tags of the same version (e.g. 'v1.2.3' and '1.2.3'): 'prefixed', 'unprefixed', or the alphabetically 'first' or 'last' (default) tag name"
+    )]
+    pub equal_tag_preference: Option<String>,
+
+    /// Commit to compute the version for instead of HEAD
+    #[arg(
+        long = "rev",
+        value_name = "COMMITISH",
+        help = "Compute the version for COMMITISH (commit hash, tag, branch, 'HEAD~3', ...) instead of HEAD, without checking it out. Distance, tag, hash and timestamp are read relative to it; a revision other than HEAD is never dirty"
+    )]
+    pub rev: Option<String>,
+
+    /// CI system whose environment names the branch of a detached checkout
+    #[arg(
+        long = "branch-from-ci",
+        value_name = "PROVIDER",
+        value_parser = ci_providers::VALID_PROVIDERS.to_vec(),
+        help = "When git reports no branch (detached HEAD), take it from the CI environment: 'github' (GITHUB_HEAD_REF, then GITHUB_REF_NAME), 'gitlab' (CI_MERGE_REQUEST_SOURCE_BRANCH_NAME, then CI_COMMIT_BRANCH) or 'auto' (whichever CI is running)"
+    )]
+    pub branch_from_ci: Option<String>,
+
+    /// Compute dirty state from the index only
+    #[arg(
+        long = "use-index",
+        help = "Compute the dirty state from the index (staged changes, as in 'git diff --cached') and ignore unstaged and untracked changes, e.g. to stamp the about-to-be-committed version in a pre-commit hook"
+    )]
+    pub use_index: bool,
+
+    /// Which tags are candidates for the version tag
+    #[arg(
+        long = "tags-reachable",
+        value_name = "MODE",
+        value_parser = tags_reachable_modes::VALID_MODES.to_vec(),
+        help = "Which tags can be the version tag: 'merged' (default) only considers tags reachable from HEAD, nearest first; 'all' picks the highest version among every tag, including tags on unmerged sibling branches. With 'all', distance counts the commits since the merge base with the tag"
+    )]
+    pub tags_reachable: Option<String>,
+
+    /// Print how long each git operation took
+    #[arg(
+        long = "timings",
+        help = "Print a table of the git subprocesses run (calls and total time per git subcommand) to stderr, to see where time goes on large repositories"
+    )]
+    pub timings: bool,
+
+    /// Prefix put in front of commit hashes
+    #[arg(
+        long = "commit-prefix",
+        value_name = "STR",
+        help = "Prefix put in front of commit hashes in the build context and templates (default: 'g', following git describe; use '' for bare hashes). Requires --source git"
+    )]
+    pub commit_prefix: Option<String>,
+
+    /// Count the tag commit itself in the distance
+    #[arg(
+        long = "distance-inclusive",
+        help = "Count the tag commit itself in the distance, so the tagged commit has distance 1 and the first commit after it 2, instead of 0 and 1 (tag^..HEAD rather than git describe's tag..HEAD). A build on the tag itself is therefore never clean: it gets the build context, e.g. 1.2.3+main.1.g<hash> instead of 1.2.3. Requires --source git"
+    )]
+    pub distance_inclusive: bool,
 }
 
 impl InputConfig {
+    /// Tag prefix handling configured by --input-prefix (or --scope-prefix) and --literal-v
+    pub fn tag_prefix(&self) -> TagPrefix {
+        let input_prefix = match self.scope_prefix {
+            Some(ref scope) => Some(format!("{scope}{}", tag_scope::SEPARATOR)),
+            None => self.input_prefix.clone(),
+        };
+        TagPrefix {
+            input_prefix,
+            literal_v: self.literal_v,
+        }
+    }
+
     /// Apply smart source default: stdin if available, otherwise git
     /// This is called after stdin detection to determine the appropriate default source
     pub fn apply_smart_source_default(&mut self, has_stdin: bool) {
@@ -48,6 +242,28 @@ impl Default for InputConfig {
             source: Some(sources::GIT.to_string()),
             input_format: formats::AUTO.to_string(),
             directory: None,
+            repo: None,
+            path_filter: None,
+            input_prefix: None,
+            literal_v: false,
+            scope_prefix: None,
+            base_from_manifest: None,
+            prefer_manifest: false,
+            first_tag: None,
+            fail_if_untagged: false,
+            distance_offset: None,
+            abbrev: None,
+            no_network: false,
+            max_tags: None,
+            no_tag_timestamp: false,
+            equal_tag_preference: None,
+            rev: None,
+            branch_from_ci: None,
+            use_index: false,
+            tags_reachable: None,
+            timings: false,
+            commit_prefix: None,
+            distance_inclusive: false,
         }
     }
 }
@@ -62,6 +278,7 @@ mod tests {
             source: Some(sources::STDIN.to_string()),
             input_format: formats::SEMVER.to_string(),
             directory: Some("/path/to/repo".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.source, Some(sources::STDIN.to_string()));
         assert_eq!(config.input_format, formats::SEMVER);
@@ -81,6 +298,7 @@ mod tests {
                 source: Some(source_value.to_string()),
                 input_format: formats::AUTO.to_string(),
                 directory: None,
+                ..Default::default()
             };
             assert_eq!(config.source.as_deref(), Some(expected_source));
         }
@@ -99,6 +317,7 @@ mod tests {
                 source: Some(sources::GIT.to_string()),
                 input_format: format_value.to_string(),
                 directory: None,
+                ..Default::default()
             };
             assert_eq!(config.input_format, expected_format);
         }
@@ -110,6 +329,7 @@ mod tests {
             source: Some("stdin".to_string()),
             input_format: "semver".to_string(),
             directory: Some("/test".to_string()),
+            ..Default::default()
         };
         let debug_str = format!("{:?}", config);
         assert!(debug_str.contains("stdin"));
@@ -123,6 +343,7 @@ mod tests {
             source: Some("stdin".to_string()),
             input_format: "semver".to_string(),
             directory: Some("/test".to_string()),
+            ..Default::default()
         };
         let cloned = config.clone();
         assert_eq!(config.source, cloned.source);
@@ -136,6 +357,7 @@ mod tests {
             source: Some(sources::GIT.to_string()),
             input_format: formats::AUTO.to_string(),
             directory: Some("".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.directory, Some("".to_string()));
     }
@@ -147,6 +369,7 @@ mod tests {
             source: Some(sources::GIT.to_string()),
             input_format: formats::SEMVER.to_string(),
             directory: Some(complex_path.to_string()),
+            ..Default::default()
         };
         assert_eq!(config.directory, Some(complex_path.to_string()));
     }
@@ -157,6 +380,7 @@ mod tests {
             source: None,
             input_format: formats::AUTO.to_string(),
             directory: None,
+            ..Default::default()
         };
         assert!(config.source.is_none());
         assert_eq!(config.input_format, formats::AUTO);
@@ -181,8 +405,26 @@ mod tests {
             source: initial_source.map(|s| s.to_string()),
             input_format: formats::AUTO.to_string(),
             directory: None,
+            ..Default::default()
         };
         config.apply_smart_source_default(has_stdin);
         assert_eq!(config.source.as_deref(), Some(expected_source));
     }
+
+    #[rstest]
+    #[case::none(None, None, None)]
+    #[case::input_prefix(Some("release-"), None, Some("release-"))]
+    #[case::scope(None, Some("frontend"), Some("frontend-"))]
+    fn test_tag_prefix(
+        #[case] input_prefix: Option<&str>,
+        #[case] scope_prefix: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        let config = InputConfig {
+            input_prefix: input_prefix.map(str::to_string),
+            scope_prefix: scope_prefix.map(str::to_string),
+            ..Default::default()
+        };
+        assert_eq!(config.tag_prefix().input_prefix.as_deref(), expected);
+    }
 }
@@ -1,7 +1,12 @@
 use clap::Parser;
 
 use crate::cli::utils::template::Template;
-use crate::utils::constants::formats;
+use crate::utils::constants::{
+    formats,
+    output_terminators,
+    prerelease_cases,
+    sbom_formats,
+};
 
 /// Reusable output configuration for version strings
 #[derive(Parser, Debug, Clone)]
@@ -10,8 +15,8 @@ pub struct OutputConfig {
     // OUTPUT OPTIONS
     // ============================================================================
     /// Output format for generated version
-    #[arg(long, default_value = formats::SEMVER, value_parser = formats::SUPPORTED_FORMATS_ARRAY,
-          help = format!("Output format: '{}' (default), '{}', or '{}' (RON format for piping)", formats::SEMVER, formats::PEP440, formats::ZERV))]
+    #[arg(long, default_value = formats::SEMVER, value_parser = formats::OUTPUT_FORMATS_ARRAY,
+          help = format!("Output format: '{}' (default), '{}', '{}' (RON format for piping), '{}' (shell-quoted KEY='value' lines for VERSION, MAJOR, MINOR, PATCH, COMMIT, DISTANCE and DIRTY, to source or append to $GITHUB_ENV), or '{}' (the same fields as lowercase key=value lines, escaped for Java .properties files)", formats::SEMVER, formats::PEP440, formats::ZERV, formats::ENV, formats::PROPERTIES))]
     pub output_format: String,
 
     /// Output template for custom formatting (Tera syntax: {{ variable }})
@@ -21,12 +26,118 @@ pub struct OutputConfig {
     )]
     pub output_template: Option<Template<String>>,
 
+    /// Fail on undefined variables in the output template
+    #[arg(
+        long,
+        requires = "output_template",
+        help = "Fail when --output-template uses an undefined variable (e.g. a typo or an unset pre_release.label) instead of rendering it empty"
+    )]
+    pub template_strict: bool,
+
     /// Prefix to add to output
     #[arg(
         long,
         help = "Prefix to add to version output (e.g., 'v' for 'v1.0.0')"
     )]
     pub output_prefix: Option<String>,
+
+    /// Fail unless the produced version is canonical PEP440
+    #[arg(
+        long,
+        help = "Validate the produced version against the canonical PEP440 grammar and fail on non-conformance"
+    )]
+    pub strict_pep440: bool,
+
+    /// GitHub Actions step output name to receive the version
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Also append NAME=<version> to the file named by the GITHUB_OUTPUT environment variable"
+    )]
+    pub github_output: Option<String>,
+
+    /// Separator used between build metadata (SemVer) or local (PEP440) segments
+    #[arg(
+        long,
+        value_name = "CHAR",
+        help = "Join build metadata / local version segments with CHAR instead of '.' (e.g. '-' for '1.2.3+main-5-gabc1234'). SemVer allows '.' or '-', PEP440 allows '.', '-' or '_' (only '.' with --strict-pep440)"
+    )]
+    pub context_separator: Option<String>,
+
+    /// Casing of the pre-release label in the output
+    #[arg(
+        long,
+        value_name = "CASE",
+        value_parser = prerelease_cases::VALID_CASES.to_vec(),
+        help = "Casing of the pre-release label in semver/pep440 output: 'lower' (default), 'upper' (1.2.3-RC.1) or 'title' (1.2.3-Rc.1). Output only; parsing is case-insensitive"
+    )]
+    pub prerelease_case: Option<String>,
+
+    /// Spelling of the post-release label in the output
+    #[arg(
+        long,
+        value_name = "LABEL",
+        help = "Spell the post-release label as LABEL in semver/pep440 output. PEP440 allows 'post' (default), 'rev' or 'r' (1.2.3.r4); SemVer allows any alphanumeric identifier (1.2.3-build.4)"
+    )]
+    pub post_label: Option<String>,
+
+    /// Spelling of the dev-release label in the output
+    #[arg(
+        long,
+        value_name = "LABEL",
+        help = "Spell the dev-release label as LABEL in semver output (1.2.3-snapshot.5); PEP440 only allows 'dev'"
+    )]
+    pub dev_label: Option<String>,
+
+    /// Drop a zero pre-release number from the output
+    #[arg(
+        long,
+        help = "Render a pre-release number of 0 without the number in semver output (1.0.0-alpha instead of 1.0.0-alpha.0). SemVer orders 1.0.0-alpha before 1.0.0-alpha.0, so only compare versions rendered the same way. PEP440 always spells the number, as it reads a missing one as 0"
+    )]
+    pub omit_zero_prerelease_num: bool,
+
+    /// Terminator written after the version
+    #[arg(
+        long,
+        value_name = "TERMINATOR",
+        default_value = output_terminators::NEWLINE,
+        value_parser = output_terminators::VALID_TERMINATORS.to_vec(),
+        help = "Terminator written after the output: 'newline' (default), 'none' (no trailing byte), or 'null' (NUL byte, for xargs -0)"
+    )]
+    pub output_terminator: String,
+
+    /// Emit the version as an SBOM component fragment
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        value_parser = sbom_formats::VALID_FORMATS.to_vec(),
+        help = "Print a JSON fragment for an SBOM component instead of the bare version: 'spdx' (versionInfo, sourceInfo, builtDate) or 'cyclonedx' (version, pedigree commit uid and timestamp). The version is formatted per --output-format"
+    )]
+    pub sbom: Option<String>,
+
+    /// Custom sanitizer for context values in the output
+    #[arg(
+        long,
+        value_name = "RON",
+        help = "Sanitize context values (branch, hash, ...) in semver build metadata with a custom RON profile instead of the default, e.g. '(separator: \"-\")' keeps case and joins with '-'. Fields: separator (replacement for non-alphanumeric characters, default \".\"; only [0-9A-Za-z-.]), allowed (characters kept as-is, only [0-9A-Za-z-]), lowercase (default false), keep_zeros (default false), max_length. Only for semver, env and properties output: it is rejected with pep440 (local segments are always normalized), zerv and --output-template"
+    )]
+    pub sanitizer_profile: Option<String>,
+
+    /// Prefix for the keys of --output-format env
+    #[arg(
+        long,
+        value_name = "PREFIX",
+        help = "Prefix the keys of --output-format env, e.g. 'APP_' gives APP_VERSION, APP_MAJOR, ..."
+    )]
+    pub env_prefix: Option<String>,
+
+    /// Print a single resolved field instead of the version
+    #[arg(
+        long,
+        value_name = "FIELD",
+        help = "Print only this field of the resolved version instead of a formatted version, e.g. 'major', 'distance', 'commit' (short commit hash), 'tag-version' (the selected tag as written, e.g. 'v1.2.3'), 'bumped_branch' or 'custom.<key>'. Any template variable name is accepted; prints an empty line when the field is unset"
+    )]
+    pub field: Option<String>,
 }
 
 impl Default for OutputConfig {
@@ -34,7 +145,20 @@ impl Default for OutputConfig {
         Self {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
+            template_strict: false,
             output_prefix: None,
+            strict_pep440: false,
+            github_output: None,
+            context_separator: None,
+            prerelease_case: None,
+            post_label: None,
+            dev_label: None,
+            omit_zero_prerelease_num: false,
+            output_terminator: output_terminators::NEWLINE.to_string(),
+            sbom: None,
+            sanitizer_profile: None,
+            env_prefix: None,
+            field: None,
         }
     }
 }
@@ -45,7 +169,20 @@ impl OutputConfig {
         Self {
             output_format: "zerv".to_string(),
             output_template: None,
+            template_strict: false,
             output_prefix: None,
+            strict_pep440: false,
+            github_output: None,
+            context_separator: None,
+            prerelease_case: None,
+            post_label: None,
+            dev_label: None,
+            omit_zero_prerelease_num: false,
+            output_terminator: output_terminators::NEWLINE.to_string(),
+            sbom: None,
+            sanitizer_profile: None,
+            env_prefix: None,
+            field: None,
         }
     }
 }
@@ -76,6 +213,7 @@ mod tests {
             output_format: formats::PEP440.to_string(),
             output_template: Some(Template::new("v{{major}}.{{minor}}".to_string())),
             output_prefix: Some("release-".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.output_format, formats::PEP440);
         assert!(config.output_template.is_some());
@@ -95,6 +233,7 @@ mod tests {
                 output_format: format_value.to_string(),
                 output_template: None,
                 output_prefix: None,
+                ..Default::default()
             };
             assert_eq!(config.output_format, expected_format);
         }
@@ -107,6 +246,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new(template_str.to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         assert!(config.output_template.is_some());
         if let Some(template) = &config.output_template {
@@ -120,6 +260,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
             output_prefix: Some("v".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.output_prefix, Some("v".to_string()));
     }
@@ -131,6 +272,7 @@ mod tests {
             output_format: formats::ZERV.to_string(),
             output_template: Some(Template::new(template_str.to_string())),
             output_prefix: Some("build-".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.output_format, formats::ZERV);
         assert!(config.output_template.is_some());
@@ -143,6 +285,7 @@ mod tests {
             output_format: "pep440".to_string(),
             output_template: Some(Template::new("v{{major}}".to_string())),
             output_prefix: Some("release-".to_string()),
+            ..Default::default()
         };
         let debug_str = format!("{:?}", config);
         assert!(debug_str.contains("pep440"));
@@ -156,6 +299,7 @@ mod tests {
             output_format: "zerv".to_string(),
             output_template: Some(Template::new("{{version}}".to_string())),
             output_prefix: Some("build-".to_string()),
+            ..Default::default()
         };
         let cloned = config.clone();
         assert_eq!(config.output_format, cloned.output_format);
@@ -169,6 +313,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
             output_prefix: Some("".to_string()),
+            ..Default::default()
         };
         assert_eq!(config.output_prefix, Some("".to_string()));
     }
@@ -180,6 +325,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new(template_str.to_string())),
             output_prefix: None,
+            ..Default::default()
         };
 
         if let Some(template) = &config.output_template {
@@ -196,6 +342,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new(complex_template.to_string())),
             output_prefix: None,
+            ..Default::default()
         };
 
         if let Some(template) = &config.output_template {
@@ -2,17 +2,86 @@ use super::{
     InputConfig,
     OutputConfig,
 };
+use crate::cli::utils::output_formatter::OutputFormatter;
 use crate::error::ZervError;
-use crate::utils::constants::formats;
+use crate::utils::constants::{
+    context_separators,
+    formats,
+    post_dev_labels,
+    sources,
+};
+use crate::utils::sanitize::SanitizerProfile;
 
 /// Shared validation methods for input and output configurations
 pub struct Validation;
 
 impl Validation {
     /// Validate input configuration
-    pub fn validate_input(_input: &InputConfig) -> Result<(), ZervError> {
-        // Input validation is handled by clap's value parser for source and input_format
-        // No additional validation needed for input config
+    pub fn validate_input(input: &InputConfig) -> Result<(), ZervError> {
+        // Source and input_format values are handled by clap's value parser
+
+        // Options that read git history, tags or the repository itself
+        if input.source.as_deref() != Some(sources::GIT) {
+            let git_only = [
+                (input.repo.is_some(), "--repo"),
+                (input.fail_if_untagged, "--fail-if-untagged"),
+                (input.commit_prefix.is_some(), "--commit-prefix"),
+                (input.distance_inclusive, "--distance-inclusive"),
+                (input.path_filter.is_some(), "--path-filter"),
+                (input.base_from_manifest.is_some(), "--base-from-manifest"),
+                (input.first_tag.is_some(), "--first-tag"),
+                (input.distance_offset.is_some(), "--distance-offset"),
+                (input.abbrev.is_some(), "--abbrev"),
+                (
+                    input.equal_tag_preference.is_some(),
+                    "--equal-tag-preference",
+                ),
+                (input.rev.is_some(), "--rev"),
+                (input.branch_from_ci.is_some(), "--branch-from-ci"),
+                (input.use_index, "--use-index"),
+                (input.tags_reachable.is_some(), "--tags-reachable"),
+                (input.timings, "--timings"),
+            ];
+            if let Some((_, flag)) = git_only.iter().find(|(is_set, _)| *is_set) {
+                return Err(ZervError::ConflictingOptions(format!(
+                    "{flag} requires --source git"
+                )));
+            }
+        }
+
+        if input.prefer_manifest && input.base_from_manifest.is_none() {
+            return Err(ZervError::ConflictingOptions(
+                "--prefer-manifest requires --base-from-manifest".to_string(),
+            ));
+        }
+
+        // A scope is itself the tag prefix
+        if input.scope_prefix.is_some() && input.input_prefix.is_some() {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use --scope-prefix with --input-prefix; the scope already selects '<SCOPE>-' tags".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Strict PEP440 only accepts the canonical '.' between local segments, so a
+    /// --context-separator that PEP440 otherwise tolerates cannot be combined with it
+    pub fn validate_strict_pep440_context_separator(
+        output: &OutputConfig,
+    ) -> Result<(), ZervError> {
+        if let Some(ref separator) = output.context_separator
+            && output.strict_pep440
+            && output.output_format == formats::PEP440
+            && separator != context_separators::DEFAULT
+        {
+            return Err(ZervError::ConflictingOptions(format!(
+                "--context-separator '{separator}' produces non-canonical PEP440 local versions, \
+                 which --strict-pep440 rejects. Use '{}' or drop --strict-pep440 \
+                 (--no-strict-pep440 under --strict)",
+                context_separators::DEFAULT
+            )));
+        }
         Ok(())
     }
 
@@ -40,6 +109,201 @@ impl Validation {
             }
         }
 
+        // Strict PEP440 checks only make sense for PEP440-shaped output
+        if output.strict_pep440
+            && output.output_template.is_none()
+            && output.output_format != formats::PEP440
+        {
+            return Err(ZervError::ConflictingOptions(format!(
+                "--strict-pep440 requires --output-format {} or --output-template",
+                formats::PEP440
+            )));
+        }
+
+        // A single field replaces the formatted version
+        if let Some(ref field) = output.field {
+            if output.output_template.is_some() || output.sbom.is_some() {
+                return Err(ZervError::ConflictingOptions(
+                    "Cannot use --field with --output-template or --sbom".to_string(),
+                ));
+            }
+            OutputFormatter::check_field(field)?;
+        }
+
+        // Env and properties output list fields under fixed keys, so they cannot be wrapped in a fragment
+        if matches!(
+            output.output_format.as_str(),
+            formats::ENV | formats::PROPERTIES
+        ) && output.sbom.is_some()
+        {
+            return Err(ZervError::ConflictingOptions(format!(
+                "--output-format {} cannot be used with --sbom",
+                output.output_format
+            )));
+        }
+        if let Some(ref prefix) = output.env_prefix {
+            if output.output_format != formats::ENV {
+                return Err(ZervError::ConflictingOptions(
+                    "--env-prefix requires --output-format env".to_string(),
+                ));
+            }
+            if !prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                || prefix.starts_with(|c: char| c.is_ascii_digit())
+            {
+                return Err(ZervError::InvalidArgument(format!(
+                    "--env-prefix '{prefix}' must be letters, digits and '_', not starting with a digit"
+                )));
+            }
+        }
+
+        if let Some(ref profile) = output.sanitizer_profile {
+            // Only semver build metadata is sanitized with the profile
+            if output.output_template.is_some()
+                || matches!(
+                    output.output_format.as_str(),
+                    formats::PEP440 | formats::ZERV
+                )
+            {
+                return Err(ZervError::ConflictingOptions(format!(
+                    "--sanitizer-profile only applies to semver build metadata, not to {}",
+                    if output.output_template.is_some() {
+                        "--output-template".to_string()
+                    } else {
+                        format!("--output-format {}", output.output_format)
+                    }
+                )));
+            }
+            SanitizerProfile::from_ron(profile)?.validate_for_semver()?;
+        }
+
+        // SBOM fragments carry a semver/pep440 version string
+        if output.sbom.is_some() {
+            if output.output_template.is_some() {
+                return Err(ZervError::ConflictingOptions(
+                    "Cannot use --sbom with --output-template".to_string(),
+                ));
+            }
+            if output.output_format == formats::ZERV {
+                return Err(ZervError::ConflictingOptions(
+                    "--sbom cannot be used with --output-format zerv".to_string(),
+                ));
+            }
+        }
+
+        // Label casing is applied to the rendered semver/pep440 string only
+        if output.prerelease_case.is_some() {
+            if output.output_template.is_some() {
+                return Err(ZervError::ConflictingOptions(
+                    "Cannot use --prerelease-case with --output-template. \
+                     Case the label in your template instead"
+                        .to_string(),
+                ));
+            }
+            if output.output_format == formats::ZERV {
+                return Err(ZervError::ConflictingOptions(
+                    "--prerelease-case cannot be used with --output-format zerv".to_string(),
+                ));
+            }
+        }
+
+        // Label spellings must keep the output within the format's spec
+        for (flag, label, pep440_allowed) in [
+            (
+                "--post-label",
+                &output.post_label,
+                post_dev_labels::PEP440_POST,
+            ),
+            (
+                "--dev-label",
+                &output.dev_label,
+                post_dev_labels::PEP440_DEV,
+            ),
+        ] {
+            let Some(label) = label else {
+                continue;
+            };
+            if output.output_template.is_some() {
+                return Err(ZervError::ConflictingOptions(format!(
+                    "Cannot use {flag} with --output-template. Spell the label in your template instead"
+                )));
+            }
+            let (valid, allowed) = match output.output_format.as_str() {
+                formats::PEP440 => (
+                    pep440_allowed.contains(&label.as_str()),
+                    pep440_allowed
+                        .iter()
+                        .map(|label| format!("'{label}'"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                formats::SEMVER => (
+                    !label.is_empty()
+                        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                        && !label.chars().all(|c| c.is_ascii_digit()),
+                    "non-numeric identifiers of [0-9A-Za-z-]".to_string(),
+                ),
+                format => {
+                    return Err(ZervError::ConflictingOptions(format!(
+                        "{flag} cannot be used with --output-format {format}"
+                    )));
+                }
+            };
+            if !valid {
+                return Err(ZervError::InvalidArgument(format!(
+                    "{flag} '{label}' is not valid for {} output. Allowed: {allowed}",
+                    output.output_format
+                )));
+            }
+        }
+
+        // Only semver output can leave out the number (PEP440 reads a missing one as 0)
+        if output.omit_zero_prerelease_num
+            && (output.output_template.is_some()
+                || !matches!(
+                    output.output_format.as_str(),
+                    formats::SEMVER | formats::ENV | formats::PROPERTIES
+                ))
+        {
+            return Err(ZervError::ConflictingOptions(
+                "--omit-zero-prerelease-num only applies to --output-format semver, env or properties"
+                    .to_string(),
+            ));
+        }
+
+        // Each format only accepts some separators in its build/local part
+        if let Some(ref separator) = output.context_separator {
+            if output.output_template.is_some() {
+                return Err(ZervError::ConflictingOptions(
+                    "Cannot use --context-separator with --output-template. \
+                     Join the context in your template instead"
+                        .to_string(),
+                ));
+            }
+            let allowed = match output.output_format.as_str() {
+                formats::SEMVER => context_separators::SEMVER,
+                formats::PEP440 => context_separators::PEP440,
+                format => {
+                    return Err(ZervError::ConflictingOptions(format!(
+                        "--context-separator cannot be used with --output-format {format}"
+                    )));
+                }
+            };
+            if !allowed.contains(&separator.as_str()) {
+                return Err(ZervError::InvalidArgument(format!(
+                    "--context-separator '{separator}' is not valid for {} output. Allowed: {}",
+                    output.output_format,
+                    allowed
+                        .iter()
+                        .map(|sep| format!("'{sep}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+        }
+        Self::validate_strict_pep440_context_separator(output)?;
+
         Ok(())
     }
 
@@ -53,11 +317,17 @@ impl Validation {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
     use crate::cli::utils::template::Template;
     use crate::utils::constants::{
+        abbrev_modes,
+        ci_providers,
+        equal_tag_preferences,
         formats,
         sources,
+        tags_reachable_modes,
     };
 
     fn create_valid_input() -> InputConfig {
@@ -65,6 +335,7 @@ mod tests {
             source: Some(sources::GIT.to_string()),
             input_format: formats::AUTO.to_string(),
             directory: Some("/test".to_string()),
+            ..Default::default()
         }
     }
 
@@ -73,6 +344,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
             output_prefix: None,
+            ..Default::default()
         }
     }
 
@@ -91,6 +363,7 @@ mod tests {
                 source: Some(source.to_string()),
                 input_format: formats::AUTO.to_string(),
                 directory: None,
+                ..Default::default()
             };
             assert!(Validation::validate_input(&input).is_ok());
         }
@@ -105,11 +378,204 @@ mod tests {
                 source: Some(sources::GIT.to_string()),
                 input_format: format.to_string(),
                 directory: None,
+                ..Default::default()
             };
             assert!(Validation::validate_input(&input).is_ok());
         }
     }
 
+    #[rstest]
+    #[case::repo("--repo", |input: &mut InputConfig| input.repo = Some("vendor/lib".to_string()))]
+    #[case::fail_if_untagged("--fail-if-untagged", |input: &mut InputConfig| input.fail_if_untagged = true)]
+    #[case::commit_prefix("--commit-prefix", |input: &mut InputConfig| input.commit_prefix = Some("x".to_string()))]
+    #[case::distance_inclusive("--distance-inclusive", |input: &mut InputConfig| input.distance_inclusive = true)]
+    #[case::path_filter("--path-filter", |input: &mut InputConfig| input.path_filter = Some("packages/core".to_string()))]
+    #[case::base_from_manifest("--base-from-manifest", |input: &mut InputConfig| input.base_from_manifest = Some("cargo".to_string()))]
+    #[case::first_tag("--first-tag", |input: &mut InputConfig| input.first_tag = Some("1.0.0".to_string()))]
+    #[case::distance_offset("--distance-offset", |input: &mut InputConfig| input.distance_offset = Some(10))]
+    #[case::abbrev("--abbrev", |input: &mut InputConfig| input.abbrev = Some(abbrev_modes::AUTO.to_string()))]
+    #[case::equal_tag_preference("--equal-tag-preference", |input: &mut InputConfig| input.equal_tag_preference = Some(equal_tag_preferences::PREFIXED.to_string()))]
+    #[case::rev("--rev", |input: &mut InputConfig| input.rev = Some("HEAD".to_string()))]
+    #[case::branch_from_ci("--branch-from-ci", |input: &mut InputConfig| input.branch_from_ci = Some(ci_providers::GITHUB.to_string()))]
+    #[case::use_index("--use-index", |input: &mut InputConfig| input.use_index = true)]
+    #[case::tags_reachable("--tags-reachable", |input: &mut InputConfig| input.tags_reachable = Some(tags_reachable_modes::ALL.to_string()))]
+    #[case::timings("--timings", |input: &mut InputConfig| input.timings = true)]
+    fn test_validate_input_requires_git_source(
+        #[case] flag: &str,
+        #[case] set: fn(&mut InputConfig),
+        #[values(sources::GIT, sources::STDIN, sources::NONE)] source: &str,
+    ) {
+        let mut input = InputConfig {
+            source: Some(source.to_string()),
+            ..Default::default()
+        };
+        set(&mut input);
+        let result = Validation::validate_input(&input);
+        match result {
+            Ok(()) => assert_eq!(source, sources::GIT),
+            Err(ZervError::ConflictingOptions(message)) => {
+                assert_ne!(source, sources::GIT);
+                assert_eq!(message, format!("{flag} requires --source git"));
+            }
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[rstest]
+    #[case(sources::GIT, Some("cargo"), false, true)]
+    #[case(sources::GIT, Some("auto"), true, true)]
+    #[case(sources::GIT, None, true, false)]
+    #[case(sources::STDIN, Some("npm"), false, false)]
+    fn test_validate_input_base_from_manifest(
+        #[case] source: &str,
+        #[case] manifest: Option<&str>,
+        #[case] prefer_manifest: bool,
+        #[case] expected_ok: bool,
+    ) {
+        let input = InputConfig {
+            source: Some(source.to_string()),
+            base_from_manifest: manifest.map(str::to_string),
+            prefer_manifest,
+            ..Default::default()
+        };
+        let result = Validation::validate_input(&input);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::ConflictingOptions(_))));
+        }
+    }
+
+    #[test]
+    fn test_validate_input_scope_prefix_conflicts_with_input_prefix() {
+        let input = InputConfig {
+            input_prefix: Some("release-".to_string()),
+            scope_prefix: Some("frontend".to_string()),
+            ..Default::default()
+        };
+        let result = Validation::validate_input(&input);
+        assert!(matches!(result, Err(ZervError::ConflictingOptions(_))));
+    }
+
+    #[rstest]
+    #[case(formats::PEP440, None, true)]
+    #[case(formats::SEMVER, Some("{{pep440}}"), true)]
+    #[case(formats::SEMVER, None, false)]
+    #[case(formats::ZERV, None, false)]
+    fn test_validate_output_strict_pep440(
+        #[case] format: &str,
+        #[case] template: Option<&str>,
+        #[case] expected_ok: bool,
+    ) {
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            output_template: template.map(|t| Template::new(t.to_string())),
+            strict_pep440: true,
+            ..Default::default()
+        };
+        let result = Validation::validate_output(&output);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::ConflictingOptions(_))));
+        }
+    }
+
+    #[rstest]
+    #[case::semver_dot(formats::SEMVER, ".", true)]
+    #[case::semver_dash(formats::SEMVER, "-", true)]
+    #[case::semver_underscore(formats::SEMVER, "_", false)]
+    #[case::pep440_dash(formats::PEP440, "-", true)]
+    #[case::pep440_underscore(formats::PEP440, "_", true)]
+    #[case::pep440_plus(formats::PEP440, "+", false)]
+    fn test_validate_output_context_separator(
+        #[case] format: &str,
+        #[case] separator: &str,
+        #[case] expected_ok: bool,
+    ) {
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            context_separator: Some(separator.to_string()),
+            ..Default::default()
+        };
+        let result = Validation::validate_output(&output);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+        }
+    }
+
+    #[rstest]
+    #[case::dot(".", true)]
+    #[case::dash("-", false)]
+    #[case::underscore("_", false)]
+    fn test_validate_output_context_separator_strict_pep440(
+        #[case] separator: &str,
+        #[case] expected_ok: bool,
+    ) {
+        let output = OutputConfig {
+            output_format: formats::PEP440.to_string(),
+            context_separator: Some(separator.to_string()),
+            strict_pep440: true,
+            ..Default::default()
+        };
+        let result = Validation::validate_output(&output);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::ConflictingOptions(_))));
+        }
+    }
+
+    #[rstest]
+    #[case::zerv(formats::ZERV, None)]
+    #[case::template(formats::SEMVER, Some("{{semver}}"))]
+    fn test_validate_output_context_separator_conflicts(
+        #[case] format: &str,
+        #[case] template: Option<&str>,
+    ) {
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            output_template: template.map(|t| Template::new(t.to_string())),
+            context_separator: Some("-".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Validation::validate_output(&output),
+            Err(ZervError::ConflictingOptions(_))
+        ));
+    }
+
+    #[rstest]
+    #[case::pep440_post(formats::PEP440, Some("rev"), None, true)]
+    #[case::pep440_custom_post(formats::PEP440, Some("build"), None, false)]
+    #[case::pep440_dev(formats::PEP440, None, Some("dev"), true)]
+    #[case::pep440_custom_dev(formats::PEP440, None, Some("snapshot"), false)]
+    #[case::semver_custom(formats::SEMVER, Some("build"), Some("snap-shot"), true)]
+    #[case::semver_dot(formats::SEMVER, Some("a.b"), None, false)]
+    #[case::semver_empty(formats::SEMVER, None, Some(""), false)]
+    #[case::semver_numeric(formats::SEMVER, Some("42"), None, false)]
+    fn test_validate_output_post_dev_label(
+        #[case] format: &str,
+        #[case] post_label: Option<&str>,
+        #[case] dev_label: Option<&str>,
+        #[case] expected_ok: bool,
+    ) {
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            post_label: post_label.map(str::to_string),
+            dev_label: dev_label.map(str::to_string),
+            ..Default::default()
+        };
+        let result = Validation::validate_output(&output);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+        }
+    }
+
     #[test]
     fn test_validate_output_success() {
         let output = create_valid_output();
@@ -125,6 +591,7 @@ mod tests {
                 output_format: format.to_string(),
                 output_template: None,
                 output_prefix: None,
+                ..Default::default()
             };
             assert!(Validation::validate_output(&output).is_ok());
         }
@@ -136,6 +603,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
             output_prefix: Some("v".to_string()),
+            ..Default::default()
         };
         assert!(Validation::validate_output(&output).is_ok());
     }
@@ -146,6 +614,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new("v{{major}}.{{minor}}".to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         assert!(Validation::validate_output(&output).is_ok());
     }
@@ -156,6 +625,7 @@ mod tests {
             output_format: formats::PEP440.to_string(),
             output_template: Some(Template::new("v{{major}}.{{minor}}".to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         let result = Validation::validate_output(&output);
         assert!(result.is_err());
@@ -171,6 +641,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new("v{{major}}.{{minor}}".to_string())),
             output_prefix: Some("release-".to_string()),
+            ..Default::default()
         };
         let result = Validation::validate_output(&output);
         assert!(result.is_err());
@@ -194,6 +665,7 @@ mod tests {
             output_format: formats::PEP440.to_string(),
             output_template: Some(Template::new("template".to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         let result = Validation::validate_io(&input, &output);
         assert!(result.is_err());
@@ -209,6 +681,7 @@ mod tests {
             output_format: formats::PEP440.to_string(),
             output_template: Some(Template::new("test".to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         let result = Validation::validate_output(&output);
         assert!(result.is_err());
@@ -224,6 +697,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: Some(Template::new("test".to_string())),
             output_prefix: Some("v".to_string()),
+            ..Default::default()
         };
         let result = Validation::validate_output(&output);
         assert!(result.is_err());
@@ -240,6 +714,7 @@ mod tests {
             source: Some(sources::GIT.to_string()),
             input_format: formats::AUTO.to_string(),
             directory: Some("/workspace/project".to_string()),
+            ..Default::default()
         };
         assert!(Validation::validate_input(&input).is_ok());
     }
@@ -250,6 +725,7 @@ mod tests {
             output_format: formats::ZERV.to_string(),
             output_template: Some(Template::new("template".to_string())),
             output_prefix: None,
+            ..Default::default()
         };
         let result = Validation::validate_output(&output);
         assert!(result.is_err());
@@ -266,6 +742,7 @@ mod tests {
             output_format: formats::SEMVER.to_string(),
             output_template: None,
             output_prefix: Some("".to_string()),
+            ..Default::default()
         };
         assert!(Validation::validate_output(&output).is_ok());
 
@@ -276,6 +753,7 @@ mod tests {
                 "v{{major}}.{{minor}}.{{patch}}-{{pre_release}}".to_string(),
             )),
             output_prefix: None,
+            ..Default::default()
         };
         assert!(Validation::validate_output(&output).is_ok());
     }
@@ -1,9 +1,14 @@
 use clap::Parser;
+use serde::Deserialize;
 
 use crate::cli::utils::template::Template;
 
 /// Common override configuration for VCS and version components
-#[derive(Parser, Default, Debug, Clone)]
+///
+/// Only deserialized flattened into an overrides file, whose unknown keys
+/// `OverridesConfig` rejects for both structs
+#[derive(Parser, Default, Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct CommonOverridesConfig {
     // ============================================================================
     // VCS OVERRIDE OPTIONS
@@ -37,6 +42,13 @@ pub struct CommonOverridesConfig {
     )]
     pub clean: bool,
 
+    /// Clean release state only when HEAD is exactly on a version tag
+    #[arg(
+        long,
+        help = "Force clean release state (distance=0, dirty=false) only when HEAD is exactly on a valid version tag; otherwise keep the detected state. Conflicts with --clean"
+    )]
+    pub clean_if_tagged: bool,
+
     /// Override the detected current branch name
     #[arg(long, help = "Override current branch name")]
     pub bumped_branch: Option<String>,
@@ -83,6 +95,34 @@ impl CommonOverridesConfig {
             (true, true) => unreachable!(), // Should be caught by validation
         }
     }
+
+    /// Fill every override not given here from `file`
+    ///
+    /// The dirty state (`dirty`, `no_dirty`, `clean`) and the distance are taken as
+    /// groups, so a flag never combines with a conflicting value from the file.
+    pub fn merge_from_file(&mut self, file: CommonOverridesConfig) {
+        let cli_sets_dirty = self.dirty || self.no_dirty || self.clean;
+        let cli_sets_distance = self.distance.is_some() || self.clean;
+        if !cli_sets_dirty {
+            self.dirty = file.dirty;
+            self.no_dirty = file.no_dirty;
+            self.clean = file.clean && self.distance.is_none();
+        }
+        if !cli_sets_distance {
+            self.distance = file.distance;
+        }
+        self.clean_if_tagged |= file.clean_if_tagged && !self.clean;
+
+        self.tag_version = self.tag_version.take().or(file.tag_version);
+        self.bumped_branch = self.bumped_branch.take().or(file.bumped_branch);
+        self.bumped_commit_hash = self.bumped_commit_hash.take().or(file.bumped_commit_hash);
+        self.bumped_timestamp = self.bumped_timestamp.or(file.bumped_timestamp);
+        self.major = self.major.take().or(file.major);
+        self.minor = self.minor.take().or(file.minor);
+        self.patch = self.patch.take().or(file.patch);
+        self.epoch = self.epoch.take().or(file.epoch);
+        self.post = self.post.take().or(file.post);
+    }
 }
 
 #[cfg(test)]
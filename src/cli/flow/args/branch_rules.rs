@@ -2,15 +2,19 @@ use clap::Parser;
 
 use crate::cli::flow::branch_rules::BranchRules;
 use crate::error::ZervError;
-use crate::utils::constants::post_modes;
+use crate::utils::constants::{
+    default_branch,
+    post_modes,
+    prerelease_num_sources,
+};
 use crate::version::zerv::core::Zerv;
 
 /// Configuration for branch-related settings
 #[derive(Parser, Debug, Clone)]
 pub struct BranchRulesConfig {
-    /// Pre-release label for flow versions (alpha, beta, rc)
-    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(crate::utils::constants::pre_release_labels::VALID_LABELS),
-          help = "Pre-release label for flow versions (alpha, beta, rc)")]
+    /// Pre-release label for flow versions (alpha, beta, rc, numeric)
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(crate::utils::constants::pre_release_labels::VALID_SETTABLE_LABELS),
+          help = "Pre-release label for flow versions (alpha, beta, rc, or numeric for the number alone, e.g. 1.0.1-12345)")]
     pub pre_release_label: Option<String>,
 
     #[arg(
@@ -20,11 +24,36 @@ pub struct BranchRulesConfig {
     )]
     pub pre_release_num: Option<u32>,
 
+    /// Use the commit date as the pre-release number
+    #[arg(
+        long,
+        conflicts_with = "pre_release_num",
+        help = "Pre-release number from the commit date as YYYYMMDD instead of the branch hash"
+    )]
+    pub prerelease_date: bool,
+
+    /// Where the default pre-release number comes from (hash, commit-count)
+    #[arg(
+        long,
+        value_parser = clap::builder::PossibleValuesParser::new(prerelease_num_sources::VALID_SOURCES),
+        conflicts_with_all = ["pre_release_num", "prerelease_date"],
+        help = "Source of the pre-release number: hash (branch hash, default) or commit-count ({{ commit_count }})"
+    )]
+    pub prerelease_num_source: Option<String>,
+
     /// Post calculation mode (commit, tag)
     #[arg(long = "post-mode", value_parser = clap::builder::PossibleValuesParser::new(post_modes::VALID_MODES),
           help = "Post calculation mode (commit, tag)")]
     pub post_mode: Option<String>,
 
+    /// Release channel for flow versions (default: the matching branch rule's channel)
+    #[arg(
+        long,
+        value_name = "CHANNEL",
+        help = "Release channel (e.g. nightly, canary, stable) exposed to templates as custom.channel. Defaults to the 'channel' of the matching branch rule, if any"
+    )]
+    pub prerelease_channel: Option<String>,
+
     /// Branch rules in RON format (default: GitFlow rules)
     #[arg(
         long = "branch-rules",
@@ -40,7 +69,10 @@ impl Default for BranchRulesConfig {
         Self {
             pre_release_label: None,
             pre_release_num: None,
+            prerelease_date: false,
+            prerelease_num_source: None,
             post_mode: None,
+            prerelease_channel: None,
             branch_rules: BranchRules::default_rules(),
         }
     }
@@ -56,8 +88,29 @@ impl BranchRulesConfig {
 
     /// Apply branch rules using provided zerv object
     pub fn apply_branch_rules(&mut self, current_zerv: &Zerv) -> Result<(), ZervError> {
+        if self.prerelease_date && current_zerv.vars.bumped_timestamp.is_none() {
+            return Err(ZervError::InvalidArgument(
+                "--prerelease-date requires a commit timestamp (use --bumped-timestamp without a VCS source)"
+                    .to_string(),
+            ));
+        }
+        if self.prerelease_num_source.as_deref() == Some(prerelease_num_sources::COMMIT_COUNT)
+            && current_zerv.vars.commit_count.is_none()
+        {
+            return Err(ZervError::InvalidArgument(
+                "--prerelease-num-source commit-count requires a commit count (use --source git)"
+                    .to_string(),
+            ));
+        }
+
+        let default_branch = current_zerv
+            .vars
+            .default_branch
+            .as_deref()
+            .unwrap_or(default_branch::FALLBACK);
         let resolved_args = self
             .branch_rules
+            .with_default_branch(default_branch)
             .resolve_for_branch(current_zerv.vars.bumped_branch.as_deref());
 
         if self.pre_release_label.is_none() {
@@ -69,6 +122,9 @@ impl BranchRulesConfig {
         if self.post_mode.is_none() {
             self.post_mode = Some(resolved_args.post_mode.to_string().into());
         }
+        if self.prerelease_channel.is_none() {
+            self.prerelease_channel = resolved_args.channel;
+        }
         Ok(())
     }
 }
@@ -93,7 +149,10 @@ mod tests {
         let config = BranchRulesConfig {
             pre_release_label: Some("alpha".to_string()),
             pre_release_num: None,
+            prerelease_date: false,
+            prerelease_num_source: None,
             post_mode: None,
+            prerelease_channel: None,
             branch_rules: BranchRules::default_rules(),
         };
         assert!(config.has_explicit_settings());
@@ -104,7 +163,10 @@ mod tests {
         let config = BranchRulesConfig {
             pre_release_label: None,
             pre_release_num: None,
+            prerelease_date: false,
+            prerelease_num_source: None,
             post_mode: None,
+            prerelease_channel: None,
             branch_rules: BranchRules::default_rules(),
         };
         assert!(!config.has_explicit_settings());
@@ -122,6 +184,15 @@ mod tests {
         assert_eq!(config.pre_release_label, Some(label.to_string()));
     }
 
+    #[rstest]
+    #[case("alpha")]
+    #[case("numeric")]
+    fn test_pre_release_label_parses(#[case] label: &str) {
+        let config =
+            BranchRulesConfig::try_parse_from(["zerv", "--pre-release-label", label]).unwrap();
+        assert_eq!(config.pre_release_label, Some(label.to_string()));
+    }
+
     #[rstest]
     #[case(1)]
     #[case(42)]
@@ -145,6 +216,60 @@ mod tests {
         assert_eq!(config.post_mode, Some(mode.to_string()));
     }
 
+    #[rstest]
+    #[case::from_rule(None, "main", Some("stable"))]
+    #[case::explicit(Some("canary"), "main", Some("canary"))]
+    #[case::no_rule_channel(None, "develop", None)]
+    fn test_apply_branch_rules_channel(
+        #[case] explicit: Option<&str>,
+        #[case] branch: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let mut config = BranchRulesConfig {
+            prerelease_channel: explicit.map(str::to_string),
+            branch_rules: r#"[
+                (pattern: "main", pre_release_label: rc, pre_release_num: 1, post_mode: tag, channel: "stable"),
+                (pattern: "develop", pre_release_label: beta, pre_release_num: 1, post_mode: commit)
+            ]"#
+            .parse()
+            .unwrap(),
+            ..BranchRulesConfig::default()
+        };
+        let zerv = crate::test_utils::zerv::ZervFixture::new()
+            .with_branch(branch.to_string())
+            .build();
+
+        config.apply_branch_rules(&zerv).unwrap();
+        assert_eq!(config.prerelease_channel.as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case::detected(Some("master"), "master", Some("stable"))]
+    #[case::not_default(Some("master"), "main", None)]
+    #[case::fallback(None, "main", Some("stable"))]
+    fn test_apply_branch_rules_default_branch_pattern(
+        #[case] default_branch: Option<&str>,
+        #[case] branch: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let mut config = BranchRulesConfig {
+            branch_rules: r#"[
+                (pattern: "@default", pre_release_label: rc, pre_release_num: 1, post_mode: tag, channel: "stable"),
+                (pattern: "*", pre_release_label: alpha, post_mode: commit)
+            ]"#
+            .parse()
+            .unwrap(),
+            ..BranchRulesConfig::default()
+        };
+        let mut zerv = crate::test_utils::zerv::ZervFixture::new()
+            .with_branch(branch.to_string())
+            .build();
+        zerv.vars.default_branch = default_branch.map(str::to_string);
+
+        config.apply_branch_rules(&zerv).unwrap();
+        assert_eq!(config.prerelease_channel.as_deref(), expected);
+    }
+
     mod branch_rules {
         use super::*;
         use crate::cli::flow::args::main::FlowArgs;
@@ -1,6 +1,10 @@
 use super::FlowArgs;
 use crate::cli::utils::template::Template;
-use crate::utils::constants::post_modes;
+use crate::utils::constants::{
+    post_modes,
+    prerelease_num_sources,
+    timestamp_patterns,
+};
 impl FlowArgs {
     /// Get the post mode for the branch configuration
     pub fn post_mode(&self) -> &str {
@@ -35,8 +39,19 @@ impl FlowArgs {
         } else {
             let hash_len = self.hash_branch_len.to_string();
 
-            let pre_release_num_content = if let Some(num) = self.branch_config.pre_release_num {
+            let pre_release_num_content = if self.branch_config.prerelease_date {
+                timestamp_patterns::PRERELEASE_DATE_TEMPLATE.to_string()
+            } else if self.branch_config.prerelease_num_source.as_deref()
+                == Some(prerelease_num_sources::COMMIT_COUNT)
+            {
+                "{{ commit_count }}".to_string()
+            } else if let Some(num) = self.branch_config.pre_release_num {
                 num.to_string()
+            } else if let Some(algo) = &self.hash_algo {
+                format!(
+                    "{{{{ hash_int(value=bumped_branch, length={}, algo='{}') }}}}",
+                    hash_len, algo
+                )
             } else {
                 format!(
                     "{{{{ hash_int(value=bumped_branch, length={}) }}}}",
@@ -183,6 +198,70 @@ mod tests {
             let expected = args.build_pre_release_bump_template(&content);
             assert_eq!(template.as_str(), expected);
         }
+
+        #[rstest]
+        #[case(None, "{{ hash_int(value=bumped_branch, length=5) }}")]
+        #[case(
+            Some("fnv"),
+            "{{ hash_int(value=bumped_branch, length=5, algo='fnv') }}"
+        )]
+        #[case(
+            Some("sha256"),
+            "{{ hash_int(value=bumped_branch, length=5, algo='sha256') }}"
+        )]
+        fn test_template_uses_hash_algo(#[case] algo: Option<&str>, #[case] content: &str) {
+            let args = FlowArgs {
+                branch_config: BranchRulesConfig {
+                    pre_release_label: Some("alpha".to_string()),
+                    ..Default::default()
+                },
+                hash_algo: algo.map(str::to_string),
+                ..FlowArgs::default()
+            };
+            let template = args.bump_pre_release_num().unwrap().unwrap();
+
+            let expected = args.build_pre_release_bump_template(content);
+            assert_eq!(template.as_str(), expected);
+        }
+
+        #[test]
+        fn test_prerelease_date_uses_date_template() {
+            let args = FlowArgs {
+                branch_config: BranchRulesConfig {
+                    pre_release_label: Some("alpha".to_string()),
+                    prerelease_date: true,
+                    ..Default::default()
+                },
+                ..FlowArgs::default()
+            };
+            let template = args.bump_pre_release_num().unwrap().unwrap();
+
+            let expected =
+                args.build_pre_release_bump_template(timestamp_patterns::PRERELEASE_DATE_TEMPLATE);
+            assert_eq!(template.as_str(), expected);
+        }
+
+        #[rstest]
+        #[case::commit_count(Some(prerelease_num_sources::COMMIT_COUNT), "{{ commit_count }}")]
+        #[case::hash(
+            Some(prerelease_num_sources::HASH),
+            "{{ hash_int(value=bumped_branch, length=5) }}"
+        )]
+        #[case::default(None, "{{ hash_int(value=bumped_branch, length=5) }}")]
+        fn test_prerelease_num_source(#[case] source: Option<&str>, #[case] content: &str) {
+            let args = FlowArgs {
+                branch_config: BranchRulesConfig {
+                    pre_release_label: Some("alpha".to_string()),
+                    prerelease_num_source: source.map(str::to_string),
+                    ..Default::default()
+                },
+                ..FlowArgs::default()
+            };
+            let template = args.bump_pre_release_num().unwrap().unwrap();
+
+            let expected = args.build_pre_release_bump_template(content);
+            assert_eq!(template.as_str(), expected);
+        }
     }
 
     mod bump_post {
@@ -6,6 +6,7 @@ use crate::cli::common::args::{
 };
 use crate::cli::flow::args::branch_rules::BranchRulesConfig;
 use crate::cli::flow::args::overrides::OverridesConfig;
+use crate::utils::constants::hash_algos;
 
 /// Generate version with intelligent pre-release management based on Git branch patterns
 #[derive(Parser)]
@@ -27,7 +28,11 @@ INPUT/OUTPUT OPTIONS:
 PRE-RELEASE OPTIONS:
   --pre-release-label <LBL> Pre-release label: alpha (default), beta, rc
   --pre-release-num <NUM>   Pre-release number: integer (default: {{hash_int bumped_branch HASH_BRANCH_LEN}})
+  --prerelease-date         Pre-release number from the commit date (YYYYMMDD)
   --hash-branch-len <LEN>   Hash length for bumped branch hash (1-10, default: 5)
+  --hash-algo <ALGO>        Algorithm for the bumped branch hash: fnv, sha256, crc32
+  --prerelease-increment-on-collision
+                            Skip pre-release numbers already taken by existing tags
 
 POST MODE OPTIONS:
   --post-mode <MODE>        Post calculation mode: commit (default), tag
@@ -64,6 +69,7 @@ VCS OVERRIDE OPTIONS:
   --distance <NUM>          Override distance from tag (number of commits since tag)
   --dirty/--no-dirty        Override dirty state to true/false
   --clean                   Force clean release state (sets distance=0, dirty=false)
+  --clean-if-tagged         Force clean release state only when HEAD is on a version tag
   --bumped-branch <BRANCH>  Override current branch name
   --bumped-commit-hash <HASH> Override commit hash (full or short form)
   --bumped-timestamp <TS>   Override commit timestamp (Unix timestamp)
@@ -137,6 +143,14 @@ pub struct FlowArgs {
     )]
     pub hash_branch_len: u32,
 
+    #[arg(
+        long = "hash-algo",
+        value_name = "ALGO",
+        value_parser = clap::builder::PossibleValuesParser::new(hash_algos::VALID_ALGOS),
+        help = "Algorithm for the bumped branch hash: fnv, sha256 or crc32. These give the same number on every platform and zerv release; without this flag the Rust standard library hasher is used, as before"
+    )]
+    pub hash_algo: Option<String>,
+
     /// Schema preset name
     #[arg(
         long,
@@ -158,8 +172,24 @@ Standard Schema Family (SemVer):
     pub schema: Option<String>,
 
     /// Custom RON schema definition
-    #[arg(long, help = "Custom schema in RON format")]
+    #[arg(long, conflicts_with = "schema", help = "Custom schema in RON format")]
     pub schema_ron: Option<String>,
+
+    /// Schema preset composed from flags
+    #[arg(
+        long,
+        value_name = "FLAGS",
+        conflicts_with_all = ["schema", "schema_ron"],
+        help = "Compose a standard schema preset from comma-separated flags instead of its full name: tiers (base, prerelease, post, dev) and context, e.g. 'base,prerelease' = standard-base-prerelease"
+    )]
+    pub schema_flags: Option<String>,
+
+    /// Bump the pre-release number past numbers already used by existing tags
+    #[arg(
+        long = "prerelease-increment-on-collision",
+        help = "Increment the pre-release number until the version does not collide with an existing tag (the result then depends on which tags exist, not only on the branch)"
+    )]
+    pub prerelease_increment_on_collision: bool,
 }
 
 impl Default for FlowArgs {
@@ -170,8 +200,11 @@ impl Default for FlowArgs {
             branch_config: BranchRulesConfig::default(),
             overrides: OverridesConfig::default(),
             hash_branch_len: 5,
+            hash_algo: None,
             schema: None,
             schema_ron: None,
+            schema_flags: None,
+            prerelease_increment_on_collision: false,
         }
     }
 }
@@ -228,11 +261,13 @@ mod tests {
                     source: Some("git".to_string()),
                     input_format: "auto".to_string(),
                     directory: Some("/test/path".to_string()),
+                    ..Default::default()
                 },
                 output: OutputConfig {
                     output_format: "zerv".to_string(),
                     output_prefix: Some("v".to_string()),
                     output_template: None,
+                    ..Default::default()
                 },
                 ..FlowArgs::default()
             };
@@ -299,7 +334,10 @@ mod tests {
                     branch_rules: custom_ron.parse().unwrap(),
                     pre_release_label: Some("alpha".to_string()), // Manual override
                     pre_release_num: Some(42),                    // Manual override
-                    post_mode: Some("tag".to_string()),           // Manual override
+                    prerelease_date: false,
+                    prerelease_num_source: None,
+                    post_mode: Some("tag".to_string()), // Manual override
+                    prerelease_channel: None,
                 },
                 ..FlowArgs::default()
             };
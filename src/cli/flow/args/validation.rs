@@ -5,6 +5,7 @@ use crate::cli::common::args::Validation as CommonValidation;
 use crate::error::ZervError;
 use crate::schema::ZervSchemaPreset;
 use crate::utils::constants::pre_release_labels::ALPHA;
+use crate::utils::constants::sources;
 use crate::version::zerv::core::Zerv;
 
 impl FlowArgs {
@@ -28,12 +29,25 @@ impl FlowArgs {
         self.validate_pre_release_num()?;
         self.validate_hash_branch_len()?;
         self.validate_post_mode()?;
+        self.resolve_schema_flags()?;
         self.validate_schema()?;
         self.validate_overrides()?;
+        self.validate_collision_mode()?;
 
         Ok(())
     }
 
+    fn validate_collision_mode(&self) -> Result<(), ZervError> {
+        if self.prerelease_increment_on_collision
+            && self.input.source.as_deref() != Some(sources::GIT)
+        {
+            return Err(ZervError::ConflictingOptions(
+                "--prerelease-increment-on-collision requires --source git".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_pre_release_label(&mut self) -> Result<(), ZervError> {
         if self.branch_config.pre_release_label.is_none() {
             self.branch_config.pre_release_label = Some(ALPHA.to_string());
@@ -71,6 +85,13 @@ impl FlowArgs {
         Ok(())
     }
 
+    fn resolve_schema_flags(&mut self) -> Result<(), ZervError> {
+        if let Some(flags) = self.schema_flags.take() {
+            self.schema = Some(ZervSchemaPreset::name_from_flags(&flags)?);
+        }
+        Ok(())
+    }
+
     fn validate_schema(&self) -> Result<(), ZervError> {
         if let Some(schema_name) = &self.schema {
             // First, validate it's a known schema
@@ -75,9 +75,23 @@ impl FlowArgs {
             ..Default::default()
         };
 
-        Ok(self.create_version_args(
+        let mut version_args = self.create_version_args(
             bumps,
             self.override_dirty(current_zerv.vars.dirty, current_zerv.vars.distance),
-        ))
+        );
+        if let Some(ref channel) = self.branch_config.prerelease_channel {
+            version_args.overrides.custom = Some(Self::custom_with_channel(current_zerv, channel));
+        }
+        Ok(version_args)
+    }
+
+    /// Custom variables of the current version with `channel` added
+    fn custom_with_channel(current_zerv: &Zerv, channel: &str) -> String {
+        let mut custom = match &current_zerv.vars.custom {
+            serde_json::Value::Object(map) => map.clone(),
+            _ => serde_json::Map::new(),
+        };
+        custom.insert("channel".to_string(), channel.into());
+        serde_json::Value::Object(custom).to_string()
     }
 }
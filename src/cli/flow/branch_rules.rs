@@ -14,6 +14,9 @@ use serde::{
 
 use crate::error::ZervError;
 
+/// Rule pattern standing for the repository's default branch (e.g. main, master, trunk)
+pub const DEFAULT_BRANCH_PATTERN: &str = "@default";
+
 /// Enum for type-safe pre-release labels
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -39,6 +42,8 @@ pub struct BranchRule {
     #[serde(default)]
     pub pre_release_num: Option<u32>, // "1" for release branches, defaults to None
     pub post_mode: PostMode,                // "tag" for release, "commit" for others
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>, // "stable", "nightly", exposed as custom.channel
 }
 
 /// Resolved branch arguments from branch rules
@@ -47,6 +52,7 @@ pub struct ResolvedBranchArgs {
     pub pre_release_label: PreReleaseLabel,
     pub pre_release_num: Option<u32>,
     pub post_mode: PostMode,
+    pub channel: Option<String>,
 }
 
 /// Collection of branch rules with pattern matching
@@ -94,6 +100,7 @@ impl BranchRule {
             pre_release_label: self.pre_release_label.clone(),
             pre_release_num: self.resolve_pre_release_num(branch_name),
             post_mode: self.post_mode.clone(),
+            channel: self.channel.clone(),
         }
     }
 
@@ -144,14 +151,34 @@ impl BranchRules {
         Ok(Self { rules })
     }
 
-    /// Preprocess RON string to convert bare numbers to Some(number) for pre_release_num
+    /// Preprocess RON string to convert bare values to Some(value) for optional fields
     fn preprocess_ron_syntax(ron_str: &str) -> String {
         use regex::Regex;
 
         // Match pattern: pre_release_num: <number> and convert to pre_release_num: Some(<number>)
         // This regex finds pre_release_num field with bare numbers and wraps them in Some()
         let re = Regex::new(r"(pre_release_num:\s*)(\d+)").expect("Failed to compile regex");
-        re.replace_all(ron_str, "${1}Some(${2})").to_string()
+        let ron_str = re.replace_all(ron_str, "${1}Some(${2})");
+
+        // Same for channel: "nightly" -> channel: Some("nightly")
+        let re = Regex::new(r#"(channel:\s*)("[^"]*")"#).expect("Failed to compile regex");
+        re.replace_all(&ron_str, "${1}Some(${2})").to_string()
+    }
+
+    /// Rules with the default branch pattern bound to `default_branch`
+    pub fn with_default_branch(&self, default_branch: &str) -> Self {
+        let rules = self
+            .rules
+            .iter()
+            .cloned()
+            .map(|mut rule| {
+                if rule.pattern == DEFAULT_BRANCH_PATTERN {
+                    rule.pattern = default_branch.to_string();
+                }
+                rule
+            })
+            .collect();
+        Self { rules }
     }
 
     /// Find a rule that matches the given branch name
@@ -167,18 +194,21 @@ impl BranchRules {
                 pre_release_label: PreReleaseLabel::Beta,
                 pre_release_num: Some(1),
                 post_mode: PostMode::Commit,
+                channel: None,
             },
             BranchRule {
                 pattern: "release/*".to_string(),
                 pre_release_label: PreReleaseLabel::Rc,
                 pre_release_num: None, // Extract from branch name
                 post_mode: PostMode::Tag,
+                channel: None,
             },
             BranchRule {
                 pattern: "*".to_string(),
                 pre_release_label: PreReleaseLabel::Alpha,
                 pre_release_num: None, // Extract from branch name
                 post_mode: PostMode::Commit,
+                channel: None,
             },
         ];
         Self::new(rules).expect("Default branch rules should be valid")
@@ -193,6 +223,7 @@ impl BranchRules {
                 pre_release_label: PreReleaseLabel::Alpha,
                 pre_release_num: None,
                 post_mode: PostMode::Commit,
+                channel: None,
             })
     }
 }
@@ -355,6 +386,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Beta,
             pre_release_num: Some(1),
             post_mode: PostMode::Commit,
+            channel: None,
         };
 
         assert_eq!(rule.matches(branch), matches);
@@ -385,6 +417,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Rc,
             pre_release_num: None,
             post_mode: PostMode::Tag,
+            channel: None,
         };
 
         assert_eq!(rule.matches(branch), matches);
@@ -433,6 +466,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Rc,
             pre_release_num: None, // Must be specified in Rust code (#[serde(default)] only for deserialization)
             post_mode: PostMode::Tag,
+            channel: None,
         };
 
         assert_eq!(rule.resolve_pre_release_num(branch_name), expected);
@@ -445,6 +479,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Beta,
             pre_release_num: Some(5),
             post_mode: PostMode::Commit,
+            channel: None,
         };
 
         // Should always use the explicit number, not extract from branch name
@@ -458,6 +493,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Alpha,
             pre_release_num: None, // Should extract from branch
             post_mode: PostMode::Commit,
+            channel: None,
         };
 
         // Should match any non-empty branch name
@@ -511,6 +547,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Rc,
             pre_release_num: Some(1), // This should be invalid for wildcard patterns
             post_mode: PostMode::Tag,
+            channel: None,
         };
 
         // Validation should fail
@@ -532,6 +569,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Rc,
             pre_release_num: Some(1), // This should be invalid for universal wildcard pattern
             post_mode: PostMode::Tag,
+            channel: None,
         };
 
         // Validation should fail
@@ -555,12 +593,14 @@ mod tests {
                 pre_release_label: PreReleaseLabel::Beta,
                 pre_release_num: Some(1),
                 post_mode: PostMode::Commit,
+                channel: None,
             },
             BranchRule {
                 pattern: "release/*".to_string(),
                 pre_release_label: PreReleaseLabel::Rc,
                 pre_release_num: None, // Valid: None for wildcard pattern
                 post_mode: PostMode::Tag,
+                channel: None,
             },
         ];
         let result = BranchRules::new(valid_rules);
@@ -572,6 +612,7 @@ mod tests {
             pre_release_label: PreReleaseLabel::Rc,
             pre_release_num: Some(1), // Invalid: Some for wildcard pattern
             post_mode: PostMode::Tag,
+            channel: None,
         }];
         let result = BranchRules::new(invalid_rules);
         assert!(result.is_err());
@@ -634,6 +675,33 @@ mod tests {
         assert_eq!(release_rule.pre_release_num, None); // Omitted becomes None
     }
 
+    #[rstest]
+    #[case::main(Some("main"), Some("stable"))]
+    #[case::feature(Some("feature/auth"), Some("nightly"))]
+    #[case::develop(Some("develop"), Some("nightly"))]
+    #[case::no_branch(None, None)]
+    fn test_branch_rules_resolve_channel(
+        #[case] branch: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        let ron_str = r#"[
+            (pattern: "main", pre_release_label: rc, pre_release_num: 1, post_mode: tag, channel: "stable"),
+            (pattern: "*", pre_release_label: alpha, post_mode: commit, channel: "nightly")
+        ]"#;
+
+        let rules: BranchRules = ron_str.parse().unwrap();
+        assert_eq!(
+            rules.resolve_for_branch(branch).channel.as_deref(),
+            expected
+        );
+
+        let reparsed: BranchRules = rules.to_string().parse().unwrap();
+        assert_eq!(
+            reparsed.resolve_for_branch(branch).channel.as_deref(),
+            expected
+        );
+    }
+
     #[test]
     fn test_branch_rules_validation_exact_pattern_requires_num() {
         // Test that exact patterns without pre_release_num fail validation
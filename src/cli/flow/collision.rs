@@ -0,0 +1,107 @@
+use crate::error::ZervError;
+use crate::utils::constants::formats;
+use crate::version::zerv::PreReleaseVar;
+use crate::version::zerv::bump::checked_bump;
+use crate::version::{
+    TagPrefix,
+    VersionObject,
+    Zerv,
+    ZervVars,
+};
+
+/// Bump the pre-release number until it no longer matches an existing tag
+///
+/// Branch hashes make pre-release numbers deterministic per branch; resolving a
+/// collision depends on which tags exist at run time, so the same branch can yield a
+/// different number once a colliding tag appears or disappears.
+pub fn increment_pre_release_on_collision(
+    zerv: &mut Zerv,
+    existing_tags: &[String],
+    tag_prefix: &TagPrefix,
+) -> Result<(), ZervError> {
+    if zerv.vars.pre_release.is_none() {
+        return Ok(());
+    }
+
+    let taken: Vec<ZervVars> = existing_tags
+        .iter()
+        .filter_map(|tag| VersionObject::parse_with_prefix(tag, formats::AUTO, tag_prefix).ok())
+        .map(ZervVars::from)
+        .collect();
+
+    while taken.iter().any(|vars| same_pre_release(vars, &zerv.vars)) {
+        if let Some(ref mut pre_release) = zerv.vars.pre_release {
+            let next = checked_bump(pre_release.number().unwrap_or(0), 1, "pre_release_num")?;
+            tracing::debug!(
+                "Pre-release number {:?} collides with an existing tag, trying {}",
+                pre_release.number(),
+                next
+            );
+            pre_release.set_number(Some(next));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether two versions share release and pre-release identity (post/dev/build ignored)
+fn same_pre_release(a: &ZervVars, b: &ZervVars) -> bool {
+    a.epoch.unwrap_or(0) == b.epoch.unwrap_or(0)
+        && a.major.unwrap_or(0) == b.major.unwrap_or(0)
+        && a.minor.unwrap_or(0) == b.minor.unwrap_or(0)
+        && a.patch.unwrap_or(0) == b.patch.unwrap_or(0)
+        && match (&a.pre_release, &b.pre_release) {
+            (Some(PreReleaseVar::Raw(a)), Some(PreReleaseVar::Raw(b))) => a == b,
+            (Some(a), Some(b)) => a.label() == b.label() && a.number() == b.number(),
+            _ => false,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::zerv::ZervFixture;
+    use crate::version::zerv::core::PreReleaseLabel;
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case::no_collision(&["v1.0.0", "v1.0.1-alpha.7"], Some(42))]
+    #[case::single_collision(&["v1.0.1-alpha.42"], Some(43))]
+    #[case::chained_collision(&["v1.0.1-alpha.42", "v1.0.1-alpha.43", "1.0.1a44"], Some(45))]
+    #[case::other_label(&["v1.0.1-beta.42"], Some(42))]
+    #[case::other_release(&["v1.0.2-alpha.42"], Some(42))]
+    #[case::post_ignored(&["v1.0.1-alpha.42.post.3"], Some(43))]
+    fn test_increment_pre_release_on_collision(
+        #[case] existing: &[&str],
+        #[case] expected_number: Option<u64>,
+    ) {
+        let mut zerv = ZervFixture::new()
+            .with_version(1, 0, 1)
+            .with_pre_release(PreReleaseLabel::Alpha, Some(42))
+            .build();
+
+        increment_pre_release_on_collision(&mut zerv, &tags(existing), &TagPrefix::default())
+            .unwrap();
+
+        assert_eq!(
+            zerv.vars.pre_release.and_then(|pr| pr.number()),
+            expected_number
+        );
+    }
+
+    #[test]
+    fn test_increment_pre_release_on_collision_without_pre_release() {
+        let mut zerv = ZervFixture::new().with_version(1, 0, 1).build();
+        let expected = zerv.clone();
+
+        increment_pre_release_on_collision(&mut zerv, &tags(&["v1.0.1"]), &TagPrefix::default())
+            .unwrap();
+
+        assert_eq!(zerv, expected);
+    }
+}
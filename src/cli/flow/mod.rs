@@ -1,5 +1,6 @@
 pub mod args;
 pub mod branch_rules;
+pub mod collision;
 pub mod pipeline;
 
 #[cfg(any(test, feature = "test-utils"))]
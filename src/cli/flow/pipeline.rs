@@ -1,9 +1,22 @@
+use std::env::current_dir;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
 use ron::from_str;
 
+use crate::cli::common::args::InputConfig;
 use crate::cli::flow::args::FlowArgs;
+use crate::cli::flow::collision::increment_pre_release_on_collision;
 use crate::cli::utils::output_formatter::OutputFormatter;
 use crate::cli::version::pipeline::run_version_pipeline;
 use crate::error::ZervError;
+use crate::vcs::git::GitOptions;
+use crate::vcs::{
+    detect_vcs_with_limit,
+    open_vcs_at_root,
+};
 use crate::version::zerv::core::Zerv;
 
 pub fn run_flow_pipeline(args: FlowArgs, stdin_content: Option<&str>) -> Result<String, ZervError> {
@@ -23,19 +36,40 @@ pub fn run_flow_pipeline(args: FlowArgs, stdin_content: Option<&str>) -> Result<
     // Step 4: Run version pipeline with stdin content
     let ron_output = run_version_pipeline(version_args, stdin_content)?;
 
-    let zerv_object: Zerv = from_str(&ron_output)
+    let mut zerv_object: Zerv = from_str(&ron_output)
         .map_err(|e| ZervError::InvalidFormat(format!("Failed to parse version output: {}", e)))?;
 
-    let output = OutputFormatter::format_output(
+    // Step 5: Optionally move the pre-release number past existing tags
+    if args.prerelease_increment_on_collision {
+        let existing_tags = list_existing_tags(&args.input)?;
+        increment_pre_release_on_collision(
+            &mut zerv_object,
+            &existing_tags,
+            &args.input.tag_prefix(),
+        )?;
+    }
+
+    let output = OutputFormatter::format_with_scope(
         &zerv_object,
-        &args.output.output_format,
-        args.output.output_prefix.as_deref(),
-        &args.output.output_template,
+        &args.output,
+        args.input.scope_prefix.as_deref(),
+        args.input.commit_prefix.as_deref(),
     )?;
 
     Ok(output)
 }
 
+fn list_existing_tags(input: &InputConfig) -> Result<Vec<String>, ZervError> {
+    if let Some(ref repo) = input.repo {
+        return open_vcs_at_root(Path::new(repo), GitOptions::default())?.get_all_tags();
+    }
+    let (work_dir, max_depth) = match input.directory.as_deref() {
+        Some(dir) => (PathBuf::from(dir), Some(0)),
+        None => (current_dir()?, None),
+    };
+    detect_vcs_with_limit(&work_dir, max_depth)?.get_all_tags()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cli::flow::test_utils::{
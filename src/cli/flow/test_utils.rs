@@ -338,10 +338,10 @@ impl FlowTestScenario {
             self.current_vars.major = Some(major);
             self.current_vars.minor = Some(minor);
             self.current_vars.patch = Some(new_patch);
-            self.current_vars.pre_release = Some(crate::version::zerv::PreReleaseVar {
-                label: crate::version::zerv::PreReleaseLabel::Alpha,
-                number: Some(68031),
-            });
+            self.current_vars.pre_release = Some(crate::version::zerv::PreReleaseVar::labeled(
+                crate::version::zerv::PreReleaseLabel::Alpha,
+                Some(68031),
+            ));
 
             test_debug!(
                 "After version bump: major={:?}, minor={:?}, patch={:?}, pre_release={:?}, post={:?}",
@@ -0,0 +1,64 @@
+use clap::Parser;
+
+use crate::error::ZervError;
+
+#[derive(Parser, Debug, Default)]
+pub struct JsonSchemaArgs {}
+
+/// JSON Schema of the Zerv object (the structure behind `--output-format zerv`)
+#[cfg(feature = "json-schema")]
+pub fn run_json_schema(_args: JsonSchemaArgs) -> Result<String, ZervError> {
+    let schema = schemars::schema_for!(crate::version::Zerv);
+    serde_json::to_string_pretty(&schema)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to serialize JSON Schema: {e}")))
+}
+
+#[cfg(not(feature = "json-schema"))]
+pub fn run_json_schema(_args: JsonSchemaArgs) -> Result<String, ZervError> {
+    Err(ZervError::NotImplemented(
+        "json-schema requires zerv to be built with the 'json-schema' feature".to_string(),
+    ))
+}
+
+#[cfg(all(test, feature = "json-schema"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::zerv::ZervFixture;
+    use crate::version::PreReleaseLabel;
+
+    fn validator() -> jsonschema::Validator {
+        let schema: serde_json::Value =
+            serde_json::from_str(&run_json_schema(JsonSchemaArgs::default()).unwrap()).unwrap();
+        jsonschema::validator_for(&schema).unwrap()
+    }
+
+    #[test]
+    fn test_json_schema_accepts_zerv_objects() {
+        let validator = validator();
+        for zerv in [
+            ZervFixture::new().with_version(1, 2, 3).build(),
+            ZervFixture::new()
+                .with_version(2, 0, 0)
+                .with_pre_release(PreReleaseLabel::Rc, Some(1))
+                .with_distance(4)
+                .with_branch("main".to_string())
+                .build(),
+        ] {
+            let value = serde_json::to_value(&zerv).unwrap();
+            let errors: Vec<String> = validator
+                .iter_errors(&value)
+                .map(|e| e.to_string())
+                .collect();
+            assert!(errors.is_empty(), "{errors:?} for {value}");
+        }
+    }
+
+    #[test]
+    fn test_json_schema_rejects_invalid_objects() {
+        let validator = validator();
+        let mut value = serde_json::to_value(ZervFixture::new().build()).unwrap();
+        value["vars"]["major"] = serde_json::json!("one");
+        assert!(!validator.is_valid(&value));
+        assert!(!validator.is_valid(&serde_json::json!({ "vars": {} })));
+    }
+}
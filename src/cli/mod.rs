@@ -1,17 +1,29 @@
 pub mod app;
+pub mod changelog_range;
 pub mod check;
 pub mod common;
 pub mod flow;
+pub mod json_schema;
 pub mod llm_help;
 pub mod parser;
 pub mod render;
+pub mod report;
+pub mod selftest;
+pub mod sort;
 pub mod utils;
+pub mod validate_tag;
+pub mod vars;
+pub mod vcs_data;
 pub mod version;
 
 pub use app::{
     run,
     run_with_args,
 };
+pub use changelog_range::{
+    ChangelogRangeArgs,
+    run_changelog_range,
+};
 pub use check::{
     CheckArgs,
     run_check_command,
@@ -20,6 +32,10 @@ pub use flow::{
     FlowArgs,
     run_flow_pipeline,
 };
+pub use json_schema::{
+    JsonSchemaArgs,
+    run_json_schema,
+};
 pub use parser::{
     Cli,
     Commands,
@@ -28,6 +44,33 @@ pub use render::{
     RenderArgs,
     run_render,
 };
+pub use report::{
+    ReportArgs,
+    run_report,
+};
+pub use selftest::{
+    SelftestArgs,
+    run_selftest,
+};
+pub use sort::{
+    ReduceArgs,
+    SortArgs,
+    run_max,
+    run_min,
+    run_sort,
+};
+pub use validate_tag::{
+    ValidateTagArgs,
+    run_validate_tag,
+};
+pub use vars::{
+    VarsArgs,
+    run_vars,
+};
+pub use vcs_data::{
+    VcsDataArgs,
+    run_vcs_data,
+};
 pub use version::{
     VersionArgs,
     run_version_pipeline,
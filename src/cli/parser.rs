@@ -1,12 +1,31 @@
 use clap::{
+    Arg,
+    Command,
+    CommandFactory,
     Parser,
     Subcommand,
 };
+use serde_json::{
+    Value,
+    json,
+};
 
+use crate::cli::changelog_range::ChangelogRangeArgs;
 use crate::cli::check::CheckArgs;
 use crate::cli::flow::FlowArgs;
+use crate::cli::json_schema::JsonSchemaArgs;
 use crate::cli::render::RenderArgs;
+use crate::cli::report::ReportArgs;
+use crate::cli::selftest::SelftestArgs;
+use crate::cli::sort::{
+    ReduceArgs,
+    SortArgs,
+};
+use crate::cli::validate_tag::ValidateTagArgs;
+use crate::cli::vars::VarsArgs;
+use crate::cli::vcs_data::VcsDataArgs;
 use crate::cli::version::VersionArgs;
+use crate::utils::constants::dump_cli_formats;
 
 #[derive(Parser, Debug)]
 #[command(name = "zerv")]
@@ -52,6 +71,30 @@ EXAMPLES:
 
   # Render - add prefix
   zerv render 1.2.3 --output-prefix release-
+
+  # Sort - print the versions read from stdin, newest last
+  git tag --list | zerv sort --input-format semver
+
+  # Max - print the newest version read from stdin
+  git tag --list | zerv max
+
+  # Vars - list the variables available to schemas and templates
+  zerv vars
+
+  # Changelog range - commits since the last tag and the next version they imply
+  zerv changelog-range
+
+  # VCS data - what zerv detected from git, before any schema processing
+  zerv vcs-data
+
+  # Report - version, tag, distance and dirty state of several repositories as JSON
+  zerv report ../api ../web
+
+  # JSON Schema of the Zerv object (requires the 'json-schema' feature)
+  zerv json-schema
+
+  # Commands and options as JSON, for tools wrapping zerv
+  zerv --dump-cli json
 "
 )]
 pub struct Cli {
@@ -60,10 +103,28 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub verbose: bool,
 
+    /// Load `ZERV_*` variables from a dotenv file as option defaults
+    #[arg(
+        long = "env-file",
+        value_name = "PATH",
+        global = true,
+        help = "Load ZERV_<OPTION> variables from a dotenv file as defaults for the matching options (e.g. ZERV_TAG_VERSION=1.2.3 for --tag-version 1.2.3). Options given on the command line take precedence"
+    )]
+    pub env_file: Option<String>,
+
     /// Display comprehensive CLI manual for humans and AI assistants
     #[arg(long = "llm-help", help = "Display comprehensive CLI manual")]
     pub llm_help: bool,
 
+    /// Print the command tree as a machine-readable manifest
+    #[arg(
+        long = "dump-cli",
+        value_name = "FORMAT",
+        value_parser = [dump_cli_formats::JSON],
+        help = "Print every command and option (names, defaults, possible values) as JSON, for tools wrapping zerv"
+    )]
+    pub dump_cli: Option<String>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -96,6 +157,119 @@ Supports SemVer, PEP440, and other version format validation."
 Supports format conversion (SemVer ↔ PEP440), normalization, templates, and custom prefixes."
     )]
     Render(Box<RenderArgs>),
+    /// Sort versions read from stdin
+    #[command(
+        long_about = "Read newline-separated versions from stdin and print them in ascending order.
+Versions are ordered with the rules of their format (SemVer or PEP440); all versions must share one format."
+    )]
+    Sort(SortArgs),
+    /// Print the highest version read from stdin
+    #[command(
+        long_about = "Read newline-separated versions from stdin and print the highest one.
+Versions are ordered with the rules of their format (SemVer or PEP440), so 1.0.0-rc.1 < 1.0.0."
+    )]
+    Max(ReduceArgs),
+    /// Print the lowest version read from stdin
+    #[command(
+        long_about = "Read newline-separated versions from stdin and print the lowest one.
+Versions are ordered with the rules of their format (SemVer or PEP440), so 1.0.0-rc.1 < 1.0.0."
+    )]
+    Min(ReduceArgs),
+    /// List the variables available to schemas and templates
+    #[command(
+        long_about = "List every variable that can be used in schemas (var(...)) and output templates ({{ ... }}),
+with a sample value resolved from demo data."
+    )]
+    Vars(VarsArgs),
+    /// Print the commit range since the last tag and the inferred next version
+    #[command(
+        long_about = "Print the commit range since the last version tag (from = tag, to = HEAD commit) and the next
+version inferred from the conventional commits in that range, as JSON for changelog tools."
+    )]
+    ChangelogRange(Box<ChangelogRangeArgs>),
+    /// Print the JSON Schema of the Zerv object
+    #[command(
+        long_about = "Print a JSON Schema document describing the Zerv object (schema and vars) emitted by
+--output-format zerv, in its JSON form. Requires zerv to be built with the 'json-schema' feature."
+    )]
+    JsonSchema(JsonSchemaArgs),
+    /// Check a tag before pushing it
+    #[command(
+        long_about = "Check that a tag is a valid version, fits --schema when given, and is greater than the
+latest version tag in the repository. Exits non-zero with the reason otherwise, for pre-push hooks."
+    )]
+    ValidateTag(Box<ValidateTagArgs>),
+    /// Print the raw VCS data detected from git
+    #[command(
+        long_about = "Print the VCS data zerv detected from git (tag, distance, hashes, timestamps, dirty state)
+as JSON, before any schema processing or overrides. The first thing to check when a version is surprising."
+    )]
+    VcsData(Box<VcsDataArgs>),
+    /// Print the versions of several repositories as one JSON report
+    #[command(
+        long_about = "Compute the version of each given repository directory and print a JSON array of
+{path, version, tag, distance, dirty} entries, in argument order. A directory that cannot be read
+gets an entry with an error message instead of stopping the report."
+    )]
+    Report(Box<ReportArgs>),
+    /// Check that versions round-trip through the Zerv conversions
+    #[command(
+        long_about = "Convert a set of representative SemVer and PEP440 versions to Zerv and back, and across
+formats, and check that rendering is idempotent. Prints any discrepancy and exits non-zero; useful
+to include when reporting a conversion bug."
+    )]
+    Selftest(SelftestArgs),
+}
+
+/// Describe the whole `zerv` command tree, as seen by clap, as JSON
+pub fn cli_manifest() -> Value {
+    command_manifest(&Cli::command())
+}
+
+fn command_manifest(command: &Command) -> Value {
+    json!({
+        "name": command.get_name(),
+        "about": command.get_about().map(|about| about.to_string()),
+        "args": command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .map(arg_manifest)
+            .collect::<Vec<_>>(),
+        "subcommands": command
+            .get_subcommands()
+            .filter(|subcommand| !subcommand.is_hide_set())
+            .map(command_manifest)
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn arg_manifest(arg: &Arg) -> Value {
+    let takes_value = arg.get_action().takes_values();
+    // Flags report true/false as possible values, which says nothing useful
+    let possible_values: Vec<String> = if takes_value {
+        arg.get_possible_values()
+            .iter()
+            .map(|value| value.get_name().to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    json!({
+        "id": arg.get_id().as_str(),
+        "long": arg.get_long(),
+        "short": arg.get_short().map(|short| short.to_string()),
+        "help": arg.get_help().map(|help| help.to_string()),
+        "takes_value": takes_value,
+        "required": arg.is_required_set(),
+        "global": arg.is_global_set(),
+        "default_values": arg
+            .get_default_values()
+            .iter()
+            .map(|value| value.to_string_lossy().into_owned())
+            .collect::<Vec<_>>(),
+        "possible_values": possible_values,
+    })
 }
 
 #[cfg(test)]
@@ -103,6 +277,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::utils::constants::formats;
 
     #[test]
     fn test_cli_structure() {
@@ -118,6 +293,36 @@ mod tests {
 
         let cli = Cli::try_parse_from(["zerv", "render", "1.2.3"]).unwrap();
         assert!(matches!(cli.command, Some(Commands::Render(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "sort"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Sort(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "max"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Max(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "min"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Min(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "vars"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Vars(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "changelog-range"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::ChangelogRange(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "json-schema"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::JsonSchema(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "validate-tag", "v1.0.0"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::ValidateTag(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "vcs-data"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::VcsData(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "report", "."]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Report(_))));
+
+        let cli = Cli::try_parse_from(["zerv", "selftest"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Selftest(_))));
     }
 
     #[test]
@@ -135,11 +340,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_manifest_describes_version_schema_arg() {
+        let manifest = cli_manifest();
+        assert_eq!(manifest["name"], "zerv");
+
+        let version = manifest["subcommands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|command| command["name"] == "version")
+            .expect("version subcommand should be listed");
+        let schema = version["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|arg| arg["long"] == "schema")
+            .expect("--schema should be listed under version");
+        assert_eq!(schema["takes_value"], true);
+        assert_eq!(schema["possible_values"], json!([]));
+        assert!(schema["help"].is_string());
+
+        let output_format = version["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|arg| arg["long"] == "output-format")
+            .unwrap();
+        assert_eq!(output_format["default_values"], json!([formats::SEMVER]));
+        assert!(
+            output_format["possible_values"]
+                .as_array()
+                .unwrap()
+                .contains(&json!(formats::PEP440))
+        );
+    }
+
     #[rstest]
     #[case(vec!["zerv", "version"], true)]
     #[case(vec!["zerv", "flow"], true)]
     #[case(vec!["zerv", "check", "1.0.0"], true)]
     #[case(vec!["zerv", "render", "1.2.3"], true)]
+    #[case(vec!["zerv", "sort", "--reverse"], true)]
     #[case(vec!["zerv", "invalid"], false)]
     fn test_cli_parsing(#[case] args: Vec<&str>, #[case] should_succeed: bool) {
         let result = Cli::try_parse_from(args);
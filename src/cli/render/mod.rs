@@ -39,6 +39,13 @@ pub struct RenderArgs {
     )]
     pub input_format: String,
 
+    /// Drop build metadata before rendering
+    #[arg(
+        long = "strip-context",
+        help = "Remove context (SemVer build metadata / PEP440 local version) before rendering"
+    )]
+    pub strip_context: bool,
+
     /// Output configuration (same as version/flow)
     #[command(flatten)]
     pub output: OutputConfig,
@@ -66,6 +73,7 @@ mod tests {
         let args = RenderArgs {
             version: version.to_string(),
             input_format: format.to_string(),
+            strip_context: false,
             output: OutputConfig::default(),
         };
         assert_eq!(args.version, version);
@@ -77,10 +85,12 @@ mod tests {
         let args = RenderArgs {
             version: "1.2.3".to_string(),
             input_format: formats::SEMVER.to_string(),
+            strip_context: false,
             output: OutputConfig {
                 output_format: formats::SEMVER.to_string(),
                 output_template: Some(Template::new("v{{major}}".to_string())),
                 output_prefix: None,
+                ..Default::default()
             },
         };
         assert_eq!(args.version, "1.2.3");
@@ -96,10 +106,12 @@ mod tests {
         let args = RenderArgs {
             version: "1.2.3".to_string(),
             input_format: formats::SEMVER.to_string(),
+            strip_context: false,
             output: OutputConfig {
                 output_format: formats::SEMVER.to_string(),
                 output_template: None,
                 output_prefix: Some("v".to_string()),
+                ..Default::default()
             },
         };
         assert_eq!(args.version, "1.2.3");
@@ -113,10 +125,12 @@ mod tests {
         let args = RenderArgs {
             version: "1.2.3".to_string(),
             input_format: formats::SEMVER.to_string(),
+            strip_context: false,
             output: OutputConfig {
                 output_format: formats::SEMVER.to_string(),
                 output_template: Some(Template::new("v{{major}}".to_string())),
                 output_prefix: Some("release-".to_string()),
+                ..Default::default()
             },
         };
         assert!(args.validate().is_err());
@@ -135,6 +149,7 @@ mod tests {
         let args = RenderArgs {
             version: "1.0.0".to_string(),
             input_format: format.to_string(),
+            strip_context: false,
             output: OutputConfig::default(),
         };
         assert_eq!(args.input_format, expected);
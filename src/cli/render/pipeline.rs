@@ -2,20 +2,19 @@ use crate::cli::render::RenderArgs;
 use crate::cli::utils::output_formatter::OutputFormatter;
 use crate::error::ZervError;
 use crate::version::VersionObject;
+use crate::version::zerv::Zerv;
 
 pub fn run_render(args: RenderArgs) -> Result<String, ZervError> {
     args.validate()?;
     let version_object = VersionObject::parse_with_format(&args.version, &args.input_format)?;
-    let zerv = match version_object {
+    let mut zerv: Zerv = match version_object {
         VersionObject::SemVer(semver) => semver.into(),
         VersionObject::PEP440(pep440) => pep440.into(),
     };
-    let output = OutputFormatter::format_output(
-        &zerv,
-        &args.output.output_format,
-        args.output.output_prefix.as_deref(),
-        &args.output.output_template,
-    )?;
+    if args.strip_context {
+        zerv.schema.set_build(Vec::new())?;
+    }
+    let output = OutputFormatter::format_with_config(&zerv, &args.output)?;
 
     Ok(output)
 }
@@ -39,10 +38,12 @@ mod tests {
         RenderArgs {
             version: version.to_string(),
             input_format: input_format.to_string(),
+            strip_context: false,
             output: OutputConfig {
                 output_format: output_format.to_string(),
                 output_template: template.map(|s| Template::new(s.to_string())),
                 output_prefix: prefix.map(|s| s.to_string()),
+                ..Default::default()
             },
         }
     }
@@ -165,10 +166,12 @@ mod tests {
         let args = RenderArgs {
             version: "1.2.3".to_string(),
             input_format: formats::SEMVER.to_string(),
+            strip_context: false,
             output: OutputConfig {
                 output_format: formats::SEMVER.to_string(),
                 output_template: Some(Template::new("v{{major}}".to_string())),
                 output_prefix: Some("release-".to_string()),
+                ..Default::default()
             },
         };
         let result = run_render(args);
@@ -345,6 +348,33 @@ mod tests {
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[rstest]
+    #[case("1.2.3+main.5.gabc", formats::SEMVER, formats::SEMVER, "1.2.3")]
+    #[case(
+        "1.2.3-alpha.1+main.5.gabc",
+        formats::AUTO,
+        formats::SEMVER,
+        "1.2.3-alpha.1"
+    )]
+    #[case(
+        "1.2.3a1.post2+main.5",
+        formats::PEP440,
+        formats::PEP440,
+        "1.2.3a1.post2"
+    )]
+    #[case("1.2.3+main.5.gabc", formats::SEMVER, formats::PEP440, "1.2.3")]
+    #[case("1.2.3", formats::SEMVER, formats::SEMVER, "1.2.3")]
+    fn test_run_render_strip_context(
+        #[case] version: &str,
+        #[case] input_format: &str,
+        #[case] output_format: &str,
+        #[case] expected: &str,
+    ) {
+        let mut args = create_args(version, input_format, output_format, None, None);
+        args.strip_context = true;
+        assert_eq!(run_render(args).unwrap(), expected);
+    }
+
     #[rstest]
     #[case("not-a-version", formats::SEMVER)]
     #[case("invalid", formats::PEP440)]
@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use clap::Parser;
+use serde::Serialize;
+
+use crate::cli::common::args::{
+    InputConfig,
+    OutputConfig,
+};
+use crate::cli::utils::output_formatter::OutputFormatter;
+use crate::cli::version::{
+    VersionArgs,
+    process_git_source,
+};
+use crate::error::ZervError;
+use crate::utils::constants::{
+    formats,
+    sources,
+};
+
+#[derive(Parser, Debug, Default)]
+pub struct ReportArgs {
+    /// Repository directories to report on
+    #[arg(
+        required = true,
+        value_name = "DIR",
+        help = "Directories of the git repositories to report on, in output order"
+    )]
+    pub paths: Vec<String>,
+
+    /// Format of each reported version
+    #[arg(
+        long = "output-format",
+        default_value = formats::SEMVER,
+        value_parser = [formats::SEMVER, formats::PEP440],
+        help = "Format of each version: 'semver' (default) or 'pep440'"
+    )]
+    pub output_format: String,
+}
+
+/// Version of one repository in a report, or the reason it could not be read
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReportEntry {
+    /// Directory as given on the command line
+    pub path: String,
+    /// Version computed as `zerv version` would
+    pub version: Option<String>,
+    /// Last version tag reachable from HEAD
+    pub tag: Option<String>,
+    /// Commits since the tag
+    pub distance: Option<u64>,
+    /// Whether the working tree has uncommitted changes
+    pub dirty: Option<bool>,
+    /// Why the directory could not be reported on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ReportEntry {
+    fn failed(path: &str, error: ZervError) -> Self {
+        Self {
+            path: path.to_string(),
+            version: None,
+            tag: None,
+            distance: None,
+            dirty: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// Print the version of every repository in `args.paths` as a JSON array
+pub fn run_report(args: ReportArgs) -> Result<String, ZervError> {
+    let entries: Vec<ReportEntry> = args
+        .paths
+        .iter()
+        .map(|path| {
+            report_entry(path, &args.output_format)
+                .unwrap_or_else(|error| ReportEntry::failed(path, error))
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to serialize report: {e}")))
+}
+
+fn report_entry(path: &str, output_format: &str) -> Result<ReportEntry, ZervError> {
+    let mut version_args = VersionArgs {
+        input: InputConfig {
+            source: Some(sources::GIT.to_string()),
+            directory: Some(path.to_string()),
+            ..Default::default()
+        },
+        output: OutputConfig {
+            output_format: output_format.to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    version_args.validate(None)?;
+
+    let draft = process_git_source(Path::new(path), &version_args)?;
+    let tag = draft.vars.last_tag_version.clone();
+    let distance = draft.vars.distance;
+    let dirty = draft.vars.dirty;
+    let zerv = draft.to_zerv(&version_args)?;
+    let version = OutputFormatter::format_with_scope(
+        &zerv,
+        &version_args.output,
+        None,
+        version_args.input.commit_prefix.as_deref(),
+    )?;
+
+    Ok(ReportEntry {
+        path: path.to_string(),
+        version: Some(version),
+        tag,
+        distance,
+        dirty,
+        error: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        GitRepoFixture,
+        should_run_docker_tests,
+    };
+
+    #[test]
+    fn test_report_mixes_repos_and_error_entries() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+        let not_a_repo = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo_path = fixture.path().to_string_lossy().to_string();
+        let other_path = not_a_repo.path().to_string_lossy().to_string();
+
+        let output = run_report(ReportArgs {
+            paths: vec![repo_path.clone(), other_path.clone()],
+            output_format: formats::SEMVER.to_string(),
+        })
+        .unwrap();
+        let entries: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(entries[0]["path"], repo_path);
+        assert_eq!(entries[0]["version"], "1.2.3");
+        assert_eq!(entries[0]["tag"], "v1.2.3");
+        assert_eq!(entries[0]["distance"], 0);
+        assert_eq!(entries[0]["dirty"], false);
+        assert!(entries[0].get("error").is_none());
+
+        assert_eq!(entries[1]["path"], other_path);
+        assert!(entries[1]["version"].is_null());
+        assert!(entries[1]["error"].is_string());
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use clap::Parser;
+
+use crate::error::ZervError;
+use crate::utils::constants::format_names;
+use crate::version::Zerv;
+use crate::version::pep440::PEP440;
+use crate::version::semver::SemVer;
+
+/// Representative SemVer versions, covering each component the conversion maps
+const SEMVER_SAMPLES: &[&str] = &[
+    "0.0.0",
+    "1.2.3",
+    "1.0.0-alpha.1",
+    "1.0.0-beta.2",
+    "1.0.0-rc.3",
+    "1.2.3-alpha",
+    "1.2.3-post.4",
+    "1.2.3-dev.5",
+    "1.2.3-alpha.1.post.2.dev.3",
+    "1.2.3+main.7.gabc1234",
+    "1.2.3-rc.1+feature.login.2.gdeadbee",
+];
+
+/// Representative PEP440 versions, covering each component the conversion maps
+const PEP440_SAMPLES: &[&str] = &[
+    "0.0.0",
+    "1.2.3",
+    "1!2.0.0",
+    "1.0.0a1",
+    "1.0.0b2",
+    "1.0.0rc3",
+    "1.2.3.post4",
+    "1.2.3.dev5",
+    "1.2.3a1.post2.dev3",
+    "1.2.3+main.7.gabc1234",
+    "1!1.2.3rc1+feature.login.2.gdeadbee",
+];
+
+#[derive(Parser, Debug, Default)]
+pub struct SelftestArgs {}
+
+/// Round-trip the sample versions through Zerv and report any discrepancy
+pub fn run_selftest(_args: SelftestArgs) -> Result<String, ZervError> {
+    let mut discrepancies = Vec::new();
+    for version in SEMVER_SAMPLES {
+        discrepancies.extend(check_round_trip::<SemVer, PEP440>(
+            version,
+            format_names::SEMVER,
+            format_names::PEP440,
+        ));
+    }
+    for version in PEP440_SAMPLES {
+        discrepancies.extend(check_round_trip::<PEP440, SemVer>(
+            version,
+            format_names::PEP440,
+            format_names::SEMVER,
+        ));
+    }
+
+    let total = SEMVER_SAMPLES.len() + PEP440_SAMPLES.len();
+    if discrepancies.is_empty() {
+        Ok(format!(
+            "✓ {total} versions round-trip through Zerv ({} and {})",
+            format_names::SEMVER,
+            format_names::PEP440
+        ))
+    } else {
+        Err(ZervError::InvalidVersion(format!(
+            "{} round-trip discrepancies in {total} versions:\n{}",
+            discrepancies.len(),
+            discrepancies.join("\n")
+        )))
+    }
+}
+
+/// Check that `version` survives parse → to_zerv → from_zerv → render in its own format
+/// (`T`) and that its conversion to the other format (`O`) is stable under the same trip
+fn check_round_trip<T, O>(version: &str, format: &str, other_format: &str) -> Vec<String>
+where
+    T: FromStr + Display + From<Zerv>,
+    O: FromStr + Display + From<Zerv>,
+    Zerv: From<T> + From<O>,
+{
+    let Ok(parsed) = T::from_str(version) else {
+        return vec![format!("{format} {version}: does not parse")];
+    };
+    let rendered = parsed.to_string();
+    let zerv = Zerv::from(parsed);
+
+    let mut discrepancies = Vec::new();
+    if let Some(issue) = same_format_trip::<T>(&rendered) {
+        discrepancies.push(format!("{format} {version}: {issue}"));
+    }
+    let converted = O::from(zerv).to_string();
+    if let Some(issue) = same_format_trip::<O>(&converted) {
+        discrepancies.push(format!(
+            "{format} {version} as {other_format} {converted}: {issue}"
+        ));
+    }
+    discrepancies
+}
+
+/// Render `rendered` after a trip through Zerv, describing any difference
+fn same_format_trip<T>(rendered: &str) -> Option<String>
+where
+    T: FromStr + Display + From<Zerv>,
+    Zerv: From<T>,
+{
+    let Ok(parsed) = T::from_str(rendered) else {
+        return Some(format!("rendered {rendered} does not parse"));
+    };
+    let round_tripped = T::from(Zerv::from(parsed)).to_string();
+    (round_tripped != rendered)
+        .then(|| format!("renders {round_tripped} after to_zerv → from_zerv, expected {rendered}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_selftest_passes() {
+        let output = run_selftest(SelftestArgs::default()).unwrap();
+        assert!(output.starts_with('✓'), "{output}");
+    }
+
+    #[test]
+    fn test_check_round_trip_reports_unparsable_version() {
+        let discrepancies = check_round_trip::<SemVer, PEP440>(
+            "not-a-version",
+            format_names::SEMVER,
+            format_names::PEP440,
+        );
+        assert_eq!(discrepancies, ["SemVer not-a-version: does not parse"]);
+    }
+}
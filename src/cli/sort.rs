@@ -0,0 +1,266 @@
+use std::str::FromStr;
+
+use clap::Parser;
+
+use crate::error::ZervError;
+use crate::utils::constants::{
+    SUPPORTED_FORMAT_NAMES,
+    format_names,
+    formats,
+};
+use crate::version::pep440::PEP440;
+use crate::version::semver::SemVer;
+
+#[derive(Parser, Debug)]
+pub struct SortArgs {
+    /// Format used to parse and order the versions
+    #[arg(short = 'f', long = "input-format", default_value = formats::AUTO, value_parser = [formats::AUTO, formats::SEMVER, formats::PEP440],
+          help = "Input format: 'auto' (all versions must parse as one format), 'semver', or 'pep440'")]
+    pub input_format: String,
+
+    /// Sort from highest to lowest
+    #[arg(short, long, help = "Sort versions from highest to lowest")]
+    pub reverse: bool,
+}
+
+impl Default for SortArgs {
+    fn default() -> Self {
+        Self {
+            input_format: formats::AUTO.to_string(),
+            reverse: false,
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+pub struct ReduceArgs {
+    /// Format used to parse and order the versions
+    #[arg(short = 'f', long = "input-format", default_value = formats::AUTO, value_parser = [formats::AUTO, formats::SEMVER, formats::PEP440],
+          help = "Input format: 'auto' (all versions must parse as one format), 'semver', or 'pep440'")]
+    pub input_format: String,
+}
+
+impl Default for ReduceArgs {
+    fn default() -> Self {
+        Self {
+            input_format: formats::AUTO.to_string(),
+        }
+    }
+}
+
+/// Sort newline-separated versions from stdin, printing them as given
+pub fn run_sort(args: SortArgs, stdin_content: Option<&str>) -> Result<String, ZervError> {
+    let mut sorted = sort_versions(&args.input_format, stdin_content, "sort")?;
+
+    if args.reverse {
+        sorted.reverse();
+    }
+
+    Ok(sorted.join("\n"))
+}
+
+/// Print the highest of the newline-separated versions from stdin
+pub fn run_max(args: ReduceArgs, stdin_content: Option<&str>) -> Result<String, ZervError> {
+    let sorted = sort_versions(&args.input_format, stdin_content, "compare")?;
+    Ok(sorted
+        .last()
+        .map(|version| version.to_string())
+        .unwrap_or_default())
+}
+
+/// Print the lowest of the newline-separated versions from stdin
+pub fn run_min(args: ReduceArgs, stdin_content: Option<&str>) -> Result<String, ZervError> {
+    let sorted = sort_versions(&args.input_format, stdin_content, "compare")?;
+    Ok(sorted
+        .first()
+        .map(|version| version.to_string())
+        .unwrap_or_default())
+}
+
+/// Parse the non-blank stdin lines and return them in ascending order
+fn sort_versions<'a>(
+    input_format: &str,
+    stdin_content: Option<&'a str>,
+    action: &str,
+) -> Result<Vec<&'a str>, ZervError> {
+    let versions: Vec<&str> = stdin_content
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if versions.is_empty() {
+        return Err(ZervError::StdinError(format!(
+            "No versions provided on stdin to {action}"
+        )));
+    }
+
+    let sorted = match input_format {
+        formats::SEMVER => sort_as::<SemVer>(&versions, format_names::SEMVER)?,
+        formats::PEP440 => sort_as::<PEP440>(&versions, format_names::PEP440)?,
+        formats::AUTO => sort_as::<SemVer>(&versions, format_names::SEMVER)
+            .or_else(|_| sort_as::<PEP440>(&versions, format_names::PEP440))
+            .map_err(|_| {
+                ZervError::InvalidVersion(format!(
+                    "Versions do not all parse as one of {}; choose one with --input-format",
+                    SUPPORTED_FORMAT_NAMES.join(", ")
+                ))
+            })?,
+        format => {
+            return Err(ZervError::UnknownFormat(format!(
+                "{format}. Supported formats: {}",
+                formats::SUPPORTED_FORMATS.join(", ")
+            )));
+        }
+    };
+
+    Ok(sorted)
+}
+
+/// Parse every version as `T` and sort the original strings by the parsed order
+fn sort_as<'a, T: FromStr + Ord>(
+    versions: &[&'a str],
+    format_name: &str,
+) -> Result<Vec<&'a str>, ZervError> {
+    let mut parsed = versions
+        .iter()
+        .map(|version| {
+            T::from_str(version)
+                .map(|parsed| (parsed, *version))
+                .map_err(|_| {
+                    ZervError::InvalidVersion(format!("{version} - Invalid {format_name} format"))
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    parsed.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(parsed.into_iter().map(|(_, version)| version).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn sort(input: &str, input_format: &str, reverse: bool) -> Result<String, ZervError> {
+        run_sort(
+            SortArgs {
+                input_format: input_format.to_string(),
+                reverse,
+            },
+            Some(input),
+        )
+    }
+
+    #[rstest]
+    #[case::semver(
+        "1.10.0\n1.2.0\n1.2.0-rc.1\n1.2.0-alpha.10\n1.2.0-alpha.2\n0.9.0",
+        formats::SEMVER,
+        "0.9.0\n1.2.0-alpha.2\n1.2.0-alpha.10\n1.2.0-rc.1\n1.2.0\n1.10.0"
+    )]
+    #[case::pep440(
+        "1.0.0.post1\n1.0.0\n1.0.0rc1\n1.0.0a1\n1.0.0rc1.dev1\n1!0.1.0",
+        formats::PEP440,
+        "1.0.0a1\n1.0.0rc1.dev1\n1.0.0rc1\n1.0.0\n1.0.0.post1\n1!0.1.0"
+    )]
+    #[case::auto_semver("2.0.0\n1.0.0-beta\n1.0.0", formats::AUTO, "1.0.0-beta\n1.0.0\n2.0.0")]
+    #[case::auto_pep440("1.0.0\n1.0.0rc1\n0.5", formats::AUTO, "0.5\n1.0.0rc1\n1.0.0")]
+    fn test_sort(#[case] input: &str, #[case] input_format: &str, #[case] expected: &str) {
+        assert_eq!(sort(input, input_format, false).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sort_reverse() {
+        assert_eq!(
+            sort("1.0.0\n3.0.0\n2.0.0", formats::SEMVER, true).unwrap(),
+            "3.0.0\n2.0.0\n1.0.0"
+        );
+    }
+
+    #[test]
+    fn test_sort_skips_blank_lines() {
+        assert_eq!(
+            sort("\n  2.0.0  \n\n1.0.0\n", formats::AUTO, false).unwrap(),
+            "1.0.0\n2.0.0"
+        );
+    }
+
+    #[rstest]
+    #[case::mixed_auto("1.0.0-alpha.beta\n1!1.0.0", formats::AUTO)]
+    #[case::invalid_semver("1.0.0\n1.0.0rc1", formats::SEMVER)]
+    #[case::invalid_pep440("1.0.0\nnot-a-version", formats::PEP440)]
+    fn test_sort_invalid(#[case] input: &str, #[case] input_format: &str) {
+        assert!(matches!(
+            sort(input, input_format, false),
+            Err(ZervError::InvalidVersion(_))
+        ));
+    }
+
+    #[rstest]
+    #[case::none(None)]
+    #[case::blank(Some("\n  \n"))]
+    fn test_sort_requires_stdin(#[case] input: Option<&str>) {
+        assert!(matches!(
+            run_sort(SortArgs::default(), input),
+            Err(ZervError::StdinError(_))
+        ));
+    }
+
+    mod reduce {
+        use super::*;
+
+        fn args(input_format: &str) -> ReduceArgs {
+            ReduceArgs {
+                input_format: input_format.to_string(),
+            }
+        }
+
+        #[rstest]
+        #[case::release_above_rc("1.0.0\n1.0.0-rc.1", formats::SEMVER, "1.0.0", "1.0.0-rc.1")]
+        #[case::numeric_components("1.9.0\n1.10.0\n1.2.0", formats::AUTO, "1.10.0", "1.2.0")]
+        #[case::pep440(
+            "1.0.0rc1\n1.0.0.post1\n1.0.0",
+            formats::PEP440,
+            "1.0.0.post1",
+            "1.0.0rc1"
+        )]
+        #[case::single("2.0.0", formats::AUTO, "2.0.0", "2.0.0")]
+        fn test_max_min(
+            #[case] input: &str,
+            #[case] input_format: &str,
+            #[case] expected_max: &str,
+            #[case] expected_min: &str,
+        ) {
+            assert_eq!(
+                run_max(args(input_format), Some(input)).unwrap(),
+                expected_max
+            );
+            assert_eq!(
+                run_min(args(input_format), Some(input)).unwrap(),
+                expected_min
+            );
+        }
+
+        #[rstest]
+        #[case::none(None)]
+        #[case::blank(Some("\n"))]
+        fn test_max_min_empty_input(#[case] input: Option<&str>) {
+            assert!(matches!(
+                run_max(ReduceArgs::default(), input),
+                Err(ZervError::StdinError(_))
+            ));
+            assert!(matches!(
+                run_min(ReduceArgs::default(), input),
+                Err(ZervError::StdinError(_))
+            ));
+        }
+
+        #[test]
+        fn test_max_invalid_version() {
+            assert!(matches!(
+                run_max(args(formats::SEMVER), Some("1.0.0\nnope")),
+                Err(ZervError::InvalidVersion(_))
+            ));
+        }
+    }
+}
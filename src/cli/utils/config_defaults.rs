@@ -0,0 +1,279 @@
+use std::path::PathBuf;
+
+use clap::parser::ValueSource;
+use clap::{
+    ArgMatches,
+    Command,
+    CommandFactory,
+};
+
+use crate::cli::parser::Cli;
+use crate::config::{
+    ZervConfig,
+    load_config_defaults,
+};
+use crate::error::ZervError;
+use crate::vcs::find_vcs_root;
+
+/// Insert option defaults from the config files into the raw command line
+///
+/// Precedence is CLI > repo config > global config > built-in defaults: an option
+/// given on the command line, or one conflicting with it, is never taken from config.
+pub fn apply_config_defaults(args: Vec<String>) -> Result<Vec<String>, ZervError> {
+    let Some(subcommand_index) = subcommand_index(&args) else {
+        return Ok(args);
+    };
+    // Read the repo config from the root of the target repository: --repo as given,
+    // otherwise the repository containing -C/--directory or the working directory
+    let matches = subcommand_matches(&args).map(|(_, matches)| matches);
+    let option = |id: &str| {
+        matches
+            .as_ref()
+            .and_then(|matches| matches.try_get_one::<String>(id).ok().flatten().cloned())
+    };
+    let repo_dir = match (option("repo"), option("directory")) {
+        (Some(repo), _) => PathBuf::from(repo),
+        (None, directory) => {
+            let start = match directory {
+                Some(dir) => PathBuf::from(dir),
+                None => std::env::current_dir()?,
+            };
+            // Outside a repository, the config sits in the start directory itself
+            find_vcs_root(&start).unwrap_or(start)
+        }
+    };
+    let defaults = load_config_defaults(
+        &args[subcommand_index],
+        ZervConfig::global_config_path().as_deref(),
+        &repo_dir,
+    )?;
+    merge_config_defaults(args, subcommand_index, &defaults)
+}
+
+/// Position of the subcommand: the first argument after the program name that is neither
+/// an option nor the value of a top-level option (`zerv --env-file ci.env version`)
+pub(crate) fn subcommand_index(args: &[String]) -> Option<usize> {
+    let command = Cli::command();
+    let mut index = 1;
+    while let Some(arg) = args.get(index) {
+        if !arg.starts_with('-') {
+            return Some(index);
+        }
+        index += if takes_separate_value(&command, arg) {
+            2
+        } else {
+            1
+        };
+    }
+    None
+}
+
+/// Whether `arg` is an option of `command` whose value is the next argument
+fn takes_separate_value(command: &Command, arg: &str) -> bool {
+    let option = match arg.strip_prefix("--") {
+        Some(long) => command
+            .get_arguments()
+            .find(|option| option.get_long() == Some(long)),
+        None => {
+            let mut shorts = arg[1..].chars();
+            match (shorts.next(), shorts.next()) {
+                (Some(short), None) => command
+                    .get_arguments()
+                    .find(|option| option.get_short() == Some(short)),
+                _ => None,
+            }
+        }
+    };
+    option.is_some_and(|option| option.get_action().takes_values())
+}
+
+/// Subcommand name and matches of a lenient parse, which tolerates the missing
+/// options config may still supply; None when clap cannot make sense of the line
+fn subcommand_matches(args: &[String]) -> Option<(String, ArgMatches)> {
+    let matches = Cli::command()
+        .ignore_errors(true)
+        .try_get_matches_from(args)
+        .ok()?;
+    let (name, matches) = matches.subcommand()?;
+    Some((name.to_string(), matches.clone()))
+}
+
+/// Whether the command line sets the option `long` or any option conflicting with it
+fn given_on_cli(command: &Command, matches: &ArgMatches, long: &str) -> bool {
+    let Some(option) = command
+        .get_arguments()
+        .find(|option| option.get_long() == Some(long))
+    else {
+        return false;
+    };
+    command
+        .get_arguments()
+        .filter(|given| {
+            matches.value_source(given.get_id().as_str()) == Some(ValueSource::CommandLine)
+        })
+        .any(|given| {
+            given.get_id() == option.get_id()
+                || command
+                    .get_arg_conflicts_with(option)
+                    .iter()
+                    .any(|conflict| conflict.get_id() == given.get_id())
+                || command
+                    .get_arg_conflicts_with(given)
+                    .iter()
+                    .any(|conflict| conflict.get_id() == option.get_id())
+        })
+}
+
+pub(crate) fn merge_config_defaults(
+    mut args: Vec<String>,
+    subcommand_index: usize,
+    defaults: &toml::Table,
+) -> Result<Vec<String>, ZervError> {
+    let mut command = Cli::command();
+    command.build();
+    let cli = subcommand_matches(&args).and_then(|(name, matches)| {
+        command
+            .find_subcommand(&name)
+            .map(|subcommand| (subcommand, matches))
+    });
+
+    let mut injected = Vec::new();
+    for (key, value) in defaults {
+        let long = key.replace('_', "-");
+        let overridden = cli
+            .as_ref()
+            .is_some_and(|(subcommand, matches)| given_on_cli(subcommand, matches, &long));
+        if !overridden {
+            push_option(&mut injected, &format!("--{long}"), value)?;
+        }
+    }
+    args.splice(subcommand_index + 1..subcommand_index + 1, injected);
+    Ok(args)
+}
+
+fn push_option(args: &mut Vec<String>, flag: &str, value: &toml::Value) -> Result<(), ZervError> {
+    match value {
+        toml::Value::Boolean(true) => args.push(flag.to_string()),
+        toml::Value::Boolean(false) => {}
+        toml::Value::String(value) => args.extend([flag.to_string(), value.clone()]),
+        toml::Value::Integer(value) => args.extend([flag.to_string(), value.to_string()]),
+        toml::Value::Float(value) => args.extend([flag.to_string(), value.to_string()]),
+        toml::Value::Array(values) => {
+            for value in values {
+                push_option(args, flag, value)?;
+            }
+        }
+        toml::Value::Datetime(_) | toml::Value::Table(_) => {
+            return Err(ZervError::InvalidFormat(format!(
+                "Config value for '{flag}' must be a string, number, boolean or array"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn table(content: &str) -> toml::Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[rstest]
+    #[case::no_subcommand(&["zerv", "--llm-help"], None)]
+    #[case::plain(&["zerv", "version"], Some(1))]
+    #[case::after_global_flag(&["zerv", "-v", "flow", "--source", "none"], Some(2))]
+    #[case::after_global_option(&["zerv", "--env-file", "ci.env", "version"], Some(3))]
+    #[case::after_global_option_equals(&["zerv", "--env-file=ci.env", "version"], Some(2))]
+    #[case::only_global_option(&["zerv", "--env-file", "ci.env"], None)]
+    fn test_subcommand_index(#[case] args: &[&str], #[case] expected: Option<usize>) {
+        assert_eq!(subcommand_index(&to_args(args)), expected);
+    }
+
+    #[test]
+    fn test_merge_config_defaults_value_types() {
+        let defaults = table(
+            r#"
+            output_format = "pep440"
+            dirty = true
+            no-dirty = false
+            distance = 3
+            bump-core = ["0", "1"]
+            "#,
+        );
+        let args = merge_config_defaults(to_args(&["zerv", "version"]), 1, &defaults).unwrap();
+        assert_eq!(
+            args,
+            to_args(&[
+                "zerv",
+                "version",
+                "--bump-core",
+                "0",
+                "--bump-core",
+                "1",
+                "--dirty",
+                "--distance",
+                "3",
+                "--output-format",
+                "pep440",
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::separate(&["zerv", "version", "--output-format", "semver"])]
+    #[case::equals(&["zerv", "version", "--output-format=semver"])]
+    fn test_merge_config_defaults_cli_wins(#[case] args: &[&str]) {
+        let defaults = table(r#"output-format = "pep440""#);
+        let merged = merge_config_defaults(to_args(args), 1, &defaults).unwrap();
+        assert_eq!(merged, to_args(args));
+    }
+
+    #[rstest]
+    #[case::short_flag(r#"source = "none""#, &["zerv", "version", "-s", "stdin"])]
+    #[case::short_flag_attached(r#"directory = "/repo""#, &["zerv", "version", "-C/other"])]
+    #[case::conflicting_option(
+        r#"schema = "calver""#,
+        &["zerv", "version", "--schema-ron", "(core: [var(Major)], extra_core: [], build: [])"]
+    )]
+    #[case::declared_conflict("fail-on-dirty = true", &["zerv", "version", "--no-fail-on-dirty"])]
+    #[case::reverse_conflict("no-fail-on-dirty = true", &["zerv", "version", "--fail-on-dirty"])]
+    #[case::after_global_option(
+        r#"source = "none""#,
+        &["zerv", "--env-file", "ci.env", "version", "--source", "stdin"]
+    )]
+    fn test_merge_config_defaults_skips_given_options(
+        #[case] defaults: &str,
+        #[case] args: &[&str],
+    ) {
+        let args = to_args(args);
+        let index = subcommand_index(&args).unwrap();
+        let merged = merge_config_defaults(args.clone(), index, &table(defaults)).unwrap();
+        assert_eq!(merged, args);
+    }
+
+    #[test]
+    fn test_merge_config_defaults_keeps_unrelated_options() {
+        let defaults = table("fail-on-dirty = true");
+        let args = to_args(&["zerv", "version", "--schema", "calver"]);
+        let merged = merge_config_defaults(args, 1, &defaults).unwrap();
+        assert_eq!(
+            merged,
+            to_args(&["zerv", "version", "--fail-on-dirty", "--schema", "calver"])
+        );
+    }
+
+    #[test]
+    fn test_merge_config_defaults_rejects_tables() {
+        let defaults = table("[schema]\nname = \"calver\"");
+        let result = merge_config_defaults(to_args(&["zerv", "version"]), 1, &defaults);
+        assert!(matches!(result, Err(ZervError::InvalidFormat(_))));
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use clap::CommandFactory;
+
+use crate::cli::parser::Cli;
+use crate::cli::utils::config_defaults::{
+    merge_config_defaults,
+    subcommand_index,
+};
+use crate::error::ZervError;
+
+/// Prefix of the dotenv variables that map to options (`ZERV_TAG_VERSION` -> `--tag-version`)
+const ENV_PREFIX: &str = "ZERV_";
+
+/// Insert options from the `--env-file` dotenv file into the raw command line
+///
+/// Each `ZERV_<OPTION>` variable becomes `--<option>` for the subcommand, unless the
+/// option is already given on the command line. Variables naming no option of the
+/// subcommand (e.g. `ZERV_FORCE_RUST_LOG_OFF`) are ignored. `true`/`false` switch a
+/// flag on or off.
+pub fn apply_env_file(args: Vec<String>) -> Result<Vec<String>, ZervError> {
+    let Some(path) = env_file_arg(&args) else {
+        return Ok(args);
+    };
+    let Some(subcommand_index) = subcommand_index(&args) else {
+        return Ok(args);
+    };
+
+    let known_options = subcommand_options(&args[subcommand_index]);
+    let mut defaults = toml::Table::new();
+    for (key, value) in read_env_file(path)? {
+        let Some(option) = key.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        let option = option.to_ascii_lowercase().replace('_', "-");
+        if known_options.contains(&option) {
+            defaults.insert(option, env_value(value));
+        }
+    }
+    merge_config_defaults(args, subcommand_index, &defaults)
+}
+
+/// Value of `--env-file`, which may appear before or after the subcommand
+fn env_file_arg(args: &[String]) -> Option<&str> {
+    args.iter().enumerate().find_map(|(index, arg)| {
+        if arg == "--env-file" {
+            args.get(index + 1).map(String::as_str)
+        } else {
+            arg.strip_prefix("--env-file=")
+        }
+    })
+}
+
+fn read_env_file(path: &str) -> Result<Vec<(String, String)>, ZervError> {
+    let invalid = |e: dotenvy::Error| {
+        ZervError::InvalidFormat(format!("Failed to read env file '{path}': {e}"))
+    };
+    dotenvy::from_path_iter(path)
+        .map_err(invalid)?
+        .map(|item| item.map_err(invalid))
+        .collect()
+}
+
+/// Long option names accepted by the subcommand
+fn subcommand_options(subcommand: &str) -> HashSet<String> {
+    Cli::command()
+        .find_subcommand(subcommand)
+        .map(|command| {
+            command
+                .get_arguments()
+                .filter_map(|arg| arg.get_long().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn env_value(value: String) -> toml::Value {
+    match value.as_str() {
+        "true" => toml::Value::Boolean(true),
+        "false" => toml::Value::Boolean(false),
+        _ => toml::Value::String(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn with_env_file(content: &str, args: &[&str]) -> Result<Vec<String>, ZervError> {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("ci.env");
+        fs::write(&path, content).unwrap();
+        let path = path.to_string_lossy().to_string();
+        let args = args.iter().map(|arg| arg.replace("{env}", &path)).collect();
+        apply_env_file(args).map(|args| {
+            args.into_iter()
+                .map(|arg| arg.replace(&path, "{env}"))
+                .collect()
+        })
+    }
+
+    #[rstest]
+    #[case::separate(&["zerv", "version", "--env-file", "ci.env"], Some("ci.env"))]
+    #[case::equals(&["zerv", "version", "--env-file=ci.env"], Some("ci.env"))]
+    #[case::before_subcommand(&["zerv", "--env-file", "ci.env", "version"], Some("ci.env"))]
+    #[case::absent(&["zerv", "version"], None)]
+    fn test_env_file_arg(#[case] args: &[&str], #[case] expected: Option<&str>) {
+        assert_eq!(env_file_arg(&to_args(args)), expected);
+    }
+
+    #[test]
+    fn test_apply_env_file_inserts_options() {
+        let args = with_env_file(
+            "ZERV_TAG_VERSION=1.2.3\nZERV_DIRTY=true\nZERV_CLEAN=false\nZERV_FORCE_RUST_LOG_OFF=1\nOTHER=x\n",
+            &["zerv", "version", "--env-file", "{env}"],
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            to_args(&[
+                "zerv",
+                "version",
+                "--dirty",
+                "--tag-version",
+                "1.2.3",
+                "--env-file",
+                "{env}",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_apply_env_file_cli_wins() {
+        let args = with_env_file(
+            "ZERV_TAG_VERSION=1.2.3\n",
+            &[
+                "zerv",
+                "version",
+                "--tag-version",
+                "2.0.0",
+                "--env-file",
+                "{env}",
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            to_args(&[
+                "zerv",
+                "version",
+                "--tag-version",
+                "2.0.0",
+                "--env-file",
+                "{env}"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_apply_env_file_missing_file() {
+        let result = apply_env_file(to_args(&[
+            "zerv",
+            "version",
+            "--env-file",
+            "/nonexistent/ci.env",
+        ]));
+        assert!(matches!(result, Err(ZervError::InvalidFormat(_))));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod config_defaults;
+pub mod env_file;
 pub mod format_handler;
 pub mod output_formatter;
 pub mod template;
@@ -1,12 +1,50 @@
+use std::collections::hash_map::RandomState;
+use std::fs::OpenOptions;
+use std::hash::BuildHasher;
+use std::io::Write;
+use std::path::Path;
+
+use crate::cli::common::args::OutputConfig;
 use crate::cli::utils::template::{
     Template,
     TemplateExt,
 };
+use crate::config::EnvVars;
 use crate::error::ZervError;
-use crate::utils::constants::formats;
+use crate::utils::constants::{
+    commit_hash_prefix,
+    context_separators,
+    formats,
+    output_fields,
+    output_terminators,
+    prerelease_cases,
+    sbom_formats,
+    tag_scope,
+};
+use crate::utils::sanitize::{
+    Sanitizer,
+    SanitizerProfile,
+};
 use crate::version::Zerv;
 use crate::version::pep440::PEP440;
 use crate::version::semver::SemVer;
+use crate::version::zerv::Var;
+
+/// Prefix of the per-write delimiter for multi-line GitHub Actions output values
+const GITHUB_OUTPUT_DELIMITER_PREFIX: &str = "ZERV_EOF_";
+
+/// Output spellings of the version labels (--prerelease-case, --post-label, --dev-label,
+/// --omit-zero-prerelease-num)
+#[derive(Debug, Default)]
+struct OutputLabels<'a> {
+    prerelease_case: Option<&'a str>,
+    post_label: Option<&'a str>,
+    dev_label: Option<&'a str>,
+    /// Drop a zero pre-release number (--omit-zero-prerelease-num)
+    omit_zero_prerelease_num: bool,
+    /// Sanitizer for semver build metadata (--sanitizer-profile)
+    context_sanitizer: Option<Sanitizer>,
+}
 
 /// Output formatter for version strings with support for prefixes and templates
 pub struct OutputFormatter;
@@ -18,12 +56,38 @@ impl OutputFormatter {
         output_format: &str,
         output_prefix: Option<&str>,
         output_template: &Option<Template<String>>,
+    ) -> Result<String, ZervError> {
+        Self::format_output_with_labels(
+            zerv_object,
+            output_format,
+            output_prefix,
+            output_template,
+            true,
+            &OutputLabels::default(),
+        )
+    }
+
+    /// Like `format_output`, spelling the pre-release, post and dev labels per `labels`.
+    /// Undefined template variables fail under `template_strict` and render empty otherwise
+    fn format_output_with_labels(
+        zerv_object: &Zerv,
+        output_format: &str,
+        output_prefix: Option<&str>,
+        output_template: &Option<Template<String>>,
+        template_strict: bool,
+        labels: &OutputLabels<'_>,
     ) -> Result<String, ZervError> {
         // 1. Resolve template if provided, otherwise use standard format
         let mut output = if let Some(template) = output_template {
-            template.render_string(Some(zerv_object))?
+            if template_strict {
+                template.render_string(Some(zerv_object))?
+            } else {
+                template
+                    .render_lenient(Some(zerv_object))?
+                    .unwrap_or_default()
+            }
         } else {
-            Self::format_base_output(zerv_object, output_format)?
+            Self::format_base_output(zerv_object, output_format, labels)?
         };
 
         // 2. Apply prefix if specified
@@ -34,11 +98,347 @@ impl OutputFormatter {
         Ok(output)
     }
 
+    /// Format the Zerv object using a full output configuration, applying strict checks
+    pub fn format_with_config(
+        zerv_object: &Zerv,
+        output: &OutputConfig,
+    ) -> Result<String, ZervError> {
+        Self::format_with_scope(zerv_object, output, None, None)
+    }
+
+    /// Like `format_with_config`, re-attaching a monorepo tag scope to version output;
+    /// `commit_prefix` is the --commit-prefix the bumped commit hash was built with
+    pub fn format_with_scope(
+        zerv_object: &Zerv,
+        output: &OutputConfig,
+        scope_prefix: Option<&str>,
+        commit_prefix: Option<&str>,
+    ) -> Result<String, ZervError> {
+        if let Some(ref field) = output.field {
+            return Self::format_field(zerv_object, field);
+        }
+
+        let labels = OutputLabels {
+            prerelease_case: output.prerelease_case.as_deref(),
+            post_label: output.post_label.as_deref(),
+            dev_label: output.dev_label.as_deref(),
+            omit_zero_prerelease_num: output.omit_zero_prerelease_num,
+            context_sanitizer: output
+                .sanitizer_profile
+                .as_deref()
+                .map(SanitizerProfile::from_ron)
+                .transpose()?
+                .map(|profile| profile.to_sanitizer()),
+        };
+        // The prefix is added after the strict checks, which validate the version itself
+        let mut formatted = Self::format_output_with_labels(
+            zerv_object,
+            &output.output_format,
+            None,
+            &output.output_template,
+            output.template_strict,
+            &labels,
+        )?;
+
+        if let Some(ref separator) = output.context_separator
+            && output.output_template.is_none()
+        {
+            formatted = Self::apply_context_separator(&formatted, separator);
+        }
+
+        if output.strict_pep440 {
+            PEP440::parse_strict(&formatted)?;
+        }
+
+        if let Some(ref prefix) = output.output_prefix {
+            formatted = format!("{prefix}{formatted}");
+        }
+
+        // The scope is not part of the version, so it is added after strict checks
+        if let Some(ref sbom) = output.sbom {
+            let commit_prefix = commit_prefix.unwrap_or(commit_hash_prefix::DEFAULT);
+            formatted = Self::format_sbom(zerv_object, &formatted, sbom, commit_prefix)?;
+        } else if let Some(scope) = scope_prefix
+            && (output.output_template.is_some() || output.output_format != formats::ZERV)
+        {
+            formatted = format!("{scope}{}{formatted}", tag_scope::SEPARATOR);
+        }
+
+        if output.output_template.is_none() {
+            match output.output_format.as_str() {
+                formats::ENV => {
+                    formatted =
+                        Self::format_env(zerv_object, &formatted, output.env_prefix.as_deref())?;
+                }
+                formats::PROPERTIES => {
+                    formatted = Self::format_properties(zerv_object, &formatted)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(formatted)
+    }
+
+    /// Fail early on a --field name that selects nothing
+    pub fn check_field(field: &str) -> Result<(), ZervError> {
+        if field != output_fields::TAG_VERSION {
+            Self::field_var(field)?;
+        }
+        Ok(())
+    }
+
+    /// Variable selected by a --field name
+    pub fn field_var(field: &str) -> Result<Var, ZervError> {
+        if field == output_fields::COMMIT {
+            return Ok(Var::BumpedCommitHashShort);
+        }
+        if let Some(key) = field.strip_prefix(output_fields::CUSTOM_PREFIX) {
+            return Ok(Var::Custom(key.to_string()));
+        }
+        field.parse::<Var>().map_err(|_| {
+            ZervError::InvalidArgument(format!(
+                "Unknown --field '{field}'. Use a template variable name such as 'major', \
+                 'distance' or 'bumped_branch', '{}', '{}' or '{}<key>'",
+                output_fields::COMMIT,
+                output_fields::TAG_VERSION,
+                output_fields::CUSTOM_PREFIX
+            ))
+        })
+    }
+
+    /// Raw value of a single field, empty when unset
+    fn format_field(zerv_object: &Zerv, field: &str) -> Result<String, ZervError> {
+        if field == output_fields::TAG_VERSION {
+            return Ok(zerv_object
+                .vars
+                .last_tag_version
+                .clone()
+                .unwrap_or_default());
+        }
+        let raw = Sanitizer::str(None, false, true, None);
+        Ok(Self::field_var(field)?
+            .resolve_value(&zerv_object.vars, &raw)
+            .unwrap_or_default())
+    }
+
+    /// Shell-quoted `KEY='value'` lines with the version and its main fields
+    fn format_env(
+        zerv_object: &Zerv,
+        version: &str,
+        prefix: Option<&str>,
+    ) -> Result<String, ZervError> {
+        let prefix = prefix.unwrap_or_default();
+        let mut lines = vec![format!("{prefix}VERSION={}", Self::shell_quote(version))];
+        for (key, field) in [
+            ("MAJOR", "major"),
+            ("MINOR", "minor"),
+            ("PATCH", "patch"),
+            ("COMMIT", output_fields::COMMIT),
+            ("DISTANCE", "distance"),
+            ("DIRTY", "dirty"),
+        ] {
+            let value = Self::format_field(zerv_object, field)?;
+            lines.push(format!("{prefix}{key}={}", Self::shell_quote(&value)));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// Java `.properties` `key=value` lines with the same fields as `format_env`
+    fn format_properties(zerv_object: &Zerv, version: &str) -> Result<String, ZervError> {
+        let mut lines = vec![format!("version={}", Self::properties_escape(version))];
+        for field in [
+            "major",
+            "minor",
+            "patch",
+            output_fields::COMMIT,
+            "distance",
+            "dirty",
+        ] {
+            let value = Self::format_field(zerv_object, field)?;
+            lines.push(format!("{field}={}", Self::properties_escape(&value)));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// Escape a value the way `java.util.Properties.store` does, so it reads back unchanged
+    fn properties_escape(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for (i, c) in value.chars().enumerate() {
+            match c {
+                '\\' => escaped.push_str(r"\\"),
+                '\n' => escaped.push_str(r"\n"),
+                '\r' => escaped.push_str(r"\r"),
+                '\t' => escaped.push_str(r"\t"),
+                '\u{0c}' => escaped.push_str(r"\f"),
+                ':' | '=' | '#' | '!' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                // Leading whitespace would be dropped when the line is read
+                ' ' if i == 0 => escaped.push_str(r"\ "),
+                ' '..='~' => escaped.push(c),
+                // Properties files are ISO-8859-1, anything else as UTF-16 \uXXXX escapes
+                _ => {
+                    for unit in c.encode_utf16(&mut [0; 2]) {
+                        escaped.push_str(&format!("\\u{unit:04X}"));
+                    }
+                }
+            }
+        }
+        escaped
+    }
+
+    /// Single-quote a value for POSIX shells, escaping embedded single quotes
+    fn shell_quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+
+    /// JSON fragment with the version, commit and commit date in the SBOM's field names
+    fn format_sbom(
+        zerv_object: &Zerv,
+        version: &str,
+        sbom: &str,
+        commit_prefix: &str,
+    ) -> Result<String, ZervError> {
+        // Git hashes carry the --commit-prefix ('g' by default) in ZervVars
+        let commit_hash = zerv_object.vars.bumped_commit_hash.as_deref().map(|hash| {
+            hash.strip_prefix(commit_prefix)
+                .filter(|rest| rest.chars().all(|c| c.is_ascii_hexdigit()))
+                .unwrap_or(hash)
+        });
+        let timestamp = zerv_object
+            .vars
+            .bumped_timestamp
+            .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp as i64, 0))
+            .map(|date| date.format("%Y-%m-%dT%H:%M:%SZ").to_string());
+
+        let mut fragment = serde_json::Map::new();
+        match sbom {
+            sbom_formats::SPDX => {
+                fragment.insert("versionInfo".to_string(), version.into());
+                if let Some(hash) = commit_hash {
+                    fragment.insert(
+                        "sourceInfo".to_string(),
+                        format!("git commit {hash}").into(),
+                    );
+                }
+                if let Some(timestamp) = timestamp {
+                    fragment.insert("builtDate".to_string(), timestamp.into());
+                }
+            }
+            _ => {
+                fragment.insert("version".to_string(), version.into());
+                if let Some(hash) = commit_hash {
+                    let mut commit = serde_json::json!({ "uid": hash });
+                    if let Some(timestamp) = timestamp {
+                        commit["committer"] = serde_json::json!({ "timestamp": timestamp });
+                    }
+                    fragment.insert(
+                        "pedigree".to_string(),
+                        serde_json::json!({ "commits": [commit] }),
+                    );
+                }
+            }
+        }
+
+        serde_json::to_string_pretty(&fragment).map_err(|e| {
+            ZervError::InvalidFormat(format!("Failed to serialize SBOM fragment: {e}"))
+        })
+    }
+
+    /// Bytes written after the formatted output, as selected by --output-terminator
+    pub fn terminator(output: &OutputConfig) -> &'static str {
+        match output.output_terminator.as_str() {
+            output_terminators::NONE => "",
+            output_terminators::NULL => "\0",
+            _ => "\n",
+        }
+    }
+
+    /// Re-join the segments after '+' (build metadata / local version) with `separator`
+    fn apply_context_separator(version: &str, separator: &str) -> String {
+        match version.split_once('+') {
+            Some((base, context)) => format!(
+                "{base}+{}",
+                context.replace(context_separators::DEFAULT, separator)
+            ),
+            None => version.to_string(),
+        }
+    }
+
+    /// Append the final output to the file named by `GITHUB_OUTPUT` when --github-output is set
+    pub fn publish_github_output(output: &OutputConfig, value: &str) -> Result<(), ZervError> {
+        match output.github_output {
+            Some(ref name) => Self::append_github_output(name, value),
+            None => Ok(()),
+        }
+    }
+
+    /// Append `name=value` to the file named by `GITHUB_OUTPUT`
+    fn append_github_output(name: &str, value: &str) -> Result<(), ZervError> {
+        let path = std::env::var(EnvVars::GITHUB_OUTPUT)
+            .ok()
+            .filter(|path| !path.is_empty())
+            .ok_or_else(|| {
+                ZervError::InvalidArgument(format!(
+                    "--github-output requires the {} environment variable to be set",
+                    EnvVars::GITHUB_OUTPUT
+                ))
+            })?;
+        Self::write_github_output(Path::new(&path), name, value)
+    }
+
+    /// Write a GitHub Actions output entry, using the heredoc form for multi-line values
+    fn write_github_output(path: &Path, name: &str, value: &str) -> Result<(), ZervError> {
+        let entry = if value.contains('\n') {
+            let delimiter = Self::github_output_delimiter(value);
+            format!("{name}<<{delimiter}\n{value}\n{delimiter}\n")
+        } else {
+            format!("{name}={value}\n")
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        file.write_all(entry.as_bytes())?;
+        Ok(())
+    }
+
+    /// A random heredoc delimiter that no line of `value` can close early, so output taken
+    /// from repository contents (e.g. a tag message) cannot inject further outputs
+    fn github_output_delimiter(value: &str) -> String {
+        loop {
+            let nonce = RandomState::new().hash_one(std::process::id());
+            let delimiter = format!("{GITHUB_OUTPUT_DELIMITER_PREFIX}{nonce:016x}");
+            if !value.lines().any(|line| line == delimiter) {
+                return delimiter;
+            }
+        }
+    }
+
     /// Generate base output according to the specified format
-    fn format_base_output(zerv_object: &Zerv, output_format: &str) -> Result<String, ZervError> {
+    fn format_base_output(
+        zerv_object: &Zerv,
+        output_format: &str,
+        labels: &OutputLabels<'_>,
+    ) -> Result<String, ZervError> {
+        let case = labels.prerelease_case.unwrap_or(prerelease_cases::LOWER);
         match output_format {
-            formats::PEP440 => Ok(PEP440::from(zerv_object.clone()).to_string()),
-            formats::SEMVER => Ok(SemVer::from(zerv_object.clone()).to_string()),
+            formats::PEP440 => Ok(PEP440::from(zerv_object.clone()).to_string_with_labels(
+                case,
+                labels.post_label,
+                labels.dev_label,
+            )),
+            formats::SEMVER | formats::ENV | formats::PROPERTIES => {
+                let mut semver = match labels.context_sanitizer {
+                    Some(ref sanitizer) => {
+                        SemVer::from_zerv_with_context_sanitizer(zerv_object.clone(), sanitizer)
+                    }
+                    None => SemVer::from(zerv_object.clone()),
+                };
+                if labels.omit_zero_prerelease_num {
+                    semver = semver.without_zero_pre_release_num();
+                }
+                Ok(semver.to_string_with_labels(case, labels.post_label, labels.dev_label))
+            }
             formats::ZERV => Ok(zerv_object.to_string()),
             format => Err(ZervError::UnknownFormat(format!(
                 "Unknown output format: '{}'. Supported formats: {}",
@@ -167,6 +567,41 @@ mod tests {
         assert!(matches!(result, Err(ZervError::UnknownFormat(_))));
     }
 
+    #[rstest]
+    #[case(formats::PEP440, None, Ok("1.2.3"))]
+    #[case(
+        formats::SEMVER,
+        Some("{{pep440}}+{{bumped_branch}}"),
+        Ok("1.2.3+main")
+    )]
+    #[case(
+        formats::SEMVER,
+        Some("{{pep440}}+Feature_{{bumped_branch}}"),
+        Err("local segment 'Feature_main'")
+    )]
+    fn test_format_with_config_strict_pep440(
+        #[case] format: &str,
+        #[case] template: Option<&str>,
+        #[case] expected: Result<&str, &str>,
+    ) {
+        let zerv = create_test_zerv();
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            output_template: template.map(|t| t.into()),
+            strict_pep440: true,
+            ..Default::default()
+        };
+        let result = OutputFormatter::format_with_config(&zerv, &output);
+        match expected {
+            Ok(version) => assert_eq!(result.unwrap(), version),
+            Err(message) => {
+                let error = result.unwrap_err();
+                assert!(matches!(error, ZervError::InvalidVersion(_)));
+                assert!(error.to_string().contains(message), "{error}");
+            }
+        }
+    }
+
     #[test]
     fn test_supported_formats() {
         let formats = OutputFormatter::supported_formats();
@@ -175,4 +610,321 @@ mod tests {
         assert!(formats.contains(&formats::ZERV));
         assert_eq!(formats.len(), 3);
     }
+
+    #[rstest]
+    #[case::semver(formats::SEMVER, None, None, "frontend-1.2.3")]
+    #[case::pep440(formats::PEP440, None, None, "frontend-1.2.3")]
+    #[case::with_output_prefix(formats::SEMVER, Some("v"), None, "frontend-v1.2.3")]
+    #[case::template(formats::SEMVER, None, Some("{{ major }}.{{ minor }}"), "frontend-1.2")]
+    fn test_format_with_scope(
+        #[case] format: &str,
+        #[case] prefix: Option<&str>,
+        #[case] template: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let output = OutputConfig {
+            output_format: format.to_string(),
+            output_prefix: prefix.map(str::to_string),
+            output_template: template.map(|t| Template::new(t.to_string())),
+            ..Default::default()
+        };
+        let result = OutputFormatter::format_with_scope(
+            &create_test_zerv(),
+            &output,
+            Some("frontend"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::dash("1.2.3+main.5.gabc1234", "-", "1.2.3+main-5-gabc1234")]
+    #[case::underscore("1.2.3+main.5", "_", "1.2.3+main_5")]
+    #[case::dot("1.2.3+main.5", ".", "1.2.3+main.5")]
+    #[case::prerelease_untouched("1.2.3-alpha.1+main.5", "-", "1.2.3-alpha.1+main-5")]
+    #[case::no_context("1.2.3-alpha.1", "-", "1.2.3-alpha.1")]
+    fn test_apply_context_separator(
+        #[case] version: &str,
+        #[case] separator: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            OutputFormatter::apply_context_separator(version, separator),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::newline(output_terminators::NEWLINE, "\n")]
+    #[case::none(output_terminators::NONE, "")]
+    #[case::null(output_terminators::NULL, "\0")]
+    fn test_terminator(#[case] terminator: &str, #[case] expected: &str) {
+        let output = OutputConfig {
+            output_terminator: terminator.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(OutputFormatter::terminator(&output), expected);
+    }
+
+    #[rstest]
+    #[case::spdx(sbom_formats::SPDX, &["builtDate", "sourceInfo", "versionInfo"])]
+    #[case::cyclonedx(sbom_formats::CYCLONEDX, &["pedigree", "version"])]
+    fn test_format_sbom_keys(#[case] sbom: &str, #[case] expected_keys: &[&str]) {
+        let mut zerv = create_test_zerv();
+        zerv.vars.bumped_commit_hash = Some("gabc1234".to_string());
+        zerv.vars.bumped_timestamp = Some(1_700_000_000);
+
+        let result = OutputFormatter::format_sbom(&zerv, "1.2.3", sbom, "g").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(keys, expected_keys);
+
+        match sbom {
+            sbom_formats::SPDX => {
+                assert_eq!(json["versionInfo"], "1.2.3");
+                assert_eq!(json["sourceInfo"], "git commit abc1234");
+                assert_eq!(json["builtDate"], "2023-11-14T22:13:20Z");
+            }
+            _ => {
+                assert_eq!(json["version"], "1.2.3");
+                let commit = &json["pedigree"]["commits"][0];
+                assert_eq!(commit["uid"], "abc1234");
+                assert_eq!(commit["committer"]["timestamp"], "2023-11-14T22:13:20Z");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::default("g", "gabc1234")]
+    #[case::custom("c", "cabc1234")]
+    #[case::multi_char("sha-", "sha-abc1234")]
+    #[case::bare("", "abc1234")]
+    fn test_format_sbom_strips_commit_prefix(#[case] prefix: &str, #[case] hash: &str) {
+        let mut zerv = create_test_zerv();
+        zerv.vars.bumped_commit_hash = Some(hash.to_string());
+
+        let spdx =
+            OutputFormatter::format_sbom(&zerv, "1.2.3", sbom_formats::SPDX, prefix).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&spdx).unwrap();
+        assert_eq!(json["sourceInfo"], "git commit abc1234");
+
+        let cyclonedx =
+            OutputFormatter::format_sbom(&zerv, "1.2.3", sbom_formats::CYCLONEDX, prefix).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&cyclonedx).unwrap();
+        assert_eq!(json["pedigree"]["commits"][0]["uid"], "abc1234");
+    }
+
+    #[test]
+    fn test_format_with_config_strict_pep440_validates_before_prefix() {
+        let zerv = create_test_zerv();
+        let output = OutputConfig {
+            output_format: formats::PEP440.to_string(),
+            output_prefix: Some("v".to_string()),
+            strict_pep440: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            OutputFormatter::format_with_config(&zerv, &output).unwrap(),
+            "v1.2.3"
+        );
+    }
+
+    #[test]
+    fn test_format_sbom_without_commit_info() {
+        let mut zerv = create_test_zerv();
+        zerv.vars.bumped_commit_hash = None;
+        zerv.vars.bumped_timestamp = None;
+        let result =
+            OutputFormatter::format_sbom(&zerv, "1.2.3", sbom_formats::CYCLONEDX, "g").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(json, serde_json::json!({ "version": "1.2.3" }));
+    }
+
+    #[rstest]
+    #[case::major("major", "1")]
+    #[case::commit("commit", "abc123")]
+    #[case::custom("custom.build_id", "42")]
+    #[case::unset("dev", "")]
+    fn test_format_with_config_field(#[case] field: &str, #[case] expected: &str) {
+        let mut zerv = create_test_zerv();
+        zerv.vars.custom = serde_json::json!({ "build_id": 42 });
+        let output = OutputConfig {
+            field: Some(field.to_string()),
+            ..Default::default()
+        };
+        let result = OutputFormatter::format_with_config(&zerv, &output).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::none(None, "VERSION='1.2.3'")]
+    #[case::prefixed(Some("APP_"), "APP_VERSION='1.2.3'")]
+    fn test_format_with_config_env(#[case] prefix: Option<&str>, #[case] first_line: &str) {
+        let output = OutputConfig {
+            output_format: formats::ENV.to_string(),
+            env_prefix: prefix.map(str::to_string),
+            ..Default::default()
+        };
+        let result = OutputFormatter::format_with_config(&create_test_zerv(), &output).unwrap();
+        let prefix = prefix.unwrap_or_default();
+        assert_eq!(
+            result.lines().collect::<Vec<_>>(),
+            [
+                first_line.to_string(),
+                format!("{prefix}MAJOR='1'"),
+                format!("{prefix}MINOR='2'"),
+                format!("{prefix}PATCH='3'"),
+                format!("{prefix}COMMIT='abc123'"),
+                format!("{prefix}DISTANCE='0'"),
+                format!("{prefix}DIRTY='false'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_with_config_properties() {
+        let output = OutputConfig {
+            output_format: formats::PROPERTIES.to_string(),
+            ..Default::default()
+        };
+        let result = OutputFormatter::format_with_config(&create_test_zerv(), &output).unwrap();
+        assert_eq!(
+            result.lines().collect::<Vec<_>>(),
+            [
+                "version=1.2.3",
+                "major=1",
+                "minor=2",
+                "patch=3",
+                "commit=abc123",
+                "distance=0",
+                "dirty=false",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::plain("1.2.3+main.1", "1.2.3+main.1")]
+    #[case::empty("", "")]
+    #[case::colon("http://host:8080", r"http\://host\:8080")]
+    #[case::equals("a=b", r"a\=b")]
+    #[case::comment_chars("#1!", r"\#1\!")]
+    #[case::backslash(r"C:\build", r"C\:\\build")]
+    #[case::leading_space(" x y", r"\ x y")]
+    #[case::control("a\tb\nc", r"a\tb\nc")]
+    #[case::non_ascii("é😀", r"\u00E9\uD83D\uDE00")]
+    fn test_properties_escape(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(OutputFormatter::properties_escape(value), expected);
+    }
+
+    #[rstest]
+    #[case::plain("1.2.3", "'1.2.3'")]
+    #[case::empty("", "''")]
+    #[case::single_quote("it's", r"'it'\''s'")]
+    #[case::shell_syntax("$(rm -rf /) `x`", "'$(rm -rf /) `x`'")]
+    fn test_shell_quote(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(OutputFormatter::shell_quote(value), expected);
+    }
+
+    #[rstest]
+    #[case::tag_version(output_fields::TAG_VERSION, true)]
+    #[case::var("distance", true)]
+    #[case::unknown("hash", false)]
+    fn test_check_field(#[case] field: &str, #[case] expected_ok: bool) {
+        assert_eq!(OutputFormatter::check_field(field).is_ok(), expected_ok);
+    }
+
+    #[test]
+    fn test_field_var_unknown() {
+        assert!(matches!(
+            OutputFormatter::field_var("hash"),
+            Err(ZervError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_with_scope_skips_zerv_ron() {
+        let output = OutputConfig::zerv();
+        let result = OutputFormatter::format_with_scope(
+            &create_test_zerv(),
+            &output,
+            Some("frontend"),
+            None,
+        )
+        .unwrap();
+        assert!(!result.starts_with("frontend-"));
+    }
+
+    mod github_output {
+        use super::*;
+        use crate::test_utils::TestDir;
+
+        #[test]
+        fn test_write_github_output_appends_single_line() {
+            let dir = TestDir::new().unwrap();
+            let path = dir.path().join("github_output");
+            std::fs::write(&path, "existing=1\n").unwrap();
+
+            OutputFormatter::write_github_output(&path, "version", "1.2.3").unwrap();
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            assert_eq!(content, "existing=1\nversion=1.2.3\n");
+        }
+
+        #[test]
+        fn test_write_github_output_multi_line_uses_delimiter() {
+            let dir = TestDir::new().unwrap();
+            let path = dir.path().join("github_output");
+
+            OutputFormatter::write_github_output(&path, "zerv", "line1\nline2").unwrap();
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            let delimiter = content
+                .lines()
+                .next()
+                .unwrap()
+                .strip_prefix("zerv<<")
+                .unwrap();
+            assert!(delimiter.starts_with(GITHUB_OUTPUT_DELIMITER_PREFIX));
+            assert_eq!(
+                content,
+                format!("zerv<<{delimiter}\nline1\nline2\n{delimiter}\n")
+            );
+        }
+
+        #[test]
+        fn test_write_github_output_value_cannot_close_delimiter() {
+            let dir = TestDir::new().unwrap();
+            let path = dir.path().join("github_output");
+            let value = "a\nZERV_EOF\nevil=1";
+
+            OutputFormatter::write_github_output(&path, "version", value).unwrap();
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            let delimiter = content
+                .lines()
+                .next()
+                .unwrap()
+                .strip_prefix("version<<")
+                .unwrap();
+            assert!(!value.lines().any(|line| line == delimiter));
+            assert_eq!(
+                content,
+                format!("version<<{delimiter}\n{value}\n{delimiter}\n")
+            );
+        }
+
+        #[test]
+        fn test_github_output_delimiter_is_random_per_write() {
+            assert_ne!(
+                OutputFormatter::github_output_delimiter("a\nb"),
+                OutputFormatter::github_output_delimiter("a\nb")
+            );
+        }
+    }
 }
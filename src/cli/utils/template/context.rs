@@ -1,7 +1,10 @@
 use crate::version::pep440::PEP440;
 use crate::version::pep440::utils::pre_release_label_to_pep440_string;
 use crate::version::semver::SemVer;
-use crate::version::zerv::Zerv;
+use crate::version::zerv::{
+    PreReleaseVar,
+    Zerv,
+};
 
 /// Template context for rendering
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
@@ -25,16 +28,25 @@ pub struct ZervTemplateContext {
     // VCS fields
     pub distance: Option<u64>,
     pub dirty: Option<bool>,
+    pub staged_dirty: Option<bool>,
+    pub unstaged_dirty: Option<bool>,
     pub bumped_branch: Option<String>,
     pub bumped_commit_hash: Option<String>,
     pub bumped_commit_hash_short: Option<String>,
     pub bumped_timestamp: Option<u64>,
+    pub commit_subject: Option<String>,
+    pub commit_count: Option<u64>,
+    pub default_branch: Option<String>,
 
     // Last version fields
     // pub last_branch: Option<String>,
     pub last_commit_hash: Option<String>,
     pub last_commit_hash_short: Option<String>,
     pub last_timestamp: Option<u64>,
+    pub tag_message: Option<String>,
+
+    // Derived fields
+    pub build_number: Option<u64>,
 
     // Custom variables
     pub custom: serde_json::Value,
@@ -88,25 +100,40 @@ impl ZervTemplateContext {
             current_timestamp: chrono::Utc::now().timestamp() as u64,
             post: vars.post,
             dev: vars.dev,
-            pre_release: vars.pre_release.as_ref().map(|pr| {
-                let code_label = Some(pre_release_label_to_pep440_string(&pr.label).to_string());
-                PreReleaseContext {
-                    label: pr.label.label_str().to_string(),
-                    number: pr.number,
-                    label_code: code_label.clone(),
-                    label_pep440: code_label,
+            pre_release: vars.pre_release.as_ref().map(|pr| match pr {
+                PreReleaseVar::Raw(raw) => PreReleaseContext {
+                    label: raw.join("."),
+                    number: None,
+                    label_code: None,
+                    label_pep440: None,
+                },
+                PreReleaseVar::Labeled { label, number, .. } => {
+                    let code_label = Some(pre_release_label_to_pep440_string(label).to_string());
+                    PreReleaseContext {
+                        label: label.label_str().to_string(),
+                        number: *number,
+                        label_code: code_label.clone(),
+                        label_pep440: code_label,
+                    }
                 }
             }),
             distance: vars.distance,
             dirty: vars.dirty,
+            staged_dirty: vars.staged_dirty,
+            unstaged_dirty: vars.unstaged_dirty,
             bumped_branch: vars.bumped_branch.clone(),
             bumped_commit_hash: vars.bumped_commit_hash.clone(),
             bumped_commit_hash_short: vars.get_bumped_commit_hash_short(),
             bumped_timestamp: vars.bumped_timestamp,
+            commit_subject: vars.commit_subject.clone(),
+            commit_count: vars.commit_count,
+            default_branch: vars.default_branch.clone(),
             // last_branch: vars.last_branch.clone(),
             last_commit_hash: vars.last_commit_hash.clone(),
             last_commit_hash_short: vars.get_last_commit_hash_short(),
             last_timestamp: vars.last_timestamp,
+            tag_message: vars.tag_message.clone(),
+            build_number: vars.build_number,
             custom: vars.custom.clone(),
             pep440: pep440.to_string(),
             semver: semver.to_string(),
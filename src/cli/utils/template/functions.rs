@@ -4,13 +4,19 @@ use std::hash::{
     Hasher,
 };
 
+use sha2::{
+    Digest,
+    Sha256,
+};
 use tera::{
     Tera,
     Value,
 };
 
 use crate::error::ZervError;
+use crate::utils::constants::hash_algos;
 use crate::utils::sanitize::Sanitizer;
+use crate::version::zerv::resolve_timestamp;
 
 /// Timestamp format patterns
 mod timestamp_patterns {
@@ -36,14 +42,22 @@ fn get_string_value(
         .ok_or_else(|| tera::Error::msg(format!("Missing required parameter '{}'", key)))
 }
 
+/// Whether the 'value' parameter is an empty string
+fn is_empty_value(args: &std::collections::HashMap<String, Value>) -> bool {
+    args.get("value").and_then(|v| v.as_str()) == Some("")
+}
+
 /// Register custom Tera functions
 pub fn register_functions(tera: &mut Tera) -> Result<(), ZervError> {
     tera.register_function("sanitize", Box::new(sanitize_function));
     tera.register_function("hash", Box::new(hash_function));
     tera.register_function("hash_int", Box::new(hash_int_function));
+    tera.register_function("short_hash", Box::new(short_hash_function));
     tera.register_function("prefix", Box::new(prefix_function));
     tera.register_function("prefix_if", Box::new(prefix_if_function));
     tera.register_function("format_timestamp", Box::new(format_timestamp_function));
+    tera.register_function("ts_format", Box::new(ts_format_function));
+    tera.register_filter("each_custom", Box::new(each_custom_filter));
     Ok(())
 }
 
@@ -125,8 +139,32 @@ fn hash_function(args: &std::collections::HashMap<String, Value>) -> Result<Valu
     Ok(Value::String(short.to_string()))
 }
 
+/// Hash `input` to an integer with a named algorithm, or with the Rust standard
+/// library hasher when none is given (the historical default, not fixed across Rust releases)
+fn hash_int_value(input: &str, algo: Option<&str>) -> Result<u64, tera::Error> {
+    match algo {
+        None => {
+            let mut hasher = DefaultHasher::new();
+            input.hash(&mut hasher);
+            Ok(hasher.finish())
+        }
+        Some(hash_algos::FNV) => Ok(fnv1a_64(input)),
+        Some(hash_algos::SHA256) => {
+            let digest = Sha256::digest(input.as_bytes());
+            let mut leading = [0u8; 8];
+            leading.copy_from_slice(&digest[..8]);
+            Ok(u64::from_be_bytes(leading))
+        }
+        Some(hash_algos::CRC32) => Ok(u64::from(crc32fast::hash(input.as_bytes()))),
+        Some(other) => Err(tera::Error::msg(format!(
+            "Unknown hash_int algo: {other}. Supported algos: {}",
+            hash_algos::VALID_ALGOS.join(", ")
+        ))),
+    }
+}
+
 /// Generate numeric hash with configurable length and leading zero options
-/// Usage: {{ hash_int(value, length=7, allow_leading_zero=false) }}
+/// Usage: {{ hash_int(value, length=7, allow_leading_zero=false, algo="fnv") }}
 fn hash_int_function(
     args: &std::collections::HashMap<String, Value>,
 ) -> Result<Value, tera::Error> {
@@ -139,9 +177,8 @@ fn hash_int_function(
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
-    let mut hasher = DefaultHasher::new();
-    input.hash(&mut hasher);
-    let hash = hasher.finish();
+    let algo = args.get("algo").and_then(|v| v.as_str());
+    let hash = hash_int_value(&input, algo)?;
 
     let result = if allow_leading_zero {
         format!("{:0width$}", hash, width = length)
@@ -158,6 +195,32 @@ fn hash_int_function(
     Ok(Value::String(short.to_string()))
 }
 
+/// 64-bit FNV-1a, fixed by its published constants rather than by the Rust release
+fn fnv1a_64(input: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    input.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Generate a stable hex digest (up to 16 characters) for cache-busting identifiers
+/// The same input yields the same digest across runs, platforms and zerv releases
+/// Usage: {{ short_hash(value=bumped_branch ~ "-" ~ distance, length=8) }}
+fn short_hash_function(
+    args: &std::collections::HashMap<String, Value>,
+) -> Result<Value, tera::Error> {
+    let input = get_string_value(args, "value")?;
+
+    let length = args.get("length").and_then(|v| v.as_u64()).unwrap_or(8) as usize;
+
+    let digest = format!("{:016x}", fnv1a_64(&input));
+    let short = &digest[..length.min(digest.len())];
+
+    Ok(Value::String(short.to_string()))
+}
+
 /// Extract prefix from string with configurable length
 /// Usage: {{ prefix(value, length=10) }}
 fn prefix_function(args: &std::collections::HashMap<String, Value>) -> Result<Value, tera::Error> {
@@ -194,10 +257,14 @@ fn prefix_if_function(
 }
 
 /// Format timestamp with customizable format
+/// An empty value (e.g. an undefined variable outside --template-strict) formats as empty
 /// Usage: {{ format_timestamp(timestamp, format="%Y-%m-%d") }}
 fn format_timestamp_function(
     args: &std::collections::HashMap<String, Value>,
 ) -> Result<Value, tera::Error> {
+    if is_empty_value(args) {
+        return Ok(Value::String(String::new()));
+    }
     let timestamp = args.get("value").and_then(|v| v.as_u64()).ok_or_else(|| {
         tera::Error::msg("format_timestamp function requires a 'value' parameter")
     })?;
@@ -228,10 +295,92 @@ fn format_timestamp_function(
     Ok(Value::String(formatted))
 }
 
+/// Format timestamp with the same patterns as schema timestamp components
+/// Separators between pattern runs are kept literally; an empty value formats as empty
+/// Usage: {{ ts_format(value=bumped_timestamp, pattern="YYYY-0M-0D") }}
+fn ts_format_function(
+    args: &std::collections::HashMap<String, Value>,
+) -> Result<Value, tera::Error> {
+    if is_empty_value(args) {
+        return Ok(Value::String(String::new()));
+    }
+    let timestamp = args
+        .get("value")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| tera::Error::msg("ts_format function requires a 'value' parameter"))?;
+    let pattern = args
+        .get("pattern")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| tera::Error::msg("ts_format function requires a 'pattern' parameter"))?;
+
+    let resolve =
+        |run: &str| resolve_timestamp(run, timestamp).map_err(|e| tera::Error::msg(e.to_string()));
+
+    if matches!(
+        pattern,
+        timestamp_patterns::COMPACT_DATE | timestamp_patterns::COMPACT_DATETIME
+    ) {
+        return resolve(pattern).map(Value::String);
+    }
+
+    let mut formatted = String::new();
+    let mut run = String::new();
+    for c in pattern.chars() {
+        if matches!(c, '0' | 'Y' | 'M' | 'D' | 'H' | 'm' | 'S' | 'W') {
+            run.push(c);
+            continue;
+        }
+        if !run.is_empty() {
+            formatted.push_str(&resolve(&run)?);
+            run.clear();
+        }
+        formatted.push(c);
+    }
+    if !run.is_empty() {
+        formatted.push_str(&resolve(&run)?);
+    }
+
+    Ok(Value::String(formatted))
+}
+
+/// Select an array or object under a dot path of the custom variables for iteration
+/// Arrays are returned as-is; objects become a list of `{key, value}` entries
+/// Usage: {% for name in custom | each_custom(path="contributors") %}{{ name }}{% endfor %}
+fn each_custom_filter(
+    value: &Value,
+    args: &std::collections::HashMap<String, Value>,
+) -> Result<Value, tera::Error> {
+    let path = args
+        .get("path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| tera::Error::msg("each_custom filter requires a 'path' parameter"))?;
+
+    let mut current = value;
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        current = current
+            .get(part)
+            .ok_or_else(|| tera::Error::msg(format!("Custom path '{path}' not found")))?;
+    }
+
+    match current {
+        Value::Array(_) => Ok(current.clone()),
+        Value::Object(map) => Ok(Value::Array(
+            map.iter()
+                .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                .collect(),
+        )),
+        _ => Err(tera::Error::msg(format!(
+            "Custom path '{path}' is not an array or object"
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -314,6 +463,49 @@ mod tests {
         assert!(hash_str.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    #[rstest]
+    #[case::empty("", "cbf29ce484222325")]
+    #[case::letter("a", "af63dc4c8601ec8c")]
+    #[case::word("foobar", "85944171f73967e8")]
+    fn test_fnv1a_64_reference_values(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(format!("{:016x}", fnv1a_64(input)), expected);
+    }
+
+    #[rstest]
+    #[case::default(None, "cbf29ce4")]
+    #[case::short(Some(4), "cbf2")]
+    #[case::full(Some(16), "cbf29ce484222325")]
+    #[case::capped(Some(40), "cbf29ce484222325")]
+    fn test_short_hash_function_length(#[case] length: Option<u64>, #[case] expected: &str) {
+        let mut args = HashMap::new();
+        args.insert("value".to_string(), Value::String(String::new()));
+        if let Some(length) = length {
+            args.insert("length".to_string(), Value::Number(length.into()));
+        }
+
+        let result = short_hash_function(&args).unwrap();
+        assert_eq!(result, Value::String(expected.to_string()));
+    }
+
+    #[test]
+    fn test_short_hash_function_deterministic() {
+        let mut args = HashMap::new();
+        args.insert(
+            "value".to_string(),
+            Value::String("feature/cache-5".to_string()),
+        );
+
+        let first = short_hash_function(&args).unwrap();
+        let second = short_hash_function(&args).unwrap();
+        assert_eq!(first, second);
+
+        args.insert(
+            "value".to_string(),
+            Value::String("feature/cache-6".to_string()),
+        );
+        assert_ne!(short_hash_function(&args).unwrap(), first);
+    }
+
     #[test]
     fn test_hash_int_function_default() {
         let mut args = HashMap::new();
@@ -338,6 +530,68 @@ mod tests {
         assert!(hash_str.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    fn hash_int_args(value: &str, algo: Option<&str>) -> HashMap<String, Value> {
+        let mut args = HashMap::new();
+        args.insert("value".to_string(), Value::String(value.to_string()));
+        args.insert("length".to_string(), Value::Number(10.into()));
+        args.insert("allow_leading_zero".to_string(), Value::Bool(true));
+        if let Some(algo) = algo {
+            args.insert("algo".to_string(), Value::String(algo.to_string()));
+        }
+        args
+    }
+
+    #[rstest]
+    #[case::fnv(hash_algos::FNV, "9625390261")]
+    #[case::sha256(hash_algos::SHA256, "1409952132")]
+    #[case::crc32(hash_algos::CRC32, "2666930069")]
+    fn test_hash_int_function_algo_reference_values(#[case] algo: &str, #[case] expected: &str) {
+        let result = hash_int_function(&hash_int_args("foobar", Some(algo))).unwrap();
+        assert_eq!(result, Value::String(expected.to_string()));
+    }
+
+    #[rstest]
+    #[case::default(None)]
+    #[case::fnv(Some(hash_algos::FNV))]
+    #[case::sha256(Some(hash_algos::SHA256))]
+    #[case::crc32(Some(hash_algos::CRC32))]
+    fn test_hash_int_function_algo_deterministic(#[case] algo: Option<&str>) {
+        let first = hash_int_function(&hash_int_args("feature/login", algo)).unwrap();
+        let second = hash_int_function(&hash_int_args("feature/login", algo)).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_hash_int_function_algo_changes_number() {
+        let numbers: Vec<Value> = [
+            None,
+            Some(hash_algos::FNV),
+            Some(hash_algos::SHA256),
+            Some(hash_algos::CRC32),
+        ]
+        .into_iter()
+        .map(|algo| hash_int_function(&hash_int_args("feature/login", algo)).unwrap())
+        .collect();
+
+        for (i, number) in numbers.iter().enumerate() {
+            assert!(
+                !numbers[i + 1..].contains(number),
+                "each algo should give its own number: {numbers:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_int_function_unknown_algo() {
+        let result = hash_int_function(&hash_int_args("feature/login", Some("md5")));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Unknown hash_int algo")
+        );
+    }
+
     #[test]
     fn test_prefix_function_default() {
         let mut args = HashMap::new();
@@ -442,4 +696,89 @@ mod tests {
         let result = format_timestamp_function(&args).unwrap();
         assert_eq!(result, Value::String("2023-10-30".to_string()));
     }
+
+    #[rstest::rstest]
+    #[case("YYYY-0M-0D", "2023-10-30")]
+    #[case("YYYY.MM.DD 0H:0m", "2023.10.30 14:20")]
+    #[case("compact_date", "20231030")]
+    fn test_ts_format_function(#[case] pattern: &str, #[case] expected: &str) {
+        let mut args = HashMap::new();
+        args.insert("value".to_string(), Value::Number(1698675600.into())); // 2023-10-30 14:20:00 UTC
+        args.insert("pattern".to_string(), Value::String(pattern.to_string()));
+
+        let result = ts_format_function(&args).unwrap();
+        assert_eq!(result, Value::String(expected.to_string()));
+    }
+
+    #[test]
+    fn test_timestamp_functions_format_empty_value_as_empty() {
+        let mut args = HashMap::new();
+        args.insert("value".to_string(), Value::String(String::new()));
+        args.insert("pattern".to_string(), Value::String("YYYY".to_string()));
+
+        assert_eq!(
+            ts_format_function(&args).unwrap(),
+            Value::String(String::new())
+        );
+        assert_eq!(
+            format_timestamp_function(&args).unwrap(),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    fn test_ts_format_function_invalid_pattern() {
+        let mut args = HashMap::new();
+        args.insert("value".to_string(), Value::Number(1698675600.into()));
+        args.insert("pattern".to_string(), Value::String("YYYY-MMM".to_string()));
+
+        assert!(ts_format_function(&args).is_err());
+    }
+
+    mod each_custom {
+        use serde_json::json;
+
+        use super::*;
+
+        fn filter(value: Value, path: &str) -> Result<Value, tera::Error> {
+            let mut args = HashMap::new();
+            args.insert("path".to_string(), Value::String(path.to_string()));
+            each_custom_filter(&value, &args)
+        }
+
+        #[test]
+        fn test_each_custom_array() {
+            let custom = json!({"contributors": ["alice", "bob"]});
+            assert_eq!(
+                filter(custom, "contributors").unwrap(),
+                json!(["alice", "bob"])
+            );
+        }
+
+        #[test]
+        fn test_each_custom_nested_object() {
+            let custom = json!({"build": {"deps": {"serde": "1.0", "tera": "1.20"}}});
+            assert_eq!(
+                filter(custom, "build.deps").unwrap(),
+                json!([
+                    {"key": "serde", "value": "1.0"},
+                    {"key": "tera", "value": "1.20"}
+                ])
+            );
+        }
+
+        #[rstest]
+        #[case::missing_path("missing")]
+        #[case::scalar("name")]
+        fn test_each_custom_errors(#[case] path: &str) {
+            let custom = json!({"name": "zerv"});
+            assert!(filter(custom, path).is_err());
+        }
+
+        #[test]
+        fn test_each_custom_missing_path_parameter() {
+            let result = each_custom_filter(&json!({}), &HashMap::new());
+            assert!(result.is_err());
+        }
+    }
 }
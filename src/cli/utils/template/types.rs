@@ -1,8 +1,13 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
 use once_cell::sync::OnceCell;
+use serde::{
+    Deserialize,
+    Deserializer,
+};
 
 use super::context::ZervTemplateContext;
 use super::functions::register_functions;
@@ -44,8 +49,29 @@ where
 
     /// Render template and parse to typed result
     pub fn render(&self, zerv: Option<&Zerv>) -> Result<Option<T>, ZervError> {
-        let rendered = self.render_string(zerv)?;
+        self.parse_rendered(self.render_string(zerv)?)
+    }
 
+    /// Like `render`, but a variable that is not defined is an empty string wherever it is used
+    /// (output, `~` concatenation, filter and function arguments) instead of failing. This is
+    /// the non-strict mode of --output-template
+    pub fn render_lenient(&self, zerv: Option<&Zerv>) -> Result<Option<T>, ZervError> {
+        let tera = self.get_tera()?;
+        let mut context = self.create_context(zerv)?;
+        let mut defaulted = HashSet::new();
+        loop {
+            match tera.render("template", &context) {
+                Ok(rendered) => return self.parse_rendered(rendered.trim().to_string()),
+                Err(error) => match undefined_variable(&error) {
+                    Some(path)
+                        if defaulted.insert(path.clone()) && insert_empty(&mut context, &path) => {}
+                    _ => return Err(self.render_error(&error)),
+                },
+            }
+        }
+    }
+
+    fn parse_rendered(&self, rendered: String) -> Result<Option<T>, ZervError> {
         // Handle empty/null results
         let trimmed = rendered.trim().to_lowercase();
         if trimmed.is_empty() || matches!(trimmed.as_str(), "none" | "null" | "nil") {
@@ -68,7 +94,8 @@ where
                 .map_err(|e| {
                     ZervError::TemplateError(format!(
                         "Failed to parse template '{}': {}",
-                        self.template, e
+                        self.template,
+                        describe_tera_error(&e)
                     ))
                 })?;
             Ok(tera)
@@ -77,17 +104,20 @@ where
 
     /// Internal method: render to string
     fn render_string(&self, zerv: Option<&Zerv>) -> Result<String, ZervError> {
-        let tera = self.get_tera()?;
         let context = self.create_context(zerv)?;
 
-        tera.render("template", &context)
+        self.get_tera()?
+            .render("template", &context)
             .map(|s| s.trim().to_string())
-            .map_err(|e| {
-                ZervError::TemplateError(format!(
-                    "Template render error '{}': {}",
-                    self.template, e
-                ))
-            })
+            .map_err(|e| self.render_error(&e))
+    }
+
+    fn render_error(&self, error: &tera::Error) -> ZervError {
+        ZervError::TemplateError(format!(
+            "Template render error '{}': {}",
+            self.template,
+            describe_tera_error(error)
+        ))
     }
 
     /// Create template context from Zerv object
@@ -102,6 +132,70 @@ where
     }
 }
 
+/// The dotted path of the variable behind a Tera "Variable `x` not found" error, if any
+fn undefined_variable(error: &tera::Error) -> Option<String> {
+    let mut source: Option<&dyn std::error::Error> = Some(error);
+    while let Some(cause) = source {
+        let message = cause.to_string();
+        if let Some((_, rest)) = message.split_once("Variable `")
+            && let Some((path, _)) = rest.split_once("` not found in context")
+        {
+            return Some(path.to_string());
+        }
+        source = cause.source();
+    }
+    None
+}
+
+/// Define the dotted `path` in `context` as an empty string, creating missing or null parent
+/// objects. Returns false for paths that cannot be defined (indexing, non-object parents)
+fn insert_empty(context: &mut tera::Context, path: &str) -> bool {
+    let segments: Vec<&str> = path.split('.').collect();
+    if segments.iter().any(|segment| {
+        segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+    }) {
+        return false;
+    }
+
+    let (root, rest) = segments
+        .split_first()
+        .expect("split yields at least one segment");
+    let mut value = context.get(root).cloned().unwrap_or(tera::Value::Null);
+    if !set_empty(&mut value, rest) {
+        return false;
+    }
+    context.insert(*root, &value);
+    true
+}
+
+fn set_empty(value: &mut tera::Value, segments: &[&str]) -> bool {
+    let Some((head, rest)) = segments.split_first() else {
+        *value = tera::Value::String(String::new());
+        return true;
+    };
+    if value.is_null() {
+        *value = tera::Value::Object(tera::Map::new());
+    }
+    match value.as_object_mut() {
+        Some(map) => set_empty(
+            map.entry(head.to_string()).or_insert(tera::Value::Null),
+            rest,
+        ),
+        None => false,
+    }
+}
+
+/// Tera reports the actual cause (e.g. an undefined variable) as the error source
+fn describe_tera_error(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    message
+}
+
 // Extension trait for common template operations on String templates
 pub trait TemplateExt {
     /// Render template and return string, using default if None
@@ -152,6 +246,27 @@ where
     }
 }
 
+/// Templates in files may be written as numbers (`"distance": 5`) or strings
+impl<'de, T> Deserialize<'de> for Template<T>
+where
+    T: FromStr + Clone + Display,
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawTemplate {
+            Str(String),
+            UInt(u64),
+        }
+
+        Ok(match RawTemplate::deserialize(deserializer)? {
+            RawTemplate::Str(template) => Self::new(template),
+            RawTemplate::UInt(value) => Self::new(value.to_string()),
+        })
+    }
+}
+
 // Additional trait implementations for clap compatibility
 impl FromStr for Template<u32> {
     type Err = ZervError;
@@ -295,6 +410,59 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[rstest::rstest]
+    #[case::top_level("{{ nonexistent }}", "nonexistent")]
+    #[case::unset_optional("{{ pre_release.label }}", "pre_release.label")]
+    fn test_template_undefined_variable_errors(#[case] template: &str, #[case] variable: &str) {
+        let template = Template::<String>::new(template.to_string());
+        let zerv_fixture = ZervFixture::new().with_version(1, 0, 0);
+        let zerv = zerv_fixture.zerv();
+
+        let message = match template.render(Some(zerv)) {
+            Err(ZervError::TemplateError(message)) => message,
+            other => panic!("Expected template error, got {other:?}"),
+        };
+        assert!(
+            message.contains(&format!("`{variable}` not found")),
+            "Error should name the undefined variable: {message}"
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::top_level("v{{ nonexistent }}", "v")]
+    #[case::unset_optional("{{ major }}{{ pre_release.label }}", "1")]
+    #[case::in_condition("{% if true %}{{ nonexistent | upper }}x{% endif %}", "x")]
+    #[case::explicit_default("{{ nonexistent | default(value='d') }}", "d")]
+    #[case::concat("{{ nonexistent ~ major }}", "1")]
+    #[case::function_arg("v{{ ts_format(value=nonexistent, pattern='YYYY') }}", "v")]
+    #[case::filter_arg("{{ major | default(value=nonexistent) }}", "1")]
+    #[case::nested_in_undefined("{{ a.b }}-{{ a.c ~ major }}", "-1")]
+    #[case::set("{% set x = nonexistent ~ 'x' %}{{ x }}", "x")]
+    #[case::condition("{% if nonexistent %}y{% else %}n{% endif %}", "n")]
+    #[case::defined("{{ major }}.{{ minor }}", "1.0")]
+    fn test_template_render_lenient(#[case] template: &str, #[case] expected: &str) {
+        let template = Template::<String>::new(template.to_string());
+        let zerv_fixture = ZervFixture::new().with_version(1, 0, 0);
+        let zerv = zerv_fixture.zerv();
+
+        let rendered = template.render_lenient(Some(zerv)).unwrap();
+        assert_eq!(rendered.unwrap_or_default(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::math("{{ nonexistent + 1 }}")]
+    #[case::field_of_string("{{ bumped_branch.x }}")]
+    #[case::index("{{ nonexistent[0] }}")]
+    fn test_template_render_lenient_still_fails(#[case] template: &str) {
+        let template = Template::<String>::new(template.to_string());
+        let zerv_fixture = ZervFixture::new()
+            .with_version(1, 0, 0)
+            .with_branch("main".to_string());
+        let zerv = zerv_fixture.zerv();
+
+        assert!(template.render_lenient(Some(zerv)).is_err());
+    }
+
     #[test]
     fn test_template_render_compatibility() {
         let template: Template<String> = Template::new("v{{ major }}.{{ minor }}".to_string());
@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+use clap::Parser;
+
+use crate::cli::common::args::InputConfig;
+use crate::cli::utils::output_formatter::OutputFormatter;
+use crate::cli::version::{
+    VersionArgs,
+    prepare_git_only_args,
+    process_git_source,
+};
+use crate::error::ZervError;
+use crate::schema::ZervSchemaPreset;
+use crate::vcs::git_utils::GitUtils;
+use crate::version::{
+    VersionObject,
+    Zerv,
+};
+
+#[derive(Parser, Debug, Default)]
+pub struct ValidateTagArgs {
+    /// Tag about to be pushed
+    pub tag: String,
+
+    /// Input configuration (same as version; the source must be git)
+    #[command(flatten)]
+    pub input: InputConfig,
+
+    /// Schema preset the tag must fit
+    #[arg(
+        long,
+        value_name = "SCHEMA",
+        help = "Fail unless the tag is exactly what this schema preset renders for it, e.g. 'standard-base' rejects pre-release tags"
+    )]
+    pub schema: Option<String>,
+}
+
+/// Check that a tag parses, fits the schema and is greater than the latest version tag
+pub fn run_validate_tag(args: ValidateTagArgs) -> Result<String, ZervError> {
+    let mut version_args = VersionArgs {
+        input: args.input,
+        ..Default::default()
+    };
+    let work_dir = prepare_git_only_args("validate-tag", &mut version_args)?;
+
+    let tag_prefix = version_args.input.tag_prefix();
+    let version =
+        VersionObject::parse_with_prefix(&args.tag, &version_args.input.input_format, &tag_prefix)
+            .map_err(|e| {
+                ZervError::InvalidVersion(format!("Tag '{}' is not a valid version: {e}", args.tag))
+            })?;
+    let format = version.format_str();
+
+    if let Some(ref schema_name) = args.schema {
+        check_schema(&args.tag, Zerv::from(version.clone()), schema_name, format)?;
+    }
+
+    let draft = process_git_source(&work_dir, &version_args)?;
+
+    let Some(latest) = draft.vars.last_tag_version else {
+        return Ok(format!(
+            "✓ Tag {} is valid (no previous version tag)",
+            args.tag
+        ));
+    };
+    // Compare in the tag's format so a semver tag is ordered against a semver latest
+    let latest_version = VersionObject::parse_with_prefix(&latest, format, &tag_prefix)?;
+    if GitUtils::compare_version_objects(&version, &latest_version)? != Ordering::Greater {
+        return Err(ZervError::InvalidVersion(format!(
+            "Tag '{}' is not greater than the latest version tag '{latest}'",
+            args.tag
+        )));
+    }
+
+    Ok(format!("✓ Tag {} is valid (latest: {latest})", args.tag))
+}
+
+/// Fail unless `schema_name` renders the tag's version unchanged
+fn check_schema(tag: &str, parsed: Zerv, schema_name: &str, format: &str) -> Result<(), ZervError> {
+    let preset = schema_name
+        .parse::<ZervSchemaPreset>()
+        .map_err(|_| ZervError::UnknownSchema(schema_name.to_string()))?;
+    let expected = OutputFormatter::format_output(&parsed, format, None, &None)?;
+    let with_schema = Zerv::new(preset.schema_with_zerv(&parsed.vars), parsed.vars)?;
+    let rendered = OutputFormatter::format_output(&with_schema, format, None, &None)?;
+
+    if rendered != expected {
+        return Err(ZervError::InvalidVersion(format!(
+            "Tag '{tag}' does not match schema '{schema_name}' (the schema renders it as '{rendered}')"
+        )));
+    }
+    Ok(())
+}
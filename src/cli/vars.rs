@@ -0,0 +1,159 @@
+use clap::Parser;
+use strum::IntoEnumIterator;
+
+use crate::utils::sanitize::Sanitizer;
+use crate::version::zerv::core::{
+    PreReleaseLabel,
+    PreReleaseVar,
+};
+use crate::version::zerv::{
+    Var,
+    ZervVars,
+};
+
+/// Custom key and timestamp pattern used to illustrate parameterised vars
+const SAMPLE_CUSTOM_KEY: &str = "build_id";
+const SAMPLE_TIMESTAMP_PATTERN: &str = "YYYY";
+
+#[derive(Parser, Debug, Default)]
+pub struct VarsArgs {}
+
+/// List every schema/template variable with a sample value from demo data
+pub fn run_vars(_args: VarsArgs) -> String {
+    let vars = demo_vars();
+    let sanitizer = Sanitizer::semver_str();
+
+    let mut rows: Vec<[String; 3]> = Var::iter()
+        .filter(|var| !matches!(var, Var::Custom(_) | Var::Timestamp(_)))
+        .map(|var| {
+            [
+                var.to_string(),
+                format!("var({var:?})"),
+                sample_value(&var, &vars, &sanitizer),
+            ]
+        })
+        .collect();
+    rows.push([
+        format!("custom.{SAMPLE_CUSTOM_KEY}"),
+        format!("var(custom(\"{SAMPLE_CUSTOM_KEY}\"))"),
+        sample_value(
+            &Var::Custom(SAMPLE_CUSTOM_KEY.to_string()),
+            &vars,
+            &sanitizer,
+        ),
+    ]);
+    rows.push([
+        "-".to_string(),
+        format!("var(ts(\"{SAMPLE_TIMESTAMP_PATTERN}\"))"),
+        sample_value(
+            &Var::Timestamp(SAMPLE_TIMESTAMP_PATTERN.to_string()),
+            &vars,
+            &sanitizer,
+        ),
+    ]);
+
+    let header = [
+        "TEMPLATE".to_string(),
+        "SCHEMA".to_string(),
+        "SAMPLE".to_string(),
+    ];
+    let name_width = rows.iter().chain([&header]).map(|row| row[0].len()).max();
+    let schema_width = rows.iter().chain([&header]).map(|row| row[1].len()).max();
+    let (name_width, schema_width) = (name_width.unwrap_or(0), schema_width.unwrap_or(0));
+
+    [header]
+        .iter()
+        .chain(rows.iter())
+        .map(|[name, schema, sample]| {
+            format!("{name:<name_width$}  {schema:<schema_width$}  {sample}")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Resolved value of the var, or "-" when unset
+fn sample_value(var: &Var, vars: &ZervVars, sanitizer: &Sanitizer) -> String {
+    let value = match var {
+        // The bare value is only the number, so show the label alongside it
+        Var::PreRelease => Some(var.resolve_expanded_values(vars, sanitizer).join(".")),
+        _ => var.resolve_value(vars, sanitizer),
+    };
+    value
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Demo data with every variable populated
+fn demo_vars() -> ZervVars {
+    let mut vars = ZervVars {
+        major: Some(1),
+        minor: Some(2),
+        patch: Some(3),
+        epoch: Some(1),
+        pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Alpha, Some(1))),
+        post: Some(2),
+        dev: Some(3),
+        distance: Some(5),
+        dirty: Some(true),
+        staged_dirty: Some(false),
+        unstaged_dirty: Some(true),
+        bumped_branch: Some("main".to_string()),
+        bumped_commit_hash: Some("g4e9af24c1b7d3e5f".to_string()),
+        bumped_timestamp: Some(1_735_689_600),
+        commit_subject: Some("Fix parser edge case".to_string()),
+        commit_count: Some(128),
+        default_branch: Some("main".to_string()),
+        last_branch: Some("main".to_string()),
+        last_commit_hash: Some("g1a2b3c4d5e6f7a8b".to_string()),
+        last_timestamp: Some(1_733_011_200),
+        last_tag_version: Some("v1.2.3".to_string()),
+        tag_message: Some("Release 1.2.3".to_string()),
+        custom: serde_json::json!({ SAMPLE_CUSTOM_KEY: "123" }),
+        ..Default::default()
+    };
+    vars.build_number = vars.compute_build_number(100_000).ok();
+    vars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_vars_lists_every_var() {
+        let output = run_vars(VarsArgs::default());
+        for var in Var::iter().filter(|var| !matches!(var, Var::Custom(_) | Var::Timestamp(_))) {
+            assert!(
+                output.contains(&format!("var({var:?})")),
+                "missing {var:?} in:\n{output}"
+            );
+        }
+        assert!(output.contains("var(custom(\"build_id\"))"));
+        assert!(output.contains("var(ts(\"YYYY\"))"));
+    }
+
+    #[test]
+    fn test_run_vars_sample_values() {
+        let output = run_vars(VarsArgs::default());
+        let row = |name: &str| {
+            output
+                .lines()
+                .find(|line| line.starts_with(&format!("{name} ")))
+                .map(|line| line.split_whitespace().collect::<Vec<_>>())
+                .unwrap_or_else(|| panic!("missing {name} in:\n{output}"))
+        };
+        assert_eq!(row("major"), ["major", "var(Major)", "1"]);
+        assert_eq!(
+            row("pre_release"),
+            ["pre_release", "var(PreRelease)", "alpha.1"]
+        );
+        assert_eq!(
+            row("bumped_branch"),
+            ["bumped_branch", "var(BumpedBranch)", "main"]
+        );
+        assert_eq!(row("custom.build_id")[2], "123");
+        assert_eq!(row("-")[2], "2025");
+    }
+}
@@ -0,0 +1,31 @@
+use clap::Parser;
+
+use crate::cli::common::args::InputConfig;
+use crate::cli::version::{
+    VersionArgs,
+    detect_git_vcs_data,
+    prepare_git_only_args,
+    print_timings,
+};
+use crate::error::ZervError;
+
+#[derive(Parser, Debug, Default)]
+pub struct VcsDataArgs {
+    /// Input configuration (same as version; the source must be git)
+    #[command(flatten)]
+    pub input: InputConfig,
+}
+
+/// Print the VcsData detected from git as JSON, before any schema processing
+pub fn run_vcs_data(args: VcsDataArgs) -> Result<String, ZervError> {
+    let mut version_args = VersionArgs {
+        input: args.input,
+        ..Default::default()
+    };
+    let work_dir = prepare_git_only_args("vcs-data", &mut version_args)?;
+    let (vcs, vcs_data) = detect_git_vcs_data(&work_dir, &version_args)?;
+    print_timings(vcs.as_ref());
+
+    serde_json::to_string_pretty(&vcs_data)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to serialize VCS data: {e}")))
+}
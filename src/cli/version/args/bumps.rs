@@ -1,9 +1,14 @@
 use clap::Parser;
 
 use crate::cli::utils::template::Template;
+use crate::utils::constants::{
+    base_bump_levels,
+    epoch_reset_modes,
+    prerelease_bump_resets,
+};
 
 /// Bump configuration for field-based and schema-based version bumping
-#[derive(Parser, Default, Debug)]
+#[derive(Parser, Default, Debug, Clone)]
 pub struct BumpsConfig {
     // ============================================================================
     // FIELD-BASED BUMP OPTIONS
@@ -36,6 +41,30 @@ pub struct BumpsConfig {
     #[arg(long, help = "Add to epoch number (default: 1)")]
     pub bump_epoch: Option<Option<Template<u32>>>,
 
+    /// Components reset by an epoch bump (default: all)
+    #[arg(
+        long,
+        value_name = "MODE",
+        value_parser = epoch_reset_modes::VALID_MODES.to_vec(),
+        help = "Components reset by --bump-epoch: 'all' (default) resets everything below the epoch (major, minor, patch to 0; pre-release, post and dev removed), 'none' keeps them"
+    )]
+    pub epoch_reset: Option<String>,
+
+    /// Keep inherited pre-release, post and dev values when the base version increases
+    #[arg(
+        long,
+        help = "Keep the inherited pre-release, post and dev values when --epoch, --major, --minor or --patch raise the base version (e.g. --minor 3 on a piped 1.2.3-alpha.4). By default they are reset, as a bump would"
+    )]
+    pub keep_prerelease: bool,
+
+    /// Upper bound for the pre-release, post and dev numbers
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Fail when the pre-release, post or dev number after overrides and bumps exceeds N, to keep versions within downstream limits (e.g. a distance-derived --bump-post). Unbounded by default"
+    )]
+    pub max_component_value: Option<u64>,
+
     /// Bump pre-release label (alpha, beta, rc, none, null) and reset number to 0
     #[arg(
         long,
@@ -43,6 +72,33 @@ pub struct BumpsConfig {
     )]
     pub bump_pre_release_label: Option<Template<String>>,
 
+    /// Components reset by a pre-release label bump (default: post,dev)
+    #[arg(
+        long,
+        value_name = "COMPONENTS",
+        value_delimiter = ',',
+        value_parser = prerelease_bump_resets::VALID_RESETS.to_vec(),
+        help = "Comma-separated components removed by --bump-pre-release-label: 'post', 'dev' (default: post,dev) or 'none' to keep both, e.g. 'dev' keeps the post number across alpha → beta"
+    )]
+    pub prerelease_bump_resets: Option<Vec<String>>,
+
+    /// Infer the major/minor/patch bump from conventional commits since the tag
+    #[arg(
+        long,
+        conflicts_with_all = ["bump_major", "bump_minor", "bump_patch"],
+        help = "Infer the bump from commit messages since the last tag: 'feat:' bumps minor, 'fix:' bumps patch, and a '!' after the type or a 'BREAKING CHANGE:' footer bumps major. No bump when no such commit exists (requires --source git)"
+    )]
+    pub conventional_commits: bool,
+
+    /// Minimum core bump for builds after the tag
+    #[arg(
+        long,
+        value_name = "LEVEL",
+        value_parser = base_bump_levels::VALID_LEVELS.to_vec(),
+        help = "Bump 'major', 'minor' or 'patch' whenever the distance from the tag is above 0, so builds after a 1.2.3 tag are versioned from 1.2.4 with 'patch'. Ignored when --bump-major, --bump-minor, --bump-patch or --conventional-commits already select a core bump"
+    )]
+    pub base_bump: Option<String>,
+
     // ============================================================================
     // SCHEMA-BASED BUMP OPTIONS
     // ============================================================================
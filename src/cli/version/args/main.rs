@@ -1,5 +1,10 @@
 use clap::Parser;
 
+use crate::utils::constants::{
+    build_number,
+    dirty_on_tag_modes,
+};
+
 /// Version-specific configuration with schema support
 #[derive(Parser, Debug, Clone, Default)]
 pub struct MainConfig {
@@ -9,7 +14,7 @@ pub struct MainConfig {
     /// Schema preset name
     #[arg(
         long,
-        help = "Schema preset name
+        help = "Schema preset name; a comma-separated list (e.g. 'standard,calver') renders one labeled version per schema from a single scan
 
 Standard Schema Family (SemVer):
   standard                        - Smart auto-detection based on repository state (clean/dirty/distance)
@@ -39,19 +44,167 @@ CalVer Schema Family:
     pub schema: Option<String>,
 
     /// Custom RON schema definition
-    #[arg(long, help = "Custom schema in RON format")]
+    #[arg(long, conflicts_with = "schema", help = "Custom schema in RON format")]
     pub schema_ron: Option<String>,
+
+    /// Partial RON schema merged into the resolved schema
+    #[arg(
+        long,
+        value_name = "RON",
+        conflicts_with = "schema_ron",
+        help = "Replace sections of the resolved schema (preset, default or piped) with a partial RON schema, e.g. '(build: [var(BumpedBranch), str(\"ci\")])'. Unlike --schema-ron, sections left out keep the preset's"
+    )]
+    pub schema_patch: Option<String>,
+
+    /// Schema preset composed from flags
+    #[arg(
+        long,
+        value_name = "FLAGS",
+        conflicts_with_all = ["schema", "schema_ron"],
+        help = "Compose a schema preset from comma-separated flags instead of its full name: optional family (standard, calver), tiers (base, prerelease, post, dev) and context, e.g. 'base,prerelease' = standard-base-prerelease"
+    )]
+    pub schema_flags: Option<String>,
+
+    /// Distance that makes the smart schemas add the post tier and build context
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Distance from the tag at which the smart schemas (standard, calver and their variants) add the post tier and build context (default: 1)"
+    )]
+    pub smart_distance_threshold: Option<u64>,
+
+    /// Whether a dirty working tree makes the smart schemas add the dev tier
+    #[arg(
+        long,
+        value_name = "BOOL",
+        value_parser = clap::value_parser!(bool),
+        help = "Whether a dirty working tree makes the smart schemas add the dev tier (default: true). With false, dirty only adds build context"
+    )]
+    pub smart_dirty_includes_dev: Option<bool>,
+
+    /// Marker appended to build metadata when the working tree is dirty
+    #[arg(
+        long,
+        value_name = "STR",
+        num_args = 0..=1,
+        default_missing_value = "dirty",
+        help = "Append a marker to build metadata when the working tree is dirty (default marker: 'dirty')"
+    )]
+    pub dirty_marker: Option<String>,
+
+    /// Keep the build metadata of the detected tag in the output
+    #[arg(
+        long,
+        help = "Keep the build metadata of the detected tag (e.g. 'ci.100' in 'v1.2.3+ci.100') at the start of the output build metadata. Build metadata never affects which tag is selected"
+    )]
+    pub preserve_tag_metadata: bool,
+
+    /// Continue the pre-release label of the detected tag after a bump releases the base
+    #[arg(
+        long,
+        help = "Keep the pre-release label of the detected tag (e.g. 'beta' from v1.2.0-beta.2) when a bump would drop it, starting again at number 0: --base-bump patch then gives 1.2.1-beta.0 instead of 1.2.1. --pre-release-label and --bump-pre-release-label take precedence"
+    )]
+    pub inherit_prerelease_label: bool,
+
+    /// How a dirty working tree on a tagged commit is encoded in the version
+    #[arg(
+        long,
+        value_name = "MODE",
+        value_parser = dirty_on_tag_modes::VALID_MODES.to_vec(),
+        help = "Encode a dirty working tree on a tagged commit as a bump so it differs from the clean tag: 'post' (post+1), 'dev' (dev+1) or 'prerelease' (pre-release number+1, or patch+1 with alpha.1 when the tag is not a pre-release)"
+    )]
+    pub dirty_on_tag: Option<String>,
+
+    /// Modulus K for the derived build number
+    #[arg(
+        long,
+        value_name = "K",
+        num_args = 0..=1,
+        default_missing_value = build_number::DEFAULT_MODULUS,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Compute build_number as distance * K + (timestamp % K) (default K: 100000). The same commit and timestamp always yield the same number, so set --bumped-timestamp to distinguish CI retries"
+    )]
+    pub build_number_formula: Option<u64>,
+
+    // ============================================================================
+    // STRICT MODE OPTIONS
+    // ============================================================================
+    /// Enable every strict check at once
+    #[arg(
+        long,
+        help = "Enable the strict bundle: --fail-on-dirty, --template-strict when --output-template is given, plus --strict-pep440 when the output format is pep440. Turn single checks back off with --no-fail-on-dirty, --no-template-strict or --no-strict-pep440"
+    )]
+    pub strict: bool,
+
+    /// Fail instead of versioning a dirty working tree
+    #[arg(
+        long,
+        conflicts_with = "no_fail_on_dirty",
+        help = "Fail when the version is computed from a dirty working tree (after overrides, so --no-dirty still passes)"
+    )]
+    pub fail_on_dirty: bool,
+
+    /// Keep --strict from enabling --fail-on-dirty
+    #[arg(long, help = "Don't fail on a dirty working tree under --strict")]
+    pub no_fail_on_dirty: bool,
+
+    /// Keep --strict from enabling --strict-pep440
+    #[arg(long, help = "Don't validate PEP440 output strictly under --strict")]
+    pub no_strict_pep440: bool,
+
+    /// Keep --strict from enabling --template-strict
+    #[arg(
+        long,
+        help = "Don't fail on undefined --output-template variables under --strict"
+    )]
+    pub no_template_strict: bool,
+
+    // ============================================================================
+    // TRANSFORM OPTIONS
+    // ============================================================================
+    /// External command that post-processes the Zerv object before rendering
+    #[arg(
+        long,
+        value_name = "CMD",
+        help = "Shell command that receives the Zerv RON on stdin and must print modified Zerv RON on stdout before rendering"
+    )]
+    pub transform_command: Option<String>,
+
+    // ============================================================================
+    // RUN MODE OPTIONS
+    // ============================================================================
+    /// Keep running and print a new version whenever the repository changes
+    #[arg(
+        long,
+        help = "Watch the git repository and recompute the version on every change (requires the 'watch' feature)"
+    )]
+    pub watch: bool,
 }
 
 impl MainConfig {
     /// Create MainConfig from schema name and schema_ron
     pub fn from_schema_and_ron(schema: Option<String>, schema_ron: Option<String>) -> Self {
-        Self { schema, schema_ron }
+        Self {
+            schema,
+            schema_ron,
+            ..Default::default()
+        }
+    }
+
+    /// Schema preset names from a comma-separated --schema list
+    pub fn schema_names(&self) -> Vec<&str> {
+        self.schema
+            .as_deref()
+            .map(|schema| schema.split(',').map(str::trim).collect())
+            .unwrap_or_default()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -59,6 +212,7 @@ mod tests {
         let config = MainConfig::default();
         assert!(config.schema.is_none());
         assert!(config.schema_ron.is_none());
+        assert!(!config.watch);
     }
 
     #[test]
@@ -100,6 +254,7 @@ mod tests {
         let config = MainConfig {
             schema: Some("calver".to_string()),
             schema_ron: None,
+            ..Default::default()
         };
         assert_eq!(config.schema, Some("calver".to_string()));
         assert!(config.schema_ron.is_none());
@@ -111,6 +266,7 @@ mod tests {
         let config = MainConfig {
             schema: None,
             schema_ron: Some(ron_schema.to_string()),
+            ..Default::default()
         };
         assert!(config.schema.is_none());
         assert_eq!(config.schema_ron, Some(ron_schema.to_string()));
@@ -122,6 +278,7 @@ mod tests {
         let config = MainConfig {
             schema: Some("calver".to_string()),
             schema_ron: Some(ron_schema.to_string()),
+            ..Default::default()
         };
         assert_eq!(config.schema, Some("calver".to_string()));
         assert_eq!(config.schema_ron, Some(ron_schema.to_string()));
@@ -140,6 +297,7 @@ mod tests {
         let config = MainConfig {
             schema: Some("test".to_string()),
             schema_ron: Some("custom schema".to_string()),
+            ..Default::default()
         };
         let debug_str = format!("{:?}", config);
         assert!(debug_str.contains("test"));
@@ -151,12 +309,42 @@ mod tests {
         let config = MainConfig {
             schema: Some("test".to_string()),
             schema_ron: Some("custom schema".to_string()),
+            ..Default::default()
         };
         let cloned = config.clone();
         assert_eq!(config.schema, cloned.schema);
         assert_eq!(config.schema_ron, cloned.schema_ron);
     }
 
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--dirty-marker"], Some("dirty"))]
+    #[case(&["--dirty-marker", "wip"], Some("wip"))]
+    fn test_main_config_dirty_marker(#[case] args: &[&str], #[case] expected: Option<&str>) {
+        let config =
+            MainConfig::try_parse_from(std::iter::once("zerv").chain(args.iter().copied()))
+                .unwrap();
+        assert_eq!(config.dirty_marker.as_deref(), expected);
+    }
+
+    #[test]
+    fn test_main_config_preserve_tag_metadata_flag() {
+        let config = MainConfig::try_parse_from(["zerv", "--preserve-tag-metadata"]).unwrap();
+        assert!(config.preserve_tag_metadata);
+    }
+
+    #[test]
+    fn test_main_config_inherit_prerelease_label_flag() {
+        let config = MainConfig::try_parse_from(["zerv", "--inherit-prerelease-label"]).unwrap();
+        assert!(config.inherit_prerelease_label);
+    }
+
+    #[test]
+    fn test_main_config_watch_flag() {
+        let config = MainConfig::try_parse_from(["zerv", "--watch"]).unwrap();
+        assert!(config.watch);
+    }
+
     #[test]
     fn test_main_config_integration_with_version_args() {
         // Test that MainConfig works correctly within VersionArgs
@@ -164,17 +352,31 @@ mod tests {
 
         let args = VersionArgs::try_parse_from([
             "version",
-            "--schema",
-            "calver",
             "--schema-ron",
             "core: [{var: \"major\"}]",
+            "--dirty-marker",
         ])
         .unwrap();
 
-        assert_eq!(args.main.schema, Some("calver".to_string()));
+        assert!(args.main.schema.is_none());
         assert_eq!(
             args.main.schema_ron,
             Some("core: [{var: \"major\"}]".to_string())
         );
+        assert_eq!(args.main.dirty_marker.as_deref(), Some("dirty"));
+    }
+
+    #[test]
+    fn test_schema_conflicts_with_schema_ron() {
+        use super::super::VersionArgs;
+
+        let result = VersionArgs::try_parse_from([
+            "version",
+            "--schema",
+            "calver",
+            "--schema-ron",
+            "core: [{var: \"major\"}]",
+        ]);
+        assert!(result.is_err());
     }
 }
@@ -39,7 +39,7 @@ use validation::Validation;
 Supports multiple input sources (git, stdin), output formats (semver, pep440, zerv), and VCS overrides
 for testing and CI/CD workflows."
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VersionArgs {
     #[command(flatten)]
     pub input: InputConfig,
@@ -61,6 +61,9 @@ impl VersionArgs {
     /// Validate arguments and return early errors
     /// This provides early validation before VCS processing
     pub fn validate(&mut self, stdin_content: Option<&str>) -> Result<(), crate::error::ZervError> {
+        // Fill overrides not given as flags from --overrides-file
+        self.overrides.apply_overrides_file()?;
+
         // Apply smart source default
         self.input
             .apply_smart_source_default(stdin_content.is_some());
@@ -69,8 +72,12 @@ impl VersionArgs {
         CommonValidation::validate_io(&self.input, &self.output)?;
 
         // Validate version-specific modules
+        Validation::validate_main_config(&self.main)?;
+        Validation::validate_schema_list(&self.main, &self.output)?;
         Validation::validate_overrides(&self.overrides)?;
         Validation::validate_bumps(&self.bumps)?;
+        Validation::validate_conventional_commits(&self.input, &self.bumps)?;
+        Validation::validate_branch_version_regex(&self.input, &self.overrides)?;
 
         // Validate cross-module conflicts
         Validation::validate_cross_module(&self.overrides, &self.bumps)?;
@@ -78,6 +85,9 @@ impl VersionArgs {
         // Resolve defaults
         Validation::resolve_context_control_defaults(&mut self.bumps)?;
         Validation::resolve_bump_defaults(&mut self.bumps)?;
+        Validation::resolve_override_defaults(&mut self.overrides);
+        Validation::resolve_schema_flags(&mut self.main)?;
+        Validation::resolve_strict(&mut self.main, &mut self.output)?;
 
         Ok(())
     }
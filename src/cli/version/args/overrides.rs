@@ -1,14 +1,32 @@
-use clap::Parser;
+use std::path::Path;
+
+use clap::{
+    CommandFactory,
+    Parser,
+};
+use serde::Deserialize;
 
 use crate::cli::common::overrides::CommonOverridesConfig;
 use crate::cli::utils::template::Template;
+use crate::error::ZervError;
 
 /// Override configuration for version command
-#[derive(Parser, Default, Debug)]
+#[derive(Parser, Default, Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct OverridesConfig {
     #[command(flatten)]
+    #[serde(flatten)]
     pub common: CommonOverridesConfig,
 
+    /// Read overrides from a JSON or RON file
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Read overrides from a JSON (.json) or RON (.ron) file with the option names as snake_case keys (e.g. {\"tag_version\": \"v1.2.3\", \"distance\": 5}). Flags on the command line take precedence"
+    )]
+    #[serde(skip)]
+    pub overrides_file: Option<String>,
+
     // ============================================================================
     // VERSION-SPECIFIC OVERRIDE OPTIONS
     // ============================================================================
@@ -19,7 +37,7 @@ pub struct OverridesConfig {
     /// Override pre-release label
     #[arg(
         long,
-        help = "Override pre-release label (alpha, beta, rc, none, null). Supports templates like '{{{{#if dirty}}}}dev{{{{else}}}}beta{{{{/if}}}}'"
+        help = "Override pre-release label (alpha, beta, rc, numeric for the number alone as in 1.0.0-1, none, null). Supports templates like '{{{{#if dirty}}}}dev{{{{else}}}}beta{{{{/if}}}}'"
     )]
     pub pre_release_label: Option<Template<String>>,
 
@@ -27,6 +45,22 @@ pub struct OverridesConfig {
     #[arg(long, help = "Override pre-release number")]
     pub pre_release_num: Option<Template<u32>>,
 
+    /// Use the commit date as the pre-release number
+    #[arg(
+        long,
+        conflicts_with = "pre_release_num",
+        help = "Set the pre-release number to the commit date as YYYYMMDD (e.g. 1.2.3-alpha.20231221)"
+    )]
+    pub prerelease_date: bool,
+
+    /// Read major/minor/patch from the current branch name
+    #[arg(
+        long,
+        value_name = "RE",
+        help = "Regex with named groups 'major', 'minor' and/or 'patch' matched against the current branch; the captured numbers override the base version, e.g. 'release/(?P<major>\\d+)\\.(?P<minor>\\d+)' turns release/1.4 into 1.4.0 (fields below a changed one restart at 0 and the tag's pre-release is dropped; after a v1.4.2 tag it stays 1.4.2, after a v2.0.0 tag it fails). Branches that do not match keep the tag's version; --major/--minor/--patch take precedence. Requires --source git"
+    )]
+    pub branch_version_regex: Option<String>,
+
     /// Override custom variables in JSON format
     #[arg(long, help = "Override custom variables in JSON format")]
     pub custom: Option<String>,
@@ -67,4 +101,150 @@ impl OverridesConfig {
     pub fn dirty_override(&self) -> Option<bool> {
         self.common.dirty_override()
     }
+
+    /// Load `--overrides-file` and fill every override not given on the command line
+    pub fn apply_overrides_file(&mut self) -> Result<(), ZervError> {
+        let Some(path) = self.overrides_file.as_deref() else {
+            return Ok(());
+        };
+        let file = Self::read_file(path)?;
+        self.merge_from_file(file);
+        Ok(())
+    }
+
+    fn read_file(path: &str) -> Result<Self, ZervError> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            ZervError::InvalidArgument(format!("Failed to read overrides file '{path}': {e}"))
+        };
+        let content = std::fs::read_to_string(path).map_err(|e| invalid(&e))?;
+        let value: serde_json::Value =
+            match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+                Some("json") => serde_json::from_str(&content).map_err(|e| invalid(&e))?,
+                // Read as a value first, since flattened fields need RON's `(key: value)` as a map
+                Some("ron") => ron::from_str::<ron::Value>(&content)
+                    .map_err(|e| invalid(&e))?
+                    .into_rust()
+                    .map_err(|e| invalid(&e))?,
+                _ => return Err(invalid(&"expected a .json or .ron file")),
+            };
+        Self::check_keys(&value).map_err(|e| invalid(&e))?;
+        serde_json::from_value(value).map_err(|e| invalid(&e))
+    }
+
+    /// Reject keys that name no override. `deny_unknown_fields` cannot do this here, since
+    /// serde does not support it together with the flattened `common` fields
+    fn check_keys(value: &serde_json::Value) -> Result<(), String> {
+        let Some(map) = value.as_object() else {
+            return Err("expected a map of overrides".to_string());
+        };
+        let command = Self::command();
+        let known = |key: &str| {
+            key != "overrides_file"
+                && command
+                    .get_arguments()
+                    .any(|arg| arg.get_id().as_str() == key)
+        };
+        match map.keys().find(|key| !known(key)) {
+            Some(key) => Err(format!(
+                "unknown override '{key}' (keys are option names in snake_case, e.g. 'tag_version')"
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn merge_from_file(&mut self, file: OverridesConfig) {
+        self.common.merge_from_file(file.common);
+
+        let cli_sets_pre_release_num = self.pre_release_num.is_some() || self.prerelease_date;
+        if !cli_sets_pre_release_num {
+            self.pre_release_num = file.pre_release_num;
+            self.prerelease_date = file.prerelease_date;
+        }
+        self.dev = self.dev.take().or(file.dev);
+        self.pre_release_label = self.pre_release_label.take().or(file.pre_release_label);
+        self.custom = self.custom.take().or(file.custom);
+        self.branch_version_regex = self
+            .branch_version_regex
+            .take()
+            .or(file.branch_version_regex);
+        if self.core.is_empty() {
+            self.core = file.core;
+        }
+        if self.extra_core.is_empty() {
+            self.extra_core = file.extra_core;
+        }
+        if self.build.is_empty() {
+            self.build = file.build;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn overrides(args: &[&str]) -> OverridesConfig {
+        OverridesConfig::try_parse_from(std::iter::once("zerv").chain(args.iter().copied()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_merge_from_file_fills_missing_overrides() {
+        let file: OverridesConfig = serde_json::from_str(
+            r#"{"tag_version": "v1.2.3", "distance": 5, "dirty": true, "major": "{{ major }}", "core": ["0=7"]}"#,
+        )
+        .unwrap();
+        let mut config = overrides(&["--bumped-branch", "main"]);
+
+        config.merge_from_file(file);
+
+        assert_eq!(config.common.tag_version.as_deref(), Some("v1.2.3"));
+        assert_eq!(config.common.distance, Some(5));
+        assert!(config.common.dirty);
+        assert_eq!(config.common.bumped_branch.as_deref(), Some("main"));
+        assert_eq!(
+            config.common.major.as_ref().map(Template::as_str),
+            Some("{{ major }}")
+        );
+        assert_eq!(config.core, vec![Template::from("0=7")]);
+    }
+
+    #[test]
+    fn test_merge_from_file_flags_win() {
+        let file: OverridesConfig = serde_json::from_str(
+            r#"{"tag_version": "v1.2.3", "distance": 5, "dirty": true, "pre_release_num": 2}"#,
+        )
+        .unwrap();
+        let mut config = overrides(&["--tag-version", "v2.0.0", "--clean", "--prerelease-date"]);
+
+        config.merge_from_file(file);
+
+        assert_eq!(config.common.tag_version.as_deref(), Some("v2.0.0"));
+        assert!(config.common.clean);
+        assert!(!config.common.dirty);
+        assert_eq!(config.common.distance, None);
+        assert!(config.prerelease_date);
+        assert!(config.pre_release_num.is_none());
+    }
+
+    #[rstest::rstest]
+    #[case::known(serde_json::json!({"distance": 9, "tag_version": "1.0.0", "core": []}), true)]
+    #[case::typo(serde_json::json!({"distanc": 9}), false)]
+    #[case::unknown_common(serde_json::json!({"tag_versions": "1.0.0"}), false)]
+    #[case::file_itself(serde_json::json!({"overrides_file": "other.json"}), false)]
+    #[case::not_a_map(serde_json::json!([1, 2]), false)]
+    fn test_check_keys(#[case] value: serde_json::Value, #[case] expected_ok: bool) {
+        assert_eq!(OverridesConfig::check_keys(&value).is_ok(), expected_ok);
+    }
+
+    #[test]
+    fn test_overrides_file_rejects_unknown_extension() {
+        let mut config = overrides(&["--overrides-file", "overrides.toml"]);
+        assert!(matches!(
+            config.apply_overrides_file(),
+            Err(ZervError::InvalidArgument(_))
+        ));
+    }
 }
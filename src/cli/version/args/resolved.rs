@@ -15,7 +15,11 @@ use crate::cli::utils::template::{
     TemplateExt,
 };
 use crate::error::ZervError;
-use crate::utils::constants::pre_release_labels;
+use crate::utils::constants::{
+    base_bump_levels,
+    pre_release_labels,
+};
+use crate::vcs::conventional_commits::ConventionalBump;
 use crate::version::Zerv;
 
 /// Shared trait for template resolution operations
@@ -77,11 +81,11 @@ pub trait TemplateResolver {
                 };
 
                 // Strict validation: ensure resolved value is a valid pre-release label
-                if !pre_release_labels::VALID_LABELS.contains(&resolved.as_str()) {
+                if !pre_release_labels::VALID_SETTABLE_LABELS.contains(&resolved.as_str()) {
                     return Err(ZervError::TemplateError(format!(
                         "Template resolved to invalid pre-release label '{}'. Must be one of: {} or None keywords: {}",
                         resolved,
-                        pre_release_labels::VALID_LABELS.join(", "),
+                        pre_release_labels::VALID_SETTABLE_LABELS.join(", "),
                         "none, null, nil"
                     )));
                 }
@@ -146,6 +150,10 @@ pub struct ResolvedBumps {
     pub bump_pre_release_num: Option<Option<u32>>,
     pub bump_epoch: Option<Option<u32>>,
     pub bump_pre_release_label: Option<String>,
+    pub prerelease_bump_resets: Option<Vec<String>>,
+    pub epoch_reset: Option<String>,
+    pub keep_prerelease: bool,
+    pub max_component_value: Option<u64>,
 
     // Schema-based bumps (resolved from templates)
     pub bump_core: Vec<String>,
@@ -159,6 +167,31 @@ pub struct ResolvedBumps {
 
 impl TemplateResolver for ResolvedBumps {}
 
+impl ResolvedBumps {
+    /// Select the core bump inferred from conventional commits
+    pub fn apply_conventional_bump(&mut self, bump: ConventionalBump) {
+        let field = match bump {
+            ConventionalBump::Major => &mut self.bump_major,
+            ConventionalBump::Minor => &mut self.bump_minor,
+            ConventionalBump::Patch => &mut self.bump_patch,
+        };
+        *field = Some(Some(1));
+    }
+
+    /// Bump `level` (see `base_bump_levels`) unless a core bump is already selected
+    pub fn apply_base_bump(&mut self, level: &str) {
+        if self.bump_major.is_some() || self.bump_minor.is_some() || self.bump_patch.is_some() {
+            return;
+        }
+        let field = match level {
+            base_bump_levels::MAJOR => &mut self.bump_major,
+            base_bump_levels::MINOR => &mut self.bump_minor,
+            _ => &mut self.bump_patch,
+        };
+        *field = Some(Some(1));
+    }
+}
+
 impl ResolvedArgs {
     /// Resolve all templates in VersionArgs using Zerv snapshot
     pub fn resolve(args: &VersionArgs, zerv: &Zerv) -> Result<Self, ZervError> {
@@ -176,6 +209,13 @@ impl ResolvedArgs {
 
 impl ResolvedOverrides {
     fn resolve(overrides: &OverridesConfig, zerv: &Zerv) -> Result<Self, ZervError> {
+        if overrides.prerelease_date && zerv.vars.bumped_timestamp.is_none() {
+            return Err(ZervError::InvalidArgument(
+                "--prerelease-date requires a commit timestamp (use --bumped-timestamp without a VCS source)"
+                    .to_string(),
+            ));
+        }
+
         Ok(ResolvedOverrides {
             // VCS overrides (copy as-is)
             tag_version: overrides.common.tag_version.clone(),
@@ -235,6 +275,10 @@ impl ResolvedBumps {
                 &bumps.bump_pre_release_label,
                 zerv,
             )?,
+            prerelease_bump_resets: bumps.prerelease_bump_resets.clone(),
+            epoch_reset: bumps.epoch_reset.clone(),
+            keep_prerelease: bumps.keep_prerelease,
+            max_component_value: bumps.max_component_value,
 
             // Schema-based bumps (resolve templates)
             bump_core: Self::resolve_template_strings(&bumps.bump_core, zerv)?,
@@ -163,6 +163,7 @@ use rstest::rstest;
 #[case(&["version", "--clean", "--distance", "5"], &["--clean", "--distance"])]
 #[case(&["version", "--clean", "--dirty"], &["--clean", "--dirty"])]
 #[case(&["version", "--clean", "--no-dirty"], &["--clean", "--no-dirty"])]
+#[case(&["version", "--clean", "--clean-if-tagged"], &["--clean", "--clean-if-tagged"])]
 #[case(&["version", "--bump-context", "--no-bump-context"], &["--bump-context", "--no-bump-context"])]
 #[case(&["zerv", "--no-bump-context", "--dirty"], &["--no-bump-context", "--dirty"])]
 fn test_validate_conflicting_options(#[case] args: &[&str], #[case] expected_flags: &[&str]) {
@@ -215,3 +215,30 @@ fn test_resolved_args_mixed_templates_and_values(
     assert_eq!(resolved.bumps.bump_major, Some(Some(bump_major)));
     assert_eq!(resolved.bumps.bump_minor, Some(Some(zerv_patch as u32)));
 }
+
+#[rstest]
+#[case::patch(&["version"], "patch", [false, false, true])]
+#[case::minor(&["version"], "minor", [false, true, false])]
+#[case::major(&["version"], "major", [true, false, false])]
+#[case::explicit_bump_wins(&["version", "--bump-minor"], "patch", [false, true, false])]
+fn test_resolved_bumps_apply_base_bump(
+    #[case] cli_args: &[&str],
+    #[case] level: &str,
+    #[case] expected_major_minor_patch: [bool; 3],
+) {
+    let args = VersionArgs::try_parse_from(cli_args).unwrap();
+    let zerv = ZervFixture::new().with_version(1, 2, 3).build();
+    let mut resolved = ResolvedArgs::resolve(&args, &zerv).unwrap();
+
+    resolved.bumps.apply_base_bump(level);
+
+    let bumps = &resolved.bumps;
+    assert_eq!(
+        [
+            bumps.bump_major.is_some(),
+            bumps.bump_minor.is_some(),
+            bumps.bump_patch.is_some()
+        ],
+        expected_major_minor_patch
+    );
+}
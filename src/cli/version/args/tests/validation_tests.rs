@@ -1,5 +1,9 @@
+use clap::Parser;
+use rstest::rstest;
+
 use super::super::*;
 use crate::error::ZervError;
+use crate::utils::constants::formats;
 
 /// Helper function to validate that an invalid input is rejected for a specific section
 fn assert_invalid_input_rejected(invalid_input: &str, section: &str, expected_arg_name: &str) {
@@ -157,3 +161,95 @@ fn test_validate_schema_bump_args_invalid_odd_count() {
     let result = args.validate(None);
     assert!(result.is_ok());
 }
+
+#[rstest]
+#[case("dirty", true)]
+#[case("wip-build", true)]
+#[case("", false)]
+#[case("has space", false)]
+#[case("dirty.tree", false)]
+fn test_validate_dirty_marker(#[case] marker: &str, #[case] expected_ok: bool) {
+    let mut args = VersionArgs {
+        main: MainConfig {
+            dirty_marker: Some(marker.to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = args.validate(None);
+    if expected_ok {
+        assert!(result.is_ok());
+    } else {
+        assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+    }
+}
+
+#[rstest]
+#[case::off(&[], formats::PEP440, false, false)]
+#[case::pep440(&["--strict"], formats::PEP440, true, true)]
+#[case::semver(&["--strict"], formats::SEMVER, true, false)]
+#[case::no_fail_on_dirty(&["--strict", "--no-fail-on-dirty"], formats::PEP440, false, true)]
+#[case::no_strict_pep440(&["--strict", "--no-strict-pep440"], formats::PEP440, true, false)]
+#[case::individual_without_strict(&["--fail-on-dirty"], formats::SEMVER, true, false)]
+fn test_resolve_strict(
+    #[case] flags: &[&str],
+    #[case] output_format: &str,
+    #[case] expected_fail_on_dirty: bool,
+    #[case] expected_strict_pep440: bool,
+) {
+    let mut args = VersionArgs::try_parse_from(
+        [
+            "version",
+            "--source",
+            "none",
+            "--output-format",
+            output_format,
+        ]
+        .iter()
+        .chain(flags),
+    )
+    .unwrap();
+    args.validate(None).unwrap();
+    assert_eq!(args.main.fail_on_dirty, expected_fail_on_dirty);
+    assert_eq!(args.output.strict_pep440, expected_strict_pep440);
+}
+
+#[rstest]
+#[case::off(&["--output-template", "{{ major }}"], false)]
+#[case::strict(&["--strict", "--output-template", "{{ major }}"], true)]
+#[case::no_template_strict(
+    &["--strict", "--no-template-strict", "--output-template", "{{ major }}"],
+    false
+)]
+#[case::strict_without_template(&["--strict"], false)]
+#[case::individual_without_strict(&["--template-strict", "--output-template", "{{ major }}"], true)]
+fn test_resolve_strict_template(#[case] flags: &[&str], #[case] expected_template_strict: bool) {
+    let mut args =
+        VersionArgs::try_parse_from(["version", "--source", "none"].iter().chain(flags)).unwrap();
+    args.validate(None).unwrap();
+    assert_eq!(args.output.template_strict, expected_template_strict);
+}
+
+#[rstest]
+#[case::git("git", r"release/(?P<major>\d+)\.(?P<minor>\d+)", true)]
+#[case::none_source("none", r"release/(?P<major>\d+)", false)]
+#[case::unnamed_group("git", r"release/(\d+)", false)]
+#[case::unrelated_group("git", r"release/(?P<line>\d+)", false)]
+#[case::invalid_regex("git", r"release/(?P<major>", false)]
+fn test_validate_branch_version_regex(
+    #[case] source: &str,
+    #[case] pattern: &str,
+    #[case] expected_ok: bool,
+) {
+    let mut args = VersionArgs::try_parse_from([
+        "version",
+        "--source",
+        source,
+        "--tag-version",
+        "1.0.0",
+        "--branch-version-regex",
+        pattern,
+    ])
+    .unwrap();
+    assert_eq!(args.validate(None).is_ok(), expected_ok);
+}
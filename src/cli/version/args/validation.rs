@@ -1,13 +1,23 @@
 use super::{
     BumpsConfig,
+    MainConfig,
     OverridesConfig,
 };
 use crate::cli::common::args::{
     InputConfig,
     OutputConfig,
+    Validation as CommonValidation,
 };
 use crate::cli::utils::template::Template;
+use crate::cli::version::git_pipeline::compile_branch_version_regex;
 use crate::error::ZervError;
+use crate::schema::ZervSchemaPreset;
+use crate::utils::constants::{
+    formats,
+    prerelease_bump_resets,
+    sources,
+    timestamp_patterns,
+};
 
 /// Validation methods for argument combinations
 pub struct Validation;
@@ -19,6 +29,23 @@ impl Validation {
         Ok(())
     }
 
+    /// Validate version-specific main options
+    pub fn validate_main_config(main: &MainConfig) -> Result<(), ZervError> {
+        if let Some(marker) = main.dirty_marker.as_deref() {
+            let is_valid_identifier = !marker.is_empty()
+                && marker
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-');
+            if !is_valid_identifier {
+                return Err(ZervError::InvalidArgument(format!(
+                    "--dirty-marker must be a non-empty alphanumeric identifier (hyphens allowed), got '{marker}'"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate overrides configuration
     pub fn validate_overrides(overrides: &OverridesConfig) -> Result<(), ZervError> {
         // Check for conflicting dirty flags
@@ -28,6 +55,12 @@ impl Validation {
             ));
         }
 
+        if overrides.common.clean && overrides.common.clean_if_tagged {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use --clean with --clean-if-tagged (conflicting options)".to_string(),
+            ));
+        }
+
         // Check for --clean conflicts
         if overrides.common.clean {
             if overrides.common.distance.is_some() {
@@ -60,12 +93,54 @@ impl Validation {
             ));
         }
 
+        // 'none' keeps everything, so it cannot be listed with components to reset
+        if let Some(ref resets) = bumps.prerelease_bump_resets
+            && resets.len() > 1
+            && resets
+                .iter()
+                .any(|reset| reset == prerelease_bump_resets::NONE)
+        {
+            return Err(ZervError::ConflictingOptions(
+                "--prerelease-bump-resets 'none' cannot be combined with other components"
+                    .to_string(),
+            ));
+        }
+
         // Validate schema-based bump arguments
         Self::validate_schema_bump_args(bumps)?;
 
         Ok(())
     }
 
+    /// Commit messages are only available from git history
+    pub fn validate_conventional_commits(
+        input: &InputConfig,
+        bumps: &BumpsConfig,
+    ) -> Result<(), ZervError> {
+        if bumps.conventional_commits && input.source.as_deref() != Some(sources::GIT) {
+            return Err(ZervError::ConflictingOptions(
+                "--conventional-commits requires --source git".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// --branch-version-regex reads the git branch and must capture a version field
+    pub fn validate_branch_version_regex(
+        input: &InputConfig,
+        overrides: &OverridesConfig,
+    ) -> Result<(), ZervError> {
+        let Some(ref pattern) = overrides.branch_version_regex else {
+            return Ok(());
+        };
+        if input.source.as_deref() != Some(sources::GIT) {
+            return Err(ZervError::ConflictingOptions(
+                "--branch-version-regex requires --source git".to_string(),
+            ));
+        }
+        compile_branch_version_regex(pattern).map(|_| ())
+    }
+
     /// Validate cross-module conflicts
     pub fn validate_cross_module(
         overrides: &OverridesConfig,
@@ -109,6 +184,101 @@ impl Validation {
         Ok(())
     }
 
+    /// Validate a comma-separated --schema list rendering several versions
+    pub fn validate_schema_list(main: &MainConfig, output: &OutputConfig) -> Result<(), ZervError> {
+        let names = main.schema_names();
+        if names.len() < 2 {
+            return Ok(());
+        }
+        if names.iter().any(|name| name.is_empty()) {
+            return Err(ZervError::InvalidArgument(format!(
+                "--schema list contains an empty entry: '{}'",
+                main.schema.as_deref().unwrap_or_default()
+            )));
+        }
+        if output.output_format == formats::ZERV {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use several --schema values with --output-format zerv".to_string(),
+            ));
+        }
+        // Multi-line outputs cannot carry the per-schema labels
+        if output.output_template.is_none()
+            && matches!(
+                output.output_format.as_str(),
+                formats::ENV | formats::PROPERTIES
+            )
+        {
+            return Err(ZervError::ConflictingOptions(format!(
+                "Cannot use several --schema values with --output-format {}",
+                output.output_format
+            )));
+        }
+        if output.sbom.is_some() {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use several --schema values with --sbom".to_string(),
+            ));
+        }
+        if main.transform_command.is_some() {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use several --schema values with --transform-command".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Resolve --schema-flags into the equivalent preset name
+    pub fn resolve_schema_flags(main: &mut MainConfig) -> Result<(), ZervError> {
+        if let Some(flags) = main.schema_flags.take() {
+            main.schema = Some(ZervSchemaPreset::name_from_flags(&flags)?);
+        }
+        Ok(())
+    }
+
+    /// Resolve --strict into the individual strict checks it bundles
+    ///
+    /// A `--no-*` flag keeps its check off; --template-strict is only implied with
+    /// --output-template and --strict-pep440 only for plain PEP440 output, where they apply.
+    pub fn resolve_strict(
+        main: &mut MainConfig,
+        output: &mut OutputConfig,
+    ) -> Result<(), ZervError> {
+        if main.no_strict_pep440 && output.strict_pep440 {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use --strict-pep440 with --no-strict-pep440".to_string(),
+            ));
+        }
+        if main.no_template_strict && output.template_strict {
+            return Err(ZervError::ConflictingOptions(
+                "Cannot use --template-strict with --no-template-strict".to_string(),
+            ));
+        }
+        if !main.strict {
+            return Ok(());
+        }
+        if !main.no_fail_on_dirty {
+            main.fail_on_dirty = true;
+        }
+        if !main.no_template_strict && output.output_template.is_some() {
+            output.template_strict = true;
+        }
+        if !main.no_strict_pep440
+            && output.output_format == formats::PEP440
+            && output.output_template.is_none()
+        {
+            output.strict_pep440 = true;
+        }
+        CommonValidation::validate_strict_pep440_context_separator(output)
+    }
+
+    /// Resolve --prerelease-date into a pre-release number template
+    pub fn resolve_override_defaults(overrides: &mut OverridesConfig) {
+        if overrides.prerelease_date {
+            overrides.pre_release_num = Some(Template::new(
+                timestamp_patterns::PRERELEASE_DATE_TEMPLATE.to_string(),
+            ));
+        }
+    }
+
     /// Resolve default bump values
     /// If a bump option is provided without a value, set it to 1 (the default)
     pub fn resolve_bump_defaults(bumps: &mut BumpsConfig) -> Result<(), ZervError> {
@@ -1,40 +1,421 @@
-use std::path::Path;
+use std::cmp::Ordering;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use regex::Regex;
 
 use super::args::VersionArgs;
 use super::zerv_draft::ZervDraft;
 use crate::error::ZervError;
 use crate::pipeline::vcs_data_to_zerv_vars;
-use crate::version::VersionObject;
+use crate::utils::constants::{
+    abbrev_modes,
+    empty_repo,
+    sources,
+};
+use crate::utils::manifest::read_manifest_version;
+use crate::vcs::ci_branch::ci_branch;
+use crate::vcs::conventional_commits::bump_from_commits;
+use crate::vcs::git::GitOptions;
+use crate::vcs::{
+    Vcs,
+    VcsData,
+};
+use crate::version::zerv::ZervVars;
+use crate::version::zerv::core::PreReleaseVar;
+use crate::version::{
+    PreReleaseLabel,
+    TagPrefix,
+    VersionObject,
+    Zerv,
+};
+
+/// Hex digits of a short commit hash, as in git's default abbreviation
+const SHORT_HASH_DIGITS: usize = 7;
+
+/// Named groups --branch-version-regex can capture
+const BRANCH_VERSION_GROUPS: [&str; 3] = ["major", "minor", "patch"];
+
+/// Validate the args of a git-only subcommand and resolve its working directory
+/// Any --source other than git is rejected, so the subcommand always reads the repository
+pub fn prepare_git_only_args(command: &str, args: &mut VersionArgs) -> Result<PathBuf, ZervError> {
+    if args
+        .input
+        .source
+        .as_deref()
+        .is_some_and(|source| source != sources::GIT)
+    {
+        return Err(ZervError::ConflictingOptions(format!(
+            "{command} requires --source git"
+        )));
+    }
+    args.input.source = Some(sources::GIT.to_string());
+    args.validate(None)?;
+
+    Ok(match args.input.directory.as_deref() {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::current_dir()?,
+    })
+}
+
+/// Detect the git repository and read its raw VcsData, before any fallback or conversion
+pub fn detect_git_vcs_data(
+    work_dir: &Path,
+    args: &VersionArgs,
+) -> Result<(Box<dyn Vcs>, VcsData), ZervError> {
+    let max_depth = search_depth(args);
+    // Resolve --path-filter against the working directory so git sees an absolute pathspec
+    let path_filter = args
+        .input
+        .path_filter
+        .as_deref()
+        .map(|filter| resolve_path_filter(work_dir, filter))
+        .transpose()?;
+    let options = GitOptions {
+        path_filter,
+        tag_prefix: args.input.tag_prefix(),
+        no_network: args.input.no_network,
+        max_tags: args.input.max_tags,
+        no_tag_timestamp: args.input.no_tag_timestamp,
+        equal_tag_preference: args.input.equal_tag_preference.clone(),
+        rev: args.input.rev.clone(),
+        use_index: args.input.use_index,
+        tags_reachable: args.input.tags_reachable.clone(),
+        timings: args.input.timings,
+        commit_hash_prefix: args.input.commit_prefix.clone(),
+        distance_inclusive: args.input.distance_inclusive,
+    };
+    let vcs = match args.input.repo.as_deref() {
+        Some(repo) => crate::vcs::open_vcs_at_root(Path::new(repo), options)?,
+        None => crate::vcs::detect_vcs_with_options(work_dir, max_depth, options)?,
+    };
+    let mut vcs_data = vcs.get_vcs_data(&args.input.input_format)?;
+
+    // Detached CI checkouts report no branch, but the CI environment still knows it
+    if vcs_data.current_branch.is_none()
+        && let Some(ref provider) = args.input.branch_from_ci
+    {
+        vcs_data.current_branch = ci_branch(provider, |key| std::env::var(key).ok());
+        tracing::debug!(
+            "Branch from {} CI environment: {:?}",
+            provider,
+            vcs_data.current_branch
+        );
+    }
+
+    Ok((vcs, vcs_data))
+}
 
 /// Process git source and return a ZervDraft object
 pub fn process_git_source(work_dir: &Path, args: &VersionArgs) -> Result<ZervDraft, ZervError> {
-    // Get git VCS data
-    // If directory was specified via -C, only look in that directory (depth 0)
-    // If no directory specified, allow unlimited depth search
-    let max_depth = if args.input.directory.is_some() {
-        Some(0)
+    let max_depth = search_depth(args);
+    let mut tag_prefix = args.input.tag_prefix();
+    let (vcs, mut vcs_data) = detect_git_vcs_data(work_dir, args)?;
+
+    // Refuse to fall back when no tag was found, unless --tag-version stands in for it
+    if args.input.fail_if_untagged
+        && vcs_data.tag_version.is_none()
+        && args.overrides.common.tag_version.is_none()
+    {
+        return Err(ZervError::VcsNotFound(
+            "no version tag is reachable from HEAD and --fail-if-untagged is set (tag a release or pass --tag-version)"
+                .to_string(),
+        ));
+    }
+
+    // In CI a tagged checkout is a release even if the build left files behind
+    if args.overrides.common.clean_if_tagged && is_on_tag(&vcs_data) {
+        tracing::debug!(
+            "HEAD is on tag {:?}, forcing clean state",
+            vcs_data.tag_version
+        );
+        vcs_data.distance = 0;
+        vcs_data.is_dirty = false;
+        vcs_data.is_staged_dirty = false;
+        vcs_data.is_unstaged_dirty = false;
+    }
+
+    // Read the commits since the tag before any fallback replaces it
+    let conventional_bump = if args.bumps.conventional_commits {
+        let messages = vcs.get_commit_messages_since(vcs_data.tag_version.as_deref())?;
+        let bump = bump_from_commits(&messages);
+        tracing::debug!(
+            "Conventional commits: {} since tag, bump {:?}",
+            messages.len(),
+            bump
+        );
+        bump
     } else {
         None
     };
-    let vcs_data = crate::vcs::detect_vcs_with_limit(work_dir, max_depth)?
-        .get_vcs_data(&args.input.input_format)?;
+
+    // Fall back to (or prefer) the manifest version as the base
+    if let Some(ref manifest) = args.input.base_from_manifest
+        && (vcs_data.tag_version.is_none() || args.input.prefer_manifest)
+    {
+        let repo_root = match args.input.repo.as_deref() {
+            Some(repo) => PathBuf::from(repo),
+            None => crate::vcs::find_vcs_root_with_limit(work_dir, max_depth)?,
+        };
+        let version = read_manifest_version(&repo_root, manifest)?;
+        tracing::debug!("Using base version {} from {} manifest", version, manifest);
+        vcs_data.tag_version = Some(version);
+        // Manifest versions are never prefixed like tags
+        tag_prefix = TagPrefix::default();
+    }
+
+    // An empty repository starts from a fixed base unless --first-tag names one
+    if vcs_data.is_empty_repo && vcs_data.tag_version.is_none() && args.input.first_tag.is_none() {
+        tracing::debug!(
+            "Repository has no commits, using base version {}",
+            empty_repo::BASE_VERSION
+        );
+        vcs_data.tag_version = Some(empty_repo::BASE_VERSION.to_string());
+        tag_prefix = TagPrefix::default();
+    }
+
+    // Without any tag, --first-tag stands in for the release tag on the root commit
+    let mut first_tag_pending = false;
+    if let Some(ref first_tag) = args.input.first_tag
+        && vcs_data.tag_version.is_none()
+    {
+        vcs_data.distance = vcs.count_commits_since_root()?;
+        tracing::debug!(
+            "No tag found, using first tag {} at distance {}",
+            first_tag,
+            vcs_data.distance
+        );
+        vcs_data.tag_version = Some(first_tag.clone());
+        tag_prefix = TagPrefix::default();
+        first_tag_pending = vcs_data.distance > 0;
+    }
+
+    // Account for commits that are missing from the imported history
+    if let Some(offset) = args.input.distance_offset {
+        vcs_data.distance = apply_distance_offset(vcs_data.distance, offset)?;
+    }
 
     // Parse git tag with input format if available and validate it
+    let mut tag_build = Vec::new();
+    let mut inherited_pre_release_label = None;
     if let Some(ref tag_version) = vcs_data.tag_version {
-        let _parsed_version =
-            VersionObject::parse_with_format(tag_version, &args.input.input_format)?;
+        let parsed_version =
+            VersionObject::parse_with_prefix(tag_version, &args.input.input_format, &tag_prefix)?;
         // Validation passed - the tag is in a valid format
+        if args.main.preserve_tag_metadata || args.main.inherit_prerelease_label {
+            let tag_zerv = Zerv::from(parsed_version);
+            if args.main.preserve_tag_metadata {
+                tag_build = tag_zerv.schema.build().clone();
+            }
+            if args.main.inherit_prerelease_label {
+                // Raw pre-releases (e.g. 1.0.0-x.7) have no label to continue
+                inherited_pre_release_label = tag_zerv
+                    .vars
+                    .pre_release
+                    .and_then(|pre_release| pre_release.label());
+            }
+        }
     }
 
+    // Let git extend the short hash until it is unique
+    let abbreviated_hash =
+        if args.input.abbrev.as_deref() == Some(abbrev_modes::AUTO) && !vcs_data.is_empty_repo {
+            Some(format!(
+                "{}{}",
+                vcs_data.commit_hash_prefix,
+                vcs.get_abbreviated_commit_hash()?
+            ))
+        } else if args.input.commit_prefix.is_some() && !vcs_data.is_empty_repo {
+            // The derived short hash is a fixed-width slice that assumes the one-character "g"
+            let digits = SHORT_HASH_DIGITS.min(vcs_data.commit_hash.len());
+            Some(format!(
+                "{}{}",
+                vcs_data.commit_hash_prefix,
+                &vcs_data.commit_hash[..digits]
+            ))
+        } else {
+            None
+        };
+
+    // --bumped-branch stands in for the detected branch, as it does in the output
+    let branch = args
+        .overrides
+        .common
+        .bumped_branch
+        .clone()
+        .or_else(|| vcs_data.current_branch.clone());
+
     // Convert VCS data to ZervVars
-    let vars = vcs_data_to_zerv_vars(vcs_data, &args.input.input_format)?;
+    let mut vars = vcs_data_to_zerv_vars(vcs_data, &args.input.input_format, &tag_prefix)?;
+    vars.bumped_commit_hash_short = abbreviated_hash;
+
+    if let Some(ref pattern) = args.overrides.branch_version_regex {
+        apply_branch_version_regex(&mut vars, branch.as_deref(), pattern)?;
+    }
+
+    // Commits after the root precede the unreleased first tag
+    if first_tag_pending && vars.pre_release.is_none() {
+        vars.pre_release = Some(PreReleaseVar::labeled(
+            PreReleaseLabel::Alpha,
+            vars.distance,
+        ));
+    }
+
+    print_timings(vcs.as_ref());
 
     // Return ZervDraft without schema (git source)
-    Ok(ZervDraft::new(vars, None))
+    Ok(ZervDraft::new(vars, None)
+        .with_conventional_bump(conventional_bump)
+        .with_tag_build(tag_build)
+        .with_inherited_pre_release_label(inherited_pre_release_label))
+}
+
+/// Print the --timings table of the git operations run so far to stderr
+pub fn print_timings(vcs: &dyn Vcs) {
+    if let Some(summary) = vcs.timings_summary() {
+        eprintln!("{summary}");
+    }
+}
+
+/// Compile --branch-version-regex, which must name at least one of the version groups
+pub fn compile_branch_version_regex(pattern: &str) -> Result<Regex, ZervError> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| ZervError::Regex(format!("Invalid --branch-version-regex: {e}")))?;
+    if !regex
+        .capture_names()
+        .flatten()
+        .any(|name| BRANCH_VERSION_GROUPS.contains(&name))
+    {
+        return Err(ZervError::InvalidArgument(
+            "--branch-version-regex must contain a named group major, minor or patch (e.g. (?P<major>\\d+))"
+                .to_string(),
+        ));
+    }
+    Ok(regex)
+}
+
+/// The major/minor/patch a branch name captures with a --branch-version-regex,
+/// or None when the branch does not match
+pub fn branch_version_line(
+    regex: &Regex,
+    branch: &str,
+) -> Result<Option<[Option<u64>; 3]>, ZervError> {
+    let Some(captures) = regex.captures(branch) else {
+        return Ok(None);
+    };
+    let mut line = [None; 3];
+    for (group, slot) in BRANCH_VERSION_GROUPS.into_iter().zip(line.iter_mut()) {
+        if let Some(value) = captures.name(group) {
+            *slot = Some(value.as_str().parse().map_err(|_| {
+                ZervError::InvalidArgument(format!(
+                    "--branch-version-regex captured {group} '{}', which is not a number",
+                    value.as_str()
+                ))
+            })?);
+        }
+    }
+    Ok(Some(line))
+}
+
+/// Order a version's major/minor/patch against the parts a branch version line names,
+/// Greater when the version is already ahead of the line
+pub fn compare_with_branch_line(line: &[Option<u64>; 3], version: [Option<u64>; 3]) -> Ordering {
+    line.iter()
+        .zip(version)
+        .filter_map(|(expected, actual)| {
+            expected.map(|expected| actual.unwrap_or(0).cmp(&expected))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Set major/minor/patch from the groups --branch-version-regex captures in the branch name
+fn apply_branch_version_regex(
+    vars: &mut ZervVars,
+    branch: Option<&str>,
+    pattern: &str,
+) -> Result<(), ZervError> {
+    let regex = compile_branch_version_regex(pattern)?;
+    let line = match branch {
+        Some(branch) => branch_version_line(&regex, branch)?,
+        None => None,
+    };
+    let (Some(branch), Some(line)) = (branch, line) else {
+        tracing::debug!("Branch {:?} does not match --branch-version-regex", branch);
+        return Ok(());
+    };
+    // Moving the version back below the tag would release an older version after a newer one
+    if compare_with_branch_line(&line, [vars.major, vars.minor, vars.patch]) == Ordering::Greater {
+        return Err(ZervError::InvalidVersion(format!(
+            "Version {}.{}.{} is ahead of the line that branch {branch} names in --branch-version-regex",
+            vars.major.unwrap_or(0),
+            vars.minor.unwrap_or(0),
+            vars.patch.unwrap_or(0)
+        )));
+    }
+    // Fields below a changed one restart at 0 (release/1.4 after v1.3.2 is 1.4.0)
+    let mut changed = false;
+    let fields = [&mut vars.major, &mut vars.minor, &mut vars.patch];
+    for (captured, field) in line.into_iter().zip(fields) {
+        match captured {
+            Some(number) => {
+                changed |= *field != Some(number);
+                *field = Some(number);
+            }
+            None if changed => *field = Some(0),
+            None => {}
+        }
+    }
+    // The tag's pre-release belongs to its own version, not to the new line
+    if changed {
+        vars.pre_release = None;
+    }
+    tracing::debug!(
+        "Base version {:?}.{:?}.{:?} from branch {:?}",
+        vars.major,
+        vars.minor,
+        vars.patch,
+        branch
+    );
+    Ok(())
+}
+
+/// With -C only look in that directory (depth 0), otherwise search parent directories
+fn search_depth(args: &VersionArgs) -> Option<usize> {
+    if args.input.directory.is_some() {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Whether the detected tag was found by `git tag --points-at` on the revision itself
+fn is_on_tag(vcs_data: &VcsData) -> bool {
+    vcs_data.tag_version.is_some()
+        && vcs_data.tag_commit_hash.as_deref() == Some(vcs_data.commit_hash.as_str())
+}
+
+fn apply_distance_offset(distance: u32, offset: u32) -> Result<u32, ZervError> {
+    distance.checked_add(offset).ok_or_else(|| {
+        ZervError::InvalidArgument(format!(
+            "--distance-offset {offset} overflows the detected distance {distance}"
+        ))
+    })
+}
+
+fn resolve_path_filter(work_dir: &Path, filter: &str) -> Result<String, ZervError> {
+    let resolved = std::path::absolute(work_dir.join(filter))?;
+    resolved.to_str().map(str::to_string).ok_or_else(|| {
+        ZervError::InvalidArgument(format!("--path-filter is not valid UTF-8: {filter}"))
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
     use crate::test_utils::{
         GitRepoFixture,
@@ -83,4 +464,123 @@ mod tests {
             "Git source should not have schema initially"
         );
     }
+
+    #[rstest]
+    #[case(0, 0, 0)]
+    #[case(3, 100, 103)]
+    #[case(0, u32::MAX, u32::MAX)]
+    fn test_apply_distance_offset(
+        #[case] distance: u32,
+        #[case] offset: u32,
+        #[case] expected: u32,
+    ) {
+        assert_eq!(apply_distance_offset(distance, offset).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::on_tag(Some("v1.0.0"), Some("abc123"), true)]
+    #[case::after_tag(Some("v1.0.0"), Some("def456"), false)]
+    #[case::no_tag(None, None, false)]
+    fn test_is_on_tag(
+        #[case] tag_version: Option<&str>,
+        #[case] tag_commit_hash: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let vcs_data = VcsData {
+            tag_version: tag_version.map(str::to_string),
+            tag_commit_hash: tag_commit_hash.map(str::to_string),
+            commit_hash: "abc123".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(is_on_tag(&vcs_data), expected);
+    }
+
+    #[test]
+    fn test_apply_distance_offset_overflow() {
+        let result = apply_distance_offset(1, u32::MAX);
+        assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+    }
+
+    const RELEASE_MAJOR_MINOR: &str = r"^release/(?P<major>\d+)\.(?P<minor>\d+)$";
+
+    #[rstest]
+    #[case::new_release_line(Some("release/1.4"), RELEASE_MAJOR_MINOR, (1, 4, 0))]
+    #[case::same_release_line(Some("release/1.3"), RELEASE_MAJOR_MINOR, (1, 3, 2))]
+    #[case::new_major_only(Some("v2"), r"^v(?P<major>\d+)$", (2, 0, 0))]
+    #[case::patch_captured(
+        Some("hotfix/1.3.7"),
+        r"^hotfix/(?P<major>\d+)\.(?P<minor>\d+)\.(?P<patch>\d+)$",
+        (1, 3, 7)
+    )]
+    #[case::not_matching(Some("feature/login"), RELEASE_MAJOR_MINOR, (1, 3, 2))]
+    #[case::detached_head(None, RELEASE_MAJOR_MINOR, (1, 3, 2))]
+    fn test_apply_branch_version_regex(
+        #[case] branch: Option<&str>,
+        #[case] pattern: &str,
+        #[case] expected: (u64, u64, u64),
+    ) {
+        let mut vars = ZervVars {
+            major: Some(1),
+            minor: Some(3),
+            patch: Some(2),
+            ..Default::default()
+        };
+
+        apply_branch_version_regex(&mut vars, branch, pattern).unwrap();
+
+        let (major, minor, patch) = expected;
+        assert_eq!(
+            (vars.major, vars.minor, vars.patch),
+            (Some(major), Some(minor), Some(patch))
+        );
+    }
+
+    #[rstest]
+    #[case::new_line(Some("release/1.4"), None)]
+    #[case::same_line(
+        Some("release/1.3"),
+        Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(1)))
+    )]
+    #[case::not_matching(
+        Some("feature/login"),
+        Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(1)))
+    )]
+    fn test_apply_branch_version_regex_pre_release(
+        #[case] branch: Option<&str>,
+        #[case] expected: Option<PreReleaseVar>,
+    ) {
+        let mut vars = ZervVars {
+            major: Some(1),
+            minor: Some(3),
+            patch: Some(0),
+            pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(1))),
+            ..Default::default()
+        };
+
+        apply_branch_version_regex(&mut vars, branch, RELEASE_MAJOR_MINOR).unwrap();
+
+        assert_eq!(vars.pre_release, expected);
+    }
+
+    #[test]
+    fn test_apply_branch_version_regex_rejects_line_below_tag() {
+        let mut vars = ZervVars {
+            major: Some(2),
+            minor: Some(0),
+            patch: Some(0),
+            pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(1))),
+            ..Default::default()
+        };
+        let result =
+            apply_branch_version_regex(&mut vars, Some("release/1.4"), RELEASE_MAJOR_MINOR);
+        assert!(matches!(result, Err(ZervError::InvalidVersion(_))));
+    }
+
+    #[test]
+    fn test_apply_branch_version_regex_rejects_non_numeric_capture() {
+        let mut vars = ZervVars::default();
+        let result =
+            apply_branch_version_regex(&mut vars, Some("release/next"), r"release/(?P<major>\w+)");
+        assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+    }
 }
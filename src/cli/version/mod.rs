@@ -3,11 +3,21 @@ pub mod git_pipeline;
 pub mod none_pipeline;
 pub mod pipeline;
 pub mod stdin_pipeline;
+pub mod watch;
 pub mod zerv_draft;
 
 pub use args::VersionArgs;
-pub use git_pipeline::process_git_source;
+pub use git_pipeline::{
+    branch_version_line,
+    compare_with_branch_line,
+    compile_branch_version_regex,
+    detect_git_vcs_data,
+    prepare_git_only_args,
+    print_timings,
+    process_git_source,
+};
 pub use none_pipeline::process_none_source;
 pub use pipeline::run_version_pipeline;
 pub use stdin_pipeline::process_cached_stdin_source;
+pub use watch::run_version_watch;
 pub use zerv_draft::ZervDraft;
@@ -1,10 +1,25 @@
+use super::args::VersionArgs;
 use super::zerv_draft::ZervDraft;
 use crate::error::ZervError;
 use crate::version::zerv::vars::ZervVars;
 
 /// Process none source (no VCS) and return a ZervDraft object
 /// All version data comes from CLI overrides (--tag-version, --distance, etc.)
-pub fn process_none_source() -> Result<ZervDraft, ZervError> {
+pub fn process_none_source(args: &VersionArgs) -> Result<ZervDraft, ZervError> {
+    // Without a repository the base version can only come from the overrides
+    let overrides = &args.overrides;
+    let has_base_version = overrides.common.tag_version.is_some()
+        || overrides.common.major.is_some()
+        || overrides.common.minor.is_some()
+        || overrides.common.patch.is_some()
+        || !overrides.core.is_empty();
+    if !has_base_version {
+        return Err(ZervError::InvalidArgument(
+            "--source none needs a base version: pass --tag-version, --major/--minor/--patch or --core"
+                .to_string(),
+        ));
+    }
+
     // Create empty ZervVars with all default (None) values
     // Overrides will be applied later via ZervDraft::to_zerv()
     let vars = ZervVars::default();
@@ -15,11 +30,23 @@ pub fn process_none_source() -> Result<ZervDraft, ZervError> {
 
 #[cfg(test)]
 mod tests {
+    use clap::Parser;
+    use rstest::rstest;
+
     use super::*;
 
+    fn none_args(overrides: &str) -> VersionArgs {
+        let mut args = VersionArgs::try_parse_from(
+            format!("version --source none {overrides}").split_whitespace(),
+        )
+        .unwrap();
+        args.validate(None).unwrap();
+        args
+    }
+
     #[test]
     fn test_process_none_source_basic() {
-        let result = process_none_source();
+        let result = process_none_source(&none_args("--tag-version 1.2.3"));
         assert!(result.is_ok());
 
         let draft = result.unwrap();
@@ -34,8 +61,27 @@ mod tests {
 
     #[test]
     fn test_process_none_source_no_schema() {
-        let draft = process_none_source().unwrap();
+        let draft = process_none_source(&none_args("--tag-version 1.2.3")).unwrap();
         // None source doesn't provide a schema
         assert!(draft.schema.is_none());
     }
+
+    #[rstest]
+    #[case::tag_version("--tag-version 1.2.3", true)]
+    #[case::major("--major 2", true)]
+    #[case::patch("--patch 4", true)]
+    #[case::core("--core 0=5", true)]
+    #[case::nothing("", false)]
+    #[case::distance_only("--distance 3 --dirty", false)]
+    fn test_process_none_source_requires_base_version(
+        #[case] overrides: &str,
+        #[case] expected_ok: bool,
+    ) {
+        let result = process_none_source(&none_args(overrides));
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+        }
+    }
 }
@@ -1,9 +1,19 @@
 use std::env::current_dir;
+use std::io::{
+    ErrorKind,
+    Write,
+};
+use std::process::{
+    Command,
+    Stdio,
+};
 
 use super::args::VersionArgs;
+use super::zerv_draft::ZervDraft;
 use crate::cli::utils::output_formatter::OutputFormatter;
 use crate::error::ZervError;
 use crate::utils::constants::sources;
+use crate::version::Zerv;
 
 pub fn run_version_pipeline(
     mut args: VersionArgs,
@@ -24,23 +34,204 @@ pub fn run_version_pipeline(
         Some(sources::STDIN) => {
             super::stdin_pipeline::process_cached_stdin_source(&args, stdin_content)?
         }
-        Some(sources::NONE) => super::none_pipeline::process_none_source()?,
+        Some(sources::NONE) => super::none_pipeline::process_none_source(&args)?,
         Some(source) => return Err(ZervError::UnknownSource(source.to_string())),
         None => {
             return Err(ZervError::UnknownSource("none (not set)".to_string()));
         }
     };
 
+    // Several schemas reuse the same draft, so the source is only scanned once
+    let schema_names = args.main.schema_names();
+    if schema_names.len() > 1 {
+        return render_schemas(&zerv_draft, &args, &schema_names);
+    }
+
+    render_draft(zerv_draft, &args)
+}
+
+/// Render the draft once per schema, each line labeled with its schema name
+fn render_schemas(
+    zerv_draft: &ZervDraft,
+    args: &VersionArgs,
+    schema_names: &[&str],
+) -> Result<String, ZervError> {
+    let mut lines = Vec::with_capacity(schema_names.len());
+    for name in schema_names {
+        let mut schema_args = args.clone();
+        schema_args.main.schema = Some(name.to_string());
+        let output = render_draft(zerv_draft.clone(), &schema_args)?;
+        lines.push(format!("{name}: {output}"));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn render_draft(zerv_draft: ZervDraft, args: &VersionArgs) -> Result<String, ZervError> {
     // 3. Convert to Zerv (applies overrides internally)
-    let zerv_object = zerv_draft.to_zerv(&args)?;
+    let mut zerv_object = zerv_draft.to_zerv(args)?;
+
+    // 3.2. Refuse to version uncommitted changes when asked to
+    if args.main.fail_on_dirty && zerv_object.vars.dirty == Some(true) {
+        return Err(ZervError::InvalidVersion(
+            "Working tree is dirty (--fail-on-dirty); commit or stash the changes first"
+                .to_string(),
+        ));
+    }
+
+    // 3.5. Let an external command post-process the Zerv object
+    if let Some(command) = args.main.transform_command.as_deref() {
+        zerv_object = apply_transform_command(&zerv_object, command)?;
+    }
 
     // 4. Apply output formatting with template resolution
-    let output = OutputFormatter::format_output(
+    let output = OutputFormatter::format_with_scope(
         &zerv_object,
-        &args.output.output_format,
-        args.output.output_prefix.as_deref(),
-        &args.output.output_template,
+        &args.output,
+        args.input.scope_prefix.as_deref(),
+        args.input.commit_prefix.as_deref(),
     )?;
 
     Ok(output)
 }
+
+/// Pipe the Zerv RON through an external shell command and parse the returned RON
+fn apply_transform_command(zerv_object: &Zerv, command: &str) -> Result<Zerv, ZervError> {
+    tracing::debug!("Running transform command: {}", command);
+
+    let mut child = shell_command(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            ZervError::CommandFailed(format!(
+                "Failed to run --transform-command '{command}': {e}"
+            ))
+        })?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The command may legitimately exit without reading its input
+        match stdin.write_all(zerv_object.to_string().as_bytes()) {
+            Err(e) if e.kind() != ErrorKind::BrokenPipe => return Err(e.into()),
+            _ => {}
+        }
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(ZervError::CommandFailed(format!(
+            "--transform-command '{command}' failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let transformed: Zerv = ron::from_str(stdout.trim()).map_err(|e| {
+        ZervError::InvalidFormat(format!(
+            "--transform-command '{command}' returned invalid Zerv RON: {e}"
+        ))
+    })?;
+
+    // Re-validate the schema in case the command changed it; schema validation reports
+    // errors as stdin input errors, but this schema came from the command
+    Zerv::new(transformed.schema, transformed.vars).map_err(|e| match e {
+        ZervError::StdinError(msg) => ZervError::InvalidFormat(format!(
+            "--transform-command '{command}' returned an invalid Zerv schema: {msg}"
+        )),
+        other => other,
+    })
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/C", command]);
+    cmd
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::zerv::ZervFixture;
+
+    #[test]
+    fn test_render_schemas_share_one_draft() {
+        let draft = ZervDraft::new(
+            ZervFixture::new()
+                .with_version(1, 2, 3)
+                .with_distance(4)
+                .build()
+                .vars,
+            None,
+        );
+        let mut args = VersionArgs::default();
+        args.input.source = Some(sources::NONE.to_string());
+
+        let output =
+            render_schemas(&draft, &args, &["standard-base", "standard-base-context"]).unwrap();
+        assert_eq!(
+            output,
+            "standard-base: 1.2.3\nstandard-base-context: 1.2.3+4"
+        );
+    }
+
+    #[test]
+    fn test_transform_command_identity() {
+        let zerv = ZervFixture::new().with_version(1, 2, 3).build();
+        let result = apply_transform_command(&zerv, "cat").unwrap();
+        assert_eq!(result, zerv);
+    }
+
+    #[test]
+    fn test_transform_command_sed_mutation() {
+        let zerv = ZervFixture::new().with_version(1, 2, 3).build();
+        let result =
+            apply_transform_command(&zerv, "sed 's/major: Some(1)/major: Some(7)/'").unwrap();
+        assert_eq!(result.vars.major, Some(7));
+        assert_eq!(result.vars.minor, Some(2));
+        assert_eq!(result.schema, zerv.schema);
+    }
+
+    #[rstest]
+    #[case::not_ron("echo 'not ron'")]
+    #[case::empty("true")]
+    #[case::ignores_input("echo '(vars: ())'")]
+    fn test_transform_command_malformed_output(#[case] command: &str) {
+        let zerv = ZervFixture::new().build();
+        let result = apply_transform_command(&zerv, command);
+        assert!(
+            matches!(result, Err(ZervError::InvalidFormat(ref msg)) if msg.contains("invalid Zerv RON")),
+            "unexpected result: {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_transform_command_invalid_schema() {
+        let zerv = ZervFixture::new().build();
+        let result = apply_transform_command(&zerv, "sed 's/var(Minor),/var(Epoch),/'");
+        assert!(
+            matches!(result, Err(ZervError::InvalidFormat(ref msg)) if msg.contains("returned an invalid Zerv schema")),
+            "unexpected result: {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_transform_command_failure_exit_code() {
+        let zerv = ZervFixture::new().build();
+        let result = apply_transform_command(&zerv, "echo boom >&2; exit 3");
+        assert!(
+            matches!(result, Err(ZervError::CommandFailed(ref msg)) if msg.contains("boom")),
+            "unexpected result: {result:?}"
+        );
+    }
+}
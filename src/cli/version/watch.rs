@@ -0,0 +1,305 @@
+use std::io::Write;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use super::args::VersionArgs;
+use crate::error::ZervError;
+use crate::vcs::git::GitVcs;
+
+/// Quiet period required after the last repository event before recomputing
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Paths inside `.git` whose changes can affect the computed version
+const WATCHED_GIT_ENTRIES: [&str; 4] = ["HEAD", "index", "packed-refs", "refs"];
+
+/// Trailing-edge debouncer: a burst of events yields a single recompute once
+/// no new event has arrived for the configured window
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    window: Duration,
+    last_event: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            last_event: None,
+        }
+    }
+
+    /// Record an event, restarting the quiet period
+    pub fn record(&mut self, at: Instant) {
+        self.last_event = Some(at);
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.last_event.is_some()
+    }
+
+    /// Time left until the pending event settles, or None if nothing is pending
+    pub fn time_until_ready(&self, now: Instant) -> Option<Duration> {
+        self.last_event.map(|last| {
+            self.window
+                .saturating_sub(now.saturating_duration_since(last))
+        })
+    }
+
+    /// Returns true (and clears the pending state) once the quiet period has elapsed
+    pub fn take_ready(&mut self, now: Instant) -> bool {
+        match self.time_until_ready(now) {
+            Some(remaining) if remaining.is_zero() => {
+                self.last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Check whether a changed path is one of the git entries that affect the version
+pub fn is_relevant_git_path(path: &Path, git_dir: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(git_dir) else {
+        return false;
+    };
+    relative
+        .components()
+        .next()
+        .and_then(|first| first.as_os_str().to_str())
+        .is_some_and(|first| WATCHED_GIT_ENTRIES.contains(&first))
+}
+
+/// Git directories to watch for the repository at `repo_root`, see [`GitVcs::git_dirs`]
+pub fn resolve_git_dirs(repo_root: &Path) -> Result<(PathBuf, PathBuf), ZervError> {
+    GitVcs::new(repo_root)?.git_dirs()
+}
+
+#[cfg(feature = "watch")]
+fn run_once(args: &VersionArgs) -> Result<String, ZervError> {
+    super::run_version_pipeline(args.clone(), None)
+}
+
+/// Run the version pipeline, then keep recomputing it whenever `.git/HEAD`,
+/// `.git/refs` or the index change
+#[cfg(feature = "watch")]
+pub fn run_version_watch<W: Write>(args: VersionArgs, writer: &mut W) -> Result<(), ZervError> {
+    use std::sync::mpsc;
+
+    use notify::{
+        RecursiveMode,
+        Watcher,
+    };
+
+    use crate::cli::utils::output_formatter::OutputFormatter;
+    use crate::utils::constants::sources;
+
+    let mut validated = args.clone();
+    validated.validate(None)?;
+    if validated.input.source.as_deref() != Some(sources::GIT) {
+        return Err(ZervError::ConflictingOptions(
+            "--watch requires --source git".to_string(),
+        ));
+    }
+
+    let work_dir = match args.input.directory.as_deref() {
+        Some(dir) => std::path::PathBuf::from(dir),
+        None => std::env::current_dir()?,
+    };
+    let repo_root = match args.input.repo.as_deref() {
+        Some(repo) => std::path::PathBuf::from(repo),
+        None => crate::vcs::find_vcs_root(&work_dir)?,
+    };
+    let (git_dir, common_dir) = resolve_git_dirs(&repo_root)?;
+
+    let watch_error = |e: notify::Error| ZervError::CommandFailed(format!("Watch failed: {e}"));
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(watch_error)?;
+    watcher
+        .watch(&git_dir, RecursiveMode::NonRecursive)
+        .map_err(watch_error)?;
+    if common_dir != git_dir {
+        watcher
+            .watch(&common_dir, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+    }
+    watcher
+        .watch(&common_dir.join("refs"), RecursiveMode::Recursive)
+        .map_err(watch_error)?;
+
+    let terminator = OutputFormatter::terminator(&args.output);
+    let initial = run_once(&args)?;
+    OutputFormatter::publish_github_output(&args.output, &initial)?;
+    write!(writer, "{initial}{terminator}")?;
+    writer.flush()?;
+
+    let mut debouncer = Debouncer::new(DEFAULT_DEBOUNCE);
+    let mut last_output = Some(initial);
+    loop {
+        let timeout = debouncer
+            .time_until_ready(Instant::now())
+            .unwrap_or(Duration::from_secs(60));
+        match rx.recv_timeout(timeout) {
+            Ok(Ok(event)) => {
+                if event.paths.iter().any(|path| {
+                    is_relevant_git_path(path, &git_dir) || is_relevant_git_path(path, &common_dir)
+                }) {
+                    debouncer.record(Instant::now());
+                }
+            }
+            Ok(Err(e)) => tracing::warn!("Watch error: {e}"),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+
+        if debouncer.take_ready(Instant::now()) {
+            match run_once(&args) {
+                Ok(output) if last_output.as_deref() != Some(output.as_str()) => {
+                    OutputFormatter::publish_github_output(&args.output, &output)?;
+                    write!(writer, "{output}{terminator}")?;
+                    writer.flush()?;
+                    last_output = Some(output);
+                }
+                Ok(_) => tracing::debug!("Version unchanged after repository event"),
+                Err(e) => tracing::warn!("Failed to recompute version: {e}"),
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "watch"))]
+pub fn run_version_watch<W: Write>(_args: VersionArgs, _writer: &mut W) -> Result<(), ZervError> {
+    Err(ZervError::NotImplemented(
+        "--watch requires zerv to be built with the 'watch' feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::git::GitRepoFixture;
+    use crate::test_utils::should_run_docker_tests;
+
+    mod debouncer {
+        use super::*;
+
+        #[test]
+        fn test_idle_debouncer_is_never_ready() {
+            let mut debouncer = Debouncer::new(DEFAULT_DEBOUNCE);
+            let now = Instant::now();
+            assert!(!debouncer.is_pending());
+            assert_eq!(debouncer.time_until_ready(now), None);
+            assert!(!debouncer.take_ready(now + Duration::from_secs(10)));
+        }
+
+        #[test]
+        fn test_single_event_fires_after_window() {
+            let window = Duration::from_millis(100);
+            let mut debouncer = Debouncer::new(window);
+            let start = Instant::now();
+            debouncer.record(start);
+
+            assert!(!debouncer.take_ready(start + Duration::from_millis(50)));
+            assert!(debouncer.take_ready(start + window));
+            assert!(!debouncer.is_pending());
+            assert!(!debouncer.take_ready(start + window * 2));
+        }
+
+        #[test]
+        fn test_burst_of_events_fires_once() {
+            let window = Duration::from_millis(100);
+            let mut debouncer = Debouncer::new(window);
+            let start = Instant::now();
+
+            for offset in [0, 30, 60, 90] {
+                debouncer.record(start + Duration::from_millis(offset));
+                assert!(!debouncer.take_ready(start + Duration::from_millis(offset + 20)));
+            }
+
+            let last = start + Duration::from_millis(90);
+            assert!(!debouncer.take_ready(last + Duration::from_millis(99)));
+            assert!(debouncer.take_ready(last + window));
+            assert!(!debouncer.take_ready(last + window * 3));
+        }
+
+        #[test]
+        fn test_time_until_ready_counts_down() {
+            let window = Duration::from_millis(100);
+            let mut debouncer = Debouncer::new(window);
+            let start = Instant::now();
+            debouncer.record(start);
+
+            assert_eq!(
+                debouncer.time_until_ready(start + Duration::from_millis(40)),
+                Some(Duration::from_millis(60))
+            );
+            assert_eq!(
+                debouncer.time_until_ready(start + Duration::from_millis(500)),
+                Some(Duration::ZERO)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("/repo/.git/HEAD", true)]
+    #[case("/repo/.git/index", true)]
+    #[case("/repo/.git/packed-refs", true)]
+    #[case("/repo/.git/refs/heads/main", true)]
+    #[case("/repo/.git/refs/tags/v1.0.0", true)]
+    #[case("/repo/.git/index.lock", false)]
+    #[case("/repo/.git/objects/ab/cdef", false)]
+    #[case("/repo/.git/FETCH_HEAD", false)]
+    #[case("/repo/src/main.rs", false)]
+    fn test_is_relevant_git_path(#[case] path: &str, #[case] expected: bool) {
+        let git_dir = PathBuf::from("/repo/.git");
+        assert_eq!(is_relevant_git_path(Path::new(path), &git_dir), expected);
+    }
+
+    #[test]
+    fn test_resolve_git_dirs_in_repository() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("should create repo");
+
+        let (git_dir, common_dir) = resolve_git_dirs(fixture.path()).unwrap();
+        assert_eq!(git_dir, fixture.path().join(".git"));
+        assert_eq!(common_dir, git_dir);
+    }
+
+    #[test]
+    fn test_resolve_git_dirs_in_linked_worktree() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("should create repo");
+        let worktree = fixture.path().join("wt");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["worktree", "add", "wt", "-b", "wt"])
+            .expect("should add worktree");
+        assert!(worktree.join(".git").is_file());
+
+        let (git_dir, common_dir) = resolve_git_dirs(&worktree).unwrap();
+        assert!(git_dir.join("HEAD").is_file());
+        assert!(git_dir.ends_with("worktrees/wt"), "{}", git_dir.display());
+        assert!(common_dir.join("refs").is_dir());
+        assert_ne!(git_dir, common_dir);
+    }
+
+    #[cfg(not(feature = "watch"))]
+    #[test]
+    fn test_watch_without_feature_is_not_implemented() {
+        let mut output = Vec::new();
+        let result = run_version_watch(VersionArgs::default(), &mut output);
+        assert!(matches!(result, Err(ZervError::NotImplemented(_))));
+        assert!(output.is_empty());
+    }
+}
@@ -4,12 +4,17 @@ use crate::cli::version::args::{
 };
 use crate::error::ZervError;
 use crate::schema::{
+    SmartThresholds,
     ZervSchemaPreset,
     parse_ron_schema,
 };
+use crate::vcs::conventional_commits::ConventionalBump;
 use crate::version::zerv::{
+    Component,
+    PreReleaseLabel,
     Zerv,
     ZervSchema,
+    ZervSchemaPatch,
     ZervVars,
 };
 
@@ -19,26 +24,73 @@ use crate::version::zerv::{
 pub struct ZervDraft {
     pub vars: ZervVars,
     pub schema: Option<ZervSchema>, // Some for stdin, None for git
+    /// Bump inferred from commit messages (--conventional-commits, git only)
+    pub conventional_bump: Option<ConventionalBump>,
+    /// Build metadata of the detected tag (--preserve-tag-metadata, git only)
+    pub tag_build: Vec<Component>,
+    /// Pre-release label of the detected tag (--inherit-prerelease-label, git only)
+    pub inherited_pre_release_label: Option<PreReleaseLabel>,
 }
 
 impl ZervDraft {
     pub fn new(vars: ZervVars, schema: Option<ZervSchema>) -> Self {
-        Self { vars, schema }
+        Self {
+            vars,
+            schema,
+            conventional_bump: None,
+            tag_build: Vec::new(),
+            inherited_pre_release_label: None,
+        }
+    }
+
+    pub fn with_conventional_bump(mut self, bump: Option<ConventionalBump>) -> Self {
+        self.conventional_bump = bump;
+        self
+    }
+
+    pub fn with_tag_build(mut self, tag_build: Vec<Component>) -> Self {
+        self.tag_build = tag_build;
+        self
+    }
+
+    pub fn with_inherited_pre_release_label(mut self, label: Option<PreReleaseLabel>) -> Self {
+        self.inherited_pre_release_label = label;
+        self
     }
 
     pub fn to_zerv(mut self, args: &VersionArgs) -> Result<Zerv, ZervError> {
         // Apply overrides first
         self.vars.apply_context_overrides(args)?;
+        let conventional_bump = self.conventional_bump;
+        let tag_build = std::mem::take(&mut self.tag_build);
+        let inherited_pre_release_label = self.inherited_pre_release_label.take();
 
         // Then create the Zerv object
         // let (schema_name, schema_ron) = args.resolve_schema();
         let mut zerv = self.create_zerv_version(args)?;
 
         // Resolve templates using the current Zerv state
-        let resolved_args = ResolvedArgs::resolve(args, &zerv)?;
+        let mut resolved_args = ResolvedArgs::resolve(args, &zerv)?;
+        if let Some(bump) = conventional_bump {
+            resolved_args.bumps.apply_conventional_bump(bump);
+        }
+        if let Some(level) = args.bumps.base_bump.as_deref()
+            && zerv.vars.distance.unwrap_or(0) > 0
+        {
+            resolved_args.bumps.apply_base_bump(level);
+        }
 
         // Apply component processing (bumps with reset logic)
         zerv.apply_component_processing(&resolved_args)?;
+        // An explicit label wins over the one inherited from the tag
+        if args.overrides.pre_release_label.is_none() && args.bumps.bump_pre_release_label.is_none()
+        {
+            zerv.apply_inherited_pre_release_label(inherited_pre_release_label);
+        }
+        zerv.apply_dirty_on_tag(args.main.dirty_on_tag.as_deref())?;
+        zerv.apply_tag_build(&tag_build)?;
+        zerv.apply_dirty_marker(args.main.dirty_marker.as_deref())?;
+        zerv.apply_build_number(args.main.build_number_formula)?;
         zerv.normalize();
 
         Ok(zerv)
@@ -49,6 +101,7 @@ impl ZervDraft {
         schema_ron: Option<&str>,
         existing_schema: Option<ZervSchema>,
         vars: &ZervVars,
+        thresholds: &SmartThresholds,
     ) -> Result<ZervSchema, ZervError> {
         match (schema_name, schema_ron) {
             // Custom RON schema
@@ -56,7 +109,7 @@ impl ZervDraft {
 
             // Built-in schema
             (Some(name), None) => match name.parse::<ZervSchemaPreset>() {
-                Ok(schema) => Ok(schema.schema_with_zerv(vars)),
+                Ok(schema) => Ok(schema.schema_with_thresholds(vars, thresholds)),
                 Err(_) => Err(ZervError::UnknownSchema(name.to_string())),
             },
 
@@ -69,19 +122,34 @@ impl ZervDraft {
                 if let Some(existing_schema) = existing_schema {
                     Ok(existing_schema)
                 } else {
-                    Ok(ZervSchemaPreset::Standard.schema_with_zerv(vars))
+                    Ok(ZervSchemaPreset::Standard.schema_with_thresholds(vars, thresholds))
                 }
             }
         }
     }
 
     pub fn create_zerv_version(self, args: &VersionArgs) -> Result<Zerv, ZervError> {
-        let schema = Self::resolve_schema(
+        let defaults = SmartThresholds::default();
+        let thresholds = SmartThresholds {
+            distance_threshold: args
+                .main
+                .smart_distance_threshold
+                .unwrap_or(defaults.distance_threshold),
+            dirty_includes_dev: args
+                .main
+                .smart_dirty_includes_dev
+                .unwrap_or(defaults.dirty_includes_dev),
+        };
+        let mut schema = Self::resolve_schema(
             args.main.schema.as_deref(),
             args.main.schema_ron.as_deref(),
             self.schema,
             &self.vars,
+            &thresholds,
         )?;
+        if let Some(patch) = args.main.schema_patch.as_deref() {
+            schema.apply_patch(ZervSchemaPatch::from_ron(patch)?)?;
+        }
         Zerv::new(schema, self.vars)
     }
 }
@@ -214,6 +282,7 @@ mod tests {
             main: MainConfig {
                 schema: Some(schema_preset_names::STANDARD.to_string()),
                 schema_ron: Some(ron_schema.to_string()),
+                ..Default::default()
             },
             ..Default::default()
         };
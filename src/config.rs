@@ -1,4 +1,11 @@
 use std::env;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use crate::error::ZervError;
+use crate::utils::constants::config_files;
 
 /// Centralized environment variable names used throughout Zerv.
 /// Following uv's pattern for maintainability and documentation.
@@ -40,6 +47,42 @@ impl EnvVars {
     ///
     /// If not set, Zerv will fall back to searching for common pagers (less, more, most).
     pub const PAGER: &'static str = "PAGER";
+
+    /// File that GitHub Actions reads step outputs from.
+    ///
+    /// Set by the Actions runner; `--github-output NAME` appends `NAME=<version>` to it.
+    pub const GITHUB_OUTPUT: &'static str = "GITHUB_OUTPUT";
+
+    /// Set to `true` by the GitHub Actions runner; `--branch-from-ci auto` detects GitHub with it.
+    pub const GITHUB_ACTIONS: &'static str = "GITHUB_ACTIONS";
+
+    /// Source branch of a GitHub pull request (empty for other events).
+    pub const GITHUB_HEAD_REF: &'static str = "GITHUB_HEAD_REF";
+
+    /// Short name of the branch or tag that triggered a GitHub workflow.
+    pub const GITHUB_REF_NAME: &'static str = "GITHUB_REF_NAME";
+
+    /// Whether `GITHUB_REF_NAME` names a `branch` or a `tag`.
+    pub const GITHUB_REF_TYPE: &'static str = "GITHUB_REF_TYPE";
+
+    /// Set to `true` in GitLab CI jobs; `--branch-from-ci auto` detects GitLab with it.
+    pub const GITLAB_CI: &'static str = "GITLAB_CI";
+
+    /// Source branch of a GitLab merge request pipeline.
+    pub const CI_MERGE_REQUEST_SOURCE_BRANCH_NAME: &'static str =
+        "CI_MERGE_REQUEST_SOURCE_BRANCH_NAME";
+
+    /// Branch of a GitLab branch pipeline (unset for tag and merge request pipelines).
+    pub const CI_COMMIT_BRANCH: &'static str = "CI_COMMIT_BRANCH";
+
+    /// Base directory for user-global configuration (XDG Base Directory spec).
+    ///
+    /// Zerv reads `$XDG_CONFIG_HOME/zerv/config.toml`, falling back to `$HOME/.config`
+    /// when unset or not an absolute path.
+    pub const XDG_CONFIG_HOME: &'static str = "XDG_CONFIG_HOME";
+
+    /// User home directory, used to locate `~/.config` when `XDG_CONFIG_HOME` is unset.
+    pub const HOME: &'static str = "HOME";
 }
 
 #[derive(Debug, Clone, Default)]
@@ -83,6 +126,60 @@ impl ZervConfig {
     pub fn should_force_rust_log_off(&self) -> bool {
         self.force_rust_log_off
     }
+
+    /// Path of the user-global config file, if a config home can be determined
+    pub fn global_config_path() -> Option<PathBuf> {
+        let config_home = env::var_os(EnvVars::XDG_CONFIG_HOME)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| {
+                env::var_os(EnvVars::HOME)
+                    .map(|home| PathBuf::from(home).join(config_files::XDG_DEFAULT_DIR))
+            })?;
+        Some(
+            config_home
+                .join(config_files::GLOBAL_DIR)
+                .join(config_files::GLOBAL_FILE),
+        )
+    }
+}
+
+/// Default option values for `subcommand` from the config files
+///
+/// Each file holds one table per subcommand keyed by long option name, e.g.
+/// `[version]` with `output-format = "pep440"`. Repo config (`zerv.toml` in the
+/// repository root `repo_dir`) is merged over the global config key by key; missing files are skipped.
+pub fn load_config_defaults(
+    subcommand: &str,
+    global_path: Option<&Path>,
+    repo_dir: &Path,
+) -> Result<toml::Table, ZervError> {
+    let repo_path = repo_dir.join(config_files::REPO_FILE);
+    let mut defaults = toml::Table::new();
+    for path in global_path.into_iter().chain([repo_path.as_path()]) {
+        if let Some(section) = read_config_section(path, subcommand)? {
+            defaults.extend(section);
+        }
+    }
+    Ok(defaults)
+}
+
+fn read_config_section(path: &Path, subcommand: &str) -> Result<Option<toml::Table>, ZervError> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)?;
+    let mut config: toml::Table = toml::from_str(&content).map_err(|e| {
+        ZervError::InvalidFormat(format!("Failed to parse {}: {e}", path.display()))
+    })?;
+    match config.remove(subcommand) {
+        None => Ok(None),
+        Some(toml::Value::Table(section)) => Ok(Some(section)),
+        Some(_) => Err(ZervError::InvalidFormat(format!(
+            "[{subcommand}] in {} must be a table of option defaults",
+            path.display()
+        ))),
+    }
 }
 
 #[cfg(test)]
@@ -240,4 +337,91 @@ mod tests {
         assert!(config.should_run_docker_tests());
         assert!(config.should_force_rust_log_off());
     }
+
+    mod config_file_lookup {
+        use std::fs;
+
+        use tempfile::TempDir;
+
+        use super::*;
+
+        const GLOBAL: &str = "[version]\noutput-format = \"pep440\"\nschema = \"calver\"\n";
+        const REPO: &str = "[version]\nschema = \"standard\"\n[flow]\nsource = \"none\"\n";
+
+        fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+            let path = dir.path().join(name);
+            fs::write(&path, content).unwrap();
+            path
+        }
+
+        #[test]
+        fn test_repo_config_overrides_global_per_key() {
+            let dir = TempDir::new().unwrap();
+            let global = write(&dir, "global.toml", GLOBAL);
+            write(&dir, config_files::REPO_FILE, REPO);
+
+            let defaults = load_config_defaults("version", Some(&global), dir.path()).unwrap();
+
+            assert_eq!(defaults["output-format"].as_str(), Some("pep440"));
+            assert_eq!(defaults["schema"].as_str(), Some("standard"));
+        }
+
+        #[test]
+        fn test_missing_files_and_sections() {
+            let dir = TempDir::new().unwrap();
+            let missing = dir.path().join("missing.toml");
+            assert!(
+                load_config_defaults("version", Some(&missing), dir.path())
+                    .unwrap()
+                    .is_empty()
+            );
+
+            let global = write(&dir, "global.toml", GLOBAL);
+            assert!(
+                load_config_defaults("check", Some(&global), dir.path())
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn test_invalid_config() {
+            let dir = TempDir::new().unwrap();
+            let broken = write(&dir, "broken.toml", "[version\n");
+            let scalar = write(&dir, "scalar.toml", "version = 1\n");
+
+            for path in [broken, scalar] {
+                let result = load_config_defaults("version", Some(&path), dir.path());
+                assert!(matches!(result, Err(ZervError::InvalidFormat(_))));
+            }
+        }
+
+        #[test]
+        #[serial]
+        fn test_global_config_path() {
+            let _guard = EnvGuard::new(&[EnvVars::XDG_CONFIG_HOME, EnvVars::HOME]);
+            unsafe {
+                env::set_var(EnvVars::XDG_CONFIG_HOME, "/xdg");
+                env::set_var(EnvVars::HOME, "/home/user");
+            }
+            assert_eq!(
+                ZervConfig::global_config_path(),
+                Some(PathBuf::from("/xdg/zerv/config.toml"))
+            );
+
+            unsafe {
+                env::set_var(EnvVars::XDG_CONFIG_HOME, "relative");
+            }
+            assert_eq!(
+                ZervConfig::global_config_path(),
+                Some(PathBuf::from("/home/user/.config/zerv/config.toml"))
+            );
+
+            unsafe {
+                env::remove_var(EnvVars::XDG_CONFIG_HOME);
+                env::remove_var(EnvVars::HOME);
+            }
+            assert_eq!(ZervConfig::global_config_path(), None);
+        }
+    }
 }
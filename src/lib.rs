@@ -10,6 +10,12 @@ pub mod utils;
 pub mod vcs;
 pub mod version;
 
+pub use pipeline::{
+    RenderOptions,
+    render,
+};
+pub use version::parse;
+
 #[cfg(test)]
 mod test_setup {
 
@@ -1,3 +1,8 @@
+pub mod render;
 pub mod vcs_data_to_zerv_vars;
 
+pub use render::{
+    RenderOptions,
+    render,
+};
 pub use vcs_data_to_zerv_vars::vcs_data_to_zerv_vars;
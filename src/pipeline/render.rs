@@ -0,0 +1,88 @@
+use crate::cli::utils::output_formatter::OutputFormatter;
+use crate::cli::utils::template::Template;
+use crate::error::ZervError;
+use crate::version::Zerv;
+
+/// Options for [`render`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Text prepended to the rendered version
+    pub prefix: Option<String>,
+    /// Text appended to the rendered version
+    pub suffix: Option<String>,
+    /// Tera template used instead of `format`, as in `--output-template`
+    pub template: Option<String>,
+}
+
+/// Render a [`Zerv`] as a version string
+///
+/// `format` is one of the output formats (`semver`, `pep440`, `zerv`) and is
+/// ignored when a template is set, matching `zerv render`.
+pub fn render(zerv: &Zerv, format: &str, options: &RenderOptions) -> Result<String, ZervError> {
+    let template = options.template.clone().map(Template::<String>::new);
+    let mut output =
+        OutputFormatter::format_output(zerv, format, options.prefix.as_deref(), &template)?;
+    if let Some(suffix) = &options.suffix {
+        output.push_str(suffix);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::utils::constants::formats;
+
+    fn zerv() -> Zerv {
+        crate::version::parse("1.2.3-alpha.1", "semver").unwrap()
+    }
+
+    #[rstest]
+    #[case(formats::SEMVER, "1.2.3-alpha.1")]
+    #[case(formats::PEP440, "1.2.3a1")]
+    fn test_render_formats(#[case] format: &str, #[case] expected: &str) {
+        assert_eq!(
+            render(&zerv(), format, &RenderOptions::default()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_render_zerv_round_trips() {
+        let zerv = zerv();
+        let output = render(&zerv, formats::ZERV, &RenderOptions::default()).unwrap();
+        assert_eq!(output.parse::<Zerv>().unwrap(), zerv);
+    }
+
+    #[test]
+    fn test_render_prefix_and_suffix() {
+        let options = RenderOptions {
+            prefix: Some("v".to_string()),
+            suffix: Some("-linux".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            render(&zerv(), formats::SEMVER, &options).unwrap(),
+            "v1.2.3-alpha.1-linux"
+        );
+    }
+
+    #[test]
+    fn test_render_template() {
+        let options = RenderOptions {
+            template: Some("{{ major }}.{{ minor }}".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(render(&zerv(), formats::SEMVER, &options).unwrap(), "1.2");
+    }
+
+    #[test]
+    fn test_render_unknown_format() {
+        assert!(matches!(
+            render(&zerv(), "json", &RenderOptions::default()),
+            Err(ZervError::UnknownFormat(_))
+        ));
+    }
+}
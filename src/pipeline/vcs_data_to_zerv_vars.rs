@@ -1,12 +1,17 @@
 use crate::error::ZervError;
 use crate::vcs::VcsData;
 use crate::version::{
+    TagPrefix,
     VersionObject,
     ZervVars,
 };
 
 /// Convert VCS data to ZervVars
-pub fn vcs_data_to_zerv_vars(vcs_data: VcsData, input_format: &str) -> Result<ZervVars, ZervError> {
+pub fn vcs_data_to_zerv_vars(
+    vcs_data: VcsData,
+    input_format: &str,
+    tag_prefix: &TagPrefix,
+) -> Result<ZervVars, ZervError> {
     tracing::debug!(
         "Converting VCS data to Zerv variables with input format: {}",
         input_format
@@ -15,7 +20,7 @@ pub fn vcs_data_to_zerv_vars(vcs_data: VcsData, input_format: &str) -> Result<Ze
 
     // Parse version from tag_version using the provided input format
     let version = if let Some(ref tag_version) = vcs_data.tag_version {
-        VersionObject::parse_with_format(tag_version, input_format).map_err(|e| {
+        VersionObject::parse_with_prefix(tag_version, input_format, tag_prefix).map_err(|e| {
             tracing::error!(
                 "Failed to parse version from tag: {} with format {}: {}",
                 tag_version,
@@ -35,17 +40,23 @@ pub fn vcs_data_to_zerv_vars(vcs_data: VcsData, input_format: &str) -> Result<Ze
     vars.distance = Some(vcs_data.distance as u64);
     vars.bumped_branch = vcs_data.current_branch;
     vars.dirty = Some(vcs_data.is_dirty);
-    vars.bumped_commit_hash = Some(format!(
-        "{}{}",
-        vcs_data.commit_hash_prefix, vcs_data.commit_hash
-    ));
+    vars.staged_dirty = Some(vcs_data.is_staged_dirty);
+    vars.unstaged_dirty = Some(vcs_data.is_unstaged_dirty);
+    // An empty repository has no commit to hash
+    vars.bumped_commit_hash = (!vcs_data.commit_hash.is_empty())
+        .then(|| format!("{}{}", vcs_data.commit_hash_prefix, vcs_data.commit_hash));
     // Set last_commit_hash if available from tag_commit_hash
     vars.last_commit_hash = vcs_data
         .tag_commit_hash
         .map(|hash| format!("{}{}", vcs_data.commit_hash_prefix, hash));
     vars.bumped_timestamp = Some(vcs_data.commit_timestamp as u64);
+    vars.commit_subject = vcs_data.commit_subject;
+    // An empty repository has no commits to count
+    vars.commit_count = (!vcs_data.is_empty_repo).then_some(vcs_data.commit_count as u64);
+    vars.default_branch = vcs_data.default_branch;
     vars.last_timestamp = vcs_data.tag_timestamp.map(|t| t as u64);
     vars.last_tag_version = vcs_data.tag_version;
+    vars.tag_message = vcs_data.tag_message;
 
     tracing::debug!("VCS data conversion complete");
     Ok(vars)
@@ -75,7 +86,7 @@ mod tests {
             return;
         }
 
-        let vars = vcs_data_to_zerv_vars(vcs_data.clone(), input_format)
+        let vars = vcs_data_to_zerv_vars(vcs_data.clone(), input_format, &TagPrefix::default())
             .unwrap_or_else(|_| panic!("Failed to convert {format_name} VCS data to ZervVars"));
 
         assert_eq!(
@@ -113,7 +124,7 @@ mod tests {
             commit_hash: "abc1234".to_string(),
             ..Default::default()
         };
-        let result = vcs_data_to_zerv_vars(vcs_data, "auto");
+        let result = vcs_data_to_zerv_vars(vcs_data, "auto", &TagPrefix::default());
         assert!(result.is_err());
 
         match result {
@@ -139,10 +150,11 @@ mod tests {
             commit_timestamp: 1703123456,
             tag_timestamp: Some(1703000000),
             is_dirty: false,
+            ..Default::default()
         };
 
-        let vars =
-            vcs_data_to_zerv_vars(vcs_data, "auto").expect("should convert vcs data to vars");
+        let vars = vcs_data_to_zerv_vars(vcs_data, "auto", &TagPrefix::default())
+            .expect("should convert vcs data to vars");
 
         // Check that last_commit_hash is set with prefix
         assert_eq!(
@@ -172,10 +184,11 @@ mod tests {
             commit_timestamp: 1703123456,
             tag_timestamp: Some(1703000000),
             is_dirty: false,
+            ..Default::default()
         };
 
-        let vars =
-            vcs_data_to_zerv_vars(vcs_data, "auto").expect("should convert vcs data to vars");
+        let vars = vcs_data_to_zerv_vars(vcs_data, "auto", &TagPrefix::default())
+            .expect("should convert vcs data to vars");
 
         // Check that last_commit_hash is None when tag_commit_hash is None
         assert_eq!(
@@ -203,7 +216,7 @@ mod tests {
             commit_hash: "abc1234".to_string(),
             ..Default::default()
         };
-        let result = vcs_data_to_zerv_vars(vcs_data, "auto");
+        let result = vcs_data_to_zerv_vars(vcs_data, "auto", &TagPrefix::default());
 
         match result {
             Err(ZervError::InvalidFormat(msg)) => {
@@ -2,7 +2,13 @@ mod components;
 mod names;
 mod presets;
 
-pub use names::schema_preset_names;
-pub use presets::ZervSchemaPreset;
+pub use names::{
+    schema_flags,
+    schema_preset_names,
+};
+pub use presets::{
+    SmartThresholds,
+    ZervSchemaPreset,
+};
 
 pub use crate::version::zerv::schema::parse_ron_schema;
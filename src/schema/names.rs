@@ -25,3 +25,20 @@ pub mod schema_preset_names {
         "calver-base-prerelease-post-dev-context";
     pub const CALVER_CONTEXT: &str = "calver-context";
 }
+
+/// Components accepted by `--schema-flags`, composed into a preset name
+pub mod schema_flags {
+    pub const STANDARD: &str = "standard";
+    pub const CALVER: &str = "calver";
+    pub const BASE: &str = "base";
+    pub const PRERELEASE: &str = "prerelease";
+    pub const POST: &str = "post";
+    pub const DEV: &str = "dev";
+    pub const CONTEXT: &str = "context";
+
+    /// Version tiers in the order they appear in preset names; each requires the previous one
+    pub const TIERS: &[&str] = &[BASE, PRERELEASE, POST, DEV];
+
+    /// Used for validation of schema-flags argument
+    pub const VALID_FLAGS: &[&str] = &[STANDARD, CALVER, BASE, PRERELEASE, POST, DEV, CONTEXT];
+}
@@ -10,6 +10,7 @@ pub use super::components::{
     prerelease_post_dev_core,
     standard_core,
 };
+use super::names::schema_flags;
 pub use super::names::schema_preset_names::{
     CALVER,
     CALVER_BASE,
@@ -40,6 +41,37 @@ use crate::version::zerv::{
     ZervVars,
 };
 
+/// Thresholds for the smart schema auto-detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmartThresholds {
+    /// Minimum distance from the tag that adds the post tier and build context
+    pub distance_threshold: u64,
+    /// Whether a dirty working tree adds the dev tier
+    pub dirty_includes_dev: bool,
+}
+
+impl SmartThresholds {
+    pub const DEFAULT_DISTANCE_THRESHOLD: u64 = 1;
+    pub const DEFAULT_DIRTY_INCLUDES_DEV: bool = true;
+
+    fn is_dirty(&self, vars: &ZervVars) -> bool {
+        vars.dirty.unwrap_or(false)
+    }
+
+    fn is_distant(&self, vars: &ZervVars) -> bool {
+        vars.distance.unwrap_or(0) >= self.distance_threshold
+    }
+}
+
+impl Default for SmartThresholds {
+    fn default() -> Self {
+        Self {
+            distance_threshold: Self::DEFAULT_DISTANCE_THRESHOLD,
+            dirty_includes_dev: Self::DEFAULT_DIRTY_INCLUDES_DEV,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ZervSchemaPreset {
     Standard,
@@ -116,32 +148,99 @@ impl ZervSchemaPreset {
     }
 
     pub fn schema_with_zerv(&self, vars: &ZervVars) -> ZervSchema {
+        self.schema_with_thresholds(vars, &SmartThresholds::default())
+    }
+
+    /// Like [`Self::schema_with_zerv`] with tuned smart-detection thresholds
+    pub fn schema_with_thresholds(
+        &self,
+        vars: &ZervVars,
+        thresholds: &SmartThresholds,
+    ) -> ZervSchema {
         match self {
-            ZervSchemaPreset::Standard => {
-                self.with_smart_build_context(self.smart_standard_schema(vars), vars)
-            }
-            ZervSchemaPreset::StandardNoContext => self.smart_standard_schema(vars),
+            ZervSchemaPreset::Standard => self.with_smart_build_context(
+                self.smart_standard_schema(vars, thresholds),
+                vars,
+                thresholds,
+            ),
+            ZervSchemaPreset::StandardNoContext => self.smart_standard_schema(vars, thresholds),
             ZervSchemaPreset::StandardContext => {
-                self.with_build_context(self.smart_standard_schema(vars))
+                self.with_build_context(self.smart_standard_schema(vars, thresholds))
             }
 
-            ZervSchemaPreset::Calver => {
-                self.with_smart_build_context(self.smart_calver_schema(vars), vars)
-            }
-            ZervSchemaPreset::CalverNoContext => self.smart_calver_schema(vars),
+            ZervSchemaPreset::Calver => self.with_smart_build_context(
+                self.smart_calver_schema(vars, thresholds),
+                vars,
+                thresholds,
+            ),
+            ZervSchemaPreset::CalverNoContext => self.smart_calver_schema(vars, thresholds),
             ZervSchemaPreset::CalverContext => {
-                self.with_build_context(self.smart_calver_schema(vars))
+                self.with_build_context(self.smart_calver_schema(vars, thresholds))
             }
 
             fixed_schema => fixed_schema.schema(),
         }
     }
 
-    fn smart_standard_schema(&self, vars: &ZervVars) -> ZervSchema {
-        if vars.dirty.unwrap_or(false) {
+    /// Compose a preset from comma-separated flags, e.g. `base,prerelease` is
+    /// `standard-base-prerelease` and `calver,base,context` is `calver-base-context`
+    pub fn from_flags(flags: &str) -> Result<Self, ZervError> {
+        Self::name_from_flags(flags)?.parse()
+    }
+
+    /// Preset name for comma-separated schema flags
+    ///
+    /// The family defaults to `standard`; without any tier the smart preset is used.
+    pub fn name_from_flags(flags: &str) -> Result<String, ZervError> {
+        let flags: Vec<&str> = flags
+            .split(',')
+            .map(str::trim)
+            .filter(|flag| !flag.is_empty())
+            .collect();
+        if let Some(unknown) = flags
+            .iter()
+            .find(|flag| !schema_flags::VALID_FLAGS.contains(flag))
+        {
+            return Err(ZervError::InvalidArgument(format!(
+                "Unknown schema flag '{unknown}'. Valid flags: {}",
+                schema_flags::VALID_FLAGS.join(", ")
+            )));
+        }
+
+        let has = |flag: &str| flags.contains(&flag);
+        let family = match (has(schema_flags::STANDARD), has(schema_flags::CALVER)) {
+            (true, true) => {
+                return Err(ZervError::ConflictingOptions(
+                    "Schema flags 'standard' and 'calver' cannot be combined".to_string(),
+                ));
+            }
+            (_, true) => schema_flags::CALVER,
+            _ => schema_flags::STANDARD,
+        };
+
+        let mut parts = vec![family];
+        for (index, tier) in schema_flags::TIERS.iter().enumerate() {
+            if !has(tier) {
+                continue;
+            }
+            if let Some(missing) = schema_flags::TIERS[..index].iter().find(|prev| !has(prev)) {
+                return Err(ZervError::InvalidArgument(format!(
+                    "Schema flag '{tier}' requires '{missing}'"
+                )));
+            }
+            parts.push(tier);
+        }
+        if has(schema_flags::CONTEXT) {
+            parts.push(schema_flags::CONTEXT);
+        }
+
+        Ok(parts.join("-"))
+    }
+
+    fn smart_standard_schema(&self, vars: &ZervVars, thresholds: &SmartThresholds) -> ZervSchema {
+        if thresholds.is_dirty(vars) && thresholds.dirty_includes_dev {
             self.standard_base_prerelease_post_dev_schema(false)
-        } else if vars.distance.unwrap_or(0) > 0
-            || (vars.pre_release.is_some() && vars.post.is_some())
+        } else if thresholds.is_distant(vars) || (vars.pre_release.is_some() && vars.post.is_some())
         {
             self.standard_base_prerelease_post_schema(false)
         } else if vars.pre_release.is_some() {
@@ -151,11 +250,10 @@ impl ZervSchemaPreset {
         }
     }
 
-    fn smart_calver_schema(&self, vars: &ZervVars) -> ZervSchema {
-        if vars.dirty.unwrap_or(false) {
+    fn smart_calver_schema(&self, vars: &ZervVars, thresholds: &SmartThresholds) -> ZervSchema {
+        if thresholds.is_dirty(vars) && thresholds.dirty_includes_dev {
             self.calver_base_prerelease_post_dev_schema(false)
-        } else if vars.distance.unwrap_or(0) > 0
-            || (vars.pre_release.is_some() && vars.post.is_some())
+        } else if thresholds.is_distant(vars) || (vars.pre_release.is_some() && vars.post.is_some())
         {
             self.calver_base_prerelease_post_schema(false)
         } else if vars.pre_release.is_some() {
@@ -251,8 +349,13 @@ impl ZervSchemaPreset {
         result
     }
 
-    fn with_smart_build_context(&self, schema: ZervSchema, vars: &ZervVars) -> ZervSchema {
-        if vars.dirty.unwrap_or(false) || vars.distance.unwrap_or(0) > 0 {
+    fn with_smart_build_context(
+        &self,
+        schema: ZervSchema,
+        vars: &ZervVars,
+        thresholds: &SmartThresholds,
+    ) -> ZervSchema {
+        if thresholds.is_dirty(vars) || thresholds.is_distant(vars) {
             self.with_build_context(schema)
         } else {
             schema
@@ -341,6 +444,39 @@ mod tests {
         );
     }
 
+    #[rstest::rstest]
+    #[case::base("base", ZervSchemaPreset::StandardBase)]
+    #[case::base_prerelease("base,prerelease", ZervSchemaPreset::StandardBasePrerelease)]
+    #[case::spaced(" prerelease , base ", ZervSchemaPreset::StandardBasePrerelease)]
+    #[case::explicit_family(
+        "standard,base,prerelease,post",
+        ZervSchemaPreset::StandardBasePrereleasePost
+    )]
+    #[case::full_context(
+        "base,prerelease,post,dev,context",
+        ZervSchemaPreset::StandardBasePrereleasePostDevContext
+    )]
+    #[case::smart("", ZervSchemaPreset::Standard)]
+    #[case::smart_context("context", ZervSchemaPreset::StandardContext)]
+    #[case::calver("calver", ZervSchemaPreset::Calver)]
+    #[case::calver_tiers(
+        "calver,base,prerelease,context",
+        ZervSchemaPreset::CalverBasePrereleaseContext
+    )]
+    fn test_from_flags(#[case] flags: &str, #[case] expected: ZervSchemaPreset) {
+        assert_eq!(ZervSchemaPreset::from_flags(flags).unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::unknown("base,nightly", "Unknown schema flag 'nightly'")]
+    #[case::missing_base("prerelease", "Schema flag 'prerelease' requires 'base'")]
+    #[case::gap("base,prerelease,dev", "Schema flag 'dev' requires 'post'")]
+    #[case::two_families("standard,calver,base", "cannot be combined")]
+    fn test_from_flags_errors(#[case] flags: &str, #[case] expected: &str) {
+        let err = ZervSchemaPreset::from_flags(flags).unwrap_err();
+        assert!(err.to_string().contains(expected), "got: {err}");
+    }
+
     #[test]
     fn test_smart_schema_detection() {
         let clean_vars = ZervVars {
@@ -368,6 +504,58 @@ mod tests {
         assert_ne!(clean_schema.extra_core(), dirty_schema.extra_core());
     }
 
+    #[rstest::rstest]
+    #[case::below_threshold(2, 3, false)]
+    #[case::at_threshold(3, 3, true)]
+    #[case::above_threshold(4, 3, true)]
+    #[case::default_threshold(1, SmartThresholds::DEFAULT_DISTANCE_THRESHOLD, true)]
+    fn test_smart_distance_threshold(
+        #[case] distance: u64,
+        #[case] threshold: u64,
+        #[case] expect_post: bool,
+    ) {
+        let vars = ZervVars {
+            dirty: Some(false),
+            distance: Some(distance),
+            ..Default::default()
+        };
+        let thresholds = SmartThresholds {
+            distance_threshold: threshold,
+            ..Default::default()
+        };
+
+        let schema = ZervSchemaPreset::Standard.schema_with_thresholds(&vars, &thresholds);
+
+        let expected = if expect_post {
+            ZervSchemaPreset::StandardBasePrereleasePostContext.schema()
+        } else {
+            ZervSchemaPreset::StandardBase.schema()
+        };
+        assert_eq!(schema, expected);
+    }
+
+    #[test]
+    fn test_smart_dirty_without_dev() {
+        let vars = ZervVars {
+            dirty: Some(true),
+            distance: Some(0),
+            ..Default::default()
+        };
+        let thresholds = SmartThresholds {
+            dirty_includes_dev: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ZervSchemaPreset::StandardNoContext.schema_with_thresholds(&vars, &thresholds),
+            ZervSchemaPreset::StandardBase.schema()
+        );
+        assert_eq!(
+            ZervSchemaPreset::StandardNoContext.schema_with_zerv(&vars),
+            ZervSchemaPreset::StandardBasePrereleasePostDev.schema()
+        );
+    }
+
     #[test]
     fn test_all_standard_schema_variants() {
         let vars = ZervVars::default();
@@ -34,6 +34,18 @@ impl GitRepoFixture {
         Ok(Self { test_dir, git_impl })
     }
 
+    /// Create a freshly initialised repository with no commits
+    pub fn without_commits() -> Result<Self, Box<dyn std::error::Error>> {
+        let test_dir = TestDir::new()?;
+        let git_impl = get_git_impl();
+
+        git_impl
+            .init_repo_no_commit(&test_dir)
+            .map_err(|e| format!("Failed to initialize Git repo: {e}"))?;
+
+        Ok(Self { test_dir, git_impl })
+    }
+
     /// Create a repository with a clean tag (Tier 1: major.minor.patch)
     pub fn tagged(tag: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let fixture = Self::empty()?;
@@ -224,6 +224,25 @@ impl VersionArgsFixture {
         self
     }
 
+    /// Set the components a pre-release label bump resets
+    pub fn with_prerelease_bump_resets(mut self, resets: &[&str]) -> Self {
+        self.args.bumps.prerelease_bump_resets =
+            Some(resets.iter().map(|reset| reset.to_string()).collect());
+        self
+    }
+
+    /// Set keep pre-release flag
+    pub fn with_keep_prerelease(mut self, keep_prerelease: bool) -> Self {
+        self.args.bumps.keep_prerelease = keep_prerelease;
+        self
+    }
+
+    /// Set the upper bound for pre-release/post/dev numbers
+    pub fn with_max_component_value(mut self, max: u64) -> Self {
+        self.args.bumps.max_component_value = Some(max);
+        self
+    }
+
     /// Set bump context flag
     pub fn with_bump_context(mut self, bump_context: bool) -> Self {
         self.args.bumps.bump_context = bump_context;
@@ -37,7 +37,7 @@ impl ZervVarsFixture {
 
     /// Add pre-release information
     pub fn with_pre_release(mut self, label: PreReleaseLabel, number: Option<u64>) -> Self {
-        self.vars.pre_release = Some(PreReleaseVar { label, number });
+        self.vars.pre_release = Some(PreReleaseVar::labeled(label, number));
         self
     }
 
@@ -200,8 +200,8 @@ mod tests {
 
         assert!(vars.pre_release.is_some());
         let pre = vars.pre_release.unwrap();
-        assert_eq!(pre.label, PreReleaseLabel::Alpha);
-        assert_eq!(pre.number, Some(5));
+        assert_eq!(pre.label(), Some(PreReleaseLabel::Alpha));
+        assert_eq!(pre.number(), Some(5));
     }
 
     #[test]
@@ -212,8 +212,8 @@ mod tests {
 
         assert!(vars.pre_release.is_some());
         let pre = vars.pre_release.unwrap();
-        assert_eq!(pre.label, PreReleaseLabel::Beta);
-        assert_eq!(pre.number, None);
+        assert_eq!(pre.label(), Some(PreReleaseLabel::Beta));
+        assert_eq!(pre.number(), None);
     }
 
     #[test]
@@ -312,22 +312,27 @@ mod tests {
             major: Some(2),
             minor: Some(1),
             patch: Some(0),
-            pre_release: Some(PreReleaseVar {
-                label: PreReleaseLabel::Rc,
-                number: Some(3),
-            }),
+            pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(3))),
             epoch: Some(1),
             post: Some(5),
             dev: Some(2),
             distance: Some(10),
             dirty: Some(true),
+            staged_dirty: Some(true),
+            unstaged_dirty: Some(false),
             bumped_branch: Some("release".to_string()),
             bumped_commit_hash: Some("hash123".to_string()),
+            bumped_commit_hash_short: Some("hash12".to_string()),
             bumped_timestamp: Some(1703123456),
+            commit_subject: Some("Prepare release".to_string()),
+            commit_count: Some(42),
+            default_branch: Some("main".to_string()),
             last_branch: Some("main".to_string()),
             last_commit_hash: Some("hash456".to_string()),
             last_timestamp: Some(1703000000),
             last_tag_version: Some("v2.1.0-rc.3".to_string()),
+            tag_message: Some("Release 2.1.0-rc.3".to_string()),
+            build_number: Some(10_000_456),
             custom: serde_json::json!({}),
         };
 
@@ -360,8 +365,8 @@ mod tests {
         assert_eq!(vars.patch, Some(1));
         assert!(vars.pre_release.is_some());
         let pre = vars.pre_release.as_ref().unwrap();
-        assert_eq!(pre.label, PreReleaseLabel::Beta);
-        assert_eq!(pre.number, Some(4));
+        assert_eq!(pre.label(), Some(PreReleaseLabel::Beta));
+        assert_eq!(pre.number(), Some(4));
         assert_eq!(vars.epoch, Some(2));
         assert_eq!(vars.post, Some(1));
         assert_eq!(vars.dev, Some(3));
@@ -52,7 +52,27 @@ impl ZervFixture {
 
     /// Set pre-release (chainable)
     pub fn with_pre_release(mut self, label: PreReleaseLabel, number: Option<u64>) -> Self {
-        self.zerv.vars.pre_release = Some(PreReleaseVar { label, number });
+        self.zerv.vars.pre_release = Some(PreReleaseVar::labeled(label, number));
+        self
+    }
+
+    /// Set trailing pre-release identifiers on an existing pre-release (chainable)
+    pub fn with_pre_release_identifiers(mut self, identifiers: Vec<&str>) -> Self {
+        if let Some(PreReleaseVar::Labeled {
+            identifiers: ref mut existing,
+            ..
+        }) = self.zerv.vars.pre_release
+        {
+            *existing = identifiers.into_iter().map(String::from).collect();
+        }
+        self
+    }
+
+    /// Set a raw pre-release that doesn't fit the label+number shape (chainable)
+    pub fn with_raw_pre_release(mut self, identifiers: Vec<&str>) -> Self {
+        self.zerv.vars.pre_release = Some(PreReleaseVar::Raw(
+            identifiers.into_iter().map(String::from).collect(),
+        ));
         self
     }
 
@@ -58,10 +58,9 @@ pub mod to {
     }
 
     pub fn v1_0_0_something_1() -> ZervFixture {
-        v1_0_0().with_extra_core_components(vec![
-            Component::Str("something".to_string()),
-            Component::UInt(1),
-        ])
+        v1_0_0()
+            .with_raw_pre_release(vec!["something", "1"])
+            .with_extra_core_components(vec![Component::Var(Var::PreRelease)])
     }
 
     pub fn v1_0_0_build() -> ZervFixture {
@@ -81,13 +80,8 @@ pub mod to {
     pub fn v1_0_0_a1_complex() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Alpha, Some(1))
-            .with_extra_core_components(vec![
-                Component::Var(Var::PreRelease),
-                Component::Str("lowercase".to_string()),
-                Component::UInt(4),
-                Component::Str("UPPERCASE".to_string()),
-                Component::UInt(5),
-            ])
+            .with_pre_release_identifiers(vec!["lowercase", "4", "UPPERCASE", "5"])
+            .with_extra_core_components(vec![Component::Var(Var::PreRelease)])
             .with_build_components(vec![
                 Component::Str("build".to_string()),
                 Component::UInt(123),
@@ -290,55 +284,42 @@ pub mod to {
     pub fn v1_0_0_foo_bar_beta_2_baz() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Beta, Some(2))
+            .with_pre_release_identifiers(vec!["baz"])
             .with_extra_core_components(vec![
                 Component::Str("foo".to_string()),
                 Component::Str("bar".to_string()),
                 Component::Var(Var::PreRelease),
-                Component::Str("baz".to_string()),
             ])
     }
 
     pub fn v1_0_0_alpha_1_beta_2() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Alpha, Some(1))
-            .with_extra_core_components(vec![
-                Component::Var(Var::PreRelease),
-                Component::Str("beta".to_string()),
-                Component::UInt(2),
-            ])
+            .with_pre_release_identifiers(vec!["beta", "2"])
+            .with_extra_core_components(vec![Component::Var(Var::PreRelease)])
     }
 
     pub fn v1_0_0_rc_1_alpha_2_beta_3() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Rc, Some(1))
-            .with_extra_core_components(vec![
-                Component::Var(Var::PreRelease),
-                Component::Str("alpha".to_string()),
-                Component::UInt(2),
-                Component::Str("beta".to_string()),
-                Component::UInt(3),
-            ])
+            .with_pre_release_identifiers(vec!["alpha", "2", "beta", "3"])
+            .with_extra_core_components(vec![Component::Var(Var::PreRelease)])
     }
 
     pub fn v1_0_0_pre_alpha_1() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Rc, None)
-            .with_extra_core_components(vec![
-                Component::Var(Var::PreRelease),
-                Component::Str("alpha".to_string()),
-                Component::UInt(1),
-            ])
+            .with_pre_release_identifiers(vec!["alpha", "1"])
+            .with_extra_core_components(vec![Component::Var(Var::PreRelease)])
     }
 
     pub fn v1_0_0_test_alpha_beta_rc_1() -> ZervFixture {
         v1_0_0()
             .with_pre_release(PreReleaseLabel::Alpha, None)
+            .with_pre_release_identifiers(vec!["beta", "rc", "1"])
             .with_extra_core_components(vec![
                 Component::Str("test".to_string()),
                 Component::Var(Var::PreRelease),
-                Component::Str("beta".to_string()),
-                Component::Str("rc".to_string()),
-                Component::UInt(1),
             ])
     }
 
@@ -556,6 +537,7 @@ pub mod to {
             .with_post(3)   // First post wins
             .with_dev(5)    // First dev wins
             .with_pre_release(PreReleaseLabel::Alpha, Some(7))  // First alpha wins
+            .with_pre_release_identifiers(vec!["alpha", "8"])  // alpha.8 trails the pre-release
             .with_extra_core_components(vec![
                 Component::Var(Var::Epoch),     // epoch.1 -> Var(Epoch)
                 Component::Str("epoch".to_string()),  // epoch.2 -> Str("epoch"), Int(2)
@@ -566,9 +548,7 @@ pub mod to {
                 Component::Var(Var::Dev),       // dev.5 -> Var(Dev)
                 Component::Str("dev".to_string()),    // dev.6 -> Str("dev"), Int(6)
                 Component::UInt(6),
-                Component::Var(Var::PreRelease), // alpha.7 -> Var(PreRelease)
-                Component::Str("alpha".to_string()),  // alpha.8 -> Str("alpha"), Int(8)
-                Component::UInt(8),
+                Component::Var(Var::PreRelease), // alpha.7.alpha.8 -> Var(PreRelease)
             ])
     }
 
@@ -577,11 +557,11 @@ pub mod to {
         base_schema()
             .with_version(1, 0, 0)
             .with_pre_release(PreReleaseLabel::Rc, None)
+            .with_pre_release_identifiers(vec!["rc"])
             .with_extra_core_components(vec![
                 Component::Var(Var::Epoch),
                 Component::Str("epoch".to_string()),
                 Component::Var(Var::PreRelease),
-                Component::Str("rc".to_string()),
                 Component::Var(Var::Post),
                 Component::Str("post".to_string()),
                 Component::Var(Var::Dev),
@@ -594,10 +574,10 @@ pub mod to {
         base_schema()
             .with_version(1, 2, 3)
             .with_pre_release(PreReleaseLabel::Alpha, None)
+            .with_pre_release_identifiers(vec!["rc"])
             .with_extra_core_components(vec![
                 Component::UInt(10),
                 Component::Var(Var::PreRelease),
-                Component::Str("rc".to_string()),
                 Component::Var(Var::Epoch),
                 Component::Str("rc".to_string()),
                 Component::UInt(3),
@@ -17,6 +17,12 @@ pub mod pre_release_labels {
     pub const RC: &str = "rc";
 
     pub const VALID_LABELS: &[&str] = &[ALPHA, BETA, RC];
+
+    /// Label-less pre-release made of the number alone (e.g. `1.0.0-1`)
+    pub const NUMERIC: &str = "numeric";
+
+    /// Labels accepted where a pre-release label is set (overrides, bumps, flow)
+    pub const VALID_SETTABLE_LABELS: &[&str] = &[ALPHA, BETA, RC, NUMERIC];
 }
 
 // Shared field names (same for both RON and template) - renamed from shared_fields
@@ -61,11 +67,54 @@ pub mod bump_types {
     pub const DEV: &str = shared_constants::DEV;
 }
 
+// What an epoch bump resets
+pub mod epoch_reset_modes {
+    pub const ALL: &str = "all";
+    pub const NONE: &str = "none";
+
+    /// Used for validation of epoch-reset argument
+    pub const VALID_MODES: &[&str] = &[ALL, NONE];
+}
+
+// Components reset by a pre-release label bump
+pub mod prerelease_bump_resets {
+    pub const POST: &str = "post";
+    pub const DEV: &str = "dev";
+    pub const NONE: &str = "none";
+
+    /// Used for validation of prerelease-bump-resets argument
+    pub const VALID_RESETS: &[&str] = &[POST, DEV, NONE];
+}
+
+// Dirty-on-tag modes
+pub mod dirty_on_tag_modes {
+    pub const POST: &str = "post";
+    pub const DEV: &str = "dev";
+    pub const PRERELEASE: &str = "prerelease";
+
+    /// Used for validation of dirty-on-tag argument
+    pub const VALID_MODES: &[&str] = &[POST, DEV, PRERELEASE];
+}
+
+// Base bump levels
+pub mod base_bump_levels {
+    pub const MAJOR: &str = "major";
+    pub const MINOR: &str = "minor";
+    pub const PATCH: &str = "patch";
+
+    /// Used for validation of base-bump argument
+    pub const VALID_LEVELS: &[&str] = &[MAJOR, MINOR, PATCH];
+}
+
 // Timestamp patterns
 pub mod timestamp_patterns {
     pub const COMPACT_DATE: &str = "compact_date";
     pub const COMPACT_DATETIME: &str = "compact_datetime";
 
+    /// Pre-release number from the commit date (YYYYMMDD), used by --prerelease-date
+    pub const PRERELEASE_DATE_TEMPLATE: &str =
+        "{{ format_timestamp(value=bumped_timestamp, format=\"compact_date\") }}";
+
     // Single component patterns
     pub const YYYY: &str = "YYYY";
     pub const YY: &str = "YY";
@@ -113,6 +162,148 @@ pub mod sources {
     pub const NONE: &str = "none";
 }
 
+// Branch names that imply a version line
+pub mod branch_version {
+    /// Default regex of `zerv check --branch-consistency` (release/1.4, release/v2)
+    pub const DEFAULT_REGEX: &str =
+        r"^release/v?(?P<major>\d+)(?:\.(?P<minor>\d+))?(?:\.(?P<patch>\d+))?";
+}
+
+// Manifest files that can provide a base version
+pub mod manifest_sources {
+    pub const CARGO: &str = "cargo";
+    pub const NPM: &str = "npm";
+    pub const AUTO: &str = "auto";
+
+    pub const CARGO_FILE: &str = "Cargo.toml";
+    pub const NPM_FILE: &str = "package.json";
+}
+
+// Output line terminators
+pub mod output_terminators {
+    pub const NEWLINE: &str = "newline";
+    pub const NONE: &str = "none";
+    pub const NULL: &str = "null";
+
+    /// Used for validation of --output-terminator argument
+    pub const VALID_TERMINATORS: &[&str] = &[NEWLINE, NONE, NULL];
+}
+
+// SBOM documents the version can be emitted for
+pub mod sbom_formats {
+    pub const SPDX: &str = "spdx";
+    pub const CYCLONEDX: &str = "cyclonedx";
+
+    /// Used for validation of --sbom argument
+    pub const VALID_FORMATS: &[&str] = &[SPDX, CYCLONEDX];
+}
+
+// Field names accepted by --field besides the template variable names
+pub mod output_fields {
+    /// Short hash of the commit being versioned (bumped_commit_hash_short)
+    pub const COMMIT: &str = "commit";
+    /// Tag the version is anchored at, as written in the repository (e.g. `v1.2.3`)
+    pub const TAG_VERSION: &str = "tag-version";
+    /// Prefix selecting a custom variable, as in `custom.build_id`
+    pub const CUSTOM_PREFIX: &str = "custom.";
+}
+
+// Separators allowed between build metadata / local version segments
+pub mod context_separators {
+    pub const DEFAULT: &str = ".";
+
+    /// SemVer build identifiers are dot-separated and may contain hyphens
+    pub const SEMVER: &[&str] = &[".", "-"];
+    /// PEP440 local segments accept '.', '-' and '_' (only '.' is canonical, see --strict-pep440)
+    pub const PEP440: &[&str] = &[".", "-", "_"];
+}
+
+// Output casing of the pre-release label
+pub mod prerelease_cases {
+    pub const LOWER: &str = "lower";
+    pub const UPPER: &str = "upper";
+    pub const TITLE: &str = "title";
+
+    /// Used for validation of prerelease-case argument
+    pub const VALID_CASES: &[&str] = &[LOWER, UPPER, TITLE];
+}
+
+// Output spellings of the post-release and dev-release labels
+pub mod post_dev_labels {
+    pub const POST: &str = "post";
+    pub const DEV: &str = "dev";
+
+    /// PEP440 spellings that normalize to `post`
+    pub const PEP440_POST: &[&str] = &[POST, "rev", "r"];
+    /// PEP440 has no alternative spelling for `dev`
+    pub const PEP440_DEV: &[&str] = &[DEV];
+}
+
+// Short commit hash abbreviation modes
+pub mod abbrev_modes {
+    pub const FIXED: &str = "fixed";
+    pub const AUTO: &str = "auto";
+
+    /// Used for validation of --abbrev argument
+    pub const VALID_MODES: &[&str] = &[FIXED, AUTO];
+}
+
+// CI systems whose environment can name the branch of a detached checkout
+pub mod ci_providers {
+    pub const AUTO: &str = "auto";
+    pub const GITHUB: &str = "github";
+    pub const GITLAB: &str = "gitlab";
+
+    /// Used for validation of --branch-from-ci argument
+    pub const VALID_PROVIDERS: &[&str] = &[AUTO, GITHUB, GITLAB];
+}
+
+// Tiebreak between tags on one commit that parse to the same version
+pub mod equal_tag_preferences {
+    pub const PREFIXED: &str = "prefixed";
+    pub const UNPREFIXED: &str = "unprefixed";
+    pub const FIRST: &str = "first";
+    pub const LAST: &str = "last";
+
+    /// Used for validation of --equal-tag-preference argument
+    pub const VALID_PREFERENCES: &[&str] = &[PREFIXED, UNPREFIXED, FIRST, LAST];
+}
+
+// Which tags are candidates for the version tag
+pub mod tags_reachable_modes {
+    /// Only tags reachable from the revision (merged into it)
+    pub const MERGED: &str = "merged";
+    /// Every tag in the repository, including tags on unmerged branches
+    pub const ALL: &str = "all";
+
+    /// Used for validation of --tags-reachable argument
+    pub const VALID_MODES: &[&str] = &[MERGED, ALL];
+}
+
+// Repositories without any commits
+pub mod empty_repo {
+    /// Base version reported before the first commit
+    pub const BASE_VERSION: &str = "0.0.0";
+}
+
+// Monorepo tag scopes (e.g. 'frontend-v1.2.3')
+pub mod tag_scope {
+    /// Joins the scope to the version in tags and output
+    pub const SEPARATOR: &str = "-";
+}
+
+// Prefix of commit hashes in ZervVars
+pub mod commit_hash_prefix {
+    /// Following the git describe convention
+    pub const DEFAULT: &str = "g";
+}
+
+// Repository default branch detection
+pub mod default_branch {
+    /// Used when neither origin's HEAD nor init.defaultBranch names a branch
+    pub const FALLBACK: &str = "main";
+}
+
 // Post distance calculation modes
 pub mod post_modes {
     pub const TAG: &str = "tag";
@@ -122,16 +313,56 @@ pub mod post_modes {
     pub const VALID_MODES: &[&str] = &[TAG, COMMIT];
 }
 
+// Flow pre-release number sources
+pub mod prerelease_num_sources {
+    pub const HASH: &str = "hash";
+    pub const COMMIT_COUNT: &str = "commit-count";
+
+    /// Used for validation of prerelease-num-source argument
+    pub const VALID_SOURCES: &[&str] = &[HASH, COMMIT_COUNT];
+}
+
+pub mod hash_algos {
+    pub const FNV: &str = "fnv";
+    pub const SHA256: &str = "sha256";
+    pub const CRC32: &str = "crc32";
+
+    /// Used for validation of the hash_int algo argument and flow --hash-algo
+    pub const VALID_ALGOS: &[&str] = &[FNV, SHA256, CRC32];
+}
+
+// Config file locations
+pub mod config_files {
+    /// Repo-local config, read from the working directory
+    pub const REPO_FILE: &str = "zerv.toml";
+    /// User-global config, read from `$XDG_CONFIG_HOME/zerv/config.toml`
+    pub const GLOBAL_DIR: &str = "zerv";
+    pub const GLOBAL_FILE: &str = "config.toml";
+    /// Fallback for `XDG_CONFIG_HOME`, relative to `HOME`
+    pub const XDG_DEFAULT_DIR: &str = ".config";
+}
+
 // Format names
 pub mod formats {
     pub const AUTO: &str = "auto";
     pub const SEMVER: &str = "semver";
     pub const PEP440: &str = "pep440";
     pub const ZERV: &str = "zerv";
+    /// Output only: shell-quoted KEY='value' lines
+    pub const ENV: &str = "env";
+    /// Output only: Java .properties key=value lines
+    pub const PROPERTIES: &str = "properties";
 
     /// Format arrays for CLI validation
     pub const SUPPORTED_FORMATS_ARRAY: [&str; 3] = [SEMVER, PEP440, ZERV];
     pub const SUPPORTED_FORMATS: &[&str] = &SUPPORTED_FORMATS_ARRAY;
+    /// Formats accepted by --output-format of version and flow
+    pub const OUTPUT_FORMATS_ARRAY: [&str; 5] = [SEMVER, PEP440, ZERV, ENV, PROPERTIES];
+}
+
+// Formats of the --dump-cli command tree manifest
+pub mod dump_cli_formats {
+    pub const JSON: &str = "json";
 }
 
 // Format display names
@@ -147,3 +378,9 @@ pub const SUPPORTED_FORMAT_NAMES: &[&str] = &[
     format_names::SEMVER,
     format_names::ZERV,
 ];
+
+// Build number derived from distance and timestamp
+pub mod build_number {
+    /// Default K in `distance * K + (timestamp % K)`
+    pub const DEFAULT_MODULUS: &str = "100000";
+}
@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use crate::error::ZervError;
+use crate::utils::constants::manifest_sources;
+
+/// Read the `version` field from a project manifest in `dir`
+///
+/// `source` is one of `cargo`, `npm` or `auto`. With `auto`, `Cargo.toml` is tried
+/// before `package.json`.
+pub fn read_manifest_version(dir: &Path, source: &str) -> Result<String, ZervError> {
+    match source {
+        manifest_sources::CARGO => read_cargo_version(dir),
+        manifest_sources::NPM => read_npm_version(dir),
+        manifest_sources::AUTO => {
+            if dir.join(manifest_sources::CARGO_FILE).is_file() {
+                read_cargo_version(dir)
+            } else if dir.join(manifest_sources::NPM_FILE).is_file() {
+                read_npm_version(dir)
+            } else {
+                Err(ZervError::InvalidArgument(format!(
+                    "No {} or {} found in {}",
+                    manifest_sources::CARGO_FILE,
+                    manifest_sources::NPM_FILE,
+                    dir.display()
+                )))
+            }
+        }
+        unknown => Err(ZervError::InvalidArgument(format!(
+            "Unknown manifest source '{unknown}'. Supported sources: {}, {}, {}",
+            manifest_sources::CARGO,
+            manifest_sources::NPM,
+            manifest_sources::AUTO
+        ))),
+    }
+}
+
+fn read_manifest(dir: &Path, file_name: &str) -> Result<String, ZervError> {
+    let path = dir.join(file_name);
+    if !path.is_file() {
+        return Err(ZervError::InvalidArgument(format!(
+            "Manifest {file_name} not found in {}",
+            dir.display()
+        )));
+    }
+    Ok(std::fs::read_to_string(path)?)
+}
+
+fn read_cargo_version(dir: &Path) -> Result<String, ZervError> {
+    let file_name = manifest_sources::CARGO_FILE;
+    let content = read_manifest(dir, file_name)?;
+    let manifest: toml::Table = toml::from_str(&content)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to parse {file_name}: {e}")))?;
+
+    // Prefer [package].version, fall back to [workspace.package].version
+    let package_version = manifest
+        .get("package")
+        .and_then(|package| package.get("version"))
+        .and_then(|version| version.as_str());
+    let workspace_version = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("package"))
+        .and_then(|package| package.get("version"))
+        .and_then(|version| version.as_str());
+
+    package_version
+        .or(workspace_version)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            ZervError::InvalidFormat(format!(
+                "{file_name} has no [package] or [workspace.package] version string"
+            ))
+        })
+}
+
+fn read_npm_version(dir: &Path) -> Result<String, ZervError> {
+    let file_name = manifest_sources::NPM_FILE;
+    let content = read_manifest(dir, file_name)?;
+    let manifest: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ZervError::InvalidFormat(format!("Failed to parse {file_name}: {e}")))?;
+
+    manifest
+        .get("version")
+        .and_then(|version| version.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| ZervError::InvalidFormat(format!("{file_name} has no version string")))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::TestDir;
+
+    const CARGO_PACKAGE: &str = "[package]\nname = \"demo\"\nversion = \"1.2.3\"\n";
+    const CARGO_WORKSPACE: &str =
+        "[workspace]\nmembers = []\n\n[workspace.package]\nversion = \"2.0.0-rc.1\"\n";
+    const NPM_PACKAGE: &str = "{\n  \"name\": \"demo\",\n  \"version\": \"3.4.5\"\n}\n";
+
+    fn dir_with(files: &[(&str, &str)]) -> TestDir {
+        let dir = TestDir::new().expect("should create test dir");
+        for (name, content) in files {
+            dir.create_file(name, content)
+                .expect("should write manifest");
+        }
+        dir
+    }
+
+    #[rstest]
+    #[case::cargo_package(manifest_sources::CARGO, &[("Cargo.toml", CARGO_PACKAGE)], "1.2.3")]
+    #[case::cargo_workspace(manifest_sources::CARGO, &[("Cargo.toml", CARGO_WORKSPACE)], "2.0.0-rc.1")]
+    #[case::npm(manifest_sources::NPM, &[("package.json", NPM_PACKAGE)], "3.4.5")]
+    #[case::auto_cargo(manifest_sources::AUTO, &[("Cargo.toml", CARGO_PACKAGE), ("package.json", NPM_PACKAGE)], "1.2.3")]
+    #[case::auto_npm(manifest_sources::AUTO, &[("package.json", NPM_PACKAGE)], "3.4.5")]
+    fn test_read_manifest_version(
+        #[case] source: &str,
+        #[case] files: &[(&str, &str)],
+        #[case] expected: &str,
+    ) {
+        let dir = dir_with(files);
+        assert_eq!(read_manifest_version(dir.path(), source).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::cargo_missing_file(manifest_sources::CARGO, &[], "Cargo.toml not found")]
+    #[case::npm_missing_file(manifest_sources::NPM, &[("Cargo.toml", CARGO_PACKAGE)], "package.json not found")]
+    #[case::auto_missing(manifest_sources::AUTO, &[], "No Cargo.toml or package.json")]
+    #[case::cargo_missing_field(manifest_sources::CARGO, &[("Cargo.toml", "[package]\nname = \"demo\"\n")], "no [package] or [workspace.package] version")]
+    #[case::npm_missing_field(manifest_sources::NPM, &[("package.json", "{\"name\": \"demo\"}")], "package.json has no version")]
+    #[case::npm_invalid_json(manifest_sources::NPM, &[("package.json", "{")], "Failed to parse package.json")]
+    #[case::unknown_source("maven", &[], "Unknown manifest source 'maven'")]
+    fn test_read_manifest_version_errors(
+        #[case] source: &str,
+        #[case] files: &[(&str, &str)],
+        #[case] expected_error: &str,
+    ) {
+        let dir = dir_with(files);
+        let error = read_manifest_version(dir.path(), source).unwrap_err();
+        assert!(
+            error.to_string().contains(expected_error),
+            "expected '{expected_error}' in '{error}'"
+        );
+    }
+}
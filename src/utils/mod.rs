@@ -1,3 +1,4 @@
 pub mod bool_resolution;
 pub mod constants;
+pub mod manifest;
 pub mod sanitize;
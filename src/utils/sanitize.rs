@@ -1,3 +1,8 @@
+use ron::extensions::Extensions;
+use serde::Deserialize;
+
+use crate::error::ZervError;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SanitizeTarget {
     /// Clean string for version identifiers (alphanumeric + separator)
@@ -18,6 +23,88 @@ pub struct Sanitizer {
     pub keep_zeros: bool,
     /// Maximum length (truncate if longer)
     pub max_length: Option<usize>,
+    /// Non-alphanumeric characters kept as-is instead of being replaced (Str target only)
+    pub allowed: String,
+}
+
+/// User-defined string sanitizer, given to --sanitizer-profile as RON, e.g. `(separator: "-")`.
+/// Unset fields keep the SemVer string defaults
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SanitizerProfile {
+    /// Replacement for non-alphanumeric characters, or None to keep them unchanged
+    pub separator: Option<String>,
+    /// Convert to lowercase
+    pub lowercase: bool,
+    /// Keep leading zeros in numeric segments
+    pub keep_zeros: bool,
+    /// Maximum length (truncate if longer)
+    pub max_length: Option<usize>,
+    /// Non-alphanumeric characters kept as-is instead of being replaced
+    pub allowed: String,
+}
+
+impl Default for SanitizerProfile {
+    fn default() -> Self {
+        Self {
+            separator: Some(".".to_string()),
+            lowercase: false,
+            keep_zeros: false,
+            max_length: None,
+            allowed: String::new(),
+        }
+    }
+}
+
+impl SanitizerProfile {
+    /// Parse a RON profile; `Some(..)` may be omitted around optional values
+    pub fn from_ron(ron: &str) -> Result<Self, ZervError> {
+        ron::Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str(ron)
+            .map_err(|e| ZervError::InvalidFormat(format!("Invalid sanitizer profile: {e}")))
+    }
+
+    pub fn to_sanitizer(&self) -> Sanitizer {
+        Sanitizer::str(
+            self.separator.as_deref(),
+            self.lowercase,
+            self.keep_zeros,
+            self.max_length,
+        )
+        .with_allowed(&self.allowed)
+    }
+
+    /// Reject profiles whose output could not be SemVer build metadata: the separator may
+    /// only use `[0-9A-Za-z-.]` and the allowed characters `[0-9A-Za-z-]`
+    pub fn validate_for_semver(&self) -> Result<(), ZervError> {
+        let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '-';
+        match self.separator.as_deref() {
+            None => {
+                return Err(ZervError::InvalidArgument(
+                    "Sanitizer profile separator None keeps characters that are invalid in \
+                     SemVer build metadata; use a separator such as \".\" or \"-\""
+                        .to_string(),
+                ));
+            }
+            Some(sep)
+                if sep.is_empty() || !sep.chars().all(|c| is_identifier_char(c) || c == '.') =>
+            {
+                return Err(ZervError::InvalidArgument(format!(
+                    "Sanitizer profile separator '{sep}' is invalid in SemVer build metadata; \
+                     use only [0-9A-Za-z-.]"
+                )));
+            }
+            Some(_) => {}
+        }
+        if let Some(c) = self.allowed.chars().find(|c| !is_identifier_char(*c)) {
+            return Err(ZervError::InvalidArgument(format!(
+                "Sanitizer profile allows '{c}', which is invalid in SemVer build metadata; \
+                 allowed characters must be in [0-9A-Za-z-]"
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl Sanitizer {
@@ -37,6 +124,7 @@ impl Sanitizer {
             lowercase: true,
             keep_zeros: false,
             max_length: None,
+            allowed: String::new(),
         }
     }
 
@@ -48,6 +136,7 @@ impl Sanitizer {
             lowercase: false,
             keep_zeros: false,
             max_length: None,
+            allowed: String::new(),
         }
     }
 
@@ -59,6 +148,7 @@ impl Sanitizer {
             lowercase: false,
             keep_zeros: false,
             max_length: None,
+            allowed: String::new(),
         }
     }
 
@@ -75,9 +165,16 @@ impl Sanitizer {
             lowercase,
             keep_zeros,
             max_length,
+            allowed: String::new(),
         }
     }
 
+    /// Keep these non-alphanumeric characters instead of replacing them with the separator
+    pub fn with_allowed(mut self, allowed: &str) -> Self {
+        self.allowed = allowed.to_string();
+        self
+    }
+
     /// Key sanitizer - for sanitizing keys
     pub fn key() -> Self {
         Self {
@@ -86,6 +183,7 @@ impl Sanitizer {
             lowercase: true,
             keep_zeros: false,
             max_length: None,
+            allowed: String::new(),
         }
     }
 
@@ -148,7 +246,7 @@ impl Sanitizer {
         let mut last_was_sep = false;
 
         for ch in input.chars() {
-            if ch.is_alphanumeric() {
+            if ch.is_alphanumeric() || self.allowed.contains(ch) {
                 result.push(ch);
                 last_was_sep = false;
             } else if !last_was_sep {
@@ -250,6 +348,53 @@ mod tests {
         assert_eq!(s.sanitize("-123"), "");
     }
 
+    #[test]
+    fn test_profile_keeps_uppercase_with_underscore() {
+        let profile = SanitizerProfile::from_ron(r#"(separator: "_")"#).unwrap();
+        let sanitizer = profile.to_sanitizer();
+
+        assert_eq!(sanitizer.sanitize("Feature/API-v2"), "Feature_API_v2");
+        assert_eq!(sanitizer.sanitize("Build-ID-0051"), "Build_ID_51");
+    }
+
+    #[test]
+    fn test_profile_fields() {
+        let profile = SanitizerProfile::from_ron(
+            r#"(separator: Some("-"), lowercase: true, keep_zeros: true, max_length: 8)"#,
+        )
+        .unwrap();
+
+        assert_eq!(profile.to_sanitizer().sanitize("Build/ID_0051"), "build-id");
+    }
+
+    #[test]
+    fn test_profile_rejects_unknown_field() {
+        let result = SanitizerProfile::from_ron("(allow: \"_\")");
+        assert!(matches!(result, Err(ZervError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_profile_allowed_characters_are_kept() {
+        let profile = SanitizerProfile::from_ron(r#"(separator: ".", allowed: "-")"#).unwrap();
+
+        assert_eq!(
+            profile.to_sanitizer().sanitize("Feature/API-v2"),
+            "Feature.API-v2"
+        );
+    }
+
+    #[rstest]
+    #[case::default("()", true)]
+    #[case::hyphen(r#"(separator: "-", allowed: "-")"#, true)]
+    #[case::underscore(r#"(separator: "_")"#, false)]
+    #[case::keep_unchanged("(separator: None)", false)]
+    #[case::empty_separator(r#"(separator: "")"#, false)]
+    #[case::allowed_underscore(r#"(allowed: "_")"#, false)]
+    fn test_profile_validate_for_semver(#[case] ron: &str, #[case] valid: bool) {
+        let profile = SanitizerProfile::from_ron(ron).unwrap();
+        assert_eq!(profile.validate_for_semver().is_ok(), valid, "{ron}");
+    }
+
     #[test]
     fn test_custom_config() {
         let sanitizer = Sanitizer::str(Some("_"), true, true, Some(10));
@@ -0,0 +1,91 @@
+use crate::config::EnvVars;
+use crate::utils::constants::ci_providers;
+
+/// Value GitHub and GitLab use for their boolean CI variables
+const CI_TRUE: &str = "true";
+/// `GITHUB_REF_TYPE` of a branch push
+const GITHUB_BRANCH_REF_TYPE: &str = "branch";
+
+/// Branch named by the CI environment (`ci_providers`), read through `env`
+///
+/// Pull/merge request source branches win over the pushed branch; tag pipelines name
+/// no branch. `auto` picks the provider from `GITHUB_ACTIONS`/`GITLAB_CI`.
+pub fn ci_branch<F>(provider: &str, env: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let var = |key: &str| env(key).filter(|value| !value.is_empty());
+    let provider = match provider {
+        ci_providers::AUTO if var(EnvVars::GITHUB_ACTIONS).as_deref() == Some(CI_TRUE) => {
+            ci_providers::GITHUB
+        }
+        ci_providers::AUTO if var(EnvVars::GITLAB_CI).as_deref() == Some(CI_TRUE) => {
+            ci_providers::GITLAB
+        }
+        provider => provider,
+    };
+
+    match provider {
+        ci_providers::GITHUB => var(EnvVars::GITHUB_HEAD_REF).or_else(|| {
+            (var(EnvVars::GITHUB_REF_TYPE).as_deref() == Some(GITHUB_BRANCH_REF_TYPE))
+                .then(|| var(EnvVars::GITHUB_REF_NAME))
+                .flatten()
+        }),
+        ci_providers::GITLAB => var(EnvVars::CI_MERGE_REQUEST_SOURCE_BRANCH_NAME)
+            .or_else(|| var(EnvVars::CI_COMMIT_BRANCH)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::github_pull_request(
+        ci_providers::GITHUB,
+        &[("GITHUB_HEAD_REF", "feature/login"), ("GITHUB_REF_NAME", "42/merge"), ("GITHUB_REF_TYPE", "branch")],
+        Some("feature/login")
+    )]
+    #[case::github_push(
+        ci_providers::GITHUB,
+        &[("GITHUB_HEAD_REF", ""), ("GITHUB_REF_NAME", "main"), ("GITHUB_REF_TYPE", "branch")],
+        Some("main")
+    )]
+    #[case::github_tag(
+        ci_providers::GITHUB,
+        &[("GITHUB_REF_NAME", "v1.2.3"), ("GITHUB_REF_TYPE", "tag")],
+        None
+    )]
+    #[case::gitlab_merge_request(
+        ci_providers::GITLAB,
+        &[("CI_MERGE_REQUEST_SOURCE_BRANCH_NAME", "fix/parser"), ("CI_COMMIT_BRANCH", "main")],
+        Some("fix/parser")
+    )]
+    #[case::gitlab_branch(ci_providers::GITLAB, &[("CI_COMMIT_BRANCH", "develop")], Some("develop"))]
+    #[case::auto_github(
+        ci_providers::AUTO,
+        &[("GITHUB_ACTIONS", "true"), ("GITHUB_HEAD_REF", "feature/x")],
+        Some("feature/x")
+    )]
+    #[case::auto_gitlab(
+        ci_providers::AUTO,
+        &[("GITLAB_CI", "true"), ("CI_COMMIT_BRANCH", "develop")],
+        Some("develop")
+    )]
+    #[case::auto_outside_ci(ci_providers::AUTO, &[("CI_COMMIT_BRANCH", "develop")], None)]
+    #[case::github_vars_ignored_for_gitlab(ci_providers::GITLAB, &[("GITHUB_HEAD_REF", "feature/x")], None)]
+    fn test_ci_branch(
+        #[case] provider: &str,
+        #[case] vars: &[(&str, &str)],
+        #[case] expected: Option<&str>,
+    ) {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        let branch = ci_branch(provider, |key| vars.get(key).map(|value| value.to_string()));
+        assert_eq!(branch.as_deref(), expected);
+    }
+}
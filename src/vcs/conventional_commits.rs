@@ -0,0 +1,121 @@
+/// Version bump implied by conventional commit messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConventionalBump {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// Commit type implying a minor bump
+const FEAT_TYPE: &str = "feat";
+/// Commit type implying a patch bump
+const FIX_TYPE: &str = "fix";
+/// Footer tokens marking a breaking change
+const BREAKING_CHANGE_TOKENS: &[&str] = &["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/// Classify a single commit message (subject line plus optional body and footers)
+///
+/// `feat:` is a minor bump, `fix:` a patch bump, and a `!` after the type/scope or a
+/// `BREAKING CHANGE:` footer a major bump. Other types (`chore:`, `docs:`, ...) and
+/// non-conventional messages imply no bump.
+pub fn classify_commit(message: &str) -> Option<ConventionalBump> {
+    let mut lines = message.lines();
+    let header = lines.next()?.trim();
+    let (commit_type, breaking) = parse_header(header)?;
+
+    let has_breaking_footer = lines.any(|line| {
+        BREAKING_CHANGE_TOKENS
+            .iter()
+            .any(|token| line.starts_with(&format!("{token}:")))
+    });
+    if breaking || has_breaking_footer {
+        return Some(ConventionalBump::Major);
+    }
+
+    match commit_type.to_ascii_lowercase().as_str() {
+        FEAT_TYPE => Some(ConventionalBump::Minor),
+        FIX_TYPE => Some(ConventionalBump::Patch),
+        _ => None,
+    }
+}
+
+/// Largest bump implied by any of the commit messages
+pub fn bump_from_commits<S: AsRef<str>>(messages: &[S]) -> Option<ConventionalBump> {
+    messages
+        .iter()
+        .filter_map(|message| classify_commit(message.as_ref()))
+        .max()
+}
+
+/// Split `type(scope)!: description` into the type and whether `!` marks a breaking change
+fn parse_header(header: &str) -> Option<(&str, bool)> {
+    let (prefix, description) = header.split_once(':')?;
+    if description.trim().is_empty() {
+        return None;
+    }
+
+    let (prefix, breaking) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) if scope.ends_with(')') => commit_type,
+        Some(_) => return None,
+        None => prefix,
+    };
+
+    let is_word = !commit_type.is_empty()
+        && commit_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    is_word.then_some((commit_type, breaking))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::feat("feat: add login", Some(ConventionalBump::Minor))]
+    #[case::feat_scope("feat(auth): add login", Some(ConventionalBump::Minor))]
+    #[case::feat_uppercase("FEAT: add login", Some(ConventionalBump::Minor))]
+    #[case::fix("fix: handle empty input", Some(ConventionalBump::Patch))]
+    #[case::fix_scope("fix(parser): handle empty input", Some(ConventionalBump::Patch))]
+    #[case::breaking_bang("feat!: drop python 3.8", Some(ConventionalBump::Major))]
+    #[case::breaking_bang_scope("fix(api)!: rename field", Some(ConventionalBump::Major))]
+    #[case::breaking_bang_other_type("chore!: drop node 16", Some(ConventionalBump::Major))]
+    #[case::breaking_footer(
+        "feat: new config\n\nBREAKING CHANGE: old keys removed",
+        Some(ConventionalBump::Major)
+    )]
+    #[case::breaking_footer_hyphen(
+        "fix: tweak\n\nBREAKING-CHANGE: output changed",
+        Some(ConventionalBump::Major)
+    )]
+    #[case::chore("chore: bump deps", None)]
+    #[case::docs("docs: fix typo", None)]
+    #[case::not_conventional("Merge branch 'main'", None)]
+    #[case::missing_description("feat:", None)]
+    #[case::unclosed_scope("feat(auth: add login", None)]
+    #[case::space_in_type("new feat: add login", None)]
+    #[case::breaking_in_body_text("docs: explain\n\nmentions a BREAKING CHANGE in prose", None)]
+    #[case::empty("", None)]
+    fn test_classify_commit(#[case] message: &str, #[case] expected: Option<ConventionalBump>) {
+        assert_eq!(classify_commit(message), expected);
+    }
+
+    #[rstest]
+    #[case::none(&[], None)]
+    #[case::only_chores(&["chore: a", "docs: b"], None)]
+    #[case::fix_only(&["chore: a", "fix: b"], Some(ConventionalBump::Patch))]
+    #[case::feat_beats_fix(&["fix: a", "feat: b", "fix: c"], Some(ConventionalBump::Minor))]
+    #[case::breaking_wins(&["feat: a", "fix!: b"], Some(ConventionalBump::Major))]
+    fn test_bump_from_commits(
+        #[case] messages: &[&str],
+        #[case] expected: Option<ConventionalBump>,
+    ) {
+        assert_eq!(bump_from_commits(messages), expected);
+    }
+}
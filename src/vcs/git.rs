@@ -3,20 +3,77 @@ use std::path::{
     PathBuf,
 };
 use std::process::Command;
+use std::time::Instant;
 
 use super::git_utils::GitUtils;
 use crate::error::{
     Result,
     ZervError,
 };
+use crate::utils::constants::{
+    commit_hash_prefix,
+    default_branch,
+    tags_reachable_modes,
+};
+use crate::vcs::timings::GitTimings;
 use crate::vcs::{
     Vcs,
     VcsData,
 };
+use crate::version::TagPrefix;
+
+/// Options that scope how git history is queried
+#[derive(Debug, Clone, Default)]
+pub struct GitOptions {
+    /// Restrict distance, tag selection and dirty state to commits touching this path
+    pub path_filter: Option<String>,
+    /// Prefix handling used when selecting version tags
+    pub tag_prefix: TagPrefix,
+    /// Refuse any git subcommand that talks to a remote
+    pub no_network: bool,
+    /// Only consider tags on the N most recently committed tagged commits
+    pub max_tags: Option<u32>,
+    /// Leave the tag timestamp unset instead of looking it up
+    pub no_tag_timestamp: bool,
+    /// Tiebreak between tags on one commit with the same version (see `equal_tag_preferences`)
+    pub equal_tag_preference: Option<String>,
+    /// Commit-ish to compute the version for instead of HEAD
+    pub rev: Option<String>,
+    /// Only staged changes make the repository dirty (unstaged ones are ignored)
+    pub use_index: bool,
+    /// Which tags are candidates (see `tags_reachable_modes`, default merged)
+    pub tags_reachable: Option<String>,
+    /// Record the duration of every git subprocess
+    pub timings: bool,
+    /// Prefix put in front of commit hashes (default "g", as in git describe)
+    pub commit_hash_prefix: Option<String>,
+    /// Count the tag commit itself in the distance (`tag^..HEAD` instead of `tag..HEAD`)
+    pub distance_inclusive: bool,
+}
+
+/// Revision queried when no --rev is given
+const DEFAULT_REV: &str = "HEAD";
+
+/// Symbolic ref set by clone to the remote's default branch
+const REMOTE_HEAD_REF: &str = "refs/remotes/origin/HEAD";
+
+/// Namespace of tag refs; selected tags are resolved through it so a branch or other ref
+/// with the same name never anchors the distance
+const TAGS_REF_PREFIX: &str = "refs/tags/";
+
+/// Fully qualified ref of a tag name, as returned by `git tag`
+fn tag_ref(tag: &str) -> String {
+    format!("{TAGS_REF_PREFIX}{tag}")
+}
+
+/// Git subcommands that may reach a remote
+const NETWORK_COMMANDS: &[&str] = &["fetch", "pull", "push", "clone", "ls-remote"];
 
 /// Git VCS implementation
 pub struct GitVcs {
     repo_path: PathBuf,
+    options: GitOptions,
+    timings: Option<GitTimings>,
     // TODO: Add optional tag_branch parameter for future extension
     // tag_branch: Option<String>,
 }
@@ -30,20 +87,132 @@ impl GitVcs {
     /// Create new Git VCS instance with optional depth limit
     pub fn new_with_limit(path: &Path, max_depth: Option<usize>) -> Result<Self> {
         let repo_path = crate::vcs::find_vcs_root_with_limit(path, max_depth)?;
-        Ok(Self { repo_path })
+        Ok(Self {
+            repo_path,
+            options: GitOptions::default(),
+            timings: None,
+        })
+    }
+
+    /// Create Git VCS instance for the repository rooted exactly at `path`, without
+    /// searching parent directories (so a repository nested in another is never skipped)
+    pub fn at_root(path: &Path) -> Result<Self> {
+        if !path.join(".git").exists() {
+            return Err(ZervError::VcsNotFound(format!(
+                "{} is not the root of a git repository",
+                path.display()
+            )));
+        }
+        Ok(Self {
+            repo_path: std::path::absolute(path)?,
+            options: GitOptions::default(),
+            timings: None,
+        })
+    }
+
+    /// Apply query options to this instance
+    pub fn with_options(mut self, options: GitOptions) -> Self {
+        self.timings = options.timings.then(GitTimings::default);
+        self.options = options;
+        self
     }
 
     /// Create new Git VCS instance for testing (bypasses VCS root detection)
     #[cfg(any(test, feature = "test-utils"))]
     pub fn new_for_test(repo_path: PathBuf) -> Self {
-        Self { repo_path }
+        Self {
+            repo_path,
+            options: GitOptions::default(),
+            timings: None,
+        }
+    }
+
+    /// Revision the version is computed for (--rev, or HEAD)
+    fn rev(&self) -> &str {
+        self.options.rev.as_deref().unwrap_or(DEFAULT_REV)
+    }
+
+    /// Prefix of commit hashes (--commit-prefix, or "g")
+    fn commit_hash_prefix(&self) -> String {
+        self.options
+            .commit_hash_prefix
+            .clone()
+            .unwrap_or_else(|| commit_hash_prefix::DEFAULT.to_string())
+    }
+
+    /// Fail with a clear message when --rev names no commit
+    fn verify_rev(&self) -> Result<()> {
+        let rev = self.rev();
+        self.probe_git_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])?
+        .map(|_| ())
+        .ok_or_else(|| ZervError::InvalidArgument(format!("Unknown revision '{rev}' (--rev)")))
+    }
+
+    /// Whether --rev points somewhere other than HEAD, so the working tree doesn't apply
+    fn is_detached_rev(&self) -> Result<bool> {
+        match self.options.rev.as_deref() {
+            Some(rev) => {
+                let rev_hash =
+                    self.run_git_command(&["rev-parse", &format!("{rev}^{{commit}}")])?;
+                Ok(rev_hash != self.run_git_command(&["rev-parse", DEFAULT_REV])?)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Fail before spawning a network-touching command when --no-network is set
+    fn check_network_allowed(&self, args: &[&str]) -> Result<()> {
+        if self.options.no_network
+            && let Some(command) = args.first()
+            && NETWORK_COMMANDS.contains(command)
+        {
+            return Err(ZervError::CommandFailed(format!(
+                "Refusing to run 'git {command}': --no-network forbids network access"
+            )));
+        }
+        Ok(())
     }
 
     /// Run git command and return output
     fn run_git_command(&self, args: &[&str]) -> Result<String> {
-        let cmd_str = args.join(" ");
-        tracing::debug!("Running git command: git {}", cmd_str);
+        let output = self.execute_git_command(args)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tracing::error!("Git command failed: git {} - {}", args.join(" "), stderr);
+            return Err(self.translate_git_error(&output.stderr));
+        }
+
+        let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        tracing::debug!("Git command output: {}", result);
+        Ok(result)
+    }
+
+    /// Run a git command whose failure is an expected answer (e.g. an unborn HEAD or an
+    /// unknown revision), so it is only logged at debug level; None when it fails
+    fn probe_git_command(&self, args: &[&str]) -> Result<Option<String>> {
+        let output = self.execute_git_command(args)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tracing::debug!("Git probe failed: git {} - {}", args.join(" "), stderr);
+            return Ok(None);
+        }
+
+        let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        tracing::debug!("Git command output: {}", result);
+        Ok(Some(result))
+    }
 
+    /// Spawn git and record its timing, leaving the exit status to the caller
+    fn execute_git_command(&self, args: &[&str]) -> Result<std::process::Output> {
+        self.check_network_allowed(args)?;
+        tracing::debug!("Running git command: git {}", args.join(" "));
+
+        let started = Instant::now();
         let output = Command::new("git")
             .args(args)
             .current_dir(&self.repo_path)
@@ -52,16 +221,22 @@ impl GitVcs {
                 tracing::error!("Failed to execute git command: {}", e);
                 self.translate_command_error(e)
             })?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            tracing::error!("Git command failed: git {} - {}", cmd_str, stderr);
-            return Err(self.translate_git_error(&output.stderr));
+        if let Some(ref timings) = self.timings {
+            timings.record(args, started.elapsed());
         }
+        Ok(output)
+    }
 
-        let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        tracing::debug!("Git command output: {}", result);
-        Ok(result)
+    /// Run git command with the path filter appended as a pathspec (if configured)
+    fn run_git_command_scoped(&self, args: &[&str]) -> Result<String> {
+        match self.options.path_filter.as_deref() {
+            Some(path) => {
+                let mut scoped_args = args.to_vec();
+                scoped_args.extend(["--", path]);
+                self.run_git_command(&scoped_args)
+            }
+            None => self.run_git_command(args),
+        }
     }
 
     /// Translate std::io::Error from git command execution to user-friendly messages
@@ -134,50 +309,63 @@ impl GitVcs {
         ZervError::CommandFailed(format!("Git command failed: {stderr_str}"))
     }
 
-    /// Get all commits from HEAD in topological order (only commits with tags)
+    /// Get all commits from the revision in topological order (only commits with tags)
     fn get_commits_in_topo_order(&self) -> Result<Vec<String>> {
-        let commits_output = self.run_git_command(&["rev-list", "--topo-order", "HEAD"])?;
-        let commits_output_only_with_tags =
-            self.run_git_command(&["log", "--tags", "--no-walk", "--format=%H"])?;
+        let commits_output = self.run_git_command(&["rev-list", "--topo-order", self.rev()])?;
+        let tagged_commits: std::collections::HashSet<String> =
+            self.get_tagged_commits()?.into_iter().collect();
 
-        let tagged_commits: std::collections::HashSet<String> = commits_output_only_with_tags
+        Ok(commits_output
             .lines()
             .map(|line| line.trim().to_string())
-            .filter(|hash| !hash.is_empty())
-            .collect();
+            .filter(|hash| !hash.is_empty() && tagged_commits.contains(hash))
+            .collect())
+    }
 
-        Ok(commits_output
+    /// Get every tagged commit in the repository, newest first
+    fn get_tagged_commits(&self) -> Result<Vec<String>> {
+        // --no-walk lists tagged commits newest first, so -n keeps the newest N
+        let max_count = self.options.max_tags.map(|n| format!("--max-count={n}"));
+        let mut tag_args = vec!["log", "--tags", "--no-walk", "--format=%H"];
+        tag_args.extend(max_count.as_deref());
+        let output = self.run_git_command(&tag_args)?;
+
+        Ok(output
             .lines()
             .map(|line| line.trim().to_string())
-            .filter(|hash| !hash.is_empty() && tagged_commits.contains(hash))
+            .filter(|hash| !hash.is_empty())
             .collect())
     }
 
+    /// Get the highest version tag in the repository, reachable from the revision or not
+    fn get_highest_tag(&self, format: &str) -> Result<Option<String>> {
+        let mut candidates = Vec::new();
+        for commit_hash in self.get_tagged_commits()? {
+            if let Some(tag) = self.get_max_version_tag_at(&commit_hash, format)? {
+                candidates.push(tag);
+            }
+        }
+
+        let valid_tags = GitUtils::filter_only_valid_tags_with_prefix(
+            &candidates,
+            format,
+            &self.options.tag_prefix,
+        );
+        GitUtils::find_max_version_tag(&valid_tags)
+    }
+
     /// Get latest version tag using enhanced algorithm
     fn get_latest_tag(&self, format: &str) -> Result<Option<String>> {
+        if self.options.tags_reachable.as_deref() == Some(tags_reachable_modes::ALL) {
+            return self.get_highest_tag(format);
+        }
+
         // Get all commits from HEAD in topological order
         let commits = self.get_commits_in_topo_order()?;
 
         // Process each commit in topological order
         for commit_hash in commits {
-            // Get all tags pointing to this commit (reusing existing function)
-            let tags = self.get_all_tags_from_commit_hash(&commit_hash);
-
-            // If no tags, continue to next commit
-            if tags.is_empty() {
-                continue;
-            }
-
-            // Filter tags by format
-            let valid_tags = GitUtils::filter_only_valid_tags(&tags, format);
-
-            // If no valid tags, continue to next commit
-            if valid_tags.is_empty() {
-                continue;
-            }
-
-            // Find and return the maximum version tag
-            if let Some(max_tag) = GitUtils::find_max_version_tag(&valid_tags)? {
+            if let Some(max_tag) = self.get_max_version_tag_at(&commit_hash, format)? {
                 return Ok(Some(max_tag));
             }
         }
@@ -186,6 +374,39 @@ impl GitVcs {
         Ok(None)
     }
 
+    /// Get the maximum valid version tag pointing at a commit, if any
+    fn get_max_version_tag_at(&self, commit_hash: &str, format: &str) -> Result<Option<String>> {
+        // Get all tags pointing to this commit (reusing existing function)
+        let tags = self.get_all_tags_from_commit_hash(commit_hash);
+
+        // If no tags, continue to next commit
+        if tags.is_empty() {
+            return Ok(None);
+        }
+
+        // With a path filter, skip tags whose history never touched the path
+        if self.options.path_filter.is_some() && !self.history_touches_path(commit_hash)? {
+            return Ok(None);
+        }
+
+        // Filter tags by format
+        let valid_tags =
+            GitUtils::filter_only_valid_tags_with_prefix(&tags, format, &self.options.tag_prefix);
+
+        // If no valid tags, continue to next commit
+        if valid_tags.is_empty() {
+            return Ok(None);
+        }
+
+        // Find the maximum version tag
+        match self.options.equal_tag_preference.as_deref() {
+            Some(preference) => {
+                GitUtils::find_max_version_tag_with_preference(&valid_tags, preference)
+            }
+            None => GitUtils::find_max_version_tag(&valid_tags),
+        }
+    }
+
     /// Get all tags pointing to a commit hash
     fn get_all_tags_from_commit_hash(&self, commit_hash: &str) -> Vec<String> {
         match self.run_git_command(&["tag", "--points-at", commit_hash]) {
@@ -198,16 +419,26 @@ impl GitVcs {
         }
     }
 
+    /// Check whether any commit reachable from the given commit touched the path filter
+    fn history_touches_path(&self, commit_hash: &str) -> Result<bool> {
+        let output = self.run_git_command_scoped(&["rev-list", "-n", "1", commit_hash])?;
+        Ok(!output.is_empty())
+    }
+
+    /// Commits since the tag, excluding the tag commit unless --distance-inclusive is set
     fn calculate_distance(&self, tag: &str) -> Result<u32> {
-        let output = self.run_git_command(&["rev-list", "--count", &format!("{tag}..HEAD")])?;
-        output
+        let range = format!("{}..{}", tag_ref(tag), self.rev());
+        let output = self.run_git_command_scoped(&["rev-list", "--count", &range])?;
+        let distance = output
             .parse::<u32>()
-            .map_err(|e| ZervError::CommandFailed(format!("Failed to parse distance: {e}")))
+            .map_err(|e| ZervError::CommandFailed(format!("Failed to parse distance: {e}")))?;
+        // Adding the tag commit rather than counting `tag^..` also works for root-commit tags
+        Ok(distance.saturating_add(u32::from(self.options.distance_inclusive)))
     }
 
     /// Get current commit hash (full)
     fn get_commit_hash(&self) -> Result<String> {
-        self.run_git_command(&["rev-parse", "HEAD"])
+        self.run_git_command(&["rev-parse", &format!("{}^{{commit}}", self.rev())])
     }
 
     /// Get current branch name
@@ -221,18 +452,56 @@ impl GitVcs {
 
     /// Get commit timestamp
     fn get_commit_timestamp(&self) -> Result<i64> {
-        let output = self.run_git_command(&["log", "-1", "--format=%ct"])?;
+        let output = self.run_git_command(&["log", "-1", "--format=%ct", self.rev()])?;
         output
             .parse::<i64>()
             .map_err(|e| ZervError::CommandFailed(format!("Failed to parse timestamp: {e}")))
     }
 
+    /// Get the subject line of the revision's commit
+    fn get_commit_subject(&self) -> Result<Option<String>> {
+        let output = self.run_git_command(&["log", "-1", "--format=%s", self.rev()])?;
+        Ok(Some(output).filter(|subject| !subject.is_empty()))
+    }
+
+    /// Get the number of commits reachable from the revision, including it
+    /// Unlike distance this counts the whole history, ignoring the path filter
+    fn get_commit_count(&self) -> Result<u32> {
+        if !self.has_commits()? {
+            return Ok(0);
+        }
+        let output = self.run_git_command(&["rev-list", "--count", self.rev()])?;
+        output
+            .parse::<u32>()
+            .map_err(|e| ZervError::CommandFailed(format!("Failed to parse commit count: {e}")))
+    }
+
+    /// Get the repository's default branch: origin's HEAD, then init.defaultBranch, then "main"
+    /// Both lookups print nothing (rather than fail) when unset
+    fn get_default_branch(&self) -> String {
+        let remote_head = self
+            .run_git_command(&["for-each-ref", "--format=%(symref:short)", REMOTE_HEAD_REF])
+            .ok()
+            .filter(|head| !head.is_empty());
+        let configured = match remote_head {
+            Some(_) => None,
+            None => self
+                .run_git_command(&["config", "--default", "", "--get", "init.defaultBranch"])
+                .ok(),
+        };
+        resolve_default_branch(remote_head.as_deref(), configured.as_deref())
+    }
+
     /// Get tag timestamp
     fn get_tag_timestamp(&self, tag: &str) -> Result<Option<i64>> {
         // Get the commit date for both annotated and lightweight tags
         // Using ^{commit} to dereference the tag to the commit it points to
-        match self.run_git_command(&["show", "-s", "--format=%ct", &format!("{}^{{commit}}", tag)])
-        {
+        match self.run_git_command(&[
+            "show",
+            "-s",
+            "--format=%ct",
+            &format!("{}^{{commit}}", tag_ref(tag)),
+        ]) {
             Ok(timestamp) => timestamp.parse::<i64>().map(Some).map_err(|e| {
                 ZervError::CommandFailed(format!("Failed to parse tag timestamp: {e}"))
             }),
@@ -240,28 +509,108 @@ impl GitVcs {
         }
     }
 
+    /// Tag timestamp, skipping the git lookup when --no-tag-timestamp is set
+    fn lookup_tag_timestamp(&self, tag: &str) -> Option<i64> {
+        if self.options.no_tag_timestamp {
+            return None;
+        }
+        self.get_tag_timestamp(tag).unwrap_or(None)
+    }
+
+    /// Get the message of an annotated tag (lightweight tags have none)
+    fn get_tag_message(&self, tag: &str) -> Result<Option<String>> {
+        // %(contents) of a lightweight tag is the commit message, so only read tag objects
+        let format = "--format=%(if:equals=tag)%(objecttype)%(then)%(contents)%(end)";
+        let output = self.run_git_command(&["tag", "-l", format, tag])?;
+        Ok(Some(output).filter(|message| !message.is_empty()))
+    }
+
     /// Get the commit hash that a tag points to
     fn get_tag_commit_hash(&self, tag: &str) -> Result<Option<String>> {
         // Use `git rev-list -n 1` to get the commit hash that the tag points to
         // This works for both annotated and lightweight tags
-        match self.run_git_command(&["rev-list", "-n", "1", tag]) {
-            Ok(hash) if !hash.trim().is_empty() => Ok(Some(hash.trim().to_string())),
-            Ok(_) | Err(_) => Ok(None),
+        Ok(self
+            .probe_git_command(&["rev-list", "-n", "1", &tag_ref(tag)])?
+            .filter(|hash| !hash.is_empty()))
+    }
+
+    /// Check whether HEAD points at a commit (false in a freshly initialised repo)
+    fn has_commits(&self) -> Result<bool> {
+        Ok(self
+            .probe_git_command(&["rev-parse", "--verify", "--quiet", "HEAD"])?
+            .is_some())
+    }
+
+    /// Check whether there are staged and/or unstaged changes, as `(staged, unstaged)`
+    fn get_dirty_state(&self) -> Result<(bool, bool)> {
+        if self.options.use_index {
+            return Ok((self.has_staged_changes()?, false));
         }
+        // v2 marks unchanged sides with '.', so trimming the output cannot shift the columns
+        let output = self.run_git_command_scoped(&["status", "--porcelain=v2"])?;
+        Ok(parse_porcelain_v2_dirty_state(&output))
     }
 
-    /// Check if working directory is dirty
-    fn is_dirty(&self) -> Result<bool> {
-        let output = self.run_git_command(&["status", "--porcelain"])?;
+    /// Check whether the index differs from HEAD (what the next commit would change)
+    fn has_staged_changes(&self) -> Result<bool> {
+        // On an unborn branch git compares the index against the empty tree
+        let output = self.run_git_command_scoped(&["diff", "--cached", "--name-only"])?;
         Ok(!output.is_empty())
     }
 
+    /// Git directory holding `HEAD` and the index, and the common one holding the refs.
+    /// They differ in linked worktrees, and `.git` is a file in worktrees and submodules
+    pub fn git_dirs(&self) -> Result<(PathBuf, PathBuf)> {
+        let output = self.run_git_command(&["rev-parse", "--git-dir", "--git-common-dir"])?;
+        // Relative paths are relative to the directory git ran in
+        let mut dirs = output.lines().map(|line| self.repo_path.join(line.trim()));
+        match (dirs.next(), dirs.next()) {
+            (Some(git_dir), Some(common_dir)) => Ok((git_dir, common_dir)),
+            _ => Err(ZervError::CommandFailed(format!(
+                "Unexpected git rev-parse output: {output}"
+            ))),
+        }
+    }
+
     /// Check for shallow clone and warn user
     fn check_shallow_clone(&self) -> bool {
         self.repo_path.join(".git/shallow").exists()
     }
 }
 
+/// Pick the default branch from origin's HEAD symref (e.g. "origin/master")
+/// or the init.defaultBranch config, falling back to "main"
+fn resolve_default_branch(remote_head: Option<&str>, configured: Option<&str>) -> String {
+    remote_head
+        .map(|head| head.strip_prefix("origin/").unwrap_or(head))
+        .or(configured)
+        .map(str::trim)
+        .filter(|branch| !branch.is_empty())
+        .unwrap_or(default_branch::FALLBACK)
+        .to_string()
+}
+
+/// Split `git status --porcelain=v2` output into `(staged, unstaged)` changes
+fn parse_porcelain_v2_dirty_state(output: &str) -> (bool, bool) {
+    let mut staged = false;
+    let mut unstaged = false;
+    for line in output.lines() {
+        let mut fields = line.split(' ');
+        match fields.next() {
+            // Changed, renamed/copied or unmerged entries carry an XY (index, worktree) code
+            Some("1" | "2" | "u") => {
+                let mut xy = fields.next().unwrap_or_default().chars();
+                staged |= xy.next().is_some_and(|x| x != '.');
+                unstaged |= xy.next().is_some_and(|y| y != '.');
+            }
+            // Untracked files only exist in the working tree
+            Some("?") => unstaged = true,
+            _ => {}
+        }
+    }
+    (staged, unstaged)
+}
+
 impl Vcs for GitVcs {
     fn get_vcs_data(&self, input_format: &str) -> Result<VcsData> {
         tracing::debug!(
@@ -274,21 +623,66 @@ impl Vcs for GitVcs {
             tracing::warn!("Shallow clone detected - distance calculations may be inaccurate");
         }
 
+        // Before the first commit there is no hash, timestamp or tag to read
+        if !self.has_commits()? {
+            tracing::debug!("Git repository has no commits yet");
+            let (is_staged_dirty, is_unstaged_dirty) = self.get_dirty_state()?;
+            return Ok(VcsData {
+                commit_hash_prefix: self.commit_hash_prefix(),
+                is_dirty: is_staged_dirty || is_unstaged_dirty,
+                is_staged_dirty,
+                is_unstaged_dirty,
+                current_branch: self.get_current_branch().unwrap_or(None),
+                default_branch: Some(self.get_default_branch()),
+                is_empty_repo: true,
+                ..Default::default()
+            });
+        }
+
+        self.verify_rev()?;
+
+        // Uncommitted changes belong to HEAD, so a past revision is always clean
+        let (is_staged_dirty, is_unstaged_dirty) = if self.is_detached_rev()? {
+            (false, false)
+        } else {
+            self.get_dirty_state()?
+        };
         let mut data = VcsData {
             commit_hash: self.get_commit_hash()?,
-            commit_hash_prefix: "g".to_string(), // Git prefix following git describe convention
+            commit_hash_prefix: self.commit_hash_prefix(),
             commit_timestamp: self.get_commit_timestamp()?,
-            is_dirty: self.is_dirty()?,
+            commit_subject: self.get_commit_subject().unwrap_or(None),
+            commit_count: self.get_commit_count()?,
+            is_dirty: is_staged_dirty || is_unstaged_dirty,
+            is_staged_dirty,
+            is_unstaged_dirty,
             current_branch: self.get_current_branch().unwrap_or(None),
+            default_branch: Some(self.get_default_branch()),
             ..Default::default()
         };
 
+        // A version tag on HEAD is what the full topological walk would find first,
+        // so use it directly and skip enumerating every tagged commit. With every tag
+        // a candidate, a higher tag elsewhere can still win
+        let all_tags = self.options.tags_reachable.as_deref() == Some(tags_reachable_modes::ALL);
+        if !all_tags && let Some(tag) = self.get_max_version_tag_at(self.rev(), input_format)? {
+            tracing::debug!("Found Git tag pointing at {}: {}", self.rev(), tag);
+            // Same count calculate_distance gives for an empty tag..HEAD range
+            data.distance = u32::from(self.options.distance_inclusive);
+            data.tag_timestamp = self.lookup_tag_timestamp(&tag);
+            data.tag_commit_hash = self.get_tag_commit_hash(&tag).unwrap_or(None);
+            data.tag_message = self.get_tag_message(&tag).unwrap_or(None);
+            data.tag_version = Some(tag);
+            return Ok(data);
+        }
+
         match self.get_latest_tag(input_format)? {
             Some(tag) => {
                 tracing::debug!("Found Git tag: {}", tag);
                 data.distance = self.calculate_distance(&tag).unwrap_or(0);
-                data.tag_timestamp = self.get_tag_timestamp(&tag).unwrap_or(None);
+                data.tag_timestamp = self.lookup_tag_timestamp(&tag);
                 data.tag_commit_hash = self.get_tag_commit_hash(&tag).unwrap_or(None);
+                data.tag_message = self.get_tag_message(&tag).unwrap_or(None);
                 data.tag_version = Some(tag);
             }
             None => {
@@ -308,6 +702,57 @@ impl Vcs for GitVcs {
         // Check if we're in a git repository
         path.join(".git").exists() || crate::vcs::find_vcs_root(path).is_ok()
     }
+
+    fn get_all_tags(&self) -> Result<Vec<String>> {
+        let output = self.run_git_command(&["tag", "--list"])?;
+        Ok(output
+            .lines()
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn count_commits_since_root(&self) -> Result<u32> {
+        if !self.has_commits()? {
+            return Ok(0);
+        }
+        let output = self.run_git_command_scoped(&["rev-list", "--count", self.rev()])?;
+        let count = output
+            .parse::<u32>()
+            .map_err(|e| ZervError::CommandFailed(format!("Failed to parse commit count: {e}")))?;
+        Ok(count.saturating_sub(1))
+    }
+
+    fn get_abbreviated_commit_hash(&self) -> Result<String> {
+        self.run_git_command(&[
+            "rev-parse",
+            "--short",
+            &format!("{}^{{commit}}", self.rev()),
+        ])
+    }
+
+    fn get_commit_messages_since(&self, tag: Option<&str>) -> Result<Vec<String>> {
+        if !self.has_commits()? {
+            return Ok(Vec::new());
+        }
+        let range = match tag {
+            Some(tag) => format!("{tag}..{}", self.rev()),
+            None => self.rev().to_string(),
+        };
+        // NUL-terminate each message since bodies can contain blank lines
+        let output = self.run_git_command_scoped(&["log", "--format=%B%x00", &range])?;
+        Ok(output
+            .split('\0')
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn timings_summary(&self) -> Option<String> {
+        self.timings.as_ref().map(GitTimings::summary)
+    }
 }
 
 #[cfg(test)]
@@ -373,6 +818,17 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_git_vcs_at_root_rejects_subdirectory() {
+        let temp_dir = TestDir::new().expect("should create temp dir");
+        fs::create_dir_all(temp_dir.path().join(".git")).expect("should create .git");
+        fs::create_dir_all(temp_dir.path().join("sub")).expect("should create subdir");
+
+        assert!(GitVcs::at_root(temp_dir.path()).is_ok());
+        let result = GitVcs::at_root(&temp_dir.path().join("sub"));
+        assert!(matches!(result, Err(ZervError::VcsNotFound(_))));
+    }
+
     #[test]
     fn test_is_available() {
         if !should_run_docker_tests() {
@@ -497,6 +953,599 @@ mod tests {
         assert_eq!(data.distance, 1);
     }
 
+    #[test]
+    fn test_distance_inclusive_counts_the_tag_commit() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+        let git = get_git_impl();
+        for i in 0..2 {
+            temp_dir
+                .create_file(format!("after{i}.txt"), "content")
+                .expect("should create file");
+            git.create_commit(&temp_dir, &format!("commit {i}"))
+                .expect("should create commit");
+        }
+
+        let exclusive = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        let inclusive = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .with_options(GitOptions {
+                distance_inclusive: true,
+                ..Default::default()
+            })
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+
+        assert_eq!(exclusive.distance, 2);
+        assert_eq!(inclusive.distance, exclusive.distance + 1);
+    }
+
+    #[test]
+    fn test_distance_inclusive_on_tagged_head() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+
+        let inclusive = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .with_options(GitOptions {
+                distance_inclusive: true,
+                ..Default::default()
+            })
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+
+        assert_eq!(inclusive.tag_version, Some("v1.0.0".to_string()));
+        assert_eq!(inclusive.distance, 1);
+    }
+
+    #[rstest]
+    #[case::valid_tags_on_head(&["v1.1.0", "v1.2.0", "nightly"], Some("v1.2.0"))]
+    #[case::only_invalid_tag_on_head(&["nightly"], None)]
+    fn test_tag_on_head_matches_full_walk(
+        #[case] head_tags: &[&str],
+        #[case] expected_head_tag: Option<&str>,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+        let git = get_git_impl();
+        temp_dir
+            .create_file("test2.txt", "test content 2")
+            .expect("should create file");
+        git.create_commit(&temp_dir, "second commit")
+            .expect("should create commit");
+        for tag in head_tags {
+            git.create_tag(&temp_dir, tag).expect("should create tag");
+        }
+
+        let git_vcs = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+        let head_tag = git_vcs.get_max_version_tag_at("HEAD", "auto").unwrap();
+        assert_eq!(head_tag.as_deref(), expected_head_tag);
+
+        // The short-circuit must agree with the full topological walk
+        let walked_tag = git_vcs.get_latest_tag("auto").unwrap().unwrap();
+        let data = git_vcs.get_vcs_data("auto").expect("should get vcs data");
+        assert_eq!(data.tag_version.as_deref(), Some(walked_tag.as_str()));
+        assert_eq!(
+            data.distance,
+            git_vcs.calculate_distance(&walked_tag).unwrap()
+        );
+        assert_eq!(
+            data.tag_commit_hash,
+            git_vcs.get_tag_commit_hash(&walked_tag).unwrap()
+        );
+    }
+
+    // `v1.2.3` and `1.2.3` on different commits, with a branch named like each tag at HEAD:
+    // the distance must be anchored at the selected tag's own commit
+    #[rstest]
+    #[case::nearest_unprefixed(None, "1.2.3", 1)]
+    #[case::prefix_selects_older(Some("v"), "v1.2.3", 3)]
+    fn test_distance_anchored_at_selected_tag_ref(
+        #[case] input_prefix: Option<&str>,
+        #[case] expected_tag: &str,
+        #[case] expected_distance: u32,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.2.3");
+        let git = get_git_impl();
+        for i in 0..3 {
+            temp_dir
+                .create_file(format!("file{i}.txt"), "content")
+                .expect("should create file");
+            git.create_commit(&temp_dir, &format!("commit {i}"))
+                .expect("should create commit");
+            if i == 1 {
+                git.create_tag(&temp_dir, "1.2.3")
+                    .expect("should create tag");
+            }
+        }
+        for branch in ["v1.2.3", "1.2.3"] {
+            git.execute_git(&temp_dir, &["branch", branch])
+                .expect("should create branch");
+        }
+
+        let git_vcs = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .with_options(GitOptions {
+                tag_prefix: TagPrefix {
+                    input_prefix: input_prefix.map(str::to_string),
+                    literal_v: false,
+                },
+                ..Default::default()
+            });
+        let data = git_vcs.get_vcs_data("auto").expect("should get vcs data");
+
+        assert_eq!(data.tag_version.as_deref(), Some(expected_tag));
+        assert_eq!(data.distance, expected_distance);
+        let tag_commit = git
+            .execute_git(
+                &temp_dir,
+                &["rev-parse", &format!("refs/tags/{expected_tag}^{{commit}}")],
+            )
+            .expect("should resolve tag");
+        assert_eq!(data.tag_commit_hash.as_deref(), Some(tag_commit.trim()));
+    }
+
+    #[rstest]
+    #[case::unlimited(None, 3)]
+    #[case::truncated(Some(2), 2)]
+    #[case::above_tag_count(Some(10), 3)]
+    fn test_max_tags_truncates_candidates(
+        #[case] max_tags: Option<u32>,
+        #[case] expected_candidates: usize,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+        let git = get_git_impl();
+        for (i, tag) in ["v1.1.0", "v1.2.0"].iter().enumerate() {
+            temp_dir
+                .create_file(format!("file{i}.txt"), "content")
+                .expect("should create file");
+            git.create_commit(&temp_dir, &format!("commit {i}"))
+                .expect("should create commit");
+            git.create_tag(&temp_dir, tag).expect("should create tag");
+        }
+
+        let git_vcs = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .with_options(GitOptions {
+                max_tags,
+                ..Default::default()
+            });
+        let candidates = git_vcs.get_commits_in_topo_order().unwrap();
+        assert_eq!(candidates.len(), expected_candidates);
+    }
+
+    #[rstest]
+    #[case::looked_up(false, true)]
+    #[case::skipped(true, false)]
+    fn test_no_tag_timestamp_skips_lookup(
+        #[case] no_tag_timestamp: bool,
+        #[case] expect_timestamp: bool,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+        let git_vcs = GitVcs::new(temp_dir.path())
+            .expect("should create GitVcs")
+            .with_options(GitOptions {
+                no_tag_timestamp,
+                ..Default::default()
+            });
+
+        assert_eq!(
+            git_vcs.lookup_tag_timestamp("v1.0.0").is_some(),
+            expect_timestamp
+        );
+        let data = git_vcs.get_vcs_data("auto").expect("should get vcs data");
+        assert_eq!(data.tag_version, Some("v1.0.0".to_string()));
+        assert_eq!(data.tag_timestamp.is_some(), expect_timestamp);
+    }
+
+    #[test]
+    fn test_rev_reads_past_commit() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_tag("v1.0.0");
+        let git = get_git_impl();
+        for i in 0..3 {
+            temp_dir
+                .create_file(format!("file{i}.txt"), "content")
+                .expect("should create file");
+            git.create_commit(&temp_dir, &format!("commit {i}"))
+                .expect("should create commit");
+            if i == 1 {
+                git.create_tag(&temp_dir, "v1.1.0")
+                    .expect("should create tag");
+            }
+        }
+        temp_dir
+            .create_file("untracked.txt", "content")
+            .expect("should create file");
+        let past_hash = git
+            .execute_git(&temp_dir, &["rev-parse", "HEAD~2"])
+            .expect("should resolve HEAD~2")
+            .trim()
+            .to_string();
+
+        let git_vcs = |rev: Option<&str>| {
+            GitVcs::new(temp_dir.path())
+                .expect("should create GitVcs")
+                .with_options(GitOptions {
+                    rev: rev.map(str::to_string),
+                    ..Default::default()
+                })
+        };
+
+        let past = git_vcs(Some("HEAD~2")).get_vcs_data("auto").unwrap();
+        assert_eq!(past.tag_version, Some("v1.0.0".to_string()));
+        assert_eq!(past.distance, 1);
+        assert_eq!(past.commit_hash, past_hash);
+        assert_eq!(past.commit_subject, Some("commit 0".to_string()));
+        assert!(!past.is_dirty);
+
+        let head = git_vcs(Some("HEAD")).get_vcs_data("auto").unwrap();
+        assert_eq!(head.tag_version, Some("v1.1.0".to_string()));
+        assert_eq!(head.distance, 1);
+        assert!(head.is_dirty);
+
+        let unknown = git_vcs(Some("no-such-branch")).get_vcs_data("auto");
+        assert!(matches!(unknown, Err(ZervError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_count_commits_since_root() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_commit();
+        let git_vcs = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+        assert_eq!(git_vcs.count_commits_since_root().unwrap(), 0);
+
+        let git = get_git_impl();
+        for i in 0..2 {
+            temp_dir
+                .create_file(format!("file{i}.txt"), "content")
+                .expect("should create file");
+            git.create_commit(&temp_dir, "another commit")
+                .expect("should create commit");
+        }
+        assert_eq!(git_vcs.count_commits_since_root().unwrap(), 2);
+    }
+
+    #[rstest]
+    #[case::fetch(&["fetch", "--tags"], false)]
+    #[case::ls_remote(&["ls-remote", "origin"], false)]
+    #[case::local(&["rev-parse", "HEAD"], true)]
+    fn test_no_network_guard(#[case] args: &[&str], #[case] expected_ok: bool) {
+        let git_vcs =
+            GitVcs::new_for_test(PathBuf::from("/nonexistent")).with_options(GitOptions {
+                no_network: true,
+                ..Default::default()
+            });
+        assert_eq!(git_vcs.check_network_allowed(args).is_ok(), expected_ok);
+    }
+
+    #[test]
+    fn test_no_network_fetch_fails_before_spawning() {
+        let git_vcs =
+            GitVcs::new_for_test(PathBuf::from("/nonexistent")).with_options(GitOptions {
+                no_network: true,
+                ..Default::default()
+            });
+        let err = git_vcs.run_git_command(&["fetch", "--tags"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Command execution failed: Refusing to run 'git fetch': --no-network forbids network access"
+        );
+    }
+
+    #[test]
+    fn test_get_abbreviated_commit_hash() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_commit();
+        let git_vcs = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+        let full_hash = git_vcs.get_commit_hash().unwrap();
+
+        let git = get_git_impl();
+        git.execute_git(&temp_dir, &["config", "core.abbrev", "12"])
+            .expect("should set core.abbrev");
+        let abbreviated = git_vcs.get_abbreviated_commit_hash().unwrap();
+        assert_eq!(abbreviated, full_hash[..12]);
+    }
+
+    #[test]
+    fn test_get_vcs_data_tag_message() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let annotated = GitRepoFixture::tagged_annotated("v1.0.0", "Release notes for 1.0.0")
+            .expect("should create annotated fixture");
+        let data = GitVcs::new(annotated.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!(
+            data.tag_message,
+            Some("Release notes for 1.0.0".to_string())
+        );
+
+        let lightweight = GitRepoFixture::tagged("v1.0.0").expect("should create fixture");
+        let data = GitVcs::new(lightweight.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!(data.tag_message, None);
+    }
+
+    #[rstest]
+    #[case::clean("", (false, false))]
+    #[case::staged("1 M. N... 100644 100644 100644 abc abc file.txt", (true, false))]
+    #[case::unstaged("1 .M N... 100644 100644 100644 abc abc file.txt", (false, true))]
+    #[case::both("1 MM N... 100644 100644 100644 abc abc file.txt", (true, true))]
+    #[case::added("1 A. N... 000000 100644 100644 000 abc new.txt", (true, false))]
+    #[case::renamed("2 R. N... 100644 100644 100644 abc abc R100 new.txt\told.txt", (true, false))]
+    #[case::untracked("? untracked.txt", (false, true))]
+    #[case::mixed("1 A. N... 000000 100644 100644 000 abc new.txt\n? untracked.txt", (true, true))]
+    fn test_parse_porcelain_v2_dirty_state(#[case] output: &str, #[case] expected: (bool, bool)) {
+        assert_eq!(parse_porcelain_v2_dirty_state(output), expected);
+    }
+
+    #[rstest]
+    #[case::staged_only(true, (true, false))]
+    #[case::unstaged_only(false, (false, true))]
+    fn test_get_vcs_data_staged_unstaged(#[case] stage: bool, #[case] expected: (bool, bool)) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("should create fixture");
+        fixture
+            .test_dir
+            .create_file("change.txt", "content")
+            .expect("should create file");
+        if stage {
+            fixture
+                .git_impl
+                .execute_git(&fixture.test_dir, &["add", "change.txt"])
+                .expect("should stage file");
+        }
+
+        let data = GitVcs::new(fixture.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!((data.is_staged_dirty, data.is_unstaged_dirty), expected);
+        assert!(data.is_dirty);
+    }
+
+    #[rstest]
+    #[case::remote_head(Some("origin/master"), Some("main"), "master")]
+    #[case::configured(None, Some("trunk"), "trunk")]
+    #[case::unset_config(None, Some(""), "main")]
+    #[case::nothing(None, None, "main")]
+    fn test_resolve_default_branch(
+        #[case] remote_head: Option<&str>,
+        #[case] configured: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(resolve_default_branch(remote_head, configured), expected);
+    }
+
+    #[test]
+    fn test_get_vcs_data_default_branch_master() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("should create fixture");
+        fixture
+            .git_impl
+            .execute_git(
+                &fixture.test_dir,
+                &["config", "init.defaultBranch", "master"],
+            )
+            .expect("should set init.defaultBranch");
+        let data = GitVcs::new(fixture.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!(data.default_branch, Some("master".to_string()));
+    }
+
+    #[test]
+    fn test_get_vcs_data_commit_subject() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::tagged("v1.0.0")
+            .expect("should create fixture")
+            .commit("Add feature X\n\nLonger description");
+        let data = GitVcs::new(fixture.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!(data.commit_subject, Some("Add feature X".to_string()));
+    }
+
+    #[test]
+    fn test_get_vcs_data_commit_count() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture = GitRepoFixture::with_distance("v1.0.0", 3).expect("should create fixture");
+        let data = GitVcs::new(fixture.path())
+            .expect("should create GitVcs")
+            .get_vcs_data("auto")
+            .expect("should get vcs data");
+        assert_eq!(data.commit_count, 4);
+    }
+
+    #[test]
+    fn test_get_vcs_data_without_commits() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = TestDir::new().expect("should create temp dir");
+        let git = get_git_impl();
+        git.init_repo_no_commit(&temp_dir)
+            .expect("should init repo");
+        let git_vcs = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+
+        let data = git_vcs.get_vcs_data("auto").expect("should get vcs data");
+        assert!(data.is_empty_repo);
+        assert_eq!(data.commit_subject, None);
+        assert_eq!(data.commit_count, 0);
+        assert!(!data.is_dirty);
+        assert_eq!(data.tag_version, None);
+        assert_eq!(data.distance, 0);
+        assert_eq!(data.commit_hash, "");
+        assert_eq!(git_vcs.count_commits_since_root().unwrap(), 0);
+
+        temp_dir
+            .create_file("staged.txt", "content")
+            .expect("should create file");
+        git.execute_git(&temp_dir, &["add", "staged.txt"])
+            .expect("should stage file");
+        let data = git_vcs.get_vcs_data("auto").expect("should get vcs data");
+        assert!(data.is_dirty);
+    }
+
+    mod path_filter {
+        use super::*;
+
+        fn scoped_git_vcs(temp_dir: &TestDir, path: &str) -> GitVcs {
+            let path_filter = temp_dir.path().join(path).to_string_lossy().to_string();
+            GitVcs::new(temp_dir.path())
+                .expect("should create GitVcs")
+                .with_options(GitOptions {
+                    path_filter: Some(path_filter),
+                    ..Default::default()
+                })
+        }
+
+        fn setup_monorepo() -> TestDir {
+            let temp_dir = setup_git_repo();
+            let git = get_git_impl();
+            temp_dir
+                .create_file("packages/core/lib.txt", "core v1")
+                .expect("should create file");
+            git.create_commit(&temp_dir, "add core")
+                .expect("should create commit");
+            git.create_tag(&temp_dir, "v1.0.0")
+                .expect("should create tag");
+            temp_dir
+        }
+
+        #[test]
+        fn test_edits_outside_path_do_not_increase_distance() {
+            if !should_run_docker_tests() {
+                return;
+            }
+            let temp_dir = setup_monorepo();
+            let git = get_git_impl();
+            for i in 0..2 {
+                temp_dir
+                    .create_file(format!("packages/web/file{i}.txt"), "web")
+                    .expect("should create file");
+                git.create_commit(&temp_dir, &format!("web change {i}"))
+                    .expect("should create commit");
+            }
+
+            let unscoped = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+            assert_eq!(unscoped.get_vcs_data("auto").unwrap().distance, 2);
+
+            let data = scoped_git_vcs(&temp_dir, "packages/core")
+                .get_vcs_data("auto")
+                .unwrap();
+            assert_eq!(data.tag_version, Some("v1.0.0".to_string()));
+            assert_eq!(data.distance, 0);
+        }
+
+        #[test]
+        fn test_edits_inside_path_increase_distance() {
+            if !should_run_docker_tests() {
+                return;
+            }
+            let temp_dir = setup_monorepo();
+            let git = get_git_impl();
+            temp_dir
+                .create_file("packages/web/index.txt", "web")
+                .expect("should create file");
+            git.create_commit(&temp_dir, "web change")
+                .expect("should create commit");
+            temp_dir
+                .create_file("packages/core/lib.txt", "core v2")
+                .expect("should create file");
+            git.create_commit(&temp_dir, "core change")
+                .expect("should create commit");
+
+            let data = scoped_git_vcs(&temp_dir, "packages/core")
+                .get_vcs_data("auto")
+                .unwrap();
+            assert_eq!(data.distance, 1);
+        }
+
+        #[test]
+        fn test_commit_count_ignores_path() {
+            if !should_run_docker_tests() {
+                return;
+            }
+            let temp_dir = setup_monorepo();
+            let unscoped = GitVcs::new(temp_dir.path()).expect("should create GitVcs");
+            let expected = unscoped.get_vcs_data("auto").unwrap().commit_count;
+
+            let data = scoped_git_vcs(&temp_dir, "packages/web")
+                .get_vcs_data("auto")
+                .unwrap();
+            assert_eq!(data.commit_count, expected);
+            assert!(data.commit_count > 0);
+        }
+
+        #[test]
+        fn test_tags_before_path_existed_are_skipped() {
+            if !should_run_docker_tests() {
+                return;
+            }
+            let temp_dir = setup_monorepo();
+            let data = scoped_git_vcs(&temp_dir, "packages/web")
+                .get_vcs_data("auto")
+                .unwrap();
+            assert_eq!(data.tag_version, None);
+        }
+
+        #[test]
+        fn test_dirty_outside_path_is_ignored() {
+            if !should_run_docker_tests() {
+                return;
+            }
+            let temp_dir = setup_monorepo();
+            temp_dir
+                .create_file("packages/web/untracked.txt", "web")
+                .expect("should create file");
+
+            let core = scoped_git_vcs(&temp_dir, "packages/core");
+            assert!(!core.get_vcs_data("auto").unwrap().is_dirty);
+            let web = scoped_git_vcs(&temp_dir, "packages/web");
+            assert!(web.get_vcs_data("auto").unwrap().is_dirty);
+        }
+    }
+
     #[test]
     fn test_dirty_working_directory() {
         if !should_run_docker_tests() {
@@ -514,6 +1563,33 @@ mod tests {
         assert!(data.is_dirty);
     }
 
+    #[rstest]
+    #[case::unstaged_only(false, false)]
+    #[case::staged(true, true)]
+    fn test_use_index_ignores_unstaged_changes(#[case] stage: bool, #[case] expected: bool) {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let temp_dir = setup_git_repo_with_commit();
+        let path = temp_dir.path();
+        fs::write(path.join("untracked.txt"), "untracked").unwrap();
+        fs::write(path.join("staged.txt"), "staged").unwrap();
+        if stage {
+            get_git_impl()
+                .execute_git(&temp_dir, &["add", "staged.txt"])
+                .expect("should stage file");
+        }
+
+        let git_vcs = GitVcs::new(path).unwrap().with_options(GitOptions {
+            use_index: true,
+            ..Default::default()
+        });
+        let data = git_vcs.get_vcs_data("auto").unwrap();
+        assert_eq!(data.is_dirty, expected);
+        assert_eq!(data.is_staged_dirty, expected);
+        assert!(!data.is_unstaged_dirty);
+    }
+
     #[test]
     fn test_clean_working_directory() {
         if !should_run_docker_tests() {
@@ -1,8 +1,14 @@
+use std::cmp::Ordering;
+
 use crate::error::{
     Result,
     ZervError,
 };
-use crate::version::VersionObject;
+use crate::utils::constants::equal_tag_preferences;
+use crate::version::{
+    TagPrefix,
+    VersionObject,
+};
 
 pub struct GitUtils;
 
@@ -11,7 +17,55 @@ impl GitUtils {
         VersionObject::parse_with_format_batch(tags, format).unwrap_or_default()
     }
 
+    /// Like `filter_only_valid_tags`, but parses each tag after prefix handling and
+    /// returns the original tag names
+    pub fn filter_only_valid_tags_with_prefix(
+        tags: &[String],
+        format: &str,
+        prefix: &TagPrefix,
+    ) -> Vec<(String, VersionObject)> {
+        if *prefix == TagPrefix::default() {
+            return Self::filter_only_valid_tags(tags, format);
+        }
+
+        let (originals, stripped): (Vec<String>, Vec<String>) = tags
+            .iter()
+            .filter_map(|tag| {
+                prefix
+                    .strip(tag)
+                    .ok()
+                    .map(|version| (tag.clone(), version.to_string()))
+            })
+            .unzip();
+        if stripped.is_empty() {
+            return Vec::new();
+        }
+
+        Self::filter_only_valid_tags(&stripped, format)
+            .into_iter()
+            .filter_map(|(version, version_obj)| {
+                stripped
+                    .iter()
+                    .position(|candidate| *candidate == version)
+                    .map(|index| (originals[index].clone(), version_obj))
+            })
+            .collect()
+    }
+
     pub fn find_max_version_tag(valid_tags: &[(String, VersionObject)]) -> Result<Option<String>> {
+        Self::find_max_version_tag_with_preference(valid_tags, equal_tag_preferences::LAST)
+    }
+
+    /// Like `find_max_version_tag`, breaking ties between tags that parse to the same
+    /// version by `preference` (see `equal_tag_preferences`)
+    ///
+    /// `first`/`last` pick by tag name, so the choice never depends on the order git
+    /// lists the tags in. `prefixed`/`unprefixed` prefer a tag that does/doesn't start
+    /// with a non-digit (`v1.2.3` vs `1.2.3`) and fall back to `last`.
+    pub fn find_max_version_tag_with_preference(
+        valid_tags: &[(String, VersionObject)],
+        preference: &str,
+    ) -> Result<Option<String>> {
         if valid_tags.is_empty() {
             return Ok(None);
         }
@@ -29,18 +83,35 @@ impl GitUtils {
             }
         }
 
-        // Find the maximum version using custom comparison
+        // Find the maximum version using custom comparison, then the preferred tag among equals
         let max_tag = valid_tags
             .iter()
-            .max_by(|(_, a), (_, b)| {
+            .max_by(|(a_tag, a), (b_tag, b)| {
                 // This should not fail since all types are now verified to be the same
-                Self::compare_version_objects(a, b).unwrap_or(std::cmp::Ordering::Equal)
+                Self::compare_version_objects(a, b)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| Self::compare_equal_tags(a_tag, b_tag, preference))
             })
             .map(|(tag, _)| tag.clone());
 
         Ok(max_tag)
     }
 
+    /// Order two tags of the same version so the preferred one compares greater
+    fn compare_equal_tags(a: &str, b: &str, preference: &str) -> Ordering {
+        let is_prefixed = |tag: &str| !tag.starts_with(|c: char| c.is_ascii_digit());
+        match preference {
+            equal_tag_preferences::FIRST => b.cmp(a),
+            equal_tag_preferences::PREFIXED => {
+                is_prefixed(a).cmp(&is_prefixed(b)).then_with(|| a.cmp(b))
+            }
+            equal_tag_preferences::UNPREFIXED => {
+                is_prefixed(b).cmp(&is_prefixed(a)).then_with(|| a.cmp(b))
+            }
+            _ => a.cmp(b),
+        }
+    }
+
     pub fn get_format_type(version_obj: &VersionObject) -> String {
         match version_obj {
             VersionObject::SemVer(_) => "semver".to_string(),
@@ -78,7 +149,11 @@ mod tests {
     use rstest::rstest;
 
     use super::GitUtils;
-    use crate::version::VersionObject;
+    use crate::utils::constants::equal_tag_preferences;
+    use crate::version::{
+        TagPrefix,
+        VersionObject,
+    };
 
     #[rstest]
     // Basic semver case
@@ -312,4 +387,71 @@ mod tests {
         let actual_max_version_tag = GitUtils::find_max_version_tag(&filtered_tags).unwrap();
         assert_eq!(actual_max_version_tag, expected_max_version_tag);
     }
+
+    #[rstest]
+    #[case::literal_v(None, true, vec!["1.0.0"], Some("1.0.0"))]
+    #[case::input_prefix_v(Some("v"), true, vec!["v2.0.0"], Some("v2.0.0"))]
+    #[case::custom_prefix(Some("release-"), false, vec!["release-3.0.0"], Some("release-3.0.0"))]
+    fn test_filter_only_valid_tags_with_prefix(
+        #[case] input_prefix: Option<&str>,
+        #[case] literal_v: bool,
+        #[case] expected_tags: Vec<&str>,
+        #[case] expected_max_version_tag: Option<&str>,
+    ) {
+        let tags: Vec<String> = ["1.0.0", "v2.0.0", "release-3.0.0"]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect();
+        let prefix = TagPrefix {
+            input_prefix: input_prefix.map(str::to_string),
+            literal_v,
+        };
+
+        let filtered_tags = GitUtils::filter_only_valid_tags_with_prefix(&tags, "semver", &prefix);
+        let filtered_names: Vec<&str> = filtered_tags.iter().map(|(tag, _)| tag.as_str()).collect();
+        assert_eq!(filtered_names, expected_tags);
+
+        let actual_max_version_tag = GitUtils::find_max_version_tag(&filtered_tags).unwrap();
+        assert_eq!(actual_max_version_tag.as_deref(), expected_max_version_tag);
+    }
+
+    #[rstest]
+    #[case::prefixed(equal_tag_preferences::PREFIXED, "v1.2.3")]
+    #[case::unprefixed(equal_tag_preferences::UNPREFIXED, "1.2.3")]
+    #[case::first(equal_tag_preferences::FIRST, "1.2.3")]
+    #[case::last(equal_tag_preferences::LAST, "v1.2.3")]
+    fn test_find_max_version_tag_equal_tag_preference(
+        #[case] preference: &str,
+        #[case] expected: &str,
+    ) {
+        // The listing order must not change which tag wins
+        for order in [["1.2.3", "v1.2.3", "v1.2.2"], ["v1.2.2", "v1.2.3", "1.2.3"]] {
+            let tags: Vec<String> = order.iter().map(|tag| tag.to_string()).collect();
+            let valid_tags = GitUtils::filter_only_valid_tags(&tags, "semver");
+            let max_tag =
+                GitUtils::find_max_version_tag_with_preference(&valid_tags, preference).unwrap();
+            assert_eq!(max_tag.as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_find_max_version_tag_ignores_build_metadata() {
+        let tags: Vec<String> = ["v1.2.3+ci.100", "v1.2.3+ci.200"]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect();
+        let valid_tags = GitUtils::filter_only_valid_tags(&tags, "semver");
+        assert_eq!(valid_tags.len(), 2);
+        assert_eq!(
+            GitUtils::compare_version_objects(&valid_tags[0].1, &valid_tags[1].1).unwrap(),
+            std::cmp::Ordering::Equal
+        );
+
+        // The tie is broken by tag name, whatever order git lists the tags in
+        for order in [[0, 1], [1, 0]] {
+            let listed: Vec<_> = order.iter().map(|&i| valid_tags[i].clone()).collect();
+            let max_tag = GitUtils::find_max_version_tag(&listed).unwrap();
+            assert_eq!(max_tag.as_deref(), Some("v1.2.3+ci.200"));
+        }
+    }
 }
@@ -8,8 +8,11 @@ use crate::error::{
     ZervError,
 };
 
+pub mod ci_branch;
+pub mod conventional_commits;
 pub mod git;
 pub mod git_utils;
+pub mod timings;
 pub mod vcs_data;
 
 pub use vcs_data::VcsData;
@@ -21,6 +24,23 @@ pub trait Vcs {
 
     /// Check if this VCS type is available in the given directory
     fn is_available(&self, path: &Path) -> bool;
+
+    /// List every tag in the repository, regardless of reachability
+    fn get_all_tags(&self) -> Result<Vec<String>>;
+
+    /// Count commits reachable from HEAD after the root commit
+    fn count_commits_since_root(&self) -> Result<u32>;
+
+    /// Shortest unambiguous abbreviation of the HEAD commit hash
+    fn get_abbreviated_commit_hash(&self) -> Result<String>;
+
+    /// Full messages of the commits after the tag up to HEAD (all of history without a tag)
+    fn get_commit_messages_since(&self, tag: Option<&str>) -> Result<Vec<String>>;
+
+    /// Summary of the time spent in VCS subprocesses, when timings are recorded
+    fn timings_summary(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Detect and create appropriate VCS implementation
@@ -30,7 +50,16 @@ pub fn detect_vcs(path: &Path) -> Result<Box<dyn Vcs>> {
 
 /// Detect and create appropriate VCS implementation with optional depth limit
 pub fn detect_vcs_with_limit(path: &Path, max_depth: Option<usize>) -> Result<Box<dyn Vcs>> {
-    let git_vcs = git::GitVcs::new_with_limit(path, max_depth)?;
+    detect_vcs_with_options(path, max_depth, git::GitOptions::default())
+}
+
+/// Detect and create appropriate VCS implementation with query options
+pub fn detect_vcs_with_options(
+    path: &Path,
+    max_depth: Option<usize>,
+    options: git::GitOptions,
+) -> Result<Box<dyn Vcs>> {
+    let git_vcs = git::GitVcs::new_with_limit(path, max_depth)?.with_options(options);
     if git_vcs.is_available(path) {
         return Ok(Box::new(git_vcs));
     }
@@ -40,6 +69,11 @@ pub fn detect_vcs_with_limit(path: &Path, max_depth: Option<usize>) -> Result<Bo
     ))
 }
 
+/// Open the repository rooted exactly at `path`, without searching parent directories
+pub fn open_vcs_at_root(path: &Path, options: git::GitOptions) -> Result<Box<dyn Vcs>> {
+    Ok(Box::new(git::GitVcs::at_root(path)?.with_options(options)))
+}
+
 /// Find the root directory of the VCS repository
 pub fn find_vcs_root(start_path: &Path) -> Result<PathBuf> {
     find_vcs_root_with_limit(start_path, None)
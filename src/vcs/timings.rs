@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// Durations of the git subprocesses run for one version computation (--timings)
+#[derive(Debug, Default)]
+pub struct GitTimings {
+    records: RefCell<Vec<(String, Duration)>>,
+}
+
+impl GitTimings {
+    /// Record how long `git <args>` took
+    pub fn record(&self, args: &[&str], duration: Duration) {
+        let operation = args.first().copied().unwrap_or_default().to_string();
+        self.records.borrow_mut().push((operation, duration));
+    }
+
+    /// Table of calls and total time per git subcommand, slowest first
+    pub fn summary(&self) -> String {
+        let records = self.records.borrow();
+        let mut operations: Vec<(&str, usize, Duration)> = Vec::new();
+        for (operation, duration) in records.iter() {
+            match operations.iter_mut().find(|(name, _, _)| name == operation) {
+                Some((_, calls, total)) => {
+                    *calls += 1;
+                    *total += *duration;
+                }
+                None => operations.push((operation, 1, *duration)),
+            }
+        }
+        operations.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(b.0)));
+
+        let total: Duration = records.iter().map(|(_, duration)| *duration).sum();
+        let mut lines = vec![format!(
+            "{:<16} {:>6} {:>12}",
+            "git operation", "calls", "time (ms)"
+        )];
+        lines.extend(operations.iter().map(|(operation, calls, duration)| {
+            format!("{operation:<16} {calls:>6} {:>12.3}", as_millis(*duration))
+        }));
+        lines.push(format!(
+            "{:<16} {:>6} {:>12.3}",
+            "total",
+            records.len(),
+            as_millis(total)
+        ));
+        lines.join("\n")
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_groups_by_subcommand() {
+        let timings = GitTimings::default();
+        timings.record(&["tag", "--points-at", "HEAD"], Duration::from_millis(2));
+        timings.record(
+            &["rev-list", "--count", "v1.0.0..HEAD"],
+            Duration::from_millis(5),
+        );
+        timings.record(&["tag", "--list"], Duration::from_millis(1));
+
+        let summary = timings.summary();
+        let rows: Vec<Vec<&str>> = summary
+            .lines()
+            .skip(1)
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ["rev-list", "1", "5.000"],
+                ["tag", "2", "3.000"],
+                ["total", "3", "8.000"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summary_without_records() {
+        let summary = GitTimings::default().summary();
+        assert_eq!(
+            summary
+                .lines()
+                .last()
+                .unwrap()
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["total", "0", "0.000"]
+        );
+    }
+}
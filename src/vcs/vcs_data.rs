@@ -1,14 +1,30 @@
-#[derive(Debug, Clone, PartialEq, Default)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct VcsData {
     /// Latest version tag (e.g., "v1.2.3")
     pub tag_version: Option<String>,
     pub tag_commit_hash: Option<String>,
     pub tag_timestamp: Option<i64>,
+    /// Message of the annotated tag (None for lightweight tags)
+    pub tag_message: Option<String>,
 
     pub commit_hash: String,
     pub commit_hash_prefix: String,
     pub commit_timestamp: i64,
+    /// Subject line of the HEAD commit
+    pub commit_subject: Option<String>,
+    /// Number of commits reachable from the revision
+    pub commit_count: u32,
     pub current_branch: Option<String>,
+    /// Branch the remote's HEAD points to (falls back to "main")
+    pub default_branch: Option<String>,
     pub is_dirty: bool,
+    /// Changes recorded in the index
+    pub is_staged_dirty: bool,
+    /// Changes (including untracked files) only in the working tree
+    pub is_unstaged_dirty: bool,
     pub distance: u32,
+    /// Repository has no commits yet (HEAD is unborn)
+    pub is_empty_repo: bool,
 }
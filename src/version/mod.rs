@@ -12,7 +12,11 @@ pub use semver::{
     PreReleaseIdentifier,
     SemVer,
 };
-pub use version_object::VersionObject;
+pub use version_object::{
+    TagPrefix,
+    VersionObject,
+    parse,
+};
 pub use zerv::{
     Component,
     PreReleaseLabel,
@@ -508,6 +508,8 @@ mod tests {
     #[case("5!1.2.3")]
     #[case("2!1")]
     #[case("1!1.2")]
+    #[case("12!1.0.0")]
+    #[case("2024!1.0.0a1")]
     // Combinations with different release formats
     #[case("1a1.post2")]
     #[case("1.2b3.dev4")]
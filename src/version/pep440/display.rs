@@ -6,6 +6,7 @@ use super::core::{
     PostLabel,
 };
 use super::utils::LocalSegment;
+use crate::version::zerv::PreReleaseLabel;
 
 /// Format local version segments into a dot-separated string
 pub fn format_local_segments(segments: &[LocalSegment]) -> String {
@@ -198,6 +199,53 @@ impl fmt::Display for PEP440 {
     }
 }
 
+impl PEP440 {
+    /// Display with the pre-release label (a, b, rc) cased per `prerelease_cases`
+    pub fn to_string_with_label_case(&self, case: &str) -> String {
+        let rendered = self.to_string();
+        let Some(label) = self.pre_label.map(|label| label.as_str()) else {
+            return rendered;
+        };
+        // Normalized form puts the label directly after the release
+        let label_start = self.to_base_part().len();
+        let label_end = label_start + label.len();
+        format!(
+            "{}{}{}",
+            &rendered[..label_start],
+            PreReleaseLabel::apply_case(label, case),
+            &rendered[label_end..]
+        )
+    }
+
+    /// Display with the pre-release label cased per `prerelease_cases` and the
+    /// post/dev labels spelled as given (e.g. `r` for `1.2.3.r4`)
+    ///
+    /// Callers must pass spellings PEP440 accepts (see `post_dev_labels`).
+    pub fn to_string_with_labels(
+        &self,
+        case: &str,
+        post_label: Option<&str>,
+        dev_label: Option<&str>,
+    ) -> String {
+        let rendered = self.to_string_with_label_case(case);
+        let (public, local) = match rendered.split_once('+') {
+            Some((public, local)) => (public.to_string(), Some(local)),
+            None => (rendered.clone(), None),
+        };
+        let mut public = public;
+        if let Some(label) = post_label.filter(|_| self.post_label.is_some()) {
+            public = public.replacen(".post", &format!(".{label}"), 1);
+        }
+        if let Some(label) = dev_label.filter(|_| self.dev_label.is_some()) {
+            public = public.replacen(".dev", &format!(".{label}"), 1);
+        }
+        match local {
+            Some(local) => format!("{public}+{local}"),
+            None => public,
+        }
+    }
+}
+
 impl fmt::Display for LocalSegment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -209,9 +257,40 @@ impl fmt::Display for LocalSegment {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
     use crate::version::zerv::PreReleaseLabel;
 
+    #[rstest::rstest]
+    #[case::default(None, "1.2.3rc1.post2.dev3+post.dev")]
+    #[case::r(Some("r"), "1.2.3rc1.r2.dev3+post.dev")]
+    #[case::rev(Some("rev"), "1.2.3rc1.rev2.dev3+post.dev")]
+    fn test_display_with_post_label(#[case] post_label: Option<&str>, #[case] expected: &str) {
+        let version = PEP440::from_str("1.2.3rc1.post2.dev3+post.dev").unwrap();
+        let rendered = version.to_string_with_labels("lower", post_label, Some("dev"));
+        assert_eq!(rendered, expected);
+        // Alternative spellings normalize back to the same version
+        assert_eq!(PEP440::from_str(&rendered).unwrap(), version);
+    }
+
+    #[rstest::rstest]
+    #[case::lower("lower", "1!1.2.3rc1.post2+local")]
+    #[case::upper("upper", "1!1.2.3RC1.post2+local")]
+    #[case::title("title", "1!1.2.3Rc1.post2+local")]
+    fn test_display_with_label_case(#[case] case: &str, #[case] expected: &str) {
+        let version = PEP440::new(vec![1, 2, 3])
+            .with_epoch(1)
+            .with_pre_release(PreReleaseLabel::Rc, Some(1))
+            .with_post(Some(2))
+            .with_local("local");
+        assert_eq!(version.to_string_with_label_case(case), expected);
+        assert_eq!(
+            PEP440::new(vec![1, 0]).to_string_with_label_case(case),
+            "1.0"
+        );
+    }
+
     #[test]
     fn test_display_simple_version() {
         let version = PEP440::new(vec![1, 2, 3]);
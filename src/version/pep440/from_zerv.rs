@@ -8,6 +8,7 @@ use crate::version::pep440::core::{
 use crate::version::zerv::core::Zerv;
 use crate::version::zerv::{
     Component,
+    PreReleaseLabel,
     Var,
 };
 
@@ -71,15 +72,33 @@ impl PEP440 {
     ) {
         let expanded = var.resolve_expanded_values(zerv_vars, local_sanitizer);
         if !expanded.is_empty() && !expanded[0].is_empty() {
-            if let Ok(label) = expanded[0].parse() {
-                self.pre_label = Some(label);
-            }
+            let Ok(label) = expanded[0].parse() else {
+                // A raw pre-release has no label. Render it as alpha so it still sorts before
+                // the release, as it does in SemVer, with a leading number as the alpha number
+                // and the other identifiers kept as local
+                self.pre_label = Some(PreReleaseLabel::Alpha);
+                let mut identifiers = expanded.into_iter().peekable();
+                if let Some(num) = identifiers.peek().and_then(|id| id.parse::<u32>().ok()) {
+                    self.pre_number = Some(num);
+                    identifiers.next();
+                }
+                for identifier in identifiers {
+                    self.add_flattened_to_local(identifier);
+                }
+                return;
+            };
+            self.pre_label = Some(label);
             if expanded.len() >= 2
                 && !expanded[1].is_empty()
                 && let Ok(num) = expanded[1].parse::<u32>()
             {
                 self.pre_number = Some(num);
             }
+            // Trailing identifiers have no PEP440 pre-release slot, keep them as local
+            let consumed = if self.pre_number.is_some() { 2 } else { 1 };
+            for identifier in expanded.into_iter().skip(consumed) {
+                self.add_flattened_to_local(identifier);
+            }
         }
     }
 
@@ -230,6 +249,9 @@ mod tests {
     #[case(from::v1_0_0_e3_b2().build(), "3!1.0.0b2")]
     #[case(from::v1_0_0_e1_rc5().build(), "1!1.0.0rc5")]
     #[case(from::v1_0_0().with_epoch(4).with_pre_release(PreReleaseLabel::Alpha, None).build(), "4!1.0.0a0")]
+    // Raw (label-less) pre-releases render as alpha, extra identifiers go to local
+    #[case(from::v1_0_0().with_raw_pre_release(vec!["1"]).with_extra_core_components(vec![Component::Var(Var::PreRelease)]).build(), "1.0.0a1")]
+    #[case(from::v1_0_0().with_raw_pre_release(vec!["x", "7", "z", "92"]).with_extra_core_components(vec![Component::Var(Var::PreRelease)]).build(), "1.0.0a0+x.7.z.92")]
     // Post + dev combinations
     #[case(from::v1_0_0_post1_dev2().build(), "1.0.0.post1.dev2")]
     // Pre-release + post combinations
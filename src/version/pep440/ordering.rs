@@ -83,13 +83,14 @@ impl PartialOrd for LocalSegment {
     }
 }
 
+// PEP440: alphanumeric local segments sort before numeric ones
 impl Ord for LocalSegment {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
             (LocalSegment::UInt(a), LocalSegment::UInt(b)) => a.cmp(b),
             (LocalSegment::Str(a), LocalSegment::Str(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
-            (LocalSegment::UInt(_), LocalSegment::Str(_)) => Ordering::Less,
-            (LocalSegment::Str(_), LocalSegment::UInt(_)) => Ordering::Greater,
+            (LocalSegment::Str(_), LocalSegment::UInt(_)) => Ordering::Less,
+            (LocalSegment::UInt(_), LocalSegment::Str(_)) => Ordering::Greater,
         }
     }
 }
@@ -176,7 +177,7 @@ mod tests {
     #[rstest]
     #[case("ubuntu.20", "ubuntu.22")]
     #[case("1.build", "1.build.2")]
-    #[case("1", "a")]
+    #[case("a", "1")]
     #[case("a", "b")]
     #[case("1", "2")]
     #[case("0", "1")]
@@ -246,6 +247,9 @@ mod tests {
     #[case("1!0.0.0", "2!0.0.0")] // epoch 1 < epoch 2
     #[case("1!999.999.999", "2!0.0.0")] // epoch 1 large < epoch 2 small
     #[case("0!1.0.0", "1!0.0.0")] // explicit epoch 0 < epoch 1
+    #[case("1!1.0.0", "2!1.0.0")] // same release, epoch decides
+    #[case("2!1.0.0", "12!1.0.0")] // numeric, not lexical, epoch comparison
+    #[case("9!9.9.9", "10!0.0.0")] // single to multi-digit epoch
     #[case("4294967294!1.0.0", "4294967295!0.0.0")] // max-1 epoch < max epoch
     // Local version ordering
     #[case("1.0.0", "1.0.0+build")] // no local < with local
@@ -258,7 +262,7 @@ mod tests {
     #[case("1.0.0+a.b.c", "1.0.0+a.b.d")] // multi-segment all string
     #[case("1.0.0+build", "1.0.0+build.1")] // fewer segments < more segments
     #[case("1.0.0+1.build", "1.0.0+2.build")] // numeric first segment
-    #[case("1.0.0+1", "1.0.0+a")] // numeric < string
+    #[case("1.0.0+a", "1.0.0+1")] // string < numeric
     #[case("1.0.0+1.alpha", "1.0.0+1.beta")] // same numeric, different string
     // Local segment count edge cases
     #[case("1.0.0+1", "1.0.0+1.2")] // single vs multi numeric
@@ -302,7 +306,7 @@ mod tests {
     #[case("1.0.0.post0", "1.0.0.post1")] // zero post-release number
     #[case("1.0.0.dev0", "1.0.0.dev1")] // zero dev-release number
     #[case("1.0.0+0", "1.0.0+1")] // zero local segment
-    #[case("1.0.0+4294967295", "1.0.0+a")] // u32::MAX local vs string
+    #[case("1.0.0+a", "1.0.0+4294967295")] // string vs u32::MAX local
     #[case("1.0.0+z", "1.0.0+z.0")] // string vs string with zero
     // Local version edge cases
     #[case("1.0.0+a", "1.0.0+b")] // single character local segments
@@ -315,6 +319,14 @@ mod tests {
     #[case("1.0.0+a.0", "1.0.0+a.1")] // mixed string and numeric
     #[case("0", "0.7")]
     #[case("0.7", "0.7.87")]
+    // PEP440 local version ordering examples
+    #[case("1.0+1", "1.0+2")] // numeric segments compare as integers
+    #[case("1.0+2", "1.0+10")] // not lexicographically
+    #[case("1.0+a", "1.0+1")] // alphanumeric < numeric
+    #[case("1.0+abc.5", "1.0+abc.5.a")] // equal prefix, longer wins
+    #[case("1.0+abc.5.a", "1.0+abc.5.1")] // alphanumeric < numeric in later segment
+    #[case("1.0+ubuntu", "1.0+ubuntu.1")] // more segments > fewer
+    #[case("1.0+ABC", "1.0+abd")] // case-insensitive string comparison
     fn test_version_ordering_less_than(#[case] left: &str, #[case] right: &str) {
         let left_version: PEP440 = left.parse().unwrap();
         let right_version: PEP440 = right.parse().unwrap();
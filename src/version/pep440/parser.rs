@@ -80,7 +80,10 @@ impl FromStr for PEP440 {
         let mut version = PEP440::new(release);
 
         if let Some(epoch_match) = captures.name("epoch") {
-            let epoch = epoch_match.as_str().parse().unwrap_or(0);
+            // An epoch past u32::MAX must not silently fall back to 0 and sort first
+            let epoch = epoch_match.as_str().parse().map_err(|_| {
+                ZervError::InvalidVersion(format!("PEP440 epoch out of range: {s}"))
+            })?;
             version = version.with_epoch(epoch);
         }
 
@@ -111,6 +114,44 @@ impl FromStr for PEP440 {
     }
 }
 
+impl PEP440 {
+    /// Parse a version that must already be in canonical PEP440 form,
+    /// naming the offending segment when it is not
+    pub fn parse_strict(s: &str) -> Result<Self, ZervError> {
+        let (public, local) = match s.split_once('+') {
+            Some((public, local)) => (public, Some(local)),
+            None => (s, None),
+        };
+
+        if let Some(local) = local {
+            for segment in local.split('.') {
+                let is_canonical = !segment.is_empty()
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+                if !is_canonical {
+                    return Err(ZervError::InvalidVersion(format!(
+                        "Invalid PEP440 local segment '{segment}' in '{s}'"
+                    )));
+                }
+            }
+        }
+
+        let parsed = PEP440::from_str(s).map_err(|_| {
+            ZervError::InvalidVersion(format!("Invalid PEP440 public version '{public}' in '{s}'"))
+        })?;
+
+        let canonical = parsed.to_string();
+        if canonical != s {
+            return Err(ZervError::InvalidVersion(format!(
+                "Non-canonical PEP440 version '{s}' (canonical form: '{canonical}')"
+            )));
+        }
+
+        Ok(parsed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -140,6 +181,9 @@ mod tests {
     #[case("5!1.2.3", 5, vec![1, 2, 3])]
     #[case("42!2025.12.31", 42, vec![2025, 12, 31])]
     #[case("0!1.0.0", 0, vec![1, 0, 0])]
+    #[case("12!1.0.0", 12, vec![1, 0, 0])]
+    #[case("2024!3.1", 2024, vec![3, 1])]
+    #[case("4294967295!1.0.0", u32::MAX, vec![1, 0, 0])]
     fn test_parse_with_epoch(#[case] input: &str, #[case] epoch: u32, #[case] release: Vec<u32>) {
         let parsed: PEP440 = input.parse().unwrap();
         let built = PEP440::new(release.clone()).with_epoch(epoch);
@@ -258,6 +302,7 @@ mod tests {
     #[case("a!1.2.3")]
     #[case("1.2!1.2.3")]
     #[case("-1!1.2.3")]
+    #[case("4294967296!1.2.3")] // past u32::MAX
     // Invalid pre-release
     #[case("1.2.3x1")]
     #[case("1.2.3gamma1")]
@@ -318,6 +363,32 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("1.2.3")]
+    #[case("2!1.2.3a1.post2.dev3")]
+    #[case("1.2.3+main.2.abc123")]
+    #[case("1.0.0rc1+build.7")]
+    fn test_parse_strict_valid(#[case] input: &str) {
+        let version = PEP440::parse_strict(input).unwrap();
+        assert_eq!(version.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("1.2.3+Feature_X", "local segment 'Feature_X'")]
+    #[case("1.2.3+main..abc", "local segment ''")]
+    #[case("1.2.3+main.feat-x", "local segment 'feat-x'")]
+    #[case("1.2.3-final+main", "public version '1.2.3-final'")]
+    #[case("1.2.3-alpha1", "Non-canonical PEP440 version '1.2.3-alpha1'")]
+    #[case("v1.2.3", "canonical form: '1.2.3'")]
+    fn test_parse_strict_invalid(#[case] input: &str, #[case] expected: &str) {
+        let error = PEP440::parse_strict(input).unwrap_err();
+        assert!(matches!(error, ZervError::InvalidVersion(_)));
+        assert!(
+            error.to_string().contains(expected),
+            "Expected '{expected}' in error: {error}"
+        );
+    }
+
     #[test]
     fn test_parse_very_long_version() {
         // This should be valid as PEP440 doesn't limit release segment count
@@ -370,6 +441,20 @@ mod tests {
         assert_eq!(parsed.pre_number, expected_number);
     }
 
+    #[rstest]
+    // c, preview and pre are input aliases of rc, rendered as rc
+    #[case("1.0c1")]
+    #[case("1.0.preview1")]
+    #[case("1.0pre1")]
+    #[case("1.0-C1")]
+    #[case("1.0_Preview1")]
+    fn test_parse_rc_aliases(#[case] input: &str) {
+        let parsed: PEP440 = input.parse().unwrap();
+        assert_eq!(parsed.pre_label, Some(PreReleaseLabel::Rc));
+        assert_eq!(parsed.pre_number, Some(1));
+        assert_eq!(parsed.to_string(), "1.0rc1");
+    }
+
     #[rstest]
     // Pre-release separator normalization: ., -, _, or none
     #[case("1.0.0.a1", PreReleaseLabel::Alpha, Some(1))]
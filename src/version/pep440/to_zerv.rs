@@ -34,10 +34,9 @@ impl PEP440 {
             epoch: (self.epoch > 0).then_some(self.epoch as u64),
             post: self.post_number.map(|n| n as u64),
             dev: self.dev_number.map(|n| n as u64),
-            pre_release: self.pre_label.map(|label| PreReleaseVar {
-                label,
-                number: self.pre_number.map(|n| n as u64),
-            }),
+            pre_release: self
+                .pre_label
+                .map(|label| PreReleaseVar::labeled(label, self.pre_number.map(|n| n as u64))),
             ..Default::default()
         };
 
@@ -141,6 +140,8 @@ mod tests {
     #[case("1.0.0.dev1")]
     #[case("1.0.0+local.1")]
     #[case("2!1.2.3a1.post1.dev1+local.1")]
+    #[case("12!1.0.0")]
+    #[case("4294967295!1.0.0")]
     fn test_round_trip_conversion(#[case] version_str: &str) {
         let original: PEP440 = version_str.parse().unwrap();
         let zerv: Zerv = original.clone().into();
@@ -149,6 +150,16 @@ mod tests {
         assert_eq!(original.to_string(), converted.to_string());
     }
 
+    #[rstest]
+    #[case("1.0.0", None)]
+    #[case("1!1.0.0", Some(1))]
+    #[case("12!1.0.0", Some(12))]
+    #[case("4294967295!1.0.0", Some(4294967295))]
+    fn test_epoch_maps_to_vars(#[case] version_str: &str, #[case] expected: Option<u64>) {
+        let zerv: Zerv = version_str.parse::<PEP440>().unwrap().into();
+        assert_eq!(zerv.vars.epoch, expected);
+    }
+
     #[test]
     fn test_custom_schema_not_supported() {
         let pep440: PEP440 = "1.2.3".parse().unwrap();
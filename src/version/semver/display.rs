@@ -5,6 +5,11 @@ use super::core::{
     PreReleaseIdentifier,
     SemVer,
 };
+use crate::utils::constants::{
+    post_dev_labels,
+    pre_release_labels,
+};
+use crate::version::zerv::PreReleaseLabel;
 
 impl fmt::Display for SemVer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -39,6 +44,64 @@ impl fmt::Display for BuildMetadata {
     }
 }
 
+impl SemVer {
+    /// Display with the pre-release label (alpha, beta, rc) cased per `prerelease_cases`
+    ///
+    /// Only the first identifier that is a known label is changed, so a custom
+    /// identifier or branch name in the pre-release is left as-is.
+    pub fn to_string_with_label_case(&self, case: &str) -> String {
+        let mut cased = self.clone();
+        if let Some(identifiers) = cased.pre_release.as_mut()
+            && let Some(PreReleaseIdentifier::Str(label)) = identifiers.iter_mut().find(|id| {
+                matches!(id, PreReleaseIdentifier::Str(s) if pre_release_labels::VALID_LABELS.contains(&s.as_str()))
+            })
+        {
+            *label = PreReleaseLabel::apply_case(label, case);
+        }
+        cased.to_string()
+    }
+
+    /// Drop a `0` pre-release number after its label (`1.0.0-alpha.0` → `1.0.0-alpha`)
+    ///
+    /// SemVer ranks the shorter form lower (`1.0.0-alpha < 1.0.0-alpha.0`), so the
+    /// result only sorts like the original among versions rendered the same way.
+    pub fn without_zero_pre_release_num(mut self) -> Self {
+        if let Some(identifiers) = self.pre_release.as_mut()
+            && let Some(index) = identifiers.iter().position(|id| {
+                matches!(id, PreReleaseIdentifier::Str(s) if PreReleaseLabel::try_from_str(s).is_some())
+            })
+            && identifiers.get(index + 1) == Some(&PreReleaseIdentifier::UInt(0))
+        {
+            identifiers.remove(index + 1);
+        }
+        self
+    }
+
+    /// Display with the pre-release label cased per `prerelease_cases` and the
+    /// `post`/`dev` identifiers renamed (e.g. `build` for `1.2.3-build.4`)
+    pub fn to_string_with_labels(
+        &self,
+        case: &str,
+        post_label: Option<&str>,
+        dev_label: Option<&str>,
+    ) -> String {
+        let mut labeled = self.clone();
+        if let Some(identifiers) = labeled.pre_release.as_mut() {
+            for id in identifiers.iter_mut() {
+                let renamed = match id {
+                    PreReleaseIdentifier::Str(s) if s == post_dev_labels::POST => post_label,
+                    PreReleaseIdentifier::Str(s) if s == post_dev_labels::DEV => dev_label,
+                    _ => None,
+                };
+                if let Some(label) = renamed {
+                    *id = PreReleaseIdentifier::Str(label.to_string());
+                }
+            }
+        }
+        labeled.to_string_with_label_case(case)
+    }
+}
+
 /// Format release version (e.g., 1, 2, 3 -> "1.2.3")
 pub fn format_release_version(major: u64, minor: u64, patch: u64) -> String {
     format!("{}.{}.{}", major, minor, patch)
@@ -110,6 +173,76 @@ mod tests {
 
     use super::*;
 
+    mod label_case {
+        use super::*;
+
+        #[rstest]
+        #[case::default(None, None, "1.2.3-RC.1.post.4.dev.5")]
+        #[case::custom(Some("build"), Some("snapshot"), "1.2.3-RC.1.build.4.snapshot.5")]
+        fn test_to_string_with_labels(
+            #[case] post_label: Option<&str>,
+            #[case] dev_label: Option<&str>,
+            #[case] expected: &str,
+        ) {
+            let version = SemVer::new(1, 2, 3).with_pre_release(vec![
+                PreReleaseIdentifier::Str("rc".to_string()),
+                PreReleaseIdentifier::UInt(1),
+                PreReleaseIdentifier::Str("post".to_string()),
+                PreReleaseIdentifier::UInt(4),
+                PreReleaseIdentifier::Str("dev".to_string()),
+                PreReleaseIdentifier::UInt(5),
+            ]);
+            assert_eq!(
+                version.to_string_with_labels("upper", post_label, dev_label),
+                expected
+            );
+        }
+
+        #[rstest]
+        #[case::lower("lower", "1.2.3-rc.1")]
+        #[case::upper("upper", "1.2.3-RC.1")]
+        #[case::title("title", "1.2.3-Rc.1")]
+        fn test_to_string_with_label_case(#[case] case: &str, #[case] expected: &str) {
+            let version = SemVer::new(1, 2, 3).with_pre_release(vec![
+                PreReleaseIdentifier::Str("rc".to_string()),
+                PreReleaseIdentifier::UInt(1),
+            ]);
+            assert_eq!(version.to_string_with_label_case(case), expected);
+        }
+
+        #[rstest]
+        #[case::zero(&["alpha", "0"], "1.2.3-alpha")]
+        #[case::zero_then_post(&["rc", "0", "post", "0"], "1.2.3-rc.post.0")]
+        #[case::nonzero(&["alpha", "1"], "1.2.3-alpha.1")]
+        #[case::zero_before_label(&["0", "beta", "2"], "1.2.3-0.beta.2")]
+        #[case::no_label(&["nightly", "0"], "1.2.3-nightly.0")]
+        fn test_without_zero_pre_release_num(#[case] identifiers: &[&str], #[case] expected: &str) {
+            let version = SemVer::new(1, 2, 3).with_pre_release(
+                identifiers
+                    .iter()
+                    .map(|id| match id.parse::<u64>() {
+                        Ok(n) => PreReleaseIdentifier::UInt(n),
+                        Err(_) => PreReleaseIdentifier::Str(id.to_string()),
+                    })
+                    .collect(),
+            );
+            assert_eq!(version.without_zero_pre_release_num().to_string(), expected);
+        }
+
+        #[test]
+        fn test_label_case_skips_non_label_identifiers() {
+            let version = SemVer::new(1, 2, 3).with_pre_release(vec![
+                PreReleaseIdentifier::Str("nightly".to_string()),
+                PreReleaseIdentifier::Str("beta".to_string()),
+                PreReleaseIdentifier::UInt(1),
+            ]);
+            assert_eq!(
+                version.to_string_with_label_case("upper"),
+                "1.2.3-nightly.BETA.1"
+            );
+        }
+    }
+
     mod basic_display {
         use super::*;
 
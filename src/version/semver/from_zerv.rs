@@ -78,7 +78,15 @@ impl SemVer {
         zerv_vars: &crate::version::zerv::vars::ZervVars,
         semver_sanitizer: &Sanitizer,
     ) {
-        let expanded = var.resolve_expanded_values(zerv_vars, semver_sanitizer);
+        // Raw identifiers came from a SemVer pre-release, sanitizing would alter them (e.g. `x-y`)
+        let expanded = match &zerv_vars.pre_release {
+            Some(crate::version::zerv::PreReleaseVar::Raw(raw))
+                if *var == crate::version::zerv::Var::PreRelease =>
+            {
+                raw.clone()
+            }
+            _ => var.resolve_expanded_values(zerv_vars, semver_sanitizer),
+        };
         for value in expanded {
             if !value.is_empty() {
                 let identifier = if let Ok(num) = value.parse::<u32>() {
@@ -133,6 +141,14 @@ impl SemVer {
 
 impl From<Zerv> for SemVer {
     fn from(zerv: Zerv) -> Self {
+        Self::from_zerv_with_context_sanitizer(zerv, &Sanitizer::semver_str())
+    }
+}
+
+impl SemVer {
+    /// Like `From<Zerv>`, sanitizing build metadata values with `context_sanitizer`
+    /// (e.g. from --sanitizer-profile) instead of the default SemVer string sanitizer
+    pub fn from_zerv_with_context_sanitizer(zerv: Zerv, context_sanitizer: &Sanitizer) -> Self {
         let mut semver = SemVer {
             major: 0,
             minor: 0,
@@ -155,7 +171,7 @@ impl From<Zerv> for SemVer {
         semver.process_extra_core(zerv.schema.extra_core(), &zerv.vars, &semver_sanitizer);
 
         // Process build - all components go to build metadata
-        semver.process_build(zerv.schema.build(), &zerv.vars, &semver_sanitizer);
+        semver.process_build(zerv.schema.build(), &zerv.vars, context_sanitizer);
 
         semver
     }
@@ -280,4 +296,25 @@ mod tests {
         let semver: SemVer = zerv.into();
         assert_eq!(semver.to_string(), expected_semver_str);
     }
+
+    #[rstest]
+    #[case(Sanitizer::semver_str(), "1.0.0+Feature.Login.3.def456")]
+    #[case(
+        Sanitizer::str(Some("_"), false, false, None),
+        "1.0.0+Feature_Login.3.def456"
+    )]
+    #[case(
+        Sanitizer::str(Some("-"), true, false, None),
+        "1.0.0+feature-login.3.def456"
+    )]
+    fn test_zerv_to_semver_context_sanitizer(
+        #[case] context_sanitizer: Sanitizer,
+        #[case] expected_semver_str: &str,
+    ) {
+        let zerv = from::v1_0_0_branch_distance_commit()
+            .with_branch("Feature/Login".to_string())
+            .build();
+        let semver = SemVer::from_zerv_with_context_sanitizer(zerv, &context_sanitizer);
+        assert_eq!(semver.to_string(), expected_semver_str);
+    }
 }
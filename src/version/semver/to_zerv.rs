@@ -18,6 +18,7 @@ struct PreReleaseProcessor<'a> {
     vars: &'a mut ZervVars,
     schema: &'a mut ZervSchema,
     pending_var: Option<Var>,
+    collecting_pre_release: bool,
 }
 
 impl<'a> PreReleaseProcessor<'a> {
@@ -26,6 +27,7 @@ impl<'a> PreReleaseProcessor<'a> {
             vars,
             schema,
             pending_var: None,
+            collecting_pre_release: false,
         }
     }
 
@@ -46,14 +48,32 @@ impl<'a> PreReleaseProcessor<'a> {
             Var::Dev => self.vars.dev = value,
             Var::PreRelease => {
                 if let Some(ref mut pr) = self.vars.pre_release {
-                    pr.number = value;
+                    pr.set_number(value);
                 }
+                self.collecting_pre_release = true;
             }
             _ => {}
         }
         self.schema.push_extra_core(Component::Var(var))
     }
 
+    /// Attach an identifier trailing the pre-release label/number to the pre-release itself
+    fn push_pre_release_identifier(&mut self, identifier: String) -> bool {
+        if !self.collecting_pre_release {
+            return false;
+        }
+        match self.vars.pre_release {
+            Some(PreReleaseVar::Labeled {
+                ref mut identifiers,
+                ..
+            }) => {
+                identifiers.push(identifier);
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn add_string(&mut self, s: &str) -> Result<(), ZervError> {
         self.schema.push_extra_core(Component::Str(s.to_string()))
     }
@@ -76,10 +96,7 @@ impl<'a> PreReleaseProcessor<'a> {
     fn process_new_var(&mut self, s: &str, var: Var) -> Result<(), ZervError> {
         if var == Var::PreRelease {
             if let Some(label) = PreReleaseLabel::try_from_str(s) {
-                self.vars.pre_release = Some(PreReleaseVar {
-                    label,
-                    number: None,
-                });
+                self.vars.pre_release = Some(PreReleaseVar::labeled(label, None));
                 self.pending_var = Some(var);
                 return Ok(());
             }
@@ -109,10 +126,25 @@ impl SemVer {
         if processor.pending_var == Some(Var::PreRelease) {
             processor.finalize_var(Var::PreRelease, None)?;
             processor.pending_var = None;
-            processor.add_string(s)?;
+            if !processor.push_pre_release_identifier(s.to_string()) {
+                processor.add_string(s)?;
+            }
             return Ok(());
         }
 
+        // Identifiers after the pre-release belong to it until a new secondary var starts
+        if processor.collecting_pre_release {
+            match Var::try_from_secondary_label(s) {
+                Some(var) if var != Var::PreRelease && !processor.is_var_set(&var) => {
+                    processor.collecting_pre_release = false;
+                }
+                _ => {
+                    processor.push_pre_release_identifier(s.to_string());
+                    return Ok(());
+                }
+            }
+        }
+
         // Handle duplicates or finalize pending vars
         if let Some(var) = Var::try_from_secondary_label(s)
             && processor.handle_duplicate(s, var)?
@@ -140,7 +172,7 @@ impl SemVer {
     ) -> Result<(), ZervError> {
         if let Some(var) = processor.pending_var.take() {
             processor.finalize_var(var, Some(n))?;
-        } else {
+        } else if !processor.push_pre_release_identifier(n.to_string()) {
             processor.schema.push_extra_core(Component::UInt(n))?;
         }
         Ok(())
@@ -163,6 +195,13 @@ impl SemVer {
         Ok(())
     }
 
+    /// No identifier maps to a zerv var (e.g. `x.7.z.92`), so keep the pre-release verbatim
+    fn is_raw_pre_release(pre_release: &[PreReleaseIdentifier]) -> bool {
+        !pre_release.iter().any(|identifier| {
+            matches!(identifier, PreReleaseIdentifier::Str(s) if Var::try_from_secondary_label(s).is_some())
+        })
+    }
+
     fn process_build_metadata(
         schema: &mut ZervSchema,
         build_metadata: &[BuildMetadata],
@@ -196,7 +235,16 @@ impl SemVer {
         let mut processor = PreReleaseProcessor::new(&mut vars, &mut result_schema);
 
         if let Some(pre_release) = &self.pre_release {
-            Self::process_pre_release(&mut processor, pre_release)?;
+            if Self::is_raw_pre_release(pre_release) {
+                processor.vars.pre_release = Some(PreReleaseVar::Raw(
+                    pre_release.iter().map(ToString::to_string).collect(),
+                ));
+                processor
+                    .schema
+                    .push_extra_core(Component::Var(Var::PreRelease))?;
+            } else {
+                Self::process_pre_release(&mut processor, pre_release)?;
+            }
         }
 
         if let Some(var) = processor.pending_var.take() {
@@ -330,6 +378,99 @@ mod tests {
         assert_eq!(original.to_string(), converted.to_string());
     }
 
+    #[rstest]
+    #[case("1.0.0-alpha.beta", None, vec!["beta"])]
+    #[case("1.0.0-alpha.beta.1", None, vec!["beta", "1"])]
+    #[case("1.0.0-alpha.1.beta", Some(1), vec!["beta"])]
+    #[case("1.0.0-rc.1.2", Some(1), vec!["2"])]
+    #[case("1.0.0-beta.alpha.3+build.7", None, vec!["alpha", "3"])]
+    fn test_multi_identifier_pre_release_round_trip(
+        #[case] version_str: &str,
+        #[case] expected_number: Option<u64>,
+        #[case] expected_identifiers: Vec<&str>,
+    ) {
+        let original: SemVer = version_str.parse().unwrap();
+        let zerv: Zerv = original.clone().into();
+
+        let pre_release = zerv.vars.pre_release.clone().unwrap();
+        assert_eq!(pre_release.number(), expected_number);
+        assert_eq!(pre_release.identifiers(), expected_identifiers);
+        assert_eq!(
+            zerv.schema.extra_core(),
+            &vec![Component::Var(Var::PreRelease)]
+        );
+
+        let ron_string = ron::to_string(&zerv).unwrap();
+        let from_ron: Zerv = ron::from_str(&ron_string).unwrap();
+        assert_eq!(from_ron, zerv);
+
+        let converted: SemVer = from_ron.into();
+        assert_eq!(converted.to_string(), original.to_string());
+    }
+
+    #[test]
+    fn test_numeric_pre_release_round_trip_and_ordering() {
+        let original: SemVer = "1.0.0-1".parse().unwrap();
+        let zerv: Zerv = original.clone().into();
+
+        let pre_release = zerv.vars.pre_release.clone().unwrap();
+        assert_eq!(pre_release, PreReleaseVar::numeric(1));
+        assert_eq!(pre_release.numeric_number(), Some(1));
+
+        let converted: SemVer = zerv.into();
+        assert_eq!(converted.to_string(), "1.0.0-1");
+
+        // Numeric identifiers have lower precedence than alphanumeric ones
+        let alpha: SemVer = "1.0.0-alpha".parse().unwrap();
+        let release: SemVer = "1.0.0".parse().unwrap();
+        assert!(converted < alpha);
+        assert!(alpha < release);
+    }
+
+    #[rstest]
+    #[case("1.0.0-1", "1.0.0a1")]
+    #[case("1.0.0-0", "1.0.0a0")]
+    #[case("1.0.0-x.7.z.92", "1.0.0a0+x.7.z.92")]
+    #[case("1.0.0-0.3.7", "1.0.0a0+3.7")]
+    fn test_label_less_pre_release_to_pep440(#[case] version_str: &str, #[case] expected: &str) {
+        use crate::version::pep440::PEP440;
+
+        let zerv: Zerv = version_str.parse::<SemVer>().unwrap().into();
+        let pep440: PEP440 = zerv.into();
+        assert_eq!(pep440.to_string(), expected);
+
+        // Still a pre-release in PEP440: below the release, not a local build after it
+        let release: PEP440 = "1.0.0".parse().unwrap();
+        assert!(pep440 < release);
+    }
+
+    #[rstest]
+    #[case("1.0.0-x.7.z.92", vec!["x", "7", "z", "92"])]
+    #[case("1.0.0-0.3.7", vec!["0", "3", "7"])]
+    #[case("1.0.0-x-y.01a+build.5", vec!["x-y", "01a"])]
+    fn test_raw_pre_release_round_trip(#[case] version_str: &str, #[case] expected_raw: Vec<&str>) {
+        let original: SemVer = version_str.parse().unwrap();
+        let zerv: Zerv = original.clone().into();
+
+        let pre_release = zerv.vars.pre_release.clone().unwrap();
+        assert_eq!(
+            pre_release,
+            PreReleaseVar::Raw(expected_raw.iter().map(|s| s.to_string()).collect())
+        );
+        assert_eq!(
+            zerv.schema.extra_core(),
+            &vec![Component::Var(Var::PreRelease)]
+        );
+
+        let ron_string = ron::to_string(&zerv).unwrap();
+        let from_ron: Zerv = ron::from_str(&ron_string).unwrap();
+        assert_eq!(from_ron, zerv);
+
+        let converted: SemVer = from_ron.into();
+        assert_eq!(converted, original);
+        assert_eq!(converted.to_string(), original.to_string());
+    }
+
     #[test]
     fn test_custom_schema_not_supported() {
         let semver: SemVer = "1.2.3".parse().unwrap();
@@ -369,10 +510,7 @@ mod tests {
     fn test_finalize_var_with_pre_release_number() {
         let schema = ZervSchema::semver_default().unwrap();
         let mut vars = ZervVars {
-            pre_release: Some(PreReleaseVar {
-                label: PreReleaseLabel::Alpha,
-                number: None,
-            }),
+            pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Alpha, None)),
             ..Default::default()
         };
         let mut result_schema = schema.clone();
@@ -380,7 +518,7 @@ mod tests {
 
         // This should execute line 49
         processor.finalize_var(Var::PreRelease, Some(5)).unwrap();
-        assert_eq!(vars.pre_release.unwrap().number, Some(5));
+        assert_eq!(vars.pre_release.unwrap().number(), Some(5));
     }
 
     #[test]
@@ -425,7 +563,10 @@ mod tests {
         processor.process_new_var("alpha", Var::PreRelease).unwrap();
         drop(processor); // Release the mutable borrow
         assert!(vars.pre_release.is_some());
-        assert_eq!(vars.pre_release.unwrap().label, PreReleaseLabel::Alpha);
+        assert_eq!(
+            vars.pre_release.unwrap().label(),
+            Some(PreReleaseLabel::Alpha)
+        );
     }
 
     #[test]
@@ -516,7 +657,7 @@ mod tests {
 
         // Create a pre-release with string identifier to trigger line 156
         // Use a valid pre-release format with a non-recognized label
-        let semver: SemVer = "1.0.0-customlabel".parse().unwrap();
+        let semver: SemVer = "1.0.0-customlabel.alpha".parse().unwrap();
         let zerv = semver.to_zerv_with_schema(&schema).unwrap();
         assert!(
             zerv.schema
@@ -8,7 +8,41 @@ use crate::version::{
     ZervVars,
 };
 
-#[derive(Debug, PartialEq)]
+/// Prefix handling applied to tags before they are parsed
+///
+/// By default a leading `v` is accepted implicitly by both parsers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagPrefix {
+    /// Literal prefix that must be present and is stripped before parsing
+    pub input_prefix: Option<String>,
+    /// Treat a leading `v` as part of the tag instead of stripping it implicitly
+    pub literal_v: bool,
+}
+
+impl TagPrefix {
+    /// Strip the configured prefix from a tag, rejecting tags it does not apply to
+    pub fn strip<'a>(&self, tag: &'a str) -> Result<&'a str, ZervError> {
+        let stripped = match &self.input_prefix {
+            Some(prefix) => tag.strip_prefix(prefix.as_str()).ok_or_else(|| {
+                ZervError::InvalidFormat(format!(
+                    "Tag '{tag}' does not start with input prefix '{prefix}'"
+                ))
+            })?,
+            None => tag,
+        };
+
+        if self.literal_v && stripped.starts_with(['v', 'V']) {
+            return Err(ZervError::InvalidFormat(format!(
+                "Tag '{tag}' has a leading 'v', which --literal-v keeps as part of the version \
+                 (use --input-prefix v to strip it)"
+            )));
+        }
+
+        Ok(stripped)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum VersionObject {
     PEP440(PEP440),
     SemVer(SemVer),
@@ -42,6 +76,15 @@ impl VersionObject {
         }
     }
 
+    /// Parse a tag after applying prefix handling
+    pub fn parse_with_prefix(
+        tag: &str,
+        format_str: &str,
+        prefix: &TagPrefix,
+    ) -> Result<Self, ZervError> {
+        Self::parse_with_format(prefix.strip(tag)?, format_str)
+    }
+
     /// Parse version string as SemVer format
     pub fn parse_semver(version: &str) -> Result<Self, ZervError> {
         Self::parse_with_format(version, "semver")
@@ -181,27 +224,85 @@ impl VersionObject {
     }
 }
 
-impl From<VersionObject> for ZervVars {
+impl From<VersionObject> for Zerv {
     fn from(version: VersionObject) -> Self {
         match version {
-            VersionObject::SemVer(semver) => {
-                let zerv: Zerv = semver.into();
-                zerv.vars
-            }
-            VersionObject::PEP440(pep440) => {
-                let zerv: Zerv = pep440.into();
-                zerv.vars
-            }
+            VersionObject::SemVer(semver) => semver.into(),
+            VersionObject::PEP440(pep440) => pep440.into(),
         }
     }
 }
 
+impl From<VersionObject> for ZervVars {
+    fn from(version: VersionObject) -> Self {
+        Zerv::from(version).vars
+    }
+}
+
+/// Parse a version string into a [`Zerv`]
+///
+/// `format` is `semver`, `pep440` or `auto` (case-insensitive), as accepted by
+/// [`VersionObject::parse_with_format`]. The returned schema mirrors the input
+/// format, so rendering it back yields the same version.
+pub fn parse(version: &str, format: &str) -> Result<Zerv, ZervError> {
+    VersionObject::parse_with_format(version, format).map(Zerv::from)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case("1.2.3", "semver")]
+    #[case("1.2.3", "pep440")]
+    #[case("1.2.3", "auto")]
+    #[case("v1.2.3", "AUTO")]
+    fn test_parse_equivalent_zerv(#[case] version: &str, #[case] format: &str) {
+        let zerv = parse(version, format).unwrap();
+        let expected: Zerv = SemVer::from_str("1.2.3").unwrap().into();
+        assert_eq!(zerv.vars, expected.vars);
+    }
+
+    #[rstest]
+    #[case("1.2.3-alpha.1", "semver")]
+    #[case("1.2.3a1", "pep440")]
+    #[case("1.2.3a1", "auto")]
+    fn test_parse_prerelease_into_same_vars(#[case] version: &str, #[case] format: &str) {
+        let zerv = parse(version, format).unwrap();
+        assert_eq!(
+            (zerv.vars.major, zerv.vars.minor, zerv.vars.patch),
+            (Some(1), Some(2), Some(3))
+        );
+        let pre_release = zerv.vars.pre_release.unwrap();
+        assert_eq!(
+            pre_release.label(),
+            Some(crate::version::PreReleaseLabel::Alpha)
+        );
+        assert_eq!(pre_release.number(), Some(1));
+    }
+
+    #[rstest]
+    #[case("1.2.3", "semver")]
+    #[case("1.2.3.post4.dev5", "pep440")]
+    fn test_parse_keeps_input_format(#[case] version: &str, #[case] format: &str) {
+        let zerv = parse(version, format).unwrap();
+        let rendered = match format {
+            "semver" => SemVer::from(zerv).to_string(),
+            _ => PEP440::from(zerv).to_string(),
+        };
+        assert_eq!(rendered, version);
+    }
+
+    #[test]
+    fn test_parse_unknown_format() {
+        assert!(matches!(
+            parse("1.2.3", "calver"),
+            Err(ZervError::UnknownFormat(_))
+        ));
+    }
+
     #[rstest]
     #[case("1.2.3", "semver", "semver")]
     #[case("1.2.3a1", "pep440", "pep440")]
@@ -502,4 +603,51 @@ mod tests {
                 .contains("Invalid PEP440 format")
         );
     }
+
+    #[rstest]
+    #[case::implicit_v(None, false, "v1.2.3", Some("v1.2.3"))]
+    #[case::literal_v_rejects(None, true, "v1.2.3", None)]
+    #[case::literal_v_plain(None, true, "1.2.3", Some("1.2.3"))]
+    #[case::literal_v_with_input_prefix(Some("v"), true, "v1.2.3", Some("1.2.3"))]
+    #[case::input_prefix_missing(Some("release-"), false, "v1.2.3", None)]
+    #[case::input_prefix_custom(Some("release-"), false, "release-1.2.3", Some("1.2.3"))]
+    fn test_tag_prefix_strip(
+        #[case] input_prefix: Option<&str>,
+        #[case] literal_v: bool,
+        #[case] tag: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let prefix = TagPrefix {
+            input_prefix: input_prefix.map(str::to_string),
+            literal_v,
+        };
+        match expected {
+            Some(expected) => assert_eq!(prefix.strip(tag).unwrap(), expected),
+            None => assert!(matches!(
+                prefix.strip(tag),
+                Err(ZervError::InvalidFormat(_))
+            )),
+        }
+    }
+
+    #[test]
+    fn test_parse_with_prefix_literal_v() {
+        let literal = TagPrefix {
+            literal_v: true,
+            ..Default::default()
+        };
+        assert!(VersionObject::parse_with_prefix("v1.2.3", "semver", &literal).is_err());
+        assert!(
+            VersionObject::parse_with_prefix("v1.2.3", "semver", &TagPrefix::default()).is_ok()
+        );
+
+        let stripped = TagPrefix {
+            input_prefix: Some("v".to_string()),
+            literal_v: true,
+        };
+        assert_eq!(
+            VersionObject::parse_with_prefix("v1.2.3", "semver", &stripped).unwrap(),
+            VersionObject::parse_semver("1.2.3").unwrap()
+        );
+    }
 }
@@ -2,6 +2,7 @@ use super::core::Zerv;
 use super::schema::SchemaPartName;
 use crate::cli::version::args::ResolvedArgs;
 use crate::error::ZervError;
+use crate::utils::constants::epoch_reset_modes;
 
 pub mod precedence;
 pub mod reset;
@@ -12,16 +13,33 @@ pub mod vars_secondary;
 pub mod vars_timestamp;
 use crate::version::zerv::bump::precedence::Precedence;
 
+/// Add a bump increment to a component value, erroring instead of overflowing
+pub(crate) fn checked_bump(current: u64, increment: u32, field: &str) -> Result<u64, ZervError> {
+    current.checked_add(increment as u64).ok_or_else(|| {
+        ZervError::InvalidArgument(format!(
+            "Bumping {field} by {increment} overflows its current value {current}"
+        ))
+    })
+}
+
 impl Zerv {
     pub fn apply_component_processing(&mut self, args: &ResolvedArgs) -> Result<(), ZervError> {
         let precedence_order: Vec<Precedence> =
             self.schema.precedence_order().iter().cloned().collect();
 
+        // Overrides raising the base would otherwise keep inherited pre-release/post/dev;
+        // bumps reset on their own
+        if !args.bumps.keep_prerelease && self.overridden_base_version(args) > self.base_version() {
+            self.reset_lower_precedence_components(&Precedence::Patch)?;
+        }
+
         for precedence in precedence_order {
             match precedence {
-                Precedence::Epoch => {
-                    self.process_epoch(args.overrides.epoch, args.bumps.bump_epoch.flatten())?
-                }
+                Precedence::Epoch => self.process_epoch_with_reset(
+                    args.overrides.epoch,
+                    args.bumps.bump_epoch.flatten(),
+                    args.bumps.epoch_reset.as_deref() != Some(epoch_reset_modes::NONE),
+                )?,
                 Precedence::Major => {
                     self.process_major(args.overrides.major, args.bumps.bump_major.flatten())?
                 }
@@ -61,14 +79,64 @@ impl Zerv {
         }
 
         self.process_bumped_timestamp(args)?;
+        if let Some(max) = args.bumps.max_component_value {
+            self.check_max_component_value(max)?;
+        }
         Ok(())
     }
+
+    /// Error when a pre-release, post or dev number exceeds `max`
+    fn check_max_component_value(&self, max: u64) -> Result<(), ZervError> {
+        let components = [
+            (
+                "pre-release number",
+                self.vars
+                    .pre_release
+                    .as_ref()
+                    .and_then(|pr| pr.effective_number()),
+            ),
+            ("post", self.vars.post),
+            ("dev", self.vars.dev),
+        ];
+        for (name, value) in components {
+            if let Some(value) = value.filter(|value| *value > max) {
+                return Err(ZervError::InvalidArgument(format!(
+                    "{name} {value} exceeds --max-component-value {max}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Epoch and release components, compared to detect a base version change
+    fn base_version(&self) -> [u64; 4] {
+        [
+            self.vars.epoch.unwrap_or(0),
+            self.vars.major.unwrap_or(0),
+            self.vars.minor.unwrap_or(0),
+            self.vars.patch.unwrap_or(0),
+        ]
+    }
+
+    /// Base version once the epoch/major/minor/patch overrides are applied
+    fn overridden_base_version(&self, args: &ResolvedArgs) -> [u64; 4] {
+        let overrides = &args.overrides;
+        let [epoch, major, minor, patch] = self.base_version();
+        [
+            overrides.epoch.map_or(epoch, u64::from),
+            overrides.major.map_or(major, u64::from),
+            overrides.minor.map_or(minor, u64::from),
+            overrides.patch.map_or(patch, u64::from),
+        ]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use rstest::*;
 
+    use super::checked_bump;
+    use crate::error::ZervError;
     use crate::schema::ZervSchemaPreset;
     use crate::test_utils::types::{
         BumpType,
@@ -179,6 +247,77 @@ mod tests {
         assert_eq!(result_version.to_string(), expected_version);
     }
 
+    // Overrides raising the base reset inherited pre-release/post/dev
+    #[rstest]
+    #[case("1.2.3-alpha.4", vec![OverrideType::Minor(3), OverrideType::Patch(0)], false, "1.3.0")]
+    #[case(
+        "1.2.3-alpha.4",
+        vec![OverrideType::Minor(3), OverrideType::Patch(0)],
+        true,
+        "1.3.0-alpha.4"
+    )]
+    #[case("1.2.3-alpha.4.post.2.dev.1", vec![OverrideType::Major(2)], false, "2.2.3")]
+    #[case("1.2.3-alpha.4", vec![OverrideType::Patch(3)], false, "1.2.3-alpha.4")]
+    #[case("1.2.3-alpha.4", vec![OverrideType::Minor(1)], false, "1.1.3-alpha.4")]
+    #[case(
+        "1.2.3-alpha.4",
+        vec![OverrideType::Minor(3), OverrideType::PreReleaseNum(1)],
+        false,
+        "1.3.3-alpha.1"
+    )]
+    fn test_apply_component_processing_base_change_reset(
+        #[case] starting_version: &str,
+        #[case] overrides: Vec<OverrideType>,
+        #[case] keep_prerelease: bool,
+        #[case] expected_version: &str,
+    ) {
+        let mut zerv = ZervFixture::from_semver_str(starting_version)
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrereleasePostDevContext)
+            .build();
+        let args = VersionArgsFixture::new()
+            .with_override_specs(overrides)
+            .with_keep_prerelease(keep_prerelease)
+            .build();
+
+        zerv.vars.apply_context_overrides(&args).unwrap();
+        let resolved_args = crate::cli::version::args::ResolvedArgs::resolve(&args, &zerv).unwrap();
+        zerv.apply_component_processing(&resolved_args).unwrap();
+
+        let result_version: SemVer = zerv.into();
+        assert_eq!(result_version.to_string(), expected_version);
+    }
+
+    // Pre-release/post/dev numbers above --max-component-value fail
+    #[rstest]
+    #[case("1.2.3-alpha.4.post.2", 1, 4, true)]
+    #[case("1.2.3-alpha.4.post.2", 3, 4, false)]
+    #[case("1.2.3-alpha.5", 1, 4, false)]
+    #[case("1.0.0-99999", 1, 1000, false)]
+    #[case("1.0.0-999", 1, 1000, true)]
+    fn test_apply_component_processing_max_component_value(
+        #[case] starting_version: &str,
+        #[case] bump_post: u32,
+        #[case] max: u64,
+        #[case] expected_ok: bool,
+    ) {
+        let mut zerv = ZervFixture::from_semver_str(starting_version)
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrereleasePostDevContext)
+            .build();
+        let args = VersionArgsFixture::new()
+            .with_bump_post(bump_post)
+            .with_max_component_value(max)
+            .build();
+
+        zerv.vars.apply_context_overrides(&args).unwrap();
+        let resolved_args = crate::cli::version::args::ResolvedArgs::resolve(&args, &zerv).unwrap();
+        let result = zerv.apply_component_processing(&resolved_args);
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+        }
+    }
+
     // Test schema-based bump functionality (Plan 26)
     #[rstest]
     #[case(
@@ -236,4 +375,24 @@ mod tests {
         let result_version: SemVer = zerv.into();
         assert_eq!(result_version.to_string(), expected_version);
     }
+
+    #[rstest]
+    #[case(0, 1, Some(1))]
+    #[case(u32::MAX as u64, u32::MAX, Some(2 * u32::MAX as u64))]
+    #[case(u64::MAX - 1, 1, Some(u64::MAX))]
+    #[case(u64::MAX, 1, None)]
+    #[case(u64::MAX - 5, u32::MAX, None)]
+    fn test_checked_bump(
+        #[case] current: u64,
+        #[case] increment: u32,
+        #[case] expected: Option<u64>,
+    ) {
+        let result = checked_bump(current, increment, "major");
+        match expected {
+            Some(value) => assert_eq!(result.unwrap(), value),
+            None => assert!(
+                matches!(result, Err(ZervError::InvalidArgument(msg)) if msg.contains("major"))
+            ),
+        }
+    }
 }
@@ -7,6 +7,7 @@ use serde::{
 /// Precedence levels for version components and schema sections
 /// Defines the order in which components are processed during bumping and reset operations
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Precedence {
     // Field-based precedence
     Epoch,
@@ -25,11 +26,18 @@ pub enum Precedence {
 }
 
 /// Precedence order management with O(1) bidirectional lookup
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct PrecedenceOrder {
     order: IndexMap<Precedence, ()>,
 }
 
+/// Order-sensitive, unlike `IndexMap` equality, so reordered precedences never compare equal
+impl PartialEq for PrecedenceOrder {
+    fn eq(&self, other: &Self) -> bool {
+        self.order.keys().eq(other.order.keys())
+    }
+}
+
 impl Serialize for PrecedenceOrder {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -190,6 +198,16 @@ mod tests {
         assert_eq!(custom_order.get_precedence(3), None);
     }
 
+    #[test]
+    fn test_precedence_order_equality_is_order_sensitive() {
+        let order = PrecedenceOrder::from_precedences(vec![Precedence::Major, Precedence::Minor]);
+        let reordered =
+            PrecedenceOrder::from_precedences(vec![Precedence::Minor, Precedence::Major]);
+
+        assert_eq!(order, order.clone());
+        assert_ne!(order, reordered);
+    }
+
     #[test]
     fn test_default_precedence_order() {
         let default_order = PrecedenceOrder::default();
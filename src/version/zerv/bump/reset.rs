@@ -7,6 +7,15 @@ impl Zerv {
     pub fn reset_lower_precedence_components(
         &mut self,
         precedence: &Precedence,
+    ) -> Result<(), ZervError> {
+        self.reset_lower_precedence_components_keeping(precedence, &[])
+    }
+
+    /// Like [`Self::reset_lower_precedence_components`], leaving the precedences in `keep` as-is
+    pub fn reset_lower_precedence_components_keeping(
+        &mut self,
+        precedence: &Precedence,
+        keep: &[Precedence],
     ) -> Result<(), ZervError> {
         let current_precedence_index = self
             .schema
@@ -20,7 +29,7 @@ impl Zerv {
 
         // Use a for loop over the precedence order
         for (index, precedence_item) in self.schema.precedence_order().iter().enumerate() {
-            if index > current_precedence_index {
+            if index > current_precedence_index && !keep.contains(precedence_item) {
                 // Map precedence to field reset
                 match precedence_item {
                     Precedence::Epoch => {
@@ -40,7 +49,7 @@ impl Zerv {
                     }
                     Precedence::PreReleaseNum => {
                         if let Some(ref mut pre_release) = self.vars.pre_release {
-                            pre_release.number = Some(0);
+                            pre_release.set_number(Some(0));
                         }
                     }
                     Precedence::Post => {
@@ -153,9 +162,9 @@ mod tests {
                 start_vars
                     .pre_release
                     .as_ref()
-                    .map(|pr| pr.label)
+                    .and_then(|pr| pr.label())
                     .unwrap_or(PreReleaseLabel::Alpha),
-                start_vars.pre_release.as_ref().and_then(|pr| pr.number),
+                start_vars.pre_release.as_ref().and_then(|pr| pr.number()),
             )
             .with_post(start_vars.post.unwrap_or(0))
             .with_dev(start_vars.dev.unwrap_or(0))
@@ -1,4 +1,6 @@
+use super::checked_bump;
 use crate::error::ZervError;
+use crate::utils::constants::dirty_on_tag_modes;
 use crate::version::zerv::components::{
     Component,
     Var,
@@ -70,14 +72,21 @@ impl Zerv {
             }
             Var::Distance
             | Var::Dirty
+            | Var::StagedDirty
+            | Var::UnstagedDirty
             | Var::BumpedBranch
             | Var::BumpedCommitHash
             | Var::BumpedCommitHashShort
             | Var::BumpedTimestamp
+            | Var::CommitSubject
+            | Var::CommitCount
+            | Var::DefaultBranch
             | Var::LastBranch
             | Var::LastCommitHash
             | Var::LastCommitHashShort
-            | Var::LastTimestamp => {
+            | Var::LastTimestamp
+            | Var::TagMessage
+            | Var::BuildNumber => {
                 return Err(ZervError::InvalidBumpTarget {
                     message: format!("Cannot process VCS-derived field: {var:?}"),
                     schema_part: schema_part.clone(),
@@ -108,6 +117,8 @@ impl Zerv {
         schema_part: ZervSchemaPart,
     ) -> Result<(), ZervError> {
         if let Component::UInt(current_value) = component {
+            let field = format!("{} UInt component", schema_part.name);
+
             // Parse override and bump values for UInt components
             let override_val =
                 Self::parse_optional_u32(override_value.as_deref(), "UInt", schema_part.clone())?;
@@ -124,7 +135,7 @@ impl Zerv {
 
             let new_value = if let Some(bump_val) = bump_val {
                 // Bump: add to base value (either override or current)
-                base_value + bump_val as u64
+                checked_bump(base_value, bump_val, &field)?
             } else {
                 // No bump: use base value as-is
                 base_value
@@ -238,6 +249,59 @@ impl Zerv {
 
         Ok(())
     }
+
+    /// Encode a dirty working tree on a tagged commit as a post/dev/pre-release bump
+    /// so it never renders the same as the clean tag
+    pub fn apply_dirty_on_tag(&mut self, mode: Option<&str>) -> Result<(), ZervError> {
+        let Some(mode) = mode else {
+            return Ok(());
+        };
+        let on_tag = self.vars.distance.unwrap_or(0) == 0;
+        if self.vars.dirty != Some(true) || !on_tag {
+            return Ok(());
+        }
+
+        let var = match mode {
+            dirty_on_tag_modes::POST => {
+                self.process_post(None, Some(1))?;
+                Var::Post
+            }
+            dirty_on_tag_modes::DEV => {
+                self.process_dev(None, Some(1))?;
+                Var::Dev
+            }
+            dirty_on_tag_modes::PRERELEASE => {
+                if self.vars.pre_release.is_some() {
+                    self.process_pre_release_num(None, Some(1))?;
+                } else {
+                    self.process_patch(None, Some(1))?;
+                    self.process_pre_release_num(Some(1), None)?;
+                }
+                Var::PreRelease
+            }
+            _ => {
+                return Err(ZervError::InvalidArgument(format!(
+                    "Unknown dirty-on-tag mode '{mode}'. Valid modes: {}",
+                    dirty_on_tag_modes::VALID_MODES.join(", ")
+                )));
+            }
+        };
+
+        // Custom schemas may not render the bumped component, so make sure it shows up
+        let component = Component::Var(var);
+        let in_schema = self
+            .schema
+            .core()
+            .iter()
+            .chain(self.schema.extra_core())
+            .any(|existing| existing == &component);
+        if !in_schema {
+            let mut extra_core = self.schema.extra_core().clone();
+            extra_core.push(component);
+            self.schema.set_extra_core(extra_core)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -597,4 +661,95 @@ mod tests {
 
         assert_eq!(zerv.schema.extra_core()[0], Component::UInt(125)); // 100 + 25
     }
+
+    mod dirty_on_tag {
+        use super::*;
+        use crate::version::zerv::core::{
+            PreReleaseLabel,
+            PreReleaseVar,
+        };
+
+        fn dirty_tagged_zerv() -> Zerv {
+            let mut zerv = ZervFixture::new()
+                .with_version(1, 2, 3)
+                .with_extra_core_components(vec![])
+                .build();
+            zerv.vars.distance = Some(0);
+            zerv.vars.dirty = Some(true);
+            zerv
+        }
+
+        #[rstest]
+        #[case::post("post", Var::Post, (1, 2, 3), None, Some(1), None)]
+        #[case::dev("dev", Var::Dev, (1, 2, 3), None, None, Some(1))]
+        #[case::prerelease("prerelease", Var::PreRelease, (1, 2, 4), Some(1), None, None)]
+        fn test_apply_dirty_on_tag_modes(
+            #[case] mode: &str,
+            #[case] expected_var: Var,
+            #[case] expected_version: (u64, u64, u64),
+            #[case] expected_pre_release_num: Option<u64>,
+            #[case] expected_post: Option<u64>,
+            #[case] expected_dev: Option<u64>,
+        ) {
+            let mut zerv = dirty_tagged_zerv();
+
+            zerv.apply_dirty_on_tag(Some(mode)).unwrap();
+
+            let version = (
+                zerv.vars.major.unwrap(),
+                zerv.vars.minor.unwrap(),
+                zerv.vars.patch.unwrap(),
+            );
+            assert_eq!(version, expected_version);
+            assert_eq!(
+                zerv.vars.pre_release.as_ref().and_then(|pr| pr.number()),
+                expected_pre_release_num
+            );
+            assert_eq!(zerv.vars.post, expected_post);
+            assert_eq!(zerv.vars.dev, expected_dev);
+            assert_eq!(
+                zerv.schema.extra_core(),
+                &vec![Component::Var(expected_var)]
+            );
+        }
+
+        #[test]
+        fn test_apply_dirty_on_tag_bumps_existing_pre_release() {
+            let mut zerv = dirty_tagged_zerv();
+            zerv.vars.pre_release = Some(PreReleaseVar::labeled(PreReleaseLabel::Rc, Some(2)));
+
+            zerv.apply_dirty_on_tag(Some("prerelease")).unwrap();
+
+            assert_eq!(zerv.vars.patch, Some(3));
+            let pre_release = zerv.vars.pre_release.unwrap();
+            assert_eq!(pre_release.label(), Some(PreReleaseLabel::Rc));
+            assert_eq!(pre_release.number(), Some(3));
+        }
+
+        #[rstest]
+        #[case::clean(Some(false), Some(0), Some("post"))]
+        #[case::off_tag(Some(true), Some(3), Some("post"))]
+        #[case::disabled(Some(true), Some(0), None)]
+        fn test_apply_dirty_on_tag_noop(
+            #[case] dirty: Option<bool>,
+            #[case] distance: Option<u64>,
+            #[case] mode: Option<&str>,
+        ) {
+            let mut zerv = dirty_tagged_zerv();
+            zerv.vars.dirty = dirty;
+            zerv.vars.distance = distance;
+            let expected = zerv.clone();
+
+            zerv.apply_dirty_on_tag(mode).unwrap();
+
+            assert_eq!(zerv, expected);
+        }
+
+        #[test]
+        fn test_apply_dirty_on_tag_unknown_mode() {
+            let mut zerv = dirty_tagged_zerv();
+            let result = zerv.apply_dirty_on_tag(Some("bogus"));
+            assert!(matches!(result, Err(ZervError::InvalidArgument(_))));
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use super::Zerv;
+use super::{
+    Zerv,
+    checked_bump,
+};
 use crate::error::ZervError;
 use crate::version::zerv::bump::precedence::Precedence;
 
@@ -15,7 +18,11 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.major = Some(self.vars.major.unwrap_or(0) + increment as u64);
+            self.vars.major = Some(checked_bump(
+                self.vars.major.unwrap_or(0),
+                increment,
+                "major",
+            )?);
             self.reset_lower_precedence_components(&Precedence::Major)?;
         }
 
@@ -34,7 +41,11 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.minor = Some(self.vars.minor.unwrap_or(0) + increment as u64);
+            self.vars.minor = Some(checked_bump(
+                self.vars.minor.unwrap_or(0),
+                increment,
+                "minor",
+            )?);
             self.reset_lower_precedence_components(&Precedence::Minor)?;
         }
 
@@ -53,7 +64,11 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.patch = Some(self.vars.patch.unwrap_or(0) + increment as u64);
+            self.vars.patch = Some(checked_bump(
+                self.vars.patch.unwrap_or(0),
+                increment,
+                "patch",
+            )?);
             self.reset_lower_precedence_components(&Precedence::Patch)?;
         }
 
@@ -65,6 +80,7 @@ impl Zerv {
 mod tests {
     use rstest::*;
 
+    use crate::error::ZervError;
     use crate::schema::ZervSchemaPreset;
     use crate::test_utils::zerv::ZervFixture;
     use crate::version::semver::SemVer;
@@ -169,4 +185,32 @@ mod tests {
         let result_version: SemVer = zerv.into();
         assert_eq!(result_version.to_string(), expected_version);
     }
+
+    #[rstest]
+    #[case::major("major")]
+    #[case::minor("minor")]
+    #[case::patch("patch")]
+    fn test_process_primary_bump_overflow_errors(#[case] field: &str) {
+        let mut zerv = ZervFixture::new()
+            .with_version(u64::MAX, u64::MAX, u64::MAX)
+            .build();
+        let result = match field {
+            "major" => zerv.process_major(None, Some(u32::MAX)),
+            "minor" => zerv.process_minor(None, Some(u32::MAX)),
+            _ => zerv.process_patch(None, Some(u32::MAX)),
+        };
+        assert!(
+            matches!(result, Err(ZervError::InvalidArgument(ref msg)) if msg.contains(field)),
+            "expected overflow error, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_process_major_near_u32_max_does_not_wrap() {
+        let mut zerv = ZervFixture::new()
+            .with_version(u32::MAX as u64, 0, 0)
+            .build();
+        zerv.process_major(None, Some(u32::MAX)).unwrap();
+        assert_eq!(zerv.vars.major, Some(2 * u32::MAX as u64));
+    }
 }
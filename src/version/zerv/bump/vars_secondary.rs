@@ -1,6 +1,13 @@
-use super::Zerv;
+use super::{
+    Zerv,
+    checked_bump,
+};
 use crate::cli::version::args::ResolvedArgs;
 use crate::error::ZervError;
+use crate::utils::constants::{
+    pre_release_labels,
+    prerelease_bump_resets,
+};
 use crate::version::zerv::bump::precedence::Precedence;
 use crate::version::zerv::core::{
     PreReleaseLabel,
@@ -20,7 +27,11 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.post = Some(self.vars.post.unwrap_or(0) + increment as u64);
+            self.vars.post = Some(checked_bump(
+                self.vars.post.unwrap_or(0),
+                increment,
+                "post",
+            )?);
             self.reset_lower_precedence_components(&Precedence::Post)?;
         }
 
@@ -39,7 +50,7 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.dev = Some(self.vars.dev.unwrap_or(0) + increment as u64);
+            self.vars.dev = Some(checked_bump(self.vars.dev.unwrap_or(0), increment, "dev")?);
             self.reset_lower_precedence_components(&Precedence::Dev)?;
         }
 
@@ -49,33 +60,70 @@ impl Zerv {
     pub fn process_pre_release_label(&mut self, args: &ResolvedArgs) -> Result<(), ZervError> {
         // 1. Override step - set absolute value if specified
         if let Some(ref label) = args.overrides.pre_release_label {
-            let existing_number = self.vars.pre_release.as_ref().and_then(|pr| pr.number);
-            self.vars.pre_release = Some(PreReleaseVar {
-                label: PreReleaseLabel::try_from_str(label).ok_or_else(|| {
-                    ZervError::InvalidVersion(format!("Invalid pre-release label: {label}"))
-                })?,
-                number: args
-                    .overrides
-                    .pre_release_num
-                    .map(|n| n as u64)
-                    .or(existing_number)
-                    .or(Some(0)),
+            let existing_number = self
+                .vars
+                .pre_release
+                .as_ref()
+                .and_then(|pr| pr.effective_number());
+            let number = args
+                .overrides
+                .pre_release_num
+                .map(|n| n as u64)
+                .or(existing_number)
+                .unwrap_or(0);
+            let existing_identifiers = self
+                .vars
+                .pre_release
+                .as_ref()
+                .map(|pr| pr.identifiers().to_vec())
+                .unwrap_or_default();
+            self.vars.pre_release = Some(if label == pre_release_labels::NUMERIC {
+                PreReleaseVar::numeric(number)
+            } else {
+                PreReleaseVar::Labeled {
+                    label: PreReleaseLabel::try_from_str(label).ok_or_else(|| {
+                        ZervError::InvalidVersion(format!("Invalid pre-release label: {label}"))
+                    })?,
+                    number: Some(number),
+                    identifiers: existing_identifiers,
+                }
             });
         }
 
-        // 2. Bump + Reset step (atomic operation)
+        self.process_pre_release_label_bump(args)
+    }
+
+    /// Bump + Reset step of the pre-release label (atomic operation)
+    fn process_pre_release_label_bump(&mut self, args: &ResolvedArgs) -> Result<(), ZervError> {
         if let Some(ref label) = args.bumps.bump_pre_release_label {
-            let pre_release_label = label.parse::<PreReleaseLabel>()?;
-            self.reset_lower_precedence_components(&Precedence::PreReleaseLabel)?;
-            self.vars.pre_release = Some(PreReleaseVar {
-                label: pre_release_label,
-                number: Some(0),
-            });
+            let pre_release = if label == pre_release_labels::NUMERIC {
+                PreReleaseVar::numeric(0)
+            } else {
+                PreReleaseVar::labeled(label.parse::<PreReleaseLabel>()?, Some(0))
+            };
+            let keep = Self::kept_on_pre_release_bump(args.bumps.prerelease_bump_resets.as_deref());
+            self.reset_lower_precedence_components_keeping(&Precedence::PreReleaseLabel, &keep)?;
+            self.vars.pre_release = Some(pre_release);
         }
 
         Ok(())
     }
 
+    /// Post and dev precedences a pre-release label bump keeps under --prerelease-bump-resets
+    fn kept_on_pre_release_bump(resets: Option<&[String]>) -> Vec<Precedence> {
+        let Some(resets) = resets else {
+            return Vec::new();
+        };
+        [
+            (prerelease_bump_resets::POST, Precedence::Post),
+            (prerelease_bump_resets::DEV, Precedence::Dev),
+        ]
+        .into_iter()
+        .filter(|(name, _)| !resets.iter().any(|reset| reset == name))
+        .map(|(_, precedence)| precedence)
+        .collect()
+    }
+
     pub fn process_pre_release_num(
         &mut self,
         override_value: Option<u32>,
@@ -84,26 +132,30 @@ impl Zerv {
         // 1. Override step - set absolute value if specified
         if let Some(pre_release_num) = override_value {
             if self.vars.pre_release.is_none() {
-                self.vars.pre_release = Some(PreReleaseVar {
-                    label: PreReleaseLabel::Alpha,
-                    number: Some(pre_release_num as u64),
-                });
+                self.vars.pre_release = Some(PreReleaseVar::labeled(
+                    PreReleaseLabel::Alpha,
+                    Some(pre_release_num as u64),
+                ));
             } else if let Some(ref mut pre_release) = self.vars.pre_release {
-                pre_release.number = Some(pre_release_num as u64);
+                pre_release.set_number(Some(pre_release_num as u64));
             }
         }
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
             if let Some(ref mut pre_release) = self.vars.pre_release {
-                pre_release.number = Some(pre_release.number.unwrap_or(0) + increment as u64);
+                pre_release.set_number(Some(checked_bump(
+                    pre_release.effective_number().unwrap_or(0),
+                    increment,
+                    "pre_release_num",
+                )?));
                 self.reset_lower_precedence_components(&Precedence::PreReleaseNum)?;
             } else {
                 // Create alpha label with the increment when no pre-release exists
-                self.vars.pre_release = Some(PreReleaseVar {
-                    label: PreReleaseLabel::Alpha,
-                    number: Some(increment as u64),
-                });
+                self.vars.pre_release = Some(PreReleaseVar::labeled(
+                    PreReleaseLabel::Alpha,
+                    Some(increment as u64),
+                ));
                 self.reset_lower_precedence_components(&Precedence::PreReleaseNum)?;
             }
         }
@@ -115,6 +167,16 @@ impl Zerv {
         &mut self,
         override_value: Option<u32>,
         bump_value: Option<u32>,
+    ) -> Result<(), ZervError> {
+        self.process_epoch_with_reset(override_value, bump_value, true)
+    }
+
+    /// Process the epoch; `reset_lower` controls whether a bump resets every lower component
+    pub fn process_epoch_with_reset(
+        &mut self,
+        override_value: Option<u32>,
+        bump_value: Option<u32>,
+        reset_lower: bool,
     ) -> Result<(), ZervError> {
         // 1. Override step - set absolute value if specified
         if let Some(override_val) = override_value {
@@ -123,8 +185,14 @@ impl Zerv {
 
         // 2. Bump + Reset step (atomic operation)
         if let Some(increment) = bump_value {
-            self.vars.epoch = Some(self.vars.epoch.unwrap_or(0) + increment as u64);
-            self.reset_lower_precedence_components(&Precedence::Epoch)?;
+            self.vars.epoch = Some(checked_bump(
+                self.vars.epoch.unwrap_or(0),
+                increment,
+                "epoch",
+            )?);
+            if reset_lower {
+                self.reset_lower_precedence_components(&Precedence::Epoch)?;
+            }
         }
 
         Ok(())
@@ -135,10 +203,12 @@ impl Zerv {
 mod tests {
     use rstest::*;
 
+    use crate::error::ZervError;
     use crate::schema::ZervSchemaPreset;
     use crate::test_utils::VersionArgsFixture;
     use crate::test_utils::zerv::ZervFixture;
     use crate::version::semver::SemVer;
+    use crate::version::zerv::core::PreReleaseLabel;
 
     #[rstest]
     // Bump only tests
@@ -221,6 +291,23 @@ mod tests {
         assert_eq!(result_version.to_string(), expected_version);
     }
 
+    #[rstest]
+    #[case::reset_all("1.2.3-alpha.1", true, "0.0.0-epoch.1")]
+    #[case::reset_none("1.2.3-alpha.1", false, "1.2.3-epoch.1.alpha.1")]
+    fn test_process_epoch_with_reset(
+        #[case] starting_version: &str,
+        #[case] reset_lower: bool,
+        #[case] expected_version: &str,
+    ) {
+        let mut zerv = ZervFixture::from_semver_str(starting_version)
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrereleasePostDevContext)
+            .build();
+        zerv.process_epoch_with_reset(None, Some(1), reset_lower)
+            .unwrap();
+        let result_version: SemVer = zerv.into();
+        assert_eq!(result_version.to_string(), expected_version);
+    }
+
     #[rstest]
     // Override only tests
     #[case("1.0.0-alpha.5", Some("beta"), None, "1.0.0-beta.5")] // Override preserves number
@@ -257,6 +344,49 @@ mod tests {
         assert_eq!(result_version.to_string(), expected_version);
     }
 
+    #[rstest]
+    #[case::default_resets_both("1.0.0-alpha.1.post.5.dev.2", None, "1.0.0-beta.0")]
+    #[case::post_and_dev(
+        "1.0.0-alpha.1.post.5.dev.2",
+        Some(&["post", "dev"][..]),
+        "1.0.0-beta.0"
+    )]
+    #[case::dev_only_keeps_post(
+        "1.0.0-alpha.1.post.5.dev.2",
+        Some(&["dev"][..]),
+        "1.0.0-beta.0.post.5"
+    )]
+    #[case::post_only_keeps_dev(
+        "1.0.0-alpha.1.post.5.dev.2",
+        Some(&["post"][..]),
+        "1.0.0-beta.0.dev.2"
+    )]
+    #[case::none_keeps_both(
+        "1.0.0-alpha.1.post.5.dev.2",
+        Some(&["none"][..]),
+        "1.0.0-beta.0.post.5.dev.2"
+    )]
+    fn test_process_pre_release_label_with_resets(
+        #[case] starting_version: &str,
+        #[case] resets: Option<&[&str]>,
+        #[case] expected_version: &str,
+    ) {
+        let mut zerv = ZervFixture::from_semver_str(starting_version)
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrereleasePostDevContext)
+            .build();
+        let mut args_fixture = VersionArgsFixture::new().with_bump_pre_release_label("beta");
+        if let Some(resets) = resets {
+            args_fixture = args_fixture.with_prerelease_bump_resets(resets);
+        }
+        let args = args_fixture.build();
+        let dummy_zerv = ZervFixture::new().build();
+        let resolved_args =
+            crate::cli::version::args::ResolvedArgs::resolve(&args, &dummy_zerv).unwrap();
+        zerv.process_pre_release_label(&resolved_args).unwrap();
+        let result_version: SemVer = zerv.into();
+        assert_eq!(result_version.to_string(), expected_version);
+    }
+
     #[rstest]
     // Bump only tests
     #[case("1.0.0-alpha.1", None, Some(2), "1.0.0-alpha.3")]
@@ -308,4 +438,28 @@ mod tests {
         // let result = zerv.process_pre_release_label(&resolved_args);
         // assert!(result.is_err());
     }
+
+    #[rstest]
+    #[case::post("post")]
+    #[case::dev("dev")]
+    #[case::epoch("epoch")]
+    #[case::pre_release_num("pre_release_num")]
+    fn test_process_secondary_bump_overflow_errors(#[case] field: &str) {
+        let mut zerv = ZervFixture::new()
+            .with_post(u64::MAX)
+            .with_dev(u64::MAX)
+            .with_epoch(u64::MAX)
+            .with_pre_release(PreReleaseLabel::Alpha, Some(u64::MAX))
+            .build();
+        let result = match field {
+            "post" => zerv.process_post(None, Some(1)),
+            "dev" => zerv.process_dev(None, Some(1)),
+            "epoch" => zerv.process_epoch(None, Some(1)),
+            _ => zerv.process_pre_release_num(None, Some(1)),
+        };
+        assert!(
+            matches!(result, Err(ZervError::InvalidArgument(ref msg)) if msg.contains(field)),
+            "expected overflow error, got {result:?}"
+        );
+    }
 }
@@ -11,7 +11,10 @@ use strum::{
 };
 
 use crate::utils::sanitize::Sanitizer;
-use crate::version::zerv::core::PreReleaseLabel;
+use crate::version::zerv::core::{
+    PreReleaseLabel,
+    PreReleaseVar,
+};
 use crate::version::zerv::resolve_timestamp;
 use crate::version::zerv::vars::ZervVars;
 
@@ -30,6 +33,7 @@ use crate::version::zerv::vars::ZervVars;
     AsRefStr,
 )]
 #[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Var {
     // Primary components (schema.core only, correct order when present, used once each)
     Major,
@@ -46,18 +50,27 @@ pub enum Var {
     // VCS state fields
     Distance,
     Dirty,
+    StagedDirty,
+    UnstagedDirty,
 
     // VCS context fields (bumped)
     BumpedBranch,
     BumpedCommitHash,
     BumpedCommitHashShort,
     BumpedTimestamp,
+    CommitSubject,
+    CommitCount,
+    DefaultBranch,
 
     // VCS context fields (last)
     LastBranch,
     LastCommitHash,
     LastCommitHashShort,
     LastTimestamp,
+    TagMessage,
+
+    // Derived fields
+    BuildNumber,
 
     // Custom fields
     #[serde(rename = "custom")]
@@ -124,7 +137,7 @@ impl Var {
             Var::PreRelease => vars
                 .pre_release
                 .as_ref()
-                .and_then(|pr| pr.number)
+                .and_then(|pr| pr.number())
                 .map(|num| sanitizer.sanitize(&num.to_string())),
 
             // VCS fields
@@ -140,6 +153,11 @@ impl Var {
             Var::BumpedTimestamp => vars
                 .bumped_timestamp
                 .map(|v| sanitizer.sanitize(&v.to_string())),
+            Var::CommitSubject => vars.commit_subject.as_ref().map(|s| sanitizer.sanitize(s)),
+            Var::CommitCount => vars
+                .commit_count
+                .map(|v| sanitizer.sanitize(&v.to_string())),
+            Var::DefaultBranch => vars.default_branch.as_ref().map(|b| sanitizer.sanitize(b)),
 
             // Last version fields
             Var::LastBranch => vars.last_branch.as_ref().map(|b| sanitizer.sanitize(b)),
@@ -153,9 +171,21 @@ impl Var {
             Var::LastTimestamp => vars
                 .last_timestamp
                 .map(|v| sanitizer.sanitize(&v.to_string())),
+            Var::TagMessage => vars.tag_message.as_ref().map(|m| sanitizer.sanitize(m)),
 
             // VCS state fields
             Var::Dirty => vars.dirty.map(|v| sanitizer.sanitize(&v.to_string())),
+            Var::StagedDirty => vars
+                .staged_dirty
+                .map(|v| sanitizer.sanitize(&v.to_string())),
+            Var::UnstagedDirty => vars
+                .unstaged_dirty
+                .map(|v| sanitizer.sanitize(&v.to_string())),
+
+            // Derived fields
+            Var::BuildNumber => vars
+                .build_number
+                .map(|v| sanitizer.sanitize(&v.to_string())),
 
             // Custom fields - lookup in JSON with dot notation
             Var::Custom(name) => vars
@@ -235,17 +265,27 @@ impl Var {
             ),
 
             // Pre-release - label + optional value
-            Var::PreRelease => {
-                if let Some(pr) = &vars.pre_release {
-                    let mut parts = vec![key_sanitizer.sanitize(pr.label.label_str())];
+            Var::PreRelease => match &vars.pre_release {
+                Some(PreReleaseVar::Raw(raw)) => raw
+                    .iter()
+                    .map(|identifier| value_sanitizer.sanitize(identifier))
+                    .collect(),
+                Some(PreReleaseVar::Labeled {
+                    label, identifiers, ..
+                }) => {
+                    let mut parts = vec![key_sanitizer.sanitize(label.label_str())];
                     if let Some(value) = self.resolve_value(vars, value_sanitizer) {
                         parts.push(value);
                     }
+                    parts.extend(
+                        identifiers
+                            .iter()
+                            .map(|identifier| value_sanitizer.sanitize(identifier)),
+                    );
                     parts
-                } else {
-                    vec![]
                 }
-            }
+                None => vec![],
+            },
 
             // VCS fields
             Var::BumpedBranch => self.resolve_parts_with_value(
@@ -273,6 +313,21 @@ impl Var {
                 value_sanitizer,
                 vec![key_sanitizer.sanitize("timestamp")],
             ),
+            Var::CommitSubject => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("commit_subject")],
+            ),
+            Var::CommitCount => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("commit_count")],
+            ),
+            Var::DefaultBranch => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("default_branch")],
+            ),
 
             // Last version fields
             Var::LastBranch => self.resolve_parts_with_value(
@@ -295,6 +350,11 @@ impl Var {
                 value_sanitizer,
                 vec![key_sanitizer.sanitize("last_timestamp")],
             ),
+            Var::TagMessage => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("tag_message")],
+            ),
 
             // VCS state fields
             Var::Dirty => self.resolve_parts_with_value(
@@ -302,6 +362,23 @@ impl Var {
                 value_sanitizer,
                 vec![key_sanitizer.sanitize("dirty")],
             ),
+            Var::StagedDirty => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("staged_dirty")],
+            ),
+            Var::UnstagedDirty => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("unstaged_dirty")],
+            ),
+
+            // Derived fields
+            Var::BuildNumber => self.resolve_parts_with_value(
+                vars,
+                value_sanitizer,
+                vec![key_sanitizer.sanitize("build")],
+            ),
 
             // Custom fields - split by dots and sanitize each part
             Var::Custom(name) => {
@@ -335,6 +412,7 @@ impl Var {
 
 /// Component enum for internal use with compact serialization
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Component {
     #[serde(rename = "str")]
     Str(String),
@@ -546,6 +624,51 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::semver(Sanitizer::semver_str(), Some("Fix.parser.edge.case"))]
+    #[case::pep440(Sanitizer::pep440_local_str(), Some("fix.parser.edge.case"))]
+    fn test_var_commit_subject(#[case] sanitizer: Sanitizer, #[case] expected: Option<&str>) {
+        let mut zerv = base_fixture().build();
+        zerv.vars.commit_subject = Some("Fix parser: edge case".to_string());
+        assert_eq!(
+            Var::CommitSubject.resolve_value(&zerv.vars, &sanitizer),
+            expected.map(str::to_string)
+        );
+    }
+
+    #[test]
+    fn test_var_commit_count() {
+        let mut zerv = base_fixture().build();
+        zerv.vars.commit_count = Some(42);
+        assert_eq!(
+            Var::CommitCount.resolve_value(&zerv.vars, &Sanitizer::semver_str()),
+            Some("42".to_string())
+        );
+    }
+
+    #[test]
+    fn test_var_default_branch() {
+        let mut zerv = base_fixture().build();
+        zerv.vars.default_branch = Some("release/trunk".to_string());
+        assert_eq!(
+            Var::DefaultBranch.resolve_value(&zerv.vars, &Sanitizer::semver_str()),
+            Some("release.trunk".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case::annotated(Some("Release notes"), Some("Release.notes"))]
+    #[case::lightweight(None, None)]
+    fn test_var_tag_message(#[case] message: Option<&str>, #[case] expected: Option<&str>) {
+        let mut zerv = base_fixture().build();
+        zerv.vars.tag_message = message.map(str::to_string);
+        let sanitizer = Sanitizer::semver_str();
+        assert_eq!(
+            Var::TagMessage.resolve_value(&zerv.vars, &sanitizer),
+            expected.map(str::to_string)
+        );
+    }
+
     #[rstest]
     #[case(1703123456)]
     fn test_var_timestamps(#[case] timestamp: u64) {
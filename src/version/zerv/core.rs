@@ -6,11 +6,16 @@ use serde::{
 };
 
 use crate::error::ZervError;
-use crate::utils::constants::pre_release_labels;
+use crate::utils::constants::{
+    pre_release_labels,
+    prerelease_cases,
+};
+use crate::version::zerv::components::Component;
 use crate::version::zerv::schema::ZervSchema;
 use crate::version::zerv::vars::ZervVars;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum PreReleaseLabel {
     Alpha,
     Beta,
@@ -43,6 +48,21 @@ impl PreReleaseLabel {
         }
     }
 
+    /// Render a label string (e.g. "rc" or PEP440 "a") in the given output case
+    pub fn apply_case(label: &str, case: &str) -> String {
+        match case {
+            prerelease_cases::UPPER => label.to_uppercase(),
+            prerelease_cases::TITLE => {
+                let mut chars = label.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            _ => label.to_string(),
+        }
+    }
+
     /// Flexible parsing with alpha fallback (for PEP440 parser compatibility)
     /// This replaces the existing normalize_pre_label function
     pub fn from_str_or_alpha(label: &str) -> Self {
@@ -68,15 +88,148 @@ impl FromStr for PreReleaseLabel {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Zerv {
     pub schema: ZervSchema,
     pub vars: ZervVars,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct PreReleaseVar {
-    pub label: PreReleaseLabel,
-    pub number: Option<u64>,
+#[serde(from = "PreReleaseVarRepr", into = "PreReleaseVarRepr")]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum PreReleaseVar {
+    /// `<label>[.<number>][.<identifiers>]` (e.g. `alpha.1` or `alpha.beta`)
+    Labeled {
+        label: PreReleaseLabel,
+        number: Option<u64>,
+        /// Dotted identifiers trailing the label and number (e.g. `beta` in `1.0.0-alpha.beta`)
+        identifiers: Vec<String>,
+    },
+    /// Label-less identifiers kept verbatim (e.g. `x.7.z.92` in `1.0.0-x.7.z.92`)
+    Raw(Vec<String>),
+}
+
+// Flat serialized form of PreReleaseVar, so existing Zerv RON stays readable
+/// Pre-release: `label` with an optional `number` and trailing `identifiers`, or label-less
+/// `raw` identifiers
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+struct PreReleaseVarRepr {
+    /// Ignored when `raw` is set
+    label: PreReleaseLabel,
+    number: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    identifiers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    raw: Option<Vec<String>>,
+}
+
+impl From<PreReleaseVarRepr> for PreReleaseVar {
+    fn from(repr: PreReleaseVarRepr) -> Self {
+        match repr.raw {
+            Some(raw) => Self::Raw(raw),
+            None => Self::Labeled {
+                label: repr.label,
+                number: repr.number,
+                identifiers: repr.identifiers,
+            },
+        }
+    }
+}
+
+impl From<PreReleaseVar> for PreReleaseVarRepr {
+    fn from(pre_release: PreReleaseVar) -> Self {
+        match pre_release {
+            PreReleaseVar::Labeled {
+                label,
+                number,
+                identifiers,
+            } => Self {
+                label,
+                number,
+                identifiers,
+                raw: None,
+            },
+            PreReleaseVar::Raw(raw) => Self {
+                label: PreReleaseLabel::Alpha,
+                number: None,
+                identifiers: Vec::new(),
+                raw: Some(raw),
+            },
+        }
+    }
+}
+
+impl PreReleaseVar {
+    /// Labeled pre-release without trailing identifiers
+    pub fn labeled(label: PreReleaseLabel, number: Option<u64>) -> Self {
+        Self::Labeled {
+            label,
+            number,
+            identifiers: Vec::new(),
+        }
+    }
+
+    /// Label-less numeric pre-release (e.g. `1` in `1.0.0-1`)
+    pub fn numeric(number: u64) -> Self {
+        Self::Raw(vec![number.to_string()])
+    }
+
+    /// The label, None for a raw pre-release
+    pub fn label(&self) -> Option<PreReleaseLabel> {
+        match self {
+            Self::Labeled { label, .. } => Some(*label),
+            Self::Raw(_) => None,
+        }
+    }
+
+    /// The number following the label, None for a raw pre-release
+    pub fn number(&self) -> Option<u64> {
+        match self {
+            Self::Labeled { number, .. } => *number,
+            Self::Raw(_) => None,
+        }
+    }
+
+    /// The identifiers trailing the label and number, empty for a raw pre-release
+    pub fn identifiers(&self) -> &[String] {
+        match self {
+            Self::Labeled { identifiers, .. } => identifiers,
+            Self::Raw(_) => &[],
+        }
+    }
+
+    /// The number of a label-less numeric pre-release, None for any other shape
+    pub fn numeric_number(&self) -> Option<u64> {
+        match self {
+            Self::Raw(raw) => match raw.as_slice() {
+                [identifier] if identifier == "0" || !identifier.starts_with('0') => {
+                    identifier.parse().ok()
+                }
+                _ => None,
+            },
+            Self::Labeled { .. } => None,
+        }
+    }
+
+    /// The pre-release number, whether it follows a label or stands alone
+    pub fn effective_number(&self) -> Option<u64> {
+        self.numeric_number().or(self.number())
+    }
+
+    /// Set the number; a numeric pre-release stays label-less, any other raw pre-release
+    /// turns into `alpha.<number>` since it has no number slot
+    pub fn set_number(&mut self, new_number: Option<u64>) {
+        match self {
+            Self::Labeled { number, .. } => *number = new_number,
+            Self::Raw(_) => match new_number {
+                Some(new_number) if self.numeric_number().is_some() => {
+                    *self = Self::numeric(new_number)
+                }
+                _ => *self = Self::labeled(PreReleaseLabel::Alpha, new_number),
+            },
+        }
+    }
 }
 
 impl Zerv {
@@ -88,6 +241,48 @@ impl Zerv {
         Ok(Self { schema, vars })
     }
 
+    /// Prepend the build metadata carried over from the tag
+    pub fn apply_tag_build(&mut self, tag_build: &[Component]) -> Result<(), ZervError> {
+        if !tag_build.is_empty() {
+            let build = tag_build
+                .iter()
+                .chain(self.schema.build())
+                .cloned()
+                .collect();
+            self.schema.set_build(build)?;
+        }
+        Ok(())
+    }
+
+    /// Restart the inherited pre-release label at 0 when a bump released the version
+    pub fn apply_inherited_pre_release_label(&mut self, label: Option<PreReleaseLabel>) {
+        if let Some(label) = label
+            && self.vars.pre_release.is_none()
+        {
+            self.vars.pre_release = Some(PreReleaseVar::labeled(label, Some(0)));
+        }
+    }
+
+    /// Append a marker to the build metadata when the working tree is dirty
+    pub fn apply_dirty_marker(&mut self, marker: Option<&str>) -> Result<(), ZervError> {
+        if let Some(marker) = marker
+            && self.vars.dirty == Some(true)
+        {
+            let mut build = self.schema.build().clone();
+            build.push(Component::Str(marker.to_string()));
+            self.schema.set_build(build)?;
+        }
+        Ok(())
+    }
+
+    /// Derive the build number from distance and timestamp when a modulus is configured
+    pub fn apply_build_number(&mut self, modulus: Option<u64>) -> Result<(), ZervError> {
+        if let Some(modulus) = modulus {
+            self.vars.build_number = Some(self.vars.compute_build_number(modulus)?);
+        }
+        Ok(())
+    }
+
     pub fn normalize(&mut self) {
         if self.vars.epoch == Some(0) {
             self.vars.epoch = None;
@@ -104,6 +299,145 @@ mod tests {
         Var,
     };
 
+    mod dirty_marker {
+        use rstest::rstest;
+
+        use super::*;
+        use crate::test_utils::zerv::ZervFixture;
+
+        #[rstest]
+        #[case::dirty_with_marker(Some(true), Some("dirty"), true)]
+        #[case::clean_with_marker(Some(false), Some("dirty"), false)]
+        #[case::unknown_with_marker(None, Some("dirty"), false)]
+        #[case::dirty_without_marker(Some(true), None, false)]
+        fn test_apply_dirty_marker(
+            #[case] dirty: Option<bool>,
+            #[case] marker: Option<&str>,
+            #[case] expect_marker: bool,
+        ) {
+            let mut zerv = ZervFixture::new().with_version(1, 2, 3).build();
+            zerv.vars.dirty = dirty;
+            let original_build = zerv.schema.build().clone();
+
+            zerv.apply_dirty_marker(marker).unwrap();
+
+            let mut expected_build = original_build;
+            if expect_marker {
+                expected_build.push(Component::Str("dirty".to_string()));
+            }
+            assert_eq!(zerv.schema.build(), &expected_build);
+        }
+    }
+
+    mod numeric_pre_release {
+        use rstest::rstest;
+
+        use super::*;
+
+        #[rstest]
+        #[case::single_number(PreReleaseVar::numeric(1), Some(1))]
+        #[case::zero(PreReleaseVar::Raw(vec!["0".to_string()]), Some(0))]
+        #[case::leading_zero(PreReleaseVar::Raw(vec!["01".to_string()]), None)]
+        #[case::several_identifiers(
+            PreReleaseVar::Raw(vec!["0".to_string(), "3".to_string()]),
+            None
+        )]
+        #[case::text(PreReleaseVar::Raw(vec!["x".to_string()]), None)]
+        fn test_numeric_number(#[case] pre_release: PreReleaseVar, #[case] expected: Option<u64>) {
+            assert_eq!(pre_release.numeric_number(), expected);
+        }
+
+        #[test]
+        fn test_set_number_keeps_numeric_pre_release_label_less() {
+            let mut pre_release = PreReleaseVar::numeric(1);
+            pre_release.set_number(Some(2));
+            assert_eq!(pre_release, PreReleaseVar::numeric(2));
+            assert_eq!(pre_release.effective_number(), Some(2));
+        }
+
+        #[test]
+        fn test_set_number_turns_other_raw_pre_release_into_alpha() {
+            let mut pre_release = PreReleaseVar::Raw(vec!["x".to_string(), "7".to_string()]);
+            pre_release.set_number(Some(2));
+            assert_eq!(
+                pre_release,
+                PreReleaseVar::labeled(PreReleaseLabel::Alpha, Some(2))
+            );
+        }
+    }
+
+    mod inherited_pre_release_label {
+        use rstest::rstest;
+
+        use super::*;
+        use crate::test_utils::zerv::ZervFixture;
+
+        #[rstest]
+        #[case::released_takes_label(None, Some(PreReleaseLabel::Beta), Some((PreReleaseLabel::Beta, Some(0))))]
+        #[case::existing_pre_release_kept(
+            Some((PreReleaseLabel::Rc, Some(3))),
+            Some(PreReleaseLabel::Beta),
+            Some((PreReleaseLabel::Rc, Some(3)))
+        )]
+        #[case::nothing_to_inherit(None, None, None)]
+        fn test_apply_inherited_pre_release_label(
+            #[case] pre_release: Option<(PreReleaseLabel, Option<u64>)>,
+            #[case] inherited: Option<PreReleaseLabel>,
+            #[case] expected: Option<(PreReleaseLabel, Option<u64>)>,
+        ) {
+            let mut fixture = ZervFixture::new().with_version(1, 2, 3);
+            if let Some((label, number)) = pre_release {
+                fixture = fixture.with_pre_release(label, number);
+            }
+            let mut zerv = fixture.build();
+
+            zerv.apply_inherited_pre_release_label(inherited);
+
+            let actual = zerv
+                .vars
+                .pre_release
+                .map(|pre_release| (pre_release.label().unwrap(), pre_release.number()));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    mod tag_build {
+        use super::*;
+        use crate::test_utils::zerv::ZervFixture;
+
+        #[test]
+        fn test_apply_tag_build() {
+            let mut zerv = ZervFixture::new().with_version(1, 2, 3).build();
+            let original_build = zerv.schema.build().clone();
+
+            zerv.apply_tag_build(&[]).unwrap();
+            assert_eq!(zerv.schema.build(), &original_build);
+
+            let tag_build = vec![Component::Str("ci".to_string()), Component::UInt(100)];
+            zerv.apply_tag_build(&tag_build).unwrap();
+            let mut expected_build = tag_build;
+            expected_build.extend(original_build);
+            assert_eq!(zerv.schema.build(), &expected_build);
+        }
+    }
+
+    mod build_number {
+        use crate::test_utils::zerv::ZervFixture;
+
+        #[test]
+        fn test_apply_build_number() {
+            let mut zerv = ZervFixture::new().with_version(1, 2, 3).build();
+            zerv.vars.distance = Some(4);
+            zerv.vars.bumped_timestamp = Some(1_700_000_321);
+
+            zerv.apply_build_number(None).unwrap();
+            assert_eq!(zerv.vars.build_number, None);
+
+            zerv.apply_build_number(Some(1000)).unwrap();
+            assert_eq!(zerv.vars.build_number, Some(4321));
+        }
+    }
+
     mod construction {
         use super::*;
 
@@ -160,13 +494,10 @@ mod tests {
 
         #[test]
         fn test_pre_release_var() {
-            let pre_release = PreReleaseVar {
-                label: PreReleaseLabel::Alpha,
-                number: Some(1),
-            };
+            let pre_release = PreReleaseVar::labeled(PreReleaseLabel::Alpha, Some(1));
 
-            assert_eq!(pre_release.label, PreReleaseLabel::Alpha);
-            assert_eq!(pre_release.number, Some(1));
+            assert_eq!(pre_release.label(), Some(PreReleaseLabel::Alpha));
+            assert_eq!(pre_release.number(), Some(1));
         }
     }
 
@@ -229,13 +560,10 @@ mod tests {
 
         #[test]
         fn test_pre_release_without_number() {
-            let pre_release = PreReleaseVar {
-                label: PreReleaseLabel::Alpha,
-                number: None,
-            };
+            let pre_release = PreReleaseVar::labeled(PreReleaseLabel::Alpha, None);
 
-            assert_eq!(pre_release.label, PreReleaseLabel::Alpha);
-            assert_eq!(pre_release.number, None);
+            assert_eq!(pre_release.label(), Some(PreReleaseLabel::Alpha));
+            assert_eq!(pre_release.number(), None);
         }
 
         #[test]
@@ -396,10 +724,7 @@ mod tests {
                 major: Some(1),
                 minor: Some(2),
                 patch: Some(3),
-                pre_release: Some(PreReleaseVar {
-                    label: PreReleaseLabel::Alpha,
-                    number: Some(1),
-                }),
+                pre_release: Some(PreReleaseVar::labeled(PreReleaseLabel::Alpha, Some(1))),
                 ..Default::default()
             };
 
@@ -410,8 +735,8 @@ mod tests {
                     .pre_release
                     .as_ref()
                     .expect("Test setup error: pre_release should be Some")
-                    .label,
-                PreReleaseLabel::Alpha
+                    .label(),
+                Some(PreReleaseLabel::Alpha)
             );
         }
 
@@ -24,10 +24,13 @@ pub use components::{
     Component,
     Var,
 };
-// Schema types
-pub use schema::ZervSchema;
 // Schema parser types
 pub use schema::parse_ron_schema;
+// Schema types
+pub use schema::{
+    ZervSchema,
+    ZervSchemaPatch,
+};
 // Utilities
 pub use utils::resolve_timestamp;
 // Vars types
@@ -17,11 +17,16 @@ use super::part::SchemaPartName;
 use crate::error::ZervError;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ZervSchema {
     core: Vec<Component>,
     extra_core: Vec<Component>,
     build: Vec<Component>,
     #[serde(default)]
+    #[cfg_attr(
+        feature = "json-schema",
+        schemars(with = "Vec<super::super::Precedence>")
+    )]
     precedence_order: PrecedenceOrder,
 }
 
@@ -197,11 +202,14 @@ impl Display for ZervSchema {
 mod tests {
     use rstest::rstest;
 
-    use super::super::super::PrecedenceOrder;
     use super::super::super::components::{
         Component,
         Var,
     };
+    use super::super::super::{
+        Precedence,
+        PrecedenceOrder,
+    };
     use super::ZervSchema;
     use crate::schema::ZervSchemaPreset;
 
@@ -328,11 +336,43 @@ mod tests {
         ).expect("Failed to create schema")
     )]
     #[case::standard_tier_1(ZervSchemaPreset::StandardBasePrereleasePost.schema())]
+    #[case::unusual_order(
+        ZervSchema::new_with_precedence(
+            vec![Component::Var(Var::Major), Component::Var(Var::Minor), Component::Var(Var::Patch)],
+            vec![
+                Component::Var(Var::Dev),
+                Component::Str("zz".to_string()),
+                Component::Var(Var::Post),
+                Component::Var(Var::Epoch),
+                Component::Var(Var::PreRelease),
+            ],
+            vec![
+                Component::UInt(7),
+                Component::Var(Var::BumpedBranch),
+                Component::Str("b".to_string()),
+                Component::Var(Var::Distance),
+            ],
+            PrecedenceOrder::from_precedences(vec![
+                Precedence::Build,
+                Precedence::Dev,
+                Precedence::Major,
+                Precedence::Epoch,
+                Precedence::Minor,
+                Precedence::Patch,
+            ]),
+        ).expect("Failed to create schema")
+    )]
     fn test_to_string_roundtrip(#[case] original: ZervSchema) {
         let ron_string = original.to_string();
         let reconstructed: ZervSchema =
             ron::from_str(&ron_string).expect("Failed to reconstruct schema");
 
         assert_eq!(original, reconstructed);
+        assert_eq!(original.extra_core(), reconstructed.extra_core());
+        assert_eq!(original.build(), reconstructed.build());
+        assert_eq!(
+            original.precedence_order().to_vec(),
+            reconstructed.precedence_order().to_vec()
+        );
     }
 }
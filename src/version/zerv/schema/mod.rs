@@ -1,6 +1,7 @@
 mod core;
 mod parser;
 mod part;
+mod patch;
 mod validation;
 
 pub use core::ZervSchema;
@@ -10,3 +11,4 @@ pub use part::{
     SchemaPartName,
     ZervSchemaPart,
 };
+pub use patch::ZervSchemaPatch;
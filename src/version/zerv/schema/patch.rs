@@ -0,0 +1,112 @@
+use ron::extensions::Extensions;
+use serde::Deserialize;
+
+use super::super::PrecedenceOrder;
+use super::super::components::Component;
+use super::core::ZervSchema;
+use crate::error::ZervError;
+
+/// Partial schema given to --schema-patch as RON, e.g. `(build: [var(BumpedBranch)])`.
+/// Set sections replace the matching section of the resolved schema, unset ones keep it
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ZervSchemaPatch {
+    pub core: Option<Vec<Component>>,
+    pub extra_core: Option<Vec<Component>>,
+    pub build: Option<Vec<Component>>,
+    pub precedence_order: Option<PrecedenceOrder>,
+}
+
+impl ZervSchemaPatch {
+    /// Parse a RON patch; `Some(..)` may be omitted around the sections
+    pub fn from_ron(ron: &str) -> Result<Self, ZervError> {
+        ron::Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str(ron)
+            .map_err(|e| ZervError::StdinError(format!("Invalid RON schema patch: {e}")))
+    }
+}
+
+impl ZervSchema {
+    /// Replace the sections set in the patch, validating the merged schema as a whole
+    pub fn apply_patch(&mut self, patch: ZervSchemaPatch) -> Result<(), ZervError> {
+        *self = Self::new_with_precedence(
+            patch.core.unwrap_or_else(|| self.core().clone()),
+            patch
+                .extra_core
+                .unwrap_or_else(|| self.extra_core().clone()),
+            patch.build.unwrap_or_else(|| self.build().clone()),
+            patch
+                .precedence_order
+                .unwrap_or_else(|| self.precedence_order().clone()),
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::super::super::components::Var;
+    use super::*;
+    use crate::schema::ZervSchemaPreset;
+
+    fn standard_base() -> ZervSchema {
+        ZervSchemaPreset::StandardBase.schema()
+    }
+
+    #[test]
+    fn test_patch_build_onto_standard_base() {
+        let mut schema = standard_base();
+        let patch =
+            ZervSchemaPatch::from_ron(r#"(build: [var(BumpedBranch), str("ci")])"#).unwrap();
+        schema.apply_patch(patch).unwrap();
+
+        let base = standard_base();
+        assert_eq!(schema.core(), base.core());
+        assert_eq!(schema.extra_core(), base.extra_core());
+        assert_eq!(schema.precedence_order(), base.precedence_order());
+        assert_eq!(
+            schema.build(),
+            &vec![
+                Component::Var(Var::BumpedBranch),
+                Component::Str("ci".to_string()),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::unnamed("()")]
+    #[case::named("ZervSchemaPatch()")]
+    fn test_empty_patch_keeps_schema(#[case] ron: &str) {
+        let mut schema = standard_base();
+        schema
+            .apply_patch(ZervSchemaPatch::from_ron(ron).unwrap())
+            .unwrap();
+        assert_eq!(schema, standard_base());
+    }
+
+    #[test]
+    fn test_explicit_some_is_accepted() {
+        let patch = ZervSchemaPatch::from_ron("(build: Some([]))").unwrap();
+        assert_eq!(patch.build, Some(vec![]));
+        assert!(patch.core.is_none());
+    }
+
+    #[rstest]
+    #[case::unknown_section("(tail: [])", "Invalid RON schema patch")]
+    #[case::not_ron("build = []", "Invalid RON schema patch")]
+    fn test_invalid_patch(#[case] ron: &str, #[case] expected: &str) {
+        let err = ZervSchemaPatch::from_ron(ron).unwrap_err();
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
+    #[test]
+    fn test_patch_result_is_validated() {
+        let mut schema = standard_base();
+        let patch = ZervSchemaPatch::from_ron("(core: [], extra_core: [], build: [])").unwrap();
+        assert!(schema.apply_patch(patch).is_err());
+        assert_eq!(schema, standard_base());
+    }
+}
@@ -11,6 +11,7 @@ use crate::version::VersionObject;
 use crate::version::zerv::core::PreReleaseVar;
 
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ZervVars {
     // Core version fields
     pub major: Option<u64>,
@@ -24,17 +25,42 @@ pub struct ZervVars {
     // VCS state fields
     pub distance: Option<u64>,
     pub dirty: Option<bool>,
+    // Split of `dirty` into index and working tree changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staged_dirty: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unstaged_dirty: Option<bool>,
 
     // Bumped fields (for template access)
     pub bumped_branch: Option<String>,
     pub bumped_commit_hash: Option<String>,
     pub bumped_timestamp: Option<u64>,
+    // Subject line of the HEAD commit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_subject: Option<String>,
+    // Number of commits reachable from the bumped commit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_count: Option<u64>,
+    // Repository default branch (origin's HEAD, init.defaultBranch or "main")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
 
     // Last version fields (for template access)
     pub last_branch: Option<String>,
     pub last_commit_hash: Option<String>,
     pub last_timestamp: Option<u64>,
     pub last_tag_version: Option<String>,
+    // Message of the annotated tag (None for lightweight tags)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_message: Option<String>,
+
+    // Abbreviated bumped hash chosen by git (see --abbrev auto)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bumped_commit_hash_short: Option<String>,
+
+    // Derived build number (see --build-number-formula)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_number: Option<u64>,
 
     // Custom variables
     #[serde(default = "default_custom_value")]
@@ -58,13 +84,35 @@ impl ZervVars {
     }
 
     pub fn get_bumped_commit_hash_short(&self) -> Option<String> {
-        Self::derive_short_hash(self.bumped_commit_hash.as_ref())
+        self.bumped_commit_hash_short
+            .clone()
+            .or_else(|| Self::derive_short_hash(self.bumped_commit_hash.as_ref()))
     }
 
     pub fn get_last_commit_hash_short(&self) -> Option<String> {
         Self::derive_short_hash(self.last_commit_hash.as_ref())
     }
 
+    /// Deterministic build number: `distance * modulus + (timestamp % modulus)`
+    /// Uses the bumped timestamp (falling back to the last commit timestamp); missing values count as 0
+    pub fn compute_build_number(&self, modulus: u64) -> Result<u64, ZervError> {
+        if modulus == 0 {
+            return Err(ZervError::InvalidArgument(
+                "Build number modulus must be greater than 0".to_string(),
+            ));
+        }
+        let distance = self.distance.unwrap_or(0);
+        let timestamp = self.bumped_timestamp.or(self.last_timestamp).unwrap_or(0);
+        distance
+            .checked_mul(modulus)
+            .and_then(|base| base.checked_add(timestamp % modulus))
+            .ok_or_else(|| {
+                ZervError::InvalidArgument(format!(
+                    "Build number overflows for distance {distance} with modulus {modulus}"
+                ))
+            })
+    }
+
     /// Get custom value by key with dot-separated nested access
     /// Examples: "build_id", "metadata.author", "config.database.host"
     pub fn get_custom_value(&self, key: &str) -> Option<String> {
@@ -129,6 +177,7 @@ impl ZervVars {
         // Apply commit hash override
         if let Some(bumped_commit_hash) = &args.overrides.common.bumped_commit_hash {
             self.bumped_commit_hash = Some(bumped_commit_hash.clone());
+            self.bumped_commit_hash_short = None;
         }
 
         // Apply timestamp override
@@ -144,8 +193,11 @@ impl ZervVars {
         // Apply tag version override (parse and extract components)
         if let Some(tag_version) = &args.overrides.common.tag_version {
             // Use consolidated VersionObject parsing
-            let version_object =
-                VersionObject::parse_with_format(tag_version, &args.input.input_format)?;
+            let version_object = VersionObject::parse_with_prefix(
+                tag_version,
+                &args.input.input_format,
+                &args.input.tag_prefix(),
+            )?;
             let parsed_vars = ZervVars::from(version_object);
 
             // Apply parsed version components to self
@@ -175,9 +227,14 @@ impl ZervVars {
             // Force clean state - no VCS metadata
             self.distance = Some(0);
             self.dirty = Some(false);
+            self.staged_dirty = Some(false);
+            self.unstaged_dirty = Some(false);
             self.bumped_branch = None;
             self.bumped_commit_hash = None;
+            self.bumped_commit_hash_short = None;
             self.bumped_timestamp = None;
+            self.commit_subject = None;
+            self.commit_count = None;
         }
         // --bump-context is default behavior, no changes needed
 
@@ -215,6 +272,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_commit_hash_short_prefers_abbreviation() {
+        let vars = ZervVars {
+            bumped_commit_hash: Some("gabcdef1234567890".to_string()),
+            bumped_commit_hash_short: Some("gabcdef1234".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vars.get_bumped_commit_hash_short(),
+            Some("gabcdef1234".to_string())
+        );
+    }
+
     #[rstest]
     #[case(Some("fedcba0987654321"), Some("fedcba09"))]
     #[case(Some("def456"), Some("def456"))]
@@ -236,6 +307,46 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::formula(Some(3), Some(1_700_000_123), None, 1000, 3123)]
+    #[case::last_timestamp_fallback(Some(2), None, Some(1_700_000_045), 100, 245)]
+    #[case::bumped_timestamp_preferred(Some(1), Some(17), Some(99), 10, 17)]
+    #[case::missing_values(None, None, None, 100_000, 0)]
+    #[case::exact_tag(Some(0), Some(1_700_000_123), None, 100_000, 123)]
+    fn test_compute_build_number(
+        #[case] distance: Option<u64>,
+        #[case] bumped_timestamp: Option<u64>,
+        #[case] last_timestamp: Option<u64>,
+        #[case] modulus: u64,
+        #[case] expected: u64,
+    ) {
+        let vars = ZervVars {
+            distance,
+            bumped_timestamp,
+            last_timestamp,
+            ..Default::default()
+        };
+
+        assert_eq!(vars.compute_build_number(modulus).unwrap(), expected);
+        // Same inputs always give the same number
+        assert_eq!(vars.compute_build_number(modulus).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::zero_modulus(Some(1), 0)]
+    #[case::overflow(Some(u64::MAX), 2)]
+    fn test_compute_build_number_errors(#[case] distance: Option<u64>, #[case] modulus: u64) {
+        let vars = ZervVars {
+            distance,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            vars.compute_build_number(modulus),
+            Err(ZervError::InvalidArgument(_))
+        ));
+    }
+
     #[test]
     fn test_custom_variables() {
         let mut vars = ZervVars::default();
@@ -439,8 +550,8 @@ mod tests {
         assert_eq!(vars.patch, Some(0));
         assert!(vars.pre_release.is_some());
         let pre_release = vars.pre_release.unwrap();
-        assert_eq!(pre_release.label, PreReleaseLabel::Beta);
-        assert_eq!(pre_release.number, Some(1));
+        assert_eq!(pre_release.label(), Some(PreReleaseLabel::Beta));
+        assert_eq!(pre_release.number(), Some(1));
     }
 
     #[test]
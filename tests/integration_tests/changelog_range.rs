@@ -0,0 +1,67 @@
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+fn changelog_range(fixture: &GitRepoFixture, extra_args: &str) -> serde_json::Value {
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(format!("changelog-range {extra_args}"))
+        .assert_success();
+    serde_json::from_str(output.stdout().trim()).expect("Output should be JSON")
+}
+
+fn head_hash(fixture: &GitRepoFixture) -> String {
+    fixture
+        .git_impl
+        .execute_git(&fixture.test_dir, &["rev-parse", "HEAD"])
+        .expect("Failed to read HEAD")
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn test_changelog_range_from_tag_to_head() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.2.3")
+        .expect("Failed to create git repository")
+        .commit("fix: handle empty input")
+        .commit("feat: add login");
+
+    let range = changelog_range(&fixture, "");
+    assert_eq!(
+        range,
+        serde_json::json!({
+            "from": "v1.2.3",
+            "to": head_hash(&fixture),
+            "next_version": "1.3.0",
+        })
+    );
+}
+
+#[test]
+fn test_changelog_range_on_tagged_head_keeps_version() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v2.0.0").expect("Failed to create git repository");
+
+    let range = changelog_range(&fixture, "--output-format pep440");
+    assert_eq!(range["from"], "v2.0.0");
+    assert_eq!(range["to"], head_hash(&fixture));
+    assert_eq!(range["next_version"], "2.0.0");
+}
+
+#[test]
+fn test_changelog_range_requires_git_source() {
+    TestCommand::new()
+        .args_from_str("changelog-range --source none")
+        .assert_failure()
+        .assert_stderr_contains("changelog-range requires --source git");
+}
@@ -0,0 +1,78 @@
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use super::TestCommand;
+
+fn release_branch_fixture(tag: &str, branch: &str) -> GitRepoFixture {
+    let fixture = GitRepoFixture::tagged(tag).expect("Failed to create git repository");
+    fixture
+        .create_branch(branch)
+        .expect("Failed to create release branch");
+    fixture
+        .checkout_branch(branch)
+        .expect("Failed to checkout release branch");
+    fixture
+}
+
+#[test]
+fn test_check_branch_consistency_same_line() {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture = release_branch_fixture("v1.4.2", "release/1.4");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("check --branch-consistency")
+        .assert_success()
+        .assert_stdout_contains("Tag v1.4.2 is on the 1.4 line of branch release/1.4");
+}
+
+#[test]
+fn test_check_branch_consistency_tag_from_newer_line_fails() {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture = release_branch_fixture("v2.0.0", "release/1.4");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("check --branch-consistency")
+        .assert_failure()
+        .assert_stderr_contains(
+            "Tag v2.0.0 is ahead of the 1.4 line that branch release/1.4 names",
+        );
+}
+
+#[test]
+fn test_check_branch_consistency_custom_regex() {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture = release_branch_fixture("v3.1.0", "maint-3");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(
+            r"check 3.1.0 --branch-consistency --branch-version-regex 'maint-(?P<major>\d+)'",
+        )
+        .assert_success()
+        .assert_stdout_contains("Version: 3.1.0")
+        .assert_stdout_contains("Tag v3.1.0 is on the 3 line of branch maint-3");
+}
+
+#[test]
+fn test_check_branch_consistency_ignores_other_branches() {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture = GitRepoFixture::tagged("v2.0.0").expect("Failed to create git repository");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("check --branch-consistency")
+        .assert_success()
+        .assert_stdout_contains("Branch main does not name a version line");
+}
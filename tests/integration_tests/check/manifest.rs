@@ -0,0 +1,49 @@
+use rstest::rstest;
+use zerv::test_utils::TestDir;
+
+use super::TestCommand;
+
+fn npm_dir(version: &str) -> TestDir {
+    let dir = TestDir::new().expect("Failed to create test directory");
+    dir.create_file(
+        "package.json",
+        &format!("{{\"name\": \"demo\", \"version\": \"{version}\"}}"),
+    )
+    .expect("Failed to create manifest");
+    dir
+}
+
+#[rstest]
+#[case::npm("npm")]
+#[case::auto("auto")]
+fn test_check_manifest_matches(#[case] manifest: &str) {
+    let dir = npm_dir("1.2.3");
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str(format!("check v1.2.3 --manifest {manifest}"))
+        .assert_success()
+        .assert_stdout_contains("Manifest version matches: 1.2.3");
+}
+
+#[test]
+fn test_check_manifest_drift_fails() {
+    let dir = npm_dir("1.2.2");
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str("check 1.2.3 --manifest npm")
+        .assert_failure()
+        .assert_stderr_contains("Manifest version 1.2.2 does not match 1.2.3");
+}
+
+#[test]
+fn test_check_manifest_missing_fails() {
+    let dir = npm_dir("1.2.3");
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str("check 1.2.3 --manifest cargo")
+        .assert_failure()
+        .assert_stderr_contains("Cargo.toml not found");
+}
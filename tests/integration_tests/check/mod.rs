@@ -1,5 +1,8 @@
 pub mod auto_detect;
+pub mod branch_consistency;
 pub mod formats;
+pub mod manifest;
+pub mod prerelease;
 pub mod validation;
 
 use crate::util::TestCommand;
@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use super::TestCommand;
+
+#[rstest]
+#[case::semver_prerelease("1.2.3-rc.1", "--is-prerelease", true)]
+#[case::semver_release("1.2.3", "--is-prerelease", false)]
+#[case::pep440_prerelease("1.2.3b2", "--is-prerelease", true)]
+#[case::prerelease_only_alias("1.2.3-alpha.1", "--prerelease-only", true)]
+#[case::release_of_prerelease("1.2.3-rc.1", "--is-release", false)]
+#[case::release_of_release("1.2.3", "--is-release", true)]
+#[case::release_of_post("1.2.3.post1", "--is-release", true)]
+#[case::release_only_alias("1.2.3", "--release-only", true)]
+fn test_check_prerelease_flags(
+    #[case] version: &str,
+    #[case] flag: &str,
+    #[case] expected_ok: bool,
+) {
+    let mut command = TestCommand::new();
+    command.args(["check", version, flag]);
+    if expected_ok {
+        command.assert_success();
+    } else {
+        command
+            .assert_failure()
+            .assert_stderr_contains(&format!("{version} is"));
+    }
+}
+
+#[test]
+fn test_check_prerelease_flags_conflict() {
+    TestCommand::new()
+        .args(["check", "1.2.3", "--is-prerelease", "--is-release"])
+        .assert_failure()
+        .assert_stderr_contains("cannot be used with");
+}
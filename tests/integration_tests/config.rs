@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::Path;
+
+use rstest::{
+    fixture,
+    rstest,
+};
+use tempfile::TempDir;
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+const VERSION_ARGS: &str = "version --source none --tag-version 1.2.3-rc.1";
+
+/// Temp dirs holding the global config home and the repo working directory
+#[fixture]
+fn config_dirs() -> (TempDir, TempDir) {
+    (TempDir::new().unwrap(), TempDir::new().unwrap())
+}
+
+fn write_global_config(config_home: &Path, content: &str) {
+    let dir = config_home.join("zerv");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("config.toml"), content).unwrap();
+}
+
+fn run_version(config_home: &Path, repo_dir: &Path, extra_args: &str) -> String {
+    TestCommand::new()
+        .env("XDG_CONFIG_HOME", config_home)
+        .current_dir(repo_dir)
+        .args_from_str(format!("{VERSION_ARGS} {extra_args}"))
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string()
+}
+
+#[rstest]
+fn test_global_config_used_when_repo_config_omits_key(config_dirs: (TempDir, TempDir)) {
+    let (config_home, repo_dir) = config_dirs;
+    write_global_config(
+        config_home.path(),
+        "[version]\noutput-format = \"pep440\"\n",
+    );
+    fs::write(
+        repo_dir.path().join("zerv.toml"),
+        "[version]\noutput-prefix = \"v\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        run_version(config_home.path(), repo_dir.path(), ""),
+        "v1.2.3rc1"
+    );
+}
+
+#[rstest]
+fn test_repo_config_overrides_global_config(config_dirs: (TempDir, TempDir)) {
+    let (config_home, repo_dir) = config_dirs;
+    write_global_config(
+        config_home.path(),
+        "[version]\noutput-format = \"pep440\"\n",
+    );
+    fs::write(
+        repo_dir.path().join("zerv.toml"),
+        "[version]\noutput-format = \"semver\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        run_version(config_home.path(), repo_dir.path(), ""),
+        "1.2.3-rc.1"
+    );
+}
+
+#[rstest]
+fn test_cli_overrides_config(config_dirs: (TempDir, TempDir)) {
+    let (config_home, repo_dir) = config_dirs;
+    write_global_config(
+        config_home.path(),
+        "[version]\noutput-format = \"pep440\"\n",
+    );
+
+    assert_eq!(
+        run_version(
+            config_home.path(),
+            repo_dir.path(),
+            "--output-format semver"
+        ),
+        "1.2.3-rc.1"
+    );
+}
+
+#[rstest]
+fn test_invalid_global_config_fails(config_dirs: (TempDir, TempDir)) {
+    let (config_home, repo_dir) = config_dirs;
+    write_global_config(config_home.path(), "[version\n");
+
+    let output = TestCommand::new()
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .current_dir(repo_dir.path())
+        .args_from_str(VERSION_ARGS)
+        .assert_failure();
+
+    assert!(
+        output.stderr().contains("Failed to parse"),
+        "unexpected stderr: {}",
+        output.stderr()
+    );
+}
+
+#[rstest]
+#[case::conflicting_option(
+    "schema = \"calver\"",
+    "--schema-ron '(core: [var(Major)], extra_core: [], build: [])'",
+    "1.0.0"
+)]
+#[case::declared_conflict("fail-on-dirty = true", "--no-fail-on-dirty", "1.2.3-rc.1")]
+#[case::short_flag("input-format = \"pep440\"", "-f semver", "1.2.3-rc.1")]
+fn test_cli_overrides_conflicting_config(
+    config_dirs: (TempDir, TempDir),
+    #[case] config: &str,
+    #[case] extra_args: &str,
+    #[case] expected: &str,
+) {
+    let (config_home, repo_dir) = config_dirs;
+    fs::write(
+        repo_dir.path().join("zerv.toml"),
+        format!("[version]\n{config}\n"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        run_version(config_home.path(), repo_dir.path(), extra_args),
+        expected
+    );
+}
+
+#[rstest]
+#[case::long("--directory")]
+#[case::short("-C")]
+fn test_repo_config_read_from_directory(config_dirs: (TempDir, TempDir), #[case] flag: &str) {
+    let (config_home, work_dir) = config_dirs;
+    let target_repo = TempDir::new().unwrap();
+    fs::write(
+        target_repo.path().join("zerv.toml"),
+        "[version]\noutput-format = \"pep440\"\n",
+    )
+    .unwrap();
+    fs::write(
+        work_dir.path().join("zerv.toml"),
+        "[version]\noutput-format = \"semver\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        run_version(
+            config_home.path(),
+            work_dir.path(),
+            &format!("{flag} {}", target_repo.path().display())
+        ),
+        "1.2.3rc1"
+    );
+}
+
+#[rstest]
+#[case::root("")]
+#[case::subdirectory("sub")]
+#[case::nested_subdirectory("sub/nested")]
+fn test_repo_config_read_from_repository_root(#[case] subdirectory: &str) {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+    fs::write(
+        fixture.path().join("zerv.toml"),
+        "[version]\noutput-format = \"pep440\"\nschema = \"standard-base\"\n",
+    )
+    .unwrap();
+    let fixture = fixture
+        .commit("Add config")
+        .create_tag("v1.2.3")
+        .commit("Feature work");
+    let work_dir = fixture.path().join(subdirectory);
+    fs::create_dir_all(&work_dir).unwrap();
+    let config_home = TempDir::new().unwrap();
+
+    let output = TestCommand::new()
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .current_dir(&work_dir)
+        .args_from_str("version --source git")
+        .assert_success();
+    assert_eq!(output.stdout().trim(), "1.2.3");
+}
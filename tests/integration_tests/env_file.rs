@@ -0,0 +1,75 @@
+use std::fs;
+
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::util::TestCommand;
+
+fn run_with_env_file(content: &str, args: &str) -> String {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("ci.env");
+    fs::write(&path, content).unwrap();
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str(format!("{args} --env-file ci.env"))
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string()
+}
+
+#[rstest]
+#[case::override_value(
+    "ZERV_TAG_VERSION=1.2.3\nZERV_DISTANCE=4\n",
+    "version --source none",
+    "1.2.3+4"
+)]
+#[case::quoted_value("ZERV_TAG_VERSION=\"2.0.0\"\n", "version --source none", "2.0.0")]
+#[case::flow("ZERV_TAG_VERSION=3.1.0\n", "flow --source none", "3.1.0")]
+#[case::cli_wins(
+    "ZERV_TAG_VERSION=1.2.3\n",
+    "version --source none --tag-version 9.9.9",
+    "9.9.9"
+)]
+#[case::unrelated_vars_ignored(
+    "ZERV_TAG_VERSION=1.0.0\nZERV_FORCE_RUST_LOG_OFF=1\nHOME_DIR=/x\n",
+    "version --source none",
+    "1.0.0"
+)]
+fn test_env_file_populates_overrides(
+    #[case] content: &str,
+    #[case] args: &str,
+    #[case] expected: &str,
+) {
+    assert_eq!(run_with_env_file(content, args), expected);
+}
+
+#[rstest]
+#[case::separate("--env-file ci.env version --source none")]
+#[case::equals("--env-file=ci.env version --source none")]
+fn test_env_file_before_subcommand(#[case] args: &str) {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("ci.env"),
+        "ZERV_TAG_VERSION=1.2.3\nZERV_DISTANCE=4\n",
+    )
+    .unwrap();
+
+    let output = TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str(args)
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string();
+    assert_eq!(output, "1.2.3+4");
+}
+
+#[test]
+fn test_env_file_missing() {
+    TestCommand::new()
+        .args_from_str("version --source none --tag-version 1.0.0 --env-file /nonexistent/ci.env")
+        .assert_failure()
+        .assert_stderr_contains("Failed to read env file");
+}
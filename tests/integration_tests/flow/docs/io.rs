@@ -50,7 +50,7 @@ fn test_io_documentation_examples() {
     let dirty_feature_branch_scenario = dirty_feature_branch_scenario.assert_commands(
         &[
             "flow --source stdin --output-format zerv",
-            "version --source stdin --major 4 --output-format semver",
+            "version --source stdin --major 4 --keep-prerelease --output-format semver",
         ],
         "4.0.1-alpha.10192.post.1.dev.{timestamp:now}+branch.name.1.g{hex:7}",
     );
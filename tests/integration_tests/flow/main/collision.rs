@@ -0,0 +1,71 @@
+// Pre-release collision safeguard tests for flow command
+
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+fn run_flow_on_feature_branch(extra_args: &str) -> String {
+    let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+
+    // Tag the would-be version on a branch that is not reachable from the feature branch
+    fixture
+        .create_branch("other")
+        .expect("Failed to create other branch");
+    fixture
+        .checkout_branch("other")
+        .expect("Failed to checkout other branch");
+    let fixture = fixture.commit("Other commit").create_tag("v1.0.1-alpha.5");
+
+    fixture
+        .checkout_branch("main")
+        .expect("Failed to checkout main");
+    fixture
+        .create_branch("feature/test")
+        .expect("Failed to create feature branch");
+    fixture
+        .checkout_branch("feature/test")
+        .expect("Failed to checkout feature branch");
+    let fixture = fixture.commit("Feature commit");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(format!(
+            "flow --source git --pre-release-num 5 --schema standard-base-prerelease --output-format semver {extra_args}"
+        ))
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn test_flow_without_collision_flag_keeps_number() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    assert_eq!(run_flow_on_feature_branch(""), "1.0.1-alpha.5");
+}
+
+#[test]
+fn test_flow_collision_flag_bumps_number() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    assert_eq!(
+        run_flow_on_feature_branch("--prerelease-increment-on-collision"),
+        "1.0.1-alpha.6"
+    );
+}
+
+#[test]
+fn test_flow_collision_flag_requires_git_source() {
+    TestCommand::new()
+        .args_from_str("flow --source none --tag-version 1.0.0 --prerelease-increment-on-collision")
+        .assert_failure()
+        .assert_stderr_contains("--prerelease-increment-on-collision");
+}
@@ -1,6 +1,7 @@
 // Main flow command integration tests
 
 pub mod basic_commands;
+pub mod collision;
 pub mod error_handling;
 pub mod output_formats;
 pub mod r#override;
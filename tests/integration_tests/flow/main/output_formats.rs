@@ -1,7 +1,11 @@
 // Output format tests for flow command using stdin input
 
 use rstest::rstest;
-use zerv::test_utils::ZervFixture;
+use zerv::test_utils::{
+    GitRepoFixture,
+    ZervFixture,
+    should_run_docker_tests,
+};
 
 use crate::util::TestCommand;
 
@@ -35,3 +39,47 @@ fn test_flow_command_invalid_output_format() {
         output
     );
 }
+
+#[rstest]
+#[case::core("v{{ major }}.{{ minor }}.{{ patch }}", "v1.2.3")]
+#[case::semver_var("{{ semver }}", "1.2.3")]
+#[case::base_part("{{ semver_obj.base_part }}-final", "1.2.3-final")]
+fn test_flow_command_output_template(#[case] template: &str, #[case] expected: &str) {
+    let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+
+    let output = TestCommand::run_with_stdin(
+        &format!("flow --source stdin --schema standard --output-template '{template}'"),
+        zerv_ron,
+    );
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_flow_command_output_template_uses_flow_resolved_values() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+    fixture
+        .create_branch("develop")
+        .expect("Failed to create develop branch");
+    fixture
+        .checkout_branch("develop")
+        .expect("Failed to checkout develop branch");
+    let fixture = fixture.commit("Develop commit");
+
+    // The develop branch rule turns the pre-release label into beta
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(
+            "flow --source git --output-template '{{ major }}.{{ minor }}.{{ patch }}-{{ pre_release.label }}@{{ bumped_branch }}'",
+        )
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string();
+
+    assert_eq!(output, "1.0.1-beta@develop");
+}
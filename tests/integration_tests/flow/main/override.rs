@@ -2,7 +2,11 @@
 // Tests for override functionality in flow command, especially post override
 
 use rstest::rstest;
-use zerv::test_utils::ZervFixture;
+use zerv::test_utils::{
+    GitRepoFixture,
+    ZervFixture,
+    should_run_docker_tests,
+};
 use zerv::version::PreReleaseLabel;
 
 use crate::util::TestCommand;
@@ -191,3 +195,124 @@ fn test_post_override_precedence_over_defaults() {
 
     assert_eq!(output, "1.2.3-post.123");
 }
+
+#[rstest]
+#[case::semver("semver", "1.2.4-alpha.20231221.post.2+feature.test.2.gabc1234")]
+#[case::pep440("pep440", "1.2.4a20231221.post2+feature.test.2.gabc1234")]
+fn test_prerelease_date_replaces_branch_hash(#[case] format: &str, #[case] expected: &str) {
+    // 2023-12-21 00:00:00 UTC
+    let output = TestCommand::run(&format!(
+        "flow --source none --tag-version 1.2.3 --distance 2 --bumped-branch feature/test \
+         --bumped-commit-hash gabc1234 --bumped-timestamp 1703116800 --prerelease-date \
+         --output-format {format}"
+    ));
+
+    assert_eq!(output, expected);
+}
+
+const CHANNEL_RULES: &str = r#"[(pattern: "main", pre_release_label: rc, pre_release_num: 1, post_mode: commit, channel: "stable"), (pattern: "*", pre_release_label: alpha, post_mode: commit, channel: "nightly")]"#;
+
+#[rstest]
+#[case::main("main", "", "stable")]
+#[case::feature("feature/test", "", "nightly")]
+#[case::develop("develop", "", "nightly")]
+#[case::explicit("main", "--prerelease-channel canary", "canary")]
+fn test_branch_rule_channel(#[case] branch: &str, #[case] flags: &str, #[case] expected: &str) {
+    let output = TestCommand::run(&format!(
+        "flow --source none --tag-version 1.2.3 --distance 2 --bumped-branch {branch} \
+         --branch-rules '{CHANNEL_RULES}' {flags} --output-template '{{{{ custom.channel }}}}'"
+    ));
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_branch_rule_channel_keeps_custom_vars() {
+    let mut zerv = ZervFixture::new()
+        .with_version(1, 2, 3)
+        .with_branch("main".to_string())
+        .build();
+    zerv.vars.custom = serde_json::json!({ "team": "core" });
+    let zerv_ron = zerv.to_string();
+
+    let output = TestCommand::run_with_stdin(
+        &format!(
+            "flow --source stdin --branch-rules '{CHANNEL_RULES}' \
+             --output-template '{{{{ custom.team }}}}-{{{{ custom.channel }}}}'"
+        ),
+        zerv_ron,
+    );
+
+    assert_eq!(output, "core-stable");
+}
+
+#[test]
+fn test_prerelease_num_source_commit_count() {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture =
+        GitRepoFixture::with_distance("v1.2.3", 2).expect("Failed to create git repository");
+
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(
+            "flow --source git --prerelease-num-source commit-count \
+             --schema standard-base-prerelease --output-format semver",
+        )
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string();
+
+    // The tagged initial commit and two commits past the tag
+    assert_eq!(output, "1.2.4-alpha.3");
+}
+
+#[test]
+fn test_prerelease_num_source_commit_count_requires_git() {
+    TestCommand::new()
+        .args_from_str(
+            "flow --source none --tag-version 1.2.3 --distance 2 --prerelease-num-source commit-count",
+        )
+        .assert_failure()
+        .assert_stderr_contains("--prerelease-num-source commit-count requires a commit count");
+}
+
+#[rstest]
+#[case::fnv("fnv", "1.0.1-alpha.57644")]
+#[case::sha256("sha256", "1.0.1-alpha.16103")]
+#[case::crc32("crc32", "1.0.1-alpha.17524")]
+fn test_hash_algo_selects_branch_number(#[case] algo: &str, #[case] expected: &str) {
+    if !should_run_docker_tests() {
+        return;
+    }
+    let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+    fixture
+        .create_branch("feature/login")
+        .expect("Failed to create feature branch");
+    fixture
+        .checkout_branch("feature/login")
+        .expect("Failed to checkout feature branch");
+    let fixture = fixture.commit("Feature commit");
+
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(format!(
+            "flow --source git --hash-algo {algo} --schema standard-base-prerelease --output-format semver"
+        ))
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_hash_algo_rejects_unknown_algo() {
+    TestCommand::new()
+        .args_from_str("flow --source none --tag-version 1.0.0 --hash-algo md5")
+        .assert_failure()
+        .assert_stderr_contains("invalid value 'md5'");
+}
@@ -118,3 +118,44 @@ fn test_flow_command_schema_ron_invalid() {
         output
     );
 }
+
+#[rstest]
+#[case::base_prerelease("base,prerelease", "standard-base-prerelease")]
+#[case::full_context(
+    "base,prerelease,post,dev,context",
+    "standard-base-prerelease-post-dev-context"
+)]
+fn test_flow_command_schema_flags(#[case] flags: &str, #[case] preset: &str) {
+    let zerv_ron = ZervFixture::new()
+        .with_version(1, 2, 3)
+        .with_branch("feature/flags".to_string())
+        .with_distance(2)
+        .build()
+        .to_string();
+
+    let from_flags = TestCommand::run_with_stdin(
+        &format!("flow --source stdin --schema-flags {flags} --output-format semver"),
+        zerv_ron.clone(),
+    );
+    let from_preset = TestCommand::run_with_stdin(
+        &format!("flow --source stdin --schema {preset} --output-format semver"),
+        zerv_ron,
+    );
+
+    assert_eq!(from_flags, from_preset);
+}
+
+#[test]
+fn test_flow_command_schema_flags_rejects_calver() {
+    let zerv_ron = ZervFixture::new().with_version(1, 0, 0).build().to_string();
+
+    let output = TestCommand::run_with_stdin_expect_fail(
+        "flow --source stdin --schema-flags calver,base --output-format semver",
+        zerv_ron,
+    );
+
+    assert!(
+        output.contains("only supports standard schema variants"),
+        "{output}"
+    );
+}
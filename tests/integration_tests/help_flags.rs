@@ -121,7 +121,7 @@ fn test_version_command_help() {
         "Should show input format values"
     );
     assert!(
-        stdout.contains("[possible values: semver, pep440, zerv]"),
+        stdout.contains("[possible values: semver, pep440, zerv, env, properties]"),
         "Should show output format values"
     );
 }
@@ -185,6 +185,37 @@ fn test_llm_help_flag(#[case] pager_env: Option<&str>, #[case] description: &str
     }
 }
 
+#[test]
+fn test_dump_cli_json() {
+    let stdout = TestCommand::new()
+        .args(["--dump-cli", "json"])
+        .assert_success()
+        .stdout();
+    let manifest: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let version = manifest["subcommands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|command| command["name"] == "version")
+        .expect("Should list the version subcommand");
+    assert!(
+        version["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|arg| arg["long"] == "schema"),
+        "Should list --schema under version: {version}"
+    );
+}
+
+#[test]
+fn test_dump_cli_rejects_unknown_format() {
+    TestCommand::new()
+        .args(["--dump-cli", "yaml"])
+        .assert_failure();
+}
+
 #[test]
 fn test_invalid_command_shows_help() {
     let test_output = TestCommand::new().arg("invalid-command").assert_failure();
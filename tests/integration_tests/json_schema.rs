@@ -0,0 +1,39 @@
+use zerv::version::Zerv;
+
+use crate::util::TestCommand;
+
+fn json_schema_validator() -> jsonschema::Validator {
+    let output = TestCommand::new()
+        .args_from_str("json-schema")
+        .assert_success();
+    let schema: serde_json::Value =
+        serde_json::from_str(output.stdout().trim()).expect("Output should be JSON");
+    jsonschema::validator_for(&schema).expect("Output should be a valid JSON Schema")
+}
+
+#[test]
+fn test_json_schema_validates_zerv_output() {
+    let validator = json_schema_validator();
+
+    for args in [
+        "version --source none --tag-version 1.2.3 --output-format zerv",
+        "version --source none --tag-version 2.0.0-rc.1 --distance 3 --dirty --bumped-branch main --output-format zerv",
+        "version --source none --tag-version 1.0.0 --schema calver --output-format zerv",
+    ] {
+        let output = TestCommand::new().args_from_str(args).assert_success();
+        let zerv: Zerv = ron::from_str(output.stdout().trim()).expect("Failed to parse Zerv");
+        let value = serde_json::to_value(&zerv).unwrap();
+
+        let errors: Vec<String> = validator
+            .iter_errors(&value)
+            .map(|e| e.to_string())
+            .collect();
+        assert!(errors.is_empty(), "{args}: {errors:?}");
+    }
+}
+
+#[test]
+fn test_json_schema_rejects_malformed_zerv() {
+    let validator = json_schema_validator();
+    assert!(!validator.is_valid(&serde_json::json!({ "schema": "1.2.3" })));
+}
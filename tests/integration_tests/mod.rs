@@ -1,7 +1,17 @@
+pub mod changelog_range;
 pub mod check;
+pub mod config;
+pub mod env_file;
 pub mod flow;
 pub mod help_flags;
+pub mod json_schema;
 pub mod logging;
 pub mod render;
+pub mod report;
+pub mod selftest;
+pub mod sort;
 pub mod util;
+pub mod validate_tag;
+pub mod vars;
+pub mod vcs_data;
 pub mod version;
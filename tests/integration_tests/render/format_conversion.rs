@@ -42,6 +42,21 @@ mod semver_to_pep440 {
         ));
         assert_eq!(output, expected);
     }
+
+    // Label-less pre-releases have no PEP440 equivalent: they render as alpha so they still
+    // sort before the release, a leading number becomes the alpha number and the remaining
+    // identifiers move to the local segment
+    #[rstest]
+    #[case::numeric("1.0.0-1", "1.0.0a1")]
+    #[case::text("1.0.0-x.7.z.92", "1.0.0a0+x.7.z.92")]
+    #[case::leading_number("1.0.0-0.3.7", "1.0.0a0+3.7")]
+    #[case::with_build("1.0.0-x.7+build", "1.0.0a0+x.7.build")]
+    fn test_raw_prerelease(#[case] input: &str, #[case] expected: &str) {
+        let output = TestCommand::run(&format!(
+            "render {input} --input-format semver --output-format pep440"
+        ));
+        assert_eq!(output, expected);
+    }
 }
 
 mod pep440_to_semver {
@@ -172,3 +187,57 @@ mod prefix {
         assert_eq!(output, expected);
     }
 }
+
+mod strict_pep440 {
+    use super::*;
+
+    #[rstest]
+    #[case("1.2.3-alpha.1+build.5", "1.2.3a1+build.5")]
+    #[case("2!1.0.0.post1", "2!1.0.0.post1")]
+    fn test_strict_pep440_valid(#[case] input: &str, #[case] expected: &str) {
+        let output = TestCommand::run(&format!(
+            "render {input} --output-format pep440 --strict-pep440"
+        ));
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_strict_pep440_with_output_prefix() {
+        let output = TestCommand::run(
+            "render 1.2.3-rc.1 --output-format pep440 --output-prefix v --strict-pep440",
+        );
+        assert_eq!(output, "v1.2.3rc1");
+    }
+
+    #[test]
+    fn test_strict_pep440_malformed_local_segment() {
+        let output = TestCommand::run_expect_fail(
+            "render 1.2.3 --output-template '{{pep440}}+Feature_Branch' --strict-pep440",
+        );
+        assert!(
+            output.contains("Invalid PEP440 local segment 'Feature_Branch'"),
+            "unexpected stderr: {output}"
+        );
+    }
+
+    #[test]
+    fn test_strict_pep440_requires_pep440_output() {
+        let output = TestCommand::run_expect_fail("render 1.2.3 --strict-pep440");
+        assert!(output.contains("--strict-pep440 requires"), "{output}");
+    }
+}
+
+mod strip_context {
+    use super::*;
+
+    #[rstest]
+    #[case("render 1.2.3+main.5.gabc --strip-context", "1.2.3")]
+    #[case("render 1.2.3+main.5.gabc", "1.2.3+main.5.gabc")]
+    #[case(
+        "render 1.2.3a1+main.5 --input-format pep440 --output-format pep440 --strip-context",
+        "1.2.3a1"
+    )]
+    fn test_strip_context(#[case] args: &str, #[case] expected: &str) {
+        assert_eq!(TestCommand::run(args), expected);
+    }
+}
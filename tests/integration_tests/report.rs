@@ -0,0 +1,63 @@
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+#[test]
+fn test_report_mixes_repos_and_non_repos() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let tagged = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+    let ahead =
+        GitRepoFixture::with_distance("v2.0.0", 2).expect("Failed to create git repository");
+    let not_a_repo = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let paths = [
+        tagged.path().to_string_lossy().to_string(),
+        not_a_repo.path().to_string_lossy().to_string(),
+        ahead.path().to_string_lossy().to_string(),
+    ];
+
+    let output = TestCommand::new()
+        .arg("report")
+        .args(paths.iter().map(String::as_str))
+        .assert_success();
+    let entries: serde_json::Value =
+        serde_json::from_str(output.stdout().trim()).expect("Output should be JSON");
+    let entries = entries.as_array().expect("Output should be a JSON array");
+
+    assert_eq!(entries.len(), 3);
+    for (entry, path) in entries.iter().zip(&paths) {
+        assert_eq!(entry["path"], path.as_str());
+    }
+
+    assert_eq!(entries[0]["version"], "1.2.3");
+    assert_eq!(entries[0]["tag"], "v1.2.3");
+    assert_eq!(entries[0]["distance"], 0);
+    assert_eq!(entries[0]["dirty"], false);
+    assert!(entries[0].get("error").is_none());
+
+    assert!(entries[1]["version"].is_null());
+    assert!(
+        entries[1]["error"]
+            .as_str()
+            .is_some_and(|error| error.contains("Not in a git repository")),
+        "entry: {}",
+        entries[1]
+    );
+
+    assert_eq!(entries[2]["tag"], "v2.0.0");
+    assert_eq!(entries[2]["distance"], 2);
+    assert!(entries[2]["version"].is_string());
+}
+
+#[test]
+fn test_report_requires_a_path() {
+    TestCommand::new()
+        .arg("report")
+        .assert_failure()
+        .assert_stderr_contains("<DIR>");
+}
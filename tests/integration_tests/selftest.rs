@@ -0,0 +1,10 @@
+use crate::util::TestCommand;
+
+#[test]
+fn test_selftest_passes_on_sample_versions() {
+    let output = TestCommand::new().arg("selftest").assert_success();
+    assert_eq!(
+        output.stdout().trim(),
+        "✓ 22 versions round-trip through Zerv (SemVer and PEP440)"
+    );
+}
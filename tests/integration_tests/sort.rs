@@ -0,0 +1,61 @@
+// Integration tests for the sort, max and min commands
+
+use rstest::rstest;
+
+use crate::util::TestCommand;
+
+#[rstest]
+#[case::semver(
+    "sort --input-format semver",
+    "2.0.0\n1.0.0-rc.1\n1.10.0\n1.0.0\n1.0.0-alpha.2\n1.2.0",
+    "1.0.0-alpha.2\n1.0.0-rc.1\n1.0.0\n1.2.0\n1.10.0\n2.0.0"
+)]
+#[case::pep440(
+    "sort --input-format pep440",
+    "1.0.0.post1\n2.0.0\n1.0.0a1\n1.0.0\n1.0.0.post1.dev3\n1.0.0rc2",
+    "1.0.0a1\n1.0.0rc2\n1.0.0\n1.0.0.post1.dev3\n1.0.0.post1\n2.0.0"
+)]
+#[case::semver_reverse(
+    "sort --reverse",
+    "1.0.0\n3.0.0-beta.1\n2.5.0",
+    "3.0.0-beta.1\n2.5.0\n1.0.0"
+)]
+fn test_sort_shuffled_versions(#[case] args: &str, #[case] input: &str, #[case] expected: &str) {
+    let output = TestCommand::run_with_stdin(args, input.to_string());
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_sort_mixed_formats_require_input_format() {
+    let stderr =
+        TestCommand::run_with_stdin_expect_fail("sort", "1.0.0-alpha.beta\n1!1.0.0".to_string());
+    assert!(
+        stderr.contains("--input-format"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+#[rstest]
+#[case::max("max", "1.0.0-rc.1\n0.9.0\n1.0.0\n1.0.0-beta.2", "1.0.0")]
+#[case::min("min", "1.0.0-rc.1\n1.0.0\n1.0.0-beta.2", "1.0.0-beta.2")]
+#[case::max_single("max", "3.1.4", "3.1.4")]
+#[case::min_pep440(
+    "min --input-format pep440",
+    "1.0.0\n1.0.0rc1\n1.0.0.post1",
+    "1.0.0rc1"
+)]
+fn test_max_min(#[case] args: &str, #[case] input: &str, #[case] expected: &str) {
+    let output = TestCommand::run_with_stdin(args, input.to_string());
+    assert_eq!(output, expected);
+}
+
+#[rstest]
+#[case("max")]
+#[case("min")]
+fn test_max_min_empty_input_fails(#[case] command: &str) {
+    TestCommand::new()
+        .arg(command)
+        .stdin("\n".to_string())
+        .assert_failure()
+        .assert_stderr_contains("No versions provided");
+}
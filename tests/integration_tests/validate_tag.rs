@@ -0,0 +1,74 @@
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+#[test]
+fn test_validate_tag_accepts_greater_tag() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("validate-tag v1.3.0")
+        .assert_success();
+    assert_eq!(
+        output.stdout().trim(),
+        "✓ Tag v1.3.0 is valid (latest: v1.2.3)"
+    );
+}
+
+#[test]
+fn test_validate_tag_rejects_lower_tag() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+
+    for tag in ["v1.2.0", "v1.2.3"] {
+        TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!("validate-tag {tag}"))
+            .assert_failure()
+            .assert_stderr_contains(&format!(
+                "Tag '{tag}' is not greater than the latest version tag 'v1.2.3'"
+            ));
+    }
+}
+
+#[test]
+fn test_validate_tag_rejects_schema_mismatch() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+
+    TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("validate-tag v1.3.0-alpha.1 --input-format semver --schema standard-base")
+        .assert_failure()
+        .assert_stderr_contains("does not match schema 'standard-base'");
+}
+
+#[test]
+fn test_validate_tag_rejects_malformed_tag() {
+    TestCommand::new()
+        .args_from_str("validate-tag not-a-version")
+        .assert_failure()
+        .assert_stderr_contains("Tag 'not-a-version' is not a valid version");
+}
+
+#[test]
+fn test_validate_tag_requires_git_source() {
+    TestCommand::new()
+        .args_from_str("validate-tag v1.0.0 --source none")
+        .assert_failure()
+        .assert_stderr_contains("validate-tag requires --source git");
+}
@@ -0,0 +1,38 @@
+use rstest::rstest;
+
+use crate::util::TestCommand;
+
+#[rstest]
+#[case::major("major", "var(Major)")]
+#[case::distance("distance", "var(Distance)")]
+#[case::bumped_branch("bumped_branch", "var(BumpedBranch)")]
+#[case::commit_hash_short("bumped_commit_hash_short", "var(BumpedCommitHashShort)")]
+#[case::build_number("build_number", "var(BuildNumber)")]
+fn test_vars_lists_variable(#[case] template_name: &str, #[case] schema_name: &str) {
+    let output = TestCommand::new().arg("vars").assert_success();
+    let stdout = output.stdout();
+
+    assert!(
+        stdout.lines().any(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            columns.first() == Some(&template_name) && columns.get(1) == Some(&schema_name)
+        }),
+        "missing {template_name} in:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_vars_header() {
+    let output = TestCommand::new().arg("vars").assert_success();
+    let header = output
+        .stdout()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    assert_eq!(
+        header.split_whitespace().collect::<Vec<_>>(),
+        ["TEMPLATE", "SCHEMA", "SAMPLE"]
+    );
+}
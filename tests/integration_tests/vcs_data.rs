@@ -0,0 +1,56 @@
+use zerv::test_utils::{
+    GitRepoFixture,
+    should_run_docker_tests,
+};
+
+use crate::util::TestCommand;
+
+#[test]
+fn test_vcs_data_on_tagged_head() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+    let head = fixture
+        .git_impl
+        .execute_git(&fixture.test_dir, &["rev-parse", "HEAD"])
+        .expect("Failed to read HEAD")
+        .trim()
+        .to_string();
+
+    let output = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("vcs-data")
+        .assert_success();
+    let data: serde_json::Value =
+        serde_json::from_str(output.stdout().trim()).expect("Output should be JSON");
+
+    let fields = data.as_object().expect("Output should be a JSON object");
+    for field in [
+        "tag_version",
+        "distance",
+        "commit_hash",
+        "tag_commit_hash",
+        "commit_timestamp",
+        "tag_timestamp",
+        "is_dirty",
+    ] {
+        assert!(fields.contains_key(field), "missing {field} in {data}");
+    }
+    assert_eq!(data["tag_version"], "v1.2.3");
+    assert_eq!(data["tag_commit_hash"], head);
+    assert_eq!(data["commit_hash"], head);
+    assert_eq!(data["distance"], 0);
+    assert_eq!(data["is_dirty"], false);
+    assert!(data["tag_timestamp"].is_i64());
+    assert!(data["commit_timestamp"].is_i64());
+}
+
+#[test]
+fn test_vcs_data_requires_git_source() {
+    TestCommand::new()
+        .args_from_str("vcs-data --source none")
+        .assert_failure()
+        .assert_stderr_contains("vcs-data requires --source git");
+}
@@ -282,3 +282,39 @@ mod primary_combinations {
         assert!(output.contains("pre_release: None"));
     }
 }
+
+mod base_bump {
+    use super::*;
+
+    #[rstest]
+    #[case::on_tag(0, "--base-bump patch", "1.2.3")]
+    #[case::patch_after_tag(3, "--base-bump patch", "1.2.4")]
+    #[case::minor_after_tag(3, "--base-bump minor", "1.3.0")]
+    #[case::explicit_bump_wins(3, "--base-bump patch --bump-major", "2.0.0")]
+    fn test_base_bump_applies_after_tag(
+        #[case] distance: u64,
+        #[case] bump_args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = ZervFixture::new()
+            .with_version(1, 2, 3)
+            .with_distance(distance)
+            .build()
+            .to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --schema standard-base --output-format semver {bump_args}"
+            ),
+            zerv_ron,
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_base_bump_rejects_unknown_level() {
+        TestCommand::new()
+            .args_from_str("version --source none --tag-version 1.2.3 --base-bump post")
+            .assert_failure()
+            .assert_stderr_contains("invalid value 'post'");
+    }
+}
@@ -76,6 +76,25 @@ mod epoch_bump {
 
         assert_eq!(output.trim(), "2!0.0.0");
     }
+
+    #[rstest]
+    #[case::reset_all("all", "2!0.0.0")]
+    #[case::reset_none("none", "2!1.2.3b2.post2")]
+    fn test_bump_epoch_reset_mode(
+        full_secondary_fixture: ZervFixture,
+        #[case] mode: &str,
+        #[case] expected: &str,
+    ) {
+        let input = full_secondary_fixture.build().to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --bump-epoch --epoch-reset {mode} --output-format pep440"
+            ),
+            input,
+        );
+
+        assert_eq!(output.trim(), expected);
+    }
 }
 
 mod post_bump {
@@ -134,6 +153,35 @@ mod post_bump {
     }
 }
 
+mod max_component_value {
+    use super::*;
+
+    #[rstest]
+    #[case::within_max("10", "1.2.3a1.post5+5")]
+    #[case::exceeds_max("4", "")]
+    fn test_distance_derived_post_against_max(
+        base_zerv_fixture: ZervFixture,
+        #[case] max: &str,
+        #[case] expected: &str,
+    ) {
+        let input = base_zerv_fixture.with_distance(5).build().to_string();
+        let args = format!(
+            "version --source stdin --bump-post '{{{{ distance }}}}' --max-component-value {max} --output-format pep440"
+        );
+
+        if expected.is_empty() {
+            let stderr = TestCommand::run_with_stdin_expect_fail(&args, input);
+            assert!(
+                stderr.contains("post 5 exceeds --max-component-value 4"),
+                "Got: {stderr}"
+            );
+        } else {
+            let output = TestCommand::run_with_stdin(&args, input);
+            assert_eq!(output.trim(), expected);
+        }
+    }
+}
+
 mod dev_bump {
     use super::*;
 
@@ -314,6 +362,66 @@ mod pre_release_label_bump {
 
         assert_eq!(output.trim(), "1.2.3a0");
     }
+
+    #[rstest]
+    #[case::post_and_dev("post,dev", "post: None", "dev: None")]
+    #[case::dev_keeps_post("dev", "post: Some(2)", "dev: None")]
+    #[case::post_keeps_dev("post", "post: None", "dev: Some(3)")]
+    #[case::none_keeps_both("none", "post: Some(2)", "dev: Some(3)")]
+    fn test_bump_pre_release_label_with_resets(
+        full_secondary_fixture: ZervFixture,
+        #[case] resets: &str,
+        #[case] expected_post: &str,
+        #[case] expected_dev: &str,
+    ) {
+        let input = full_secondary_fixture.build().to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --bump-pre-release-label rc --prerelease-bump-resets {resets} --output-format zerv"
+            ),
+            input,
+        );
+
+        for expected in [expected_post, expected_dev] {
+            assert!(
+                output.contains(expected),
+                "Expected {expected} in output: {output}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::dev_keeps_post("dev", "1!1.2.3rc0.post2")]
+    #[case::post_resets_post("post", "1!1.2.3rc0")]
+    fn test_bump_pre_release_label_with_resets_pep440(
+        full_secondary_fixture: ZervFixture,
+        #[case] resets: &str,
+        #[case] expected: &str,
+    ) {
+        let input = full_secondary_fixture.build().to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --bump-pre-release-label rc --prerelease-bump-resets {resets} --output-format pep440"
+            ),
+            input,
+        );
+
+        assert_eq!(output.trim(), expected);
+    }
+
+    #[test]
+    fn test_prerelease_bump_resets_none_with_other_components_fails() {
+        let input = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        let stderr = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --bump-pre-release-label rc --prerelease-bump-resets none,post",
+            input,
+        );
+
+        assert!(
+            stderr.contains("--prerelease-bump-resets 'none' cannot be combined"),
+            "stderr: {stderr}"
+        );
+    }
 }
 
 mod secondary_combinations {
@@ -1,6 +1,10 @@
 use rstest::rstest;
 use zerv::schema::ZervSchemaPreset;
-use zerv::test_utils::ZervFixture;
+use zerv::test_utils::{
+    GitRepoFixture,
+    ZervFixture,
+    should_run_docker_tests,
+};
 use zerv::version::PreReleaseLabel;
 
 use crate::util::TestCommand;
@@ -252,3 +256,612 @@ mod format_roundtrip {
         assert_eq!(parsed_zerv, original_zerv);
     }
 }
+
+mod github_output {
+    //! Tests for appending the version to the GitHub Actions output file
+    use zerv::test_utils::TestDir;
+
+    use super::*;
+
+    #[test]
+    fn test_github_output_appends_version() {
+        let dir = TestDir::new().expect("Failed to create test directory");
+        let output_file = dir.path().join("github_output");
+        std::fs::write(&output_file, "previous=value\n").expect("Failed to seed output file");
+
+        let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        let output = TestCommand::new()
+            .args_from_str("version --source stdin --github-output version")
+            .env("GITHUB_OUTPUT", &output_file)
+            .stdin(zerv_ron)
+            .assert_success();
+
+        assert_eq!(output.stdout().trim(), "1.2.3");
+        let content = std::fs::read_to_string(&output_file).expect("Failed to read output file");
+        assert_eq!(content, "previous=value\nversion=1.2.3\n");
+    }
+
+    #[test]
+    fn test_github_output_multi_line_value_cannot_inject_outputs() {
+        let dir = TestDir::new().expect("Failed to create test directory");
+        let output_file = dir.path().join("github_output");
+
+        let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        TestCommand::new()
+            .args([
+                "version",
+                "--source",
+                "stdin",
+                "--github-output",
+                "v",
+                "--output-template",
+                "a\nZERV_EOF\nevil=1",
+            ])
+            .env("GITHUB_OUTPUT", &output_file)
+            .stdin(zerv_ron)
+            .assert_success();
+
+        let content = std::fs::read_to_string(&output_file).expect("Failed to read output file");
+        let delimiter = content
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("v<<"))
+            .expect("Expected a heredoc entry");
+        assert_ne!(delimiter, "ZERV_EOF");
+        assert_eq!(
+            content,
+            format!("v<<{delimiter}\na\nZERV_EOF\nevil=1\n{delimiter}\n")
+        );
+    }
+
+    #[test]
+    fn test_github_output_requires_env_var() {
+        let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        let output = TestCommand::new()
+            .args_from_str("version --source stdin --github-output version")
+            .env("GITHUB_OUTPUT", "")
+            .stdin(zerv_ron)
+            .assert_failure();
+
+        assert!(
+            output.stderr().contains("GITHUB_OUTPUT"),
+            "unexpected stderr: {}",
+            output.stderr()
+        );
+    }
+}
+
+mod context_separator {
+    //! Tests for re-joining build metadata / local segments with --context-separator
+    use super::*;
+
+    const CONTEXT_ARGS: &str = "version --source none --tag-version 1.2.3 --distance 5 --bumped-branch main --bumped-commit-hash gabc1234 --schema standard-base-context";
+
+    #[rstest]
+    #[case::semver_dot("semver", ".", "1.2.3+main.5.gabc1234")]
+    #[case::semver_dash("semver", "-", "1.2.3+main-5-gabc1234")]
+    #[case::pep440_dot("pep440", ".", "1.2.3+main.5.gabc1234")]
+    #[case::pep440_dash("pep440", "-", "1.2.3+main-5-gabc1234")]
+    fn test_context_separator(
+        #[case] format: &str,
+        #[case] separator: &str,
+        #[case] expected: &str,
+    ) {
+        let output = TestCommand::new()
+            .args_from_str(format!(
+                "{CONTEXT_ARGS} --output-format {format} --context-separator {separator}"
+            ))
+            .assert_success();
+
+        assert_eq!(output.stdout().trim(), expected);
+    }
+
+    #[test]
+    fn test_context_separator_rejected_for_format() {
+        TestCommand::new()
+            .args_from_str(format!(
+                "{CONTEXT_ARGS} --output-format semver --context-separator _"
+            ))
+            .assert_failure()
+            .assert_stderr_contains("not valid for semver output");
+    }
+}
+
+mod output_terminator {
+    //! Tests for the exact bytes written after the version by --output-terminator
+    use super::*;
+
+    #[rstest]
+    #[case::default("", b"1.2.3\n")]
+    #[case::newline("--output-terminator newline", b"1.2.3\n")]
+    #[case::none("--output-terminator none", b"1.2.3")]
+    #[case::null("--output-terminator null", b"1.2.3\0")]
+    fn test_output_terminator(#[case] flag: &str, #[case] expected: &[u8]) {
+        let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        let output = TestCommand::new()
+            .args_from_str(format!("version --source stdin {flag}"))
+            .stdin(zerv_ron)
+            .assert_success();
+
+        assert_eq!(output.stdout().as_bytes(), expected);
+    }
+
+    #[test]
+    fn test_output_terminator_flow() {
+        let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+        let output = TestCommand::new()
+            .args_from_str("flow --source stdin --output-terminator none")
+            .stdin(zerv_ron)
+            .assert_success();
+
+        assert!(!output.stdout().ends_with('\n'));
+    }
+
+    #[rstest]
+    #[case::default("", b"1.2.3\n")]
+    #[case::none("--output-terminator none", b"1.2.3")]
+    #[case::null("--output-terminator null", b"1.2.3\0")]
+    fn test_output_terminator_render(#[case] flag: &str, #[case] expected: &[u8]) {
+        let output = TestCommand::new()
+            .args_from_str(format!("render 1.2.3 {flag}"))
+            .assert_success();
+
+        assert_eq!(output.stdout().as_bytes(), expected);
+    }
+}
+
+mod prerelease_case {
+    //! Tests for output-only casing of the pre-release label
+    use super::*;
+
+    const RC_ARGS: &str =
+        "version --source none --tag-version 1.2.3-rc.2 --distance 1 --bumped-branch rc";
+
+    #[rstest]
+    #[case::lower_semver("lower", "semver", "1.2.3-rc.2+rc.1")]
+    #[case::upper_semver("upper", "semver", "1.2.3-RC.2+rc.1")]
+    #[case::title_semver("title", "semver", "1.2.3-Rc.2+rc.1")]
+    #[case::lower_pep440("lower", "pep440", "1.2.3rc2+rc.1")]
+    #[case::upper_pep440("upper", "pep440", "1.2.3RC2+rc.1")]
+    #[case::title_pep440("title", "pep440", "1.2.3Rc2+rc.1")]
+    fn test_prerelease_case(#[case] case: &str, #[case] format: &str, #[case] expected: &str) {
+        let output = TestCommand::run(&format!(
+            "{RC_ARGS} --prerelease-case {case} --output-format {format}"
+        ));
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    #[case::semver("1.2.3-RC.2", "semver", "1.2.3-rc.2")]
+    #[case::pep440("1.2.3RC2", "pep440", "1.2.3rc2")]
+    fn test_cased_output_reparses_to_same_version(
+        #[case] cased: &str,
+        #[case] format: &str,
+        #[case] expected: &str,
+    ) {
+        let output = TestCommand::run(&format!(
+            "render {cased} --input-format {format} --output-format {format}"
+        ));
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    #[case::zerv("--output-format zerv", "--output-format zerv")]
+    #[case::template("--output-template '{{ major }}'", "--output-template")]
+    fn test_prerelease_case_conflicts(#[case] args: &str, #[case] expected: &str) {
+        let output =
+            TestCommand::run_expect_fail(&format!("{RC_ARGS} --prerelease-case upper {args}"));
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod post_dev_label {
+    //! Tests for output-only spellings of the post and dev labels
+    use super::*;
+
+    const POST_DEV_ARGS: &str = "version --source none --tag-version 1.2.3.post4.dev5 --input-format pep440 --schema standard-base-prerelease-post-dev";
+
+    #[rstest]
+    #[case::pep440_default("pep440", "", "1.2.3.post4.dev5")]
+    #[case::pep440_r("pep440", "--post-label r", "1.2.3.r4.dev5")]
+    #[case::pep440_rev("pep440", "--post-label rev", "1.2.3.rev4.dev5")]
+    #[case::semver_default("semver", "", "1.2.3-post.4.dev.5")]
+    #[case::semver_custom(
+        "semver",
+        "--post-label build --dev-label snapshot",
+        "1.2.3-build.4.snapshot.5"
+    )]
+    fn test_post_dev_label(#[case] format: &str, #[case] flags: &str, #[case] expected: &str) {
+        let output = TestCommand::run(&format!("{POST_DEV_ARGS} --output-format {format} {flags}"));
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    #[case::r("1.2.3.r4.dev5")]
+    #[case::rev("1.2.3.rev4.dev5")]
+    fn test_pep440_post_label_round_trips(#[case] labeled: &str) {
+        let output = TestCommand::run(&format!(
+            "render {labeled} --input-format pep440 --output-format pep440"
+        ));
+        assert_eq!(output, "1.2.3.post4.dev5");
+    }
+
+    #[rstest]
+    #[case::pep440_post(
+        "--output-format pep440 --post-label build",
+        "not valid for pep440 output"
+    )]
+    #[case::pep440_dev(
+        "--output-format pep440 --dev-label snapshot",
+        "not valid for pep440 output"
+    )]
+    #[case::semver_dot(
+        "--output-format semver --post-label a.b",
+        "not valid for semver output"
+    )]
+    #[case::semver_numeric("--output-format semver --dev-label 42", "not valid for semver output")]
+    #[case::zerv("--output-format zerv --post-label r", "--output-format zerv")]
+    #[case::template("--output-template '{{ major }}' --post-label r", "--output-template")]
+    fn test_post_dev_label_rejected(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_expect_fail(&format!("{POST_DEV_ARGS} {args}"));
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod sbom {
+    //! Tests for the SPDX/CycloneDX version fragments
+    use super::*;
+
+    const SBOM_ARGS: &str = "version --source none --tag-version 1.2.3 --bumped-commit-hash gabc1234 --bumped-timestamp 1700000000";
+
+    fn run_sbom(args: &str) -> serde_json::Value {
+        let output = TestCommand::run(&format!("{SBOM_ARGS} {args}"));
+        serde_json::from_str(&output).unwrap_or_else(|e| panic!("{e}: {output}"))
+    }
+
+    #[test]
+    fn test_sbom_spdx() {
+        let json = run_sbom("--sbom spdx");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "versionInfo": "1.2.3",
+                "sourceInfo": "git commit abc1234",
+                "builtDate": "2023-11-14T22:13:20Z",
+            })
+        );
+    }
+
+    #[test]
+    fn test_sbom_cyclonedx() {
+        let json = run_sbom("--sbom cyclonedx");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "version": "1.2.3",
+                "pedigree": {
+                    "commits": [{
+                        "uid": "abc1234",
+                        "committer": { "timestamp": "2023-11-14T22:13:20Z" },
+                    }],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_sbom_uses_output_format() {
+        let output = TestCommand::run(
+            "version --source none --tag-version 1.2.3-rc.1 --output-format pep440 --sbom spdx",
+        );
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["versionInfo"], "1.2.3rc1");
+    }
+
+    #[rstest]
+    #[case::zerv("--output-format zerv", "--output-format zerv")]
+    #[case::template("--output-template '{{ major }}'", "--output-template")]
+    fn test_sbom_conflicts(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_expect_fail(&format!("{SBOM_ARGS} --sbom spdx {args}"));
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod field {
+    //! Tests for printing a single resolved field
+    use super::*;
+
+    fn field_fixture() -> String {
+        ZervFixture::new()
+            .with_version(1, 2, 3)
+            .with_vcs_data(
+                Some(4),
+                Some(false),
+                Some("main".to_string()),
+                Some("gabc1234def".to_string()),
+                None,
+                None,
+                None,
+            )
+            .build()
+            .to_string()
+    }
+
+    #[rstest]
+    #[case::major("major", "1")]
+    #[case::commit("commit", "gabc1234")]
+    #[case::distance("distance", "4")]
+    #[case::bumped_branch("bumped_branch", "main")]
+    #[case::unset("dev", "")]
+    fn test_field(#[case] field: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --field {field}"),
+            field_fixture(),
+        );
+        assert_eq!(output.trim_end_matches('\n'), expected);
+    }
+
+    #[rstest]
+    #[case::tag_version("tag-version", "v1.0.0")]
+    #[case::distance("distance", "2")]
+    fn test_field_anchor_tag_and_distance(#[case] field: &str, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.0.0", 2).expect("Failed to create git repository");
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!("version --source git --field {field}"))
+            .assert_success();
+        assert_eq!(output.stdout().trim_end_matches('\n'), expected);
+    }
+
+    #[rstest]
+    #[case::unknown("--field hash", "Unknown --field 'hash'")]
+    #[case::template("--field major --output-template '{{ major }}'", "--field")]
+    fn test_field_rejected(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin {args}"),
+            field_fixture(),
+        );
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod sanitizer_profile {
+    //! Tests for custom sanitizing of semver build metadata
+    use super::*;
+
+    fn branch_fixture() -> String {
+        ZervFixture::new()
+            .with_version(1, 2, 3)
+            .with_schema_preset(ZervSchemaPreset::StandardBaseContext)
+            .with_vcs_data(
+                Some(3),
+                Some(false),
+                Some("Feature/Log-in_v2".to_string()),
+                Some("gabc1234".to_string()),
+                None,
+                None,
+                None,
+            )
+            .build()
+            .to_string()
+    }
+
+    #[rstest]
+    #[case::default("", "1.2.3+Feature.Log.in.v2.3.gabc1234")]
+    #[case::uppercase_hyphen(
+        r#"--sanitizer-profile '(separator: "-")'"#,
+        "1.2.3+Feature-Log-in-v2.3.gabc1234"
+    )]
+    #[case::lowercase(
+        "--sanitizer-profile '(lowercase: true)'",
+        "1.2.3+feature.log.in.v2.3.gabc1234"
+    )]
+    // The allowed '-' survives while '/' and '_' are still replaced
+    #[case::allowed(
+        r#"--sanitizer-profile '(lowercase: true, allowed: "-")'"#,
+        "1.2.3+feature.log-in.v2.3.gabc1234"
+    )]
+    fn test_sanitizer_profile(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --output-format semver {args}"),
+            branch_fixture(),
+        );
+        assert_eq!(output.trim(), expected);
+    }
+
+    #[rstest]
+    #[case::unknown_field(r#"'(allow: "_")'"#, "Invalid sanitizer profile")]
+    #[case::underscore_separator(r#"'(separator: "_")'"#, "invalid in SemVer build metadata")]
+    #[case::keep_unchanged("'(separator: None)'", "invalid in SemVer build metadata")]
+    #[case::allowed_underscore(r#"'(allowed: "_")'"#, "invalid in SemVer build metadata")]
+    #[case::pep440(
+        r#"'(separator: "-")' --output-format pep440"#,
+        "--output-format pep440"
+    )]
+    #[case::template(
+        r#"'(separator: "-")' --output-template '{{ major }}'"#,
+        "--output-template"
+    )]
+    fn test_sanitizer_profile_invalid(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin --sanitizer-profile {args}"),
+            branch_fixture(),
+        );
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod env_format {
+    //! Tests for shell-quoted KEY='value' output
+    use std::process::Command;
+
+    use super::*;
+
+    fn env_output(args: &str) -> String {
+        let input = ZervFixture::new()
+            .with_version(1, 2, 3)
+            .with_vcs_data(
+                Some(4),
+                Some(true),
+                Some("main".to_string()),
+                Some("gabc1234def".to_string()),
+                None,
+                None,
+                None,
+            )
+            .build()
+            .to_string();
+        TestCommand::run_with_stdin(
+            &format!("version --source stdin --output-format env {args}"),
+            input,
+        )
+    }
+
+    #[test]
+    fn test_env_values_are_quoted() {
+        let output = env_output("--env-prefix APP_");
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "APP_VERSION='1.2.3'",
+                "APP_MAJOR='1'",
+                "APP_MINOR='2'",
+                "APP_PATCH='3'",
+                "APP_COMMIT='gabc1234'",
+                "APP_DISTANCE='4'",
+                "APP_DIRTY='true'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_env_output_is_sourceable() {
+        let script = format!(
+            "{}\nprintf '%s|%s|%s' \"$VERSION\" \"$COMMIT\" \"$DISTANCE\"",
+            env_output("")
+        );
+        let result = Command::new("sh")
+            .args(["-c", &script])
+            .output()
+            .expect("Failed to run sh");
+        assert!(result.status.success());
+        assert_eq!(String::from_utf8_lossy(&result.stdout), "1.2.3|gabc1234|4");
+    }
+
+    #[rstest]
+    #[case::prefix_without_env("--output-format semver --env-prefix APP_", "--env-prefix requires")]
+    #[case::invalid_prefix("--output-format env --env-prefix 1-APP", "--env-prefix '1-APP'")]
+    #[case::sbom("--output-format env --sbom spdx", "--output-format env")]
+    fn test_env_rejected(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_expect_fail(&format!(
+            "version --source none --tag-version 1.2.3 {args}"
+        ));
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod properties_format {
+    //! Tests for Java .properties key=value output
+    use super::*;
+
+    #[test]
+    fn test_properties_output() {
+        let input = ZervFixture::new()
+            .with_version(1, 2, 3)
+            .with_vcs_data(
+                Some(4),
+                Some(true),
+                Some("main".to_string()),
+                Some("gabc1234def".to_string()),
+                None,
+                None,
+                None,
+            )
+            .build()
+            .to_string();
+        let output =
+            TestCommand::run_with_stdin("version --source stdin --output-format properties", input);
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "version=1.2.3",
+                "major=1",
+                "minor=2",
+                "patch=3",
+                "commit=gabc1234",
+                "distance=4",
+                "dirty=true",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::sbom("--output-format properties --sbom spdx", "--output-format properties")]
+    #[case::env_prefix(
+        "--output-format properties --env-prefix APP_",
+        "--env-prefix requires"
+    )]
+    fn test_properties_rejected(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_expect_fail(&format!(
+            "version --source none --tag-version 1.2.3 {args}"
+        ));
+        assert!(output.contains(expected), "{output}");
+    }
+}
+
+mod omit_zero_prerelease_num {
+    //! Tests for rendering a zero pre-release number without the number
+    use super::*;
+
+    fn alpha_zero_fixture() -> String {
+        ZervFixture::new()
+            .with_version(1, 0, 0)
+            .with_pre_release(PreReleaseLabel::Alpha, Some(0))
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrerelease)
+            .build()
+            .to_string()
+    }
+
+    #[rstest]
+    #[case::without_flag("", "1.0.0-alpha.0")]
+    #[case::with_flag("--omit-zero-prerelease-num", "1.0.0-alpha")]
+    fn test_alpha_zero(#[case] flag: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --output-format semver {flag}"),
+            alpha_zero_fixture(),
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_keeps_nonzero_number() {
+        let zerv_ron = ZervFixture::new()
+            .with_version(1, 0, 0)
+            .with_pre_release(PreReleaseLabel::Alpha, Some(1))
+            .with_schema_preset(ZervSchemaPreset::StandardBasePrerelease)
+            .build()
+            .to_string();
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --output-format semver --omit-zero-prerelease-num",
+            zerv_ron,
+        );
+        assert_eq!(output, "1.0.0-alpha.1");
+    }
+
+    #[test]
+    fn test_rejected_for_pep440() {
+        let output = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --output-format pep440 --omit-zero-prerelease-num",
+            alpha_zero_fixture(),
+        );
+        assert!(
+            output.contains("--omit-zero-prerelease-num only applies to"),
+            "{output}"
+        );
+    }
+}
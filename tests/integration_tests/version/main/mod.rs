@@ -3,4 +3,6 @@ pub mod directory;
 pub mod formats;
 pub mod schemas;
 pub mod sources;
+pub mod strict;
 pub mod templates;
+pub mod transform;
@@ -282,6 +282,67 @@ mod schema_ron_custom {
     }
 }
 
+mod schema_patch {
+    //! Tests for merging a partial RON schema into the resolved schema
+    use super::*;
+
+    const BUILD_PATCH: &str = r#"(build: [var(BumpedBranch), str("ci")])"#;
+
+    #[rstest]
+    #[case::onto_preset("--schema standard-base", "1.0.0+feature.branch.ci")]
+    #[case::onto_piped_schema("", "1.0.0-alpha.1.dev.1234567890+feature.branch.ci")]
+    fn test_patch_build(
+        tier_3_fixture: ZervFixture,
+        #[case] schema_args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = tier_3_fixture
+            .with_pre_release(PreReleaseLabel::Alpha, Some(1))
+            .build()
+            .to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin {schema_args} --schema-patch '{BUILD_PATCH}'"),
+            zerv_ron,
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    fn test_patch_keeps_other_sections(tier_3_fixture: ZervFixture) {
+        let zerv_ron = tier_3_fixture.build().to_string();
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --schema standard-base --schema-patch '{BUILD_PATCH}' \
+                 --output-format zerv"
+            ),
+            zerv_ron,
+        );
+        let zerv: zerv::version::Zerv = output.parse().unwrap();
+        let base = ZervSchemaPreset::StandardBase.schema();
+        assert_eq!(zerv.schema.core(), base.core());
+        assert_eq!(zerv.schema.extra_core(), base.extra_core());
+        assert_ne!(zerv.schema.build(), base.build());
+    }
+
+    #[rstest]
+    #[case::schema_ron_conflict(
+        "--schema-ron '(core: [var(Major)], extra_core: [], build: [])' --schema-patch '(build: [])'",
+        "cannot be used with"
+    )]
+    #[case::invalid_patch("--schema-patch '(tail: [])'", "Invalid RON schema patch")]
+    #[case::empty_result(
+        "--schema-patch '(core: [], extra_core: [], build: [])'",
+        "at least one component"
+    )]
+    fn test_patch_errors(tier_1_fixture: ZervFixture, #[case] args: &str, #[case] expected: &str) {
+        let result = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin {args}"),
+            tier_1_fixture.build().to_string(),
+        );
+        assert!(result.contains(expected), "{result}");
+    }
+}
+
 mod schema_validation {
     //! Tests for schema validation and error handling
     use super::*;
@@ -312,7 +373,7 @@ mod schema_validation {
             zerv_ron,
         );
         assert!(
-            result.contains("Conflicting") || result.contains("both"),
+            result.contains("cannot be used with"),
             "Should show conflict error when both schema \
              and schema-ron are specified, got: {result}"
         );
@@ -334,6 +395,241 @@ mod schema_validation {
     }
 }
 
+mod smart_thresholds {
+    //! Tests for tuning the smart schema detection
+    use super::*;
+
+    #[rstest]
+    #[case::default("", "1.0.0+5")]
+    #[case::at_threshold("--smart-distance-threshold 5", "1.0.0+5")]
+    #[case::above_distance("--smart-distance-threshold 6", "1.0.0")]
+    fn test_distance_threshold(
+        tier_2_fixture: ZervFixture,
+        #[case] flags: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = tier_2_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --schema standard {flags}"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    #[case::default("", true)]
+    #[case::disabled("--smart-dirty-includes-dev false", false)]
+    fn test_dirty_includes_dev(
+        tier_1_fixture: ZervFixture,
+        #[case] flags: &str,
+        #[case] expect_dev: bool,
+    ) {
+        let zerv_ron = tier_1_fixture
+            .with_vcs_data(Some(0), Some(true), None, None, None, None, None)
+            .with_dev(DEV_TIMESTAMP)
+            .build()
+            .to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --schema standard-no-context {flags}"),
+            zerv_ron,
+        );
+
+        assert_eq!(output.contains("dev"), expect_dev, "got: {output}");
+    }
+
+    #[rstest]
+    fn test_distance_threshold_rejects_zero(tier_1_fixture: ZervFixture) {
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --smart-distance-threshold 0",
+            zerv_ron,
+        );
+        assert!(
+            result.contains("--smart-distance-threshold"),
+            "got: {result}"
+        );
+    }
+}
+
+mod schema_flags {
+    //! Tests for composing presets with --schema-flags
+    use super::*;
+
+    #[rstest]
+    #[case::base_prerelease("base,prerelease", "standard-base-prerelease")]
+    #[case::full(
+        "base,prerelease,post,dev,context",
+        "standard-base-prerelease-post-dev-context"
+    )]
+    #[case::unordered(
+        "context,post,base,prerelease",
+        "standard-base-prerelease-post-context"
+    )]
+    #[case::calver("calver,base,prerelease", "calver-base-prerelease")]
+    #[case::smart("context", "standard-context")]
+    fn test_schema_flags_match_preset(
+        tier_3_fixture: ZervFixture,
+        #[case] flags: &str,
+        #[case] preset: &str,
+    ) {
+        let zerv_ron = tier_3_fixture.build().to_string();
+        // Pin the timestamp so both runs render identical vars
+        let common = "version --source stdin --bumped-timestamp 1703116800 --output-format zerv";
+
+        let from_flags = TestCommand::run_with_stdin(
+            &format!("{common} --schema-flags {flags}"),
+            zerv_ron.clone(),
+        );
+        let from_preset =
+            TestCommand::run_with_stdin(&format!("{common} --schema {preset}"), zerv_ron);
+
+        assert_eq!(from_flags, from_preset);
+    }
+
+    #[rstest]
+    #[case::unknown("base,nightly", "Unknown schema flag 'nightly'")]
+    #[case::missing_tier("base,post", "Schema flag 'post' requires 'prerelease'")]
+    #[case::two_families("standard,calver", "cannot be combined")]
+    fn test_schema_flags_errors(
+        tier_1_fixture: ZervFixture,
+        #[case] flags: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin --schema-flags {flags}"),
+            zerv_ron,
+        );
+        assert!(result.contains(expected), "got: {result}");
+    }
+
+    #[rstest]
+    fn test_schema_flags_conflicts_with_schema(tier_1_fixture: ZervFixture) {
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --schema standard --schema-flags base",
+            zerv_ron,
+        );
+        assert!(result.contains("cannot be used with"), "got: {result}");
+    }
+}
+
+mod multiple_schemas {
+    //! Tests for rendering a comma-separated --schema list
+    use zerv::test_utils::{
+        GitRepoFixture,
+        should_run_docker_tests,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_each_schema_matches_single_run() {
+        if !should_run_docker_tests() {
+            return;
+        }
+        let fixture =
+            GitRepoFixture::with_distance("v1.2.0", 2).expect("Failed to create git repository");
+        let run = |schema: &str| {
+            TestCommand::new()
+                .current_dir(fixture.path())
+                .args_from_str(format!("version --source git --schema {schema}"))
+                .assert_success()
+                .stdout()
+                .trim()
+                .to_string()
+        };
+
+        let expected = format!(
+            "standard: {}\ncalver-base: {}",
+            run("standard"),
+            run("calver-base")
+        );
+        assert_eq!(run("standard,calver-base"), expected);
+    }
+
+    #[rstest]
+    fn test_labels_follow_list_order(tier_1_fixture: ZervFixture) {
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --schema standard-base-prerelease,standard-base --output-format pep440",
+            zerv_ron,
+        );
+        assert_eq!(
+            output,
+            "standard-base-prerelease: 1.0.0\nstandard-base: 1.0.0"
+        );
+    }
+
+    #[rstest]
+    fn test_github_output_written_once(tier_1_fixture: ZervFixture) {
+        let dir = zerv::test_utils::TestDir::new().expect("Failed to create test directory");
+        let output_file = dir.path().join("github_output");
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let output = TestCommand::new()
+            .args_from_str(
+                "version --source stdin --schema standard-base-prerelease,standard-base \
+                 --output-format pep440 --github-output version",
+            )
+            .env("GITHUB_OUTPUT", &output_file)
+            .stdin(zerv_ron)
+            .assert_success();
+
+        let content = std::fs::read_to_string(&output_file).expect("Failed to read output file");
+        let delimiter = content
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("version<<"))
+            .expect("Expected a heredoc entry");
+        assert_eq!(
+            content,
+            format!(
+                "version<<{delimiter}\n{}\n{delimiter}\n",
+                output.stdout().trim()
+            )
+        );
+    }
+
+    #[rstest]
+    #[case::empty_entry("--schema standard,,calver", "empty entry")]
+    #[case::unknown_entry("--schema standard,bogus", "Unknown schema: bogus")]
+    #[case::zerv_output(
+        "--schema standard,calver --output-format zerv",
+        "--output-format zerv"
+    )]
+    #[case::env_output("--schema standard,calver --output-format env", "--output-format env")]
+    #[case::properties_output(
+        "--schema standard,calver --output-format properties",
+        "--output-format properties"
+    )]
+    #[case::sbom("--schema standard,calver --sbom spdx", "--sbom")]
+    #[case::transform_command(
+        "--schema standard,calver --transform-command cat",
+        "--transform-command"
+    )]
+    fn test_schema_list_errors(
+        tier_1_fixture: ZervFixture,
+        #[case] args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = tier_1_fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin {args}"),
+            zerv_ron,
+        );
+        assert!(result.contains(expected), "got: {result}");
+    }
+}
+
 mod schema_output_formats {
     //! Tests for schema interaction with output formats
     use super::*;
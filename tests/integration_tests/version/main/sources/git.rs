@@ -1,3 +1,4 @@
+use rstest::rstest;
 use zerv::schema::ZervSchemaPreset;
 use zerv::test_utils::{
     GitRepoFixture,
@@ -69,6 +70,11 @@ fn test_git_source_comprehensive() {
     expected.vars.bumped_timestamp = parsed_zerv.vars.bumped_timestamp;
     expected.vars.last_commit_hash = parsed_zerv.vars.last_commit_hash.clone();
     expected.vars.last_tag_version = Some("v1.2.3".to_string()); // From the fixture tag
+    expected.vars.commit_subject = Some("Initial commit".to_string()); // From the fixture commit
+    expected.vars.commit_count = Some(1); // The fixture has a single commit
+    expected.vars.default_branch = parsed_zerv.vars.default_branch.clone(); // Depends on git config
+    expected.vars.staged_dirty = Some(false); // The fixture only adds an untracked file
+    expected.vars.unstaged_dirty = Some(true);
 
     // Git source doesn't provide last_branch - it should be None
     assert_eq!(
@@ -118,3 +124,1371 @@ fn test_git_source_no_tag_version() {
         "stderr should contain expected error message. Got: {stderr}"
     );
 }
+
+#[rstest]
+#[case::repo("--repo vendor/lib", "--repo")]
+#[case::fail_if_untagged("--fail-if-untagged", "--fail-if-untagged")]
+#[case::commit_prefix("--commit-prefix h", "--commit-prefix")]
+#[case::distance_inclusive("--distance-inclusive", "--distance-inclusive")]
+#[case::path_filter("--path-filter packages/core", "--path-filter")]
+#[case::base_from_manifest("--base-from-manifest cargo", "--base-from-manifest")]
+#[case::first_tag("--first-tag 1.0.0", "--first-tag")]
+#[case::distance_offset("--distance-offset 5", "--distance-offset")]
+#[case::abbrev("--abbrev auto", "--abbrev")]
+#[case::equal_tag_preference("--equal-tag-preference prefixed", "--equal-tag-preference")]
+#[case::rev("--rev HEAD", "--rev")]
+#[case::branch_from_ci("--branch-from-ci github", "--branch-from-ci")]
+#[case::use_index("--use-index", "--use-index")]
+#[case::tags_reachable("--tags-reachable all", "--tags-reachable")]
+#[case::timings("--timings", "--timings")]
+#[case::conventional_commits("--conventional-commits", "--conventional-commits")]
+#[case::branch_version_regex(r"--branch-version-regex '(?P<major>\d+)'", "--branch-version-regex")]
+fn test_git_only_option_requires_git_source(#[case] option: &str, #[case] flag: &str) {
+    TestCommand::new()
+        .args_from_str(format!(
+            "version --source none --tag-version 1.0.0 {option}"
+        ))
+        .assert_failure()
+        .assert_stderr_contains(&format!("{flag} requires --source git"));
+}
+
+#[test]
+fn test_git_source_path_filter_ignores_commits_outside_path() {
+    if !should_run_docker_tests() {
+        return;
+    }
+
+    let fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+    fixture
+        .test_dir
+        .create_file("packages/core/lib.txt", "core")
+        .expect("Failed to create file");
+    fixture
+        .git_impl
+        .create_commit(&fixture.test_dir, "add core")
+        .expect("Failed to create commit");
+    let fixture = fixture
+        .create_tag("v1.0.0")
+        .commit("outside change 1")
+        .commit("outside change 2");
+
+    let unscoped = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str("version --source git --output-template '{{ distance }}'")
+        .assert_success();
+    assert_eq!(unscoped.stdout().trim(), "2");
+
+    let scoped = TestCommand::new()
+        .current_dir(fixture.path())
+        .args_from_str(
+            "version --source git --path-filter packages/core --output-template '{{ distance }}'",
+        )
+        .assert_success();
+    assert_eq!(scoped.stdout().trim(), "0");
+}
+
+mod base_from_manifest {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::cargo(
+        "cargo",
+        "Cargo.toml",
+        "[package]\nname = \"demo\"\nversion = \"1.4.0\"\n"
+    )]
+    #[case::npm("npm", "package.json", "{\"name\": \"demo\", \"version\": \"1.4.0\"}")]
+    #[case::auto("auto", "package.json", "{\"name\": \"demo\", \"version\": \"1.4.0\"}")]
+    fn test_manifest_used_when_no_tag(
+        #[case] manifest: &str,
+        #[case] file_name: &str,
+        #[case] content: &str,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file(file_name, content)
+            .expect("Failed to create manifest");
+        fixture
+            .git_impl
+            .create_commit(&fixture.test_dir, "add manifest")
+            .expect("Failed to create commit");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --base-from-manifest {manifest} --output-template '{{{{ major }}}}.{{{{ minor }}}}.{{{{ patch }}}}'"
+            ))
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.4.0");
+    }
+
+    #[test]
+    fn test_tag_wins_unless_prefer_manifest() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v2.0.0").expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file(
+                "Cargo.toml",
+                "[package]\nname = \"demo\"\nversion = \"3.0.0\"\n",
+            )
+            .expect("Failed to create manifest");
+
+        let template = "--output-template '{{ major }}.{{ minor }}.{{ patch }}'";
+        let from_tag = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --base-from-manifest cargo {template}"
+            ))
+            .assert_success();
+        assert_eq!(from_tag.stdout().trim(), "2.0.0");
+
+        let from_manifest = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --base-from-manifest cargo --prefer-manifest {template}"
+            ))
+            .assert_success();
+        assert_eq!(from_manifest.stdout().trim(), "3.0.0");
+    }
+
+    #[test]
+    fn test_missing_manifest_errors() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --base-from-manifest npm")
+            .assert_failure();
+        assert!(
+            output.stderr().contains("package.json not found"),
+            "unexpected stderr: {}",
+            output.stderr()
+        );
+    }
+}
+
+mod distance_offset {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::no_offset("", "2")]
+    #[case::offset_added("--distance-offset 100", "102")]
+    fn test_distance_offset_adds_to_detected_distance(
+        #[case] extra_args: &str,
+        #[case] expected: &str,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.0.0", 2).expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-template '{{{{ distance }}}}'"
+            ))
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+}
+
+mod scope_prefix {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::semver("semver", "frontend-1.2.3")]
+    #[case::pep440("pep440", "frontend-1.2.3")]
+    fn test_scoped_tag_is_selected_and_re_emitted(#[case] format: &str, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v2.0.0")
+            .expect("Failed to create git repository")
+            .commit("frontend change")
+            .create_tag("frontend-v1.2.3");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --scope-prefix frontend --output-format {format}"
+            ))
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+
+    #[test]
+    fn test_scope_prefix_conflicts_with_input_prefix() {
+        TestCommand::new()
+            .args_from_str("version --source none --scope-prefix frontend --input-prefix release-")
+            .assert_failure()
+            .assert_stderr_contains("--scope-prefix");
+    }
+}
+
+mod staged_unstaged {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::staged_only(true, "staged=true unstaged=false dirty=true")]
+    #[case::unstaged_only(false, "staged=false unstaged=true dirty=true")]
+    fn test_staged_unstaged_dirty(#[case] stage: bool, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file("change.txt", "content")
+            .expect("Failed to create file");
+        if stage {
+            fixture
+                .git_impl
+                .execute_git(&fixture.test_dir, &["add", "change.txt"])
+                .expect("Failed to stage file");
+        }
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --output-template 'staged={{ staged_dirty }} unstaged={{ unstaged_dirty }} dirty={{ dirty }}'",
+            )
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+}
+
+mod clean_if_tagged {
+    use super::*;
+
+    fn dirty_fixture(tag: &str, commit_after_tag: bool) -> GitRepoFixture {
+        let mut fixture = GitRepoFixture::tagged(tag).expect("Failed to create git repository");
+        if commit_after_tag {
+            fixture = fixture.commit("feature");
+        }
+        fixture
+            .test_dir
+            .create_file("build.log", "content")
+            .expect("Failed to create file");
+        fixture
+    }
+
+    fn version_vars(fixture: &GitRepoFixture) -> String {
+        TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --clean-if-tagged --output-template 'distance={{ distance }} dirty={{ dirty }}'",
+            )
+            .assert_success()
+            .stdout()
+            .trim()
+            .to_string()
+    }
+
+    #[test]
+    fn test_clean_if_tagged_on_tagged_head() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = dirty_fixture("v1.0.0", false);
+        assert_eq!(version_vars(&fixture), "distance=0 dirty=false");
+    }
+
+    #[test]
+    fn test_clean_if_tagged_keeps_untagged_head() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = dirty_fixture("v1.0.0", true);
+        assert_eq!(version_vars(&fixture), "distance=1 dirty=true");
+    }
+
+    #[test]
+    fn test_clean_if_tagged_conflicts_with_clean() {
+        TestCommand::new()
+            .args_from_str("version --source git --clean --clean-if-tagged")
+            .assert_failure()
+            .assert_stderr_contains("Cannot use --clean with --clean-if-tagged");
+    }
+}
+
+mod tag_build_metadata {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::dropped("", "1.2.3")]
+    #[case::preserved("--preserve-tag-metadata", "1.2.3+ci.200")]
+    fn test_tags_differing_in_build_metadata(#[case] extra_args: &str, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::tagged("v1.2.3+ci.100").expect("Failed to create git repository");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["tag", "v1.2.3+ci.200"])
+            .expect("Failed to create tag");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --schema standard-base --output-format semver {extra_args}"
+            ))
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+}
+
+mod inherit_prerelease_label {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::released_by_base_bump("--base-bump patch", "1.2.1")]
+    #[case::inherited("--base-bump patch --inherit-prerelease-label", "1.2.1-beta.0")]
+    #[case::explicit_label_wins(
+        "--base-bump patch --inherit-prerelease-label --pre-release-label rc",
+        "1.2.1-rc.0"
+    )]
+    #[case::unbumped_tag_kept("--inherit-prerelease-label", "1.2.0-beta.2")]
+    fn test_inherit_prerelease_label_from_beta_tag(
+        #[case] extra_args: &str,
+        #[case] expected: &str,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::with_distance("v1.2.0-beta.2", 1)
+            .expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --schema standard-base-prerelease --output-format semver {extra_args}"
+            ))
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+}
+
+mod timings {
+    use super::*;
+
+    #[test]
+    fn test_timings_summary_lists_git_operations() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --timings")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.0.0");
+
+        let stderr = output.stderr();
+        let operations: Vec<&str> = stderr
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        for expected in ["git", "rev-parse", "status", "tag", "log", "total"] {
+            assert!(
+                operations.contains(&expected),
+                "missing {expected} in timings:\n{stderr}"
+            );
+        }
+    }
+}
+
+mod repo {
+    use super::*;
+
+    #[test]
+    fn test_repo_uses_nested_repository_as_is() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        for args in [
+            &["init", "vendor/lib"][..],
+            &[
+                "-C",
+                "vendor/lib",
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--allow-empty",
+                "-m",
+                "init",
+            ],
+            &["-C", "vendor/lib", "tag", "v2.0.0"],
+        ] {
+            fixture
+                .git_impl
+                .execute_git(&fixture.test_dir, args)
+                .expect("Failed to set up nested repository");
+        }
+        // Keep the outer working tree clean despite the untracked nested repository
+        std::fs::write(fixture.path().join(".git/info/exclude"), "vendor/\n")
+            .expect("Failed to exclude nested repository");
+
+        let outer = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --output-format semver")
+            .assert_success();
+        assert_eq!(outer.stdout().trim(), "1.0.0");
+
+        let nested = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --repo vendor/lib --output-format semver")
+            .assert_success();
+        assert_eq!(nested.stdout().trim(), "2.0.0");
+    }
+
+    #[test]
+    fn test_repo_rejects_non_root_directory() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file("sub/file.txt", "content")
+            .expect("Failed to create file");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --repo sub")
+            .assert_failure();
+        assert!(output.stderr().contains("not the root of a git repository"));
+    }
+}
+
+mod commit_subject {
+    use super::*;
+
+    #[test]
+    fn test_commit_subject_template() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0")
+            .expect("Failed to create git repository")
+            .commit("Fix parser edge case");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --output-template '{{ commit_subject }}'")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "Fix parser edge case");
+    }
+}
+
+mod default_branch {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::init_default_branch(&["config", "init.defaultBranch", "master"], "master")]
+    #[case::remote_head(
+        &["symbolic-ref", "refs/remotes/origin/HEAD", "refs/remotes/origin/master"],
+        "master"
+    )]
+    fn test_default_branch_template(#[case] git_args: &[&str], #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        fixture
+            .git_impl
+            .execute_git(
+                &fixture.test_dir,
+                &["update-ref", "refs/remotes/origin/master", "HEAD"],
+            )
+            .expect("Failed to create remote branch");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, git_args)
+            .expect("Failed to configure default branch");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --output-template '{{ default_branch }}'")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+}
+
+mod tag_message {
+    use super::*;
+
+    const TEMPLATE_ARGS: &str = "version --source git --output-template '{% if tag_message %}{{tag_message}}{% else %}lightweight{% endif %}'";
+
+    #[test]
+    fn test_tag_message_from_annotated_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged_annotated("v1.0.0", "First stable release")
+            .expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(TEMPLATE_ARGS)
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "First stable release");
+    }
+
+    #[test]
+    fn test_tag_message_absent_for_lightweight_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(TEMPLATE_ARGS)
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "lightweight");
+    }
+}
+
+mod no_network {
+    use super::*;
+
+    #[test]
+    fn test_no_network_reads_local_history() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.2.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --no-network")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.2.0");
+    }
+}
+
+mod max_tags {
+    use super::*;
+
+    #[test]
+    fn test_max_tags_keeps_newest_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.0.0", 2).expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --max-tags 1 --output-template '{{ major }}.{{ minor }}.{{ patch }}+{{ distance }}'",
+            )
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.0.0+2");
+    }
+
+    #[test]
+    fn test_max_tags_rejects_zero() {
+        TestCommand::new()
+            .args_from_str("version --source git --max-tags 0")
+            .assert_failure()
+            .assert_stderr_contains("--max-tags");
+    }
+}
+
+mod no_tag_timestamp {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::looked_up("", true)]
+    #[case::skipped("--no-tag-timestamp", false)]
+    fn test_no_tag_timestamp_leaves_last_timestamp_unset(
+        #[case] extra_args: &str,
+        #[case] expect_timestamp: bool,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-template '{{{{ last_timestamp }}}}'"
+            ))
+            .assert_success();
+        assert_eq!(!output.stdout().trim().is_empty(), expect_timestamp);
+    }
+}
+
+mod equal_tag_preference {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::default("", "v1.2.3")]
+    #[case::prefixed("--equal-tag-preference prefixed", "v1.2.3")]
+    #[case::unprefixed("--equal-tag-preference unprefixed", "1.2.3")]
+    #[case::first("--equal-tag-preference first", "1.2.3")]
+    #[case::last("--equal-tag-preference last", "v1.2.3")]
+    fn test_equal_tag_preference_picks_tag(#[case] extra_args: &str, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.2.3")
+            .expect("Failed to create git repository")
+            .create_tag("1.2.3");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-format zerv"
+            ))
+            .assert_success();
+        let zerv: Zerv = ron::from_str(output.stdout().trim()).expect("Failed to parse Zerv");
+        assert_eq!(zerv.vars.last_tag_version.as_deref(), Some(expected));
+    }
+}
+
+mod rev {
+    use super::*;
+
+    #[test]
+    fn test_rev_computes_past_commit_without_checkout() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0")
+            .expect("Failed to create git repository")
+            .commit("a");
+        let past_commit = fixture.get_head_commit().expect("Failed to read HEAD");
+        let fixture = fixture.commit("b").create_tag("v2.0.0").commit("c");
+        fixture.make_dirty().expect("Failed to make dirty");
+
+        let template = "'{{ major }}.{{ minor }}.{{ patch }}+{{ distance }}.{{ dirty }}.{{ bumped_commit_hash }}'";
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --rev {past_commit} --output-template {template}"
+            ))
+            .assert_success();
+        assert_eq!(
+            output.stdout().trim(),
+            format!("1.0.0+1.false.g{past_commit}")
+        );
+
+        // HEAD itself is untouched by the lookup
+        let head = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!("version --source git --output-template {template}"))
+            .assert_success();
+        assert!(head.stdout().trim().starts_with("2.0.0+1.true."));
+    }
+
+    #[test]
+    fn test_rev_unknown_revision() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --rev does-not-exist")
+            .assert_failure();
+        output.assert_stderr_contains("Unknown revision 'does-not-exist'");
+        assert!(
+            !output.stderr().contains("Git command failed"),
+            "{}",
+            output.stderr()
+        );
+    }
+}
+
+mod branch_from_ci {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Tagged repository with HEAD detached, as in CI checkouts
+    fn detached_fixture() -> GitRepoFixture {
+        let fixture =
+            GitRepoFixture::with_distance("v1.2.3", 1).expect("Failed to create git repository");
+        let head = fixture.get_head_commit().expect("Failed to read HEAD");
+        fixture.checkout(&head)
+    }
+
+    fn bumped_branch(fixture: &GitRepoFixture, extra_args: &str, env: &[(&str, &str)]) -> String {
+        let mut command = TestCommand::new();
+        command.current_dir(fixture.path());
+        for var in [
+            "GITHUB_ACTIONS",
+            "GITHUB_HEAD_REF",
+            "GITHUB_REF_NAME",
+            "GITLAB_CI",
+        ] {
+            command.env(var, "");
+        }
+        for (key, value) in env {
+            command.env(key, value);
+        }
+        command
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-template '{{{{ bumped_branch }}}}'"
+            ))
+            .assert_success()
+            .stdout()
+            .trim()
+            .to_string()
+    }
+
+    #[rstest]
+    #[case::github_pull_request("--branch-from-ci github", &[("GITHUB_HEAD_REF", "feature/login")], "feature/login")]
+    #[case::github_push(
+        "--branch-from-ci github",
+        &[("GITHUB_REF_NAME", "main"), ("GITHUB_REF_TYPE", "branch")],
+        "main"
+    )]
+    #[case::auto(
+        "--branch-from-ci auto",
+        &[("GITHUB_ACTIONS", "true"), ("GITHUB_HEAD_REF", "develop")],
+        "develop"
+    )]
+    #[case::without_flag("", &[("GITHUB_HEAD_REF", "feature/login")], "")]
+    fn test_branch_from_ci_on_detached_head(
+        #[case] extra_args: &str,
+        #[case] env: &[(&str, &str)],
+        #[case] expected: &str,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = detached_fixture();
+        assert_eq!(bumped_branch(&fixture, extra_args, env), expected);
+    }
+
+    #[test]
+    fn test_branch_from_ci_keeps_git_branch() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.2.3", 1).expect("Failed to create git repository");
+        let git_branch = bumped_branch(&fixture, "", &[]);
+        assert!(!git_branch.is_empty());
+        assert_eq!(
+            bumped_branch(
+                &fixture,
+                "--branch-from-ci github",
+                &[("GITHUB_HEAD_REF", "feature/login")]
+            ),
+            git_branch
+        );
+    }
+
+    #[test]
+    fn test_branch_from_ci_drives_flow_branch_rules() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = detached_fixture();
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .env("GITHUB_HEAD_REF", "develop")
+            .args_from_str("flow --branch-from-ci github --schema standard-base-prerelease")
+            .assert_success();
+        assert!(
+            output.stdout().contains("beta"),
+            "develop should map to beta: {}",
+            output.stdout()
+        );
+    }
+}
+
+mod use_index {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn dirty(fixture: &GitRepoFixture, extra_args: &str) -> String {
+        TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-template '{{{{ dirty }}}}'"
+            ))
+            .assert_success()
+            .stdout()
+            .trim()
+            .to_string()
+    }
+
+    #[rstest]
+    #[case::unstaged_ignored(false, "false")]
+    #[case::staged_detected(true, "true")]
+    fn test_use_index_reflects_staged_changes(#[case] stage: bool, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file("hook.txt", "about to be committed")
+            .expect("Failed to create file");
+        if stage {
+            fixture
+                .git_impl
+                .execute_git(&fixture.test_dir, &["add", "hook.txt"])
+                .expect("Failed to stage file");
+        }
+
+        assert_eq!(dirty(&fixture, "--use-index"), expected);
+        // The working tree view sees the change either way
+        assert_eq!(dirty(&fixture, ""), "true");
+    }
+}
+
+mod tags_reachable {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// v1.0.0 on main with one more commit, and v2.0.0 on a sibling branch never merged into it
+    fn sibling_tag_fixture() -> GitRepoFixture {
+        GitRepoFixture::tagged("v1.0.0")
+            .expect("Failed to create git repository")
+            .with_branch("sibling")
+            .with_checkout("sibling")
+            .commit("sibling")
+            .create_tag("v2.0.0")
+            .with_checkout("main")
+            .commit("main")
+    }
+
+    #[rstest]
+    #[case::default("", "v1.0.0", 1)]
+    #[case::merged("--tags-reachable merged", "v1.0.0", 1)]
+    #[case::all("--tags-reachable all", "v2.0.0", 1)]
+    fn test_tags_reachable_selects_sibling_tag(
+        #[case] extra_args: &str,
+        #[case] expected_tag: &str,
+        #[case] expected_distance: u64,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = sibling_tag_fixture();
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-format zerv"
+            ))
+            .assert_success();
+        let zerv: Zerv = ron::from_str(output.stdout().trim()).expect("Failed to parse Zerv");
+        assert_eq!(zerv.vars.last_tag_version.as_deref(), Some(expected_tag));
+        assert_eq!(zerv.vars.distance, Some(expected_distance));
+    }
+
+    #[rstest]
+    #[case::default("", "v1.1.0", 0)]
+    #[case::all("--tags-reachable all", "v2.0.0", 1)]
+    fn test_tags_reachable_with_tagged_head(
+        #[case] extra_args: &str,
+        #[case] expected_tag: &str,
+        #[case] expected_distance: u64,
+    ) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        // HEAD carries v1.1.0 while the higher v2.0.0 sits on an unmerged sibling branch
+        let fixture = sibling_tag_fixture().create_tag("v1.1.0");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {extra_args} --output-format zerv"
+            ))
+            .assert_success();
+        let zerv: Zerv = ron::from_str(output.stdout().trim()).expect("Failed to parse Zerv");
+        assert_eq!(zerv.vars.last_tag_version.as_deref(), Some(expected_tag));
+        assert_eq!(zerv.vars.distance, Some(expected_distance));
+    }
+}
+
+mod conventional_commits {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::fix(&["fix: handle empty input"], "1.2.4")]
+    #[case::feat(&["fix: a", "feat: add login"], "1.3.0")]
+    #[case::breaking_bang(&["feat: a", "refactor!: drop old api"], "2.0.0")]
+    #[case::breaking_footer(&["feat: config\n\nBREAKING CHANGE: keys renamed"], "2.0.0")]
+    #[case::no_release_commits(&["chore: bump deps", "docs: typo"], "1.2.3")]
+    fn test_conventional_commits_select_bump(#[case] messages: &[&str], #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = messages.iter().fold(
+            GitRepoFixture::tagged("v1.2.3").expect("Failed to create git repository"),
+            |fixture, message| fixture.commit(message),
+        );
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --conventional-commits --schema standard-base")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+
+    #[test]
+    fn test_conventional_commits_conflicts_with_explicit_bump() {
+        TestCommand::new()
+            .args_from_str("version --source git --conventional-commits --bump-minor")
+            .assert_failure()
+            .assert_stderr_contains("cannot be used with");
+    }
+}
+
+mod abbrev {
+    use rstest::rstest;
+
+    use super::*;
+
+    // A 7-character collision cannot be forced cheaply, so raise git's own minimum
+    // instead: auto mode must follow whatever length git settles on
+    #[rstest]
+    #[case::fixed("fixed", 8)]
+    #[case::auto("auto", 13)]
+    fn test_abbrev_mode(#[case] mode: &str, #[case] expected_len: usize) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["config", "core.abbrev", "12"])
+            .expect("Failed to set core.abbrev");
+        let full_hash = fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["rev-parse", "HEAD"])
+            .expect("Failed to read HEAD");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --abbrev {mode} --output-template {{{{bumped_commit_hash_short}}}}"
+            ))
+            .assert_success();
+        let short = output.stdout().trim().to_string();
+        assert_eq!(short.len(), expected_len, "{short}");
+        assert_eq!(short, format!("g{}", &full_hash.trim()[..expected_len - 1]));
+    }
+}
+
+mod empty_repo {
+    use super::*;
+
+    #[test]
+    fn test_empty_repo_uses_base_version() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::without_commits().expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --output-format zerv")
+            .assert_success();
+        let stdout = output.stdout();
+        assert!(stdout.contains("distance: Some(0)"), "{stdout}");
+        assert!(stdout.contains("dirty: Some(false)"), "{stdout}");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --schema standard-base")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "0.0.0");
+        // Probing for a first commit is expected to fail, so it must not log an error
+        assert_eq!(output.stderr(), "");
+    }
+
+    #[test]
+    fn test_empty_repo_with_staged_files_is_dirty() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::without_commits().expect("Failed to create git repository");
+        fixture
+            .test_dir
+            .create_file("staged.txt", "content")
+            .expect("Failed to create file");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["add", "staged.txt"])
+            .expect("Failed to stage file");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --output-format zerv")
+            .assert_success();
+        assert!(output.stdout().contains("dirty: Some(true)"));
+    }
+
+    #[test]
+    fn test_empty_repo_with_first_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::without_commits().expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --first-tag 1.0.0 --schema standard-base")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.0.0");
+    }
+}
+
+mod first_tag {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::root_commit(0, "1.0.0")]
+    #[case::later_commits(2, "1.0.0-alpha.2")]
+    fn test_first_tag_without_tags(#[case] extra_commits: usize, #[case] expected: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let mut fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+        for i in 0..extra_commits {
+            fixture = fixture.commit(&format!("commit {i}"));
+        }
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --first-tag v1.0.0 --schema standard-base-prerelease",
+            )
+            .assert_success();
+        assert_eq!(output.stdout().trim(), expected);
+    }
+
+    #[test]
+    fn test_first_tag_ignored_once_tagged() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v2.0.0").expect("Failed to create git repository");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --first-tag 1.0.0")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "2.0.0");
+    }
+
+    #[test]
+    fn test_first_tag_keeps_distance_with_tag_version() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::empty()
+            .expect("Failed to create git repository")
+            .commit("commit 1")
+            .commit("commit 2");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --first-tag 0.1.0 --tag-version 2.0.0 --schema standard-base-context",
+            )
+            .assert_success();
+        let version = output.stdout().trim().to_string();
+        assert!(version.starts_with("2.0.0+main.2.g"), "{version}");
+    }
+}
+
+mod commit_prefix {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::default("", "g")]
+    #[case::custom("--commit-prefix h", "h")]
+    #[case::bare("--commit-prefix ''", "")]
+    fn test_commit_prefix_in_describe_style_build(#[case] flag: &str, #[case] prefix: &str) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.0.0", 1).expect("Failed to create git repository");
+        let head = fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["rev-parse", "HEAD"])
+            .expect("Failed to read HEAD");
+        let branch = fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["branch", "--show-current"])
+            .expect("Failed to read branch");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git --schema standard-base-context {flag} --output-format semver"
+            ))
+            .assert_success();
+        assert_eq!(
+            output.stdout().trim(),
+            format!("1.0.0+{}.1.{prefix}{}", branch.trim(), &head.trim()[..7])
+        );
+    }
+
+    #[test]
+    fn test_commit_prefix_stripped_from_sbom_commit() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::with_distance("v1.0.0", 1).expect("Failed to create git repository");
+        let head = fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["rev-parse", "HEAD"])
+            .expect("Failed to read HEAD");
+        let head = head.trim();
+
+        let spdx = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --commit-prefix c --sbom spdx")
+            .assert_success();
+        let json: serde_json::Value =
+            serde_json::from_str(&spdx.stdout()).expect("Failed to parse SPDX fragment");
+        assert_eq!(json["sourceInfo"], format!("git commit {head}"));
+
+        let cyclonedx = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --commit-prefix c --sbom cyclonedx")
+            .assert_success();
+        let json: serde_json::Value =
+            serde_json::from_str(&cyclonedx.stdout()).expect("Failed to parse CycloneDX fragment");
+        assert_eq!(json["pedigree"]["commits"][0]["uid"], head);
+    }
+}
+
+mod branch_version_regex {
+    use super::*;
+
+    #[test]
+    fn test_branch_version_regex_reads_release_line() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.3.2").expect("Failed to create git repository");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["checkout", "-b", "release/1.4"])
+            .expect("Failed to create branch");
+
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                r"version --source git --schema standard-base --branch-version-regex 'release/(?P<major>\d+)\.(?P<minor>\d+)'",
+            )
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.4.0");
+    }
+
+    #[test]
+    fn test_branch_version_regex_rejects_line_below_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture =
+            GitRepoFixture::tagged("v2.0.0-rc.1").expect("Failed to create git repository");
+        fixture
+            .git_impl
+            .execute_git(&fixture.test_dir, &["checkout", "-b", "release/1.4"])
+            .expect("Failed to create branch");
+
+        TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                r"version --source git --branch-version-regex 'release/(?P<major>\d+)\.(?P<minor>\d+)'",
+            )
+            .assert_failure()
+            .assert_stderr_contains("Version 2.0.0 is ahead of the line that branch release/1.4 names");
+    }
+}
+
+mod distance_inclusive {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn distance(fixture: &GitRepoFixture, flag: &str) -> u64 {
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(format!(
+                "version --source git {flag} --output-template {{{{distance}}}}"
+            ))
+            .assert_success();
+        output
+            .stdout()
+            .trim()
+            .parse()
+            .expect("distance should be a number")
+    }
+
+    #[rstest]
+    #[case::one_commit_ahead(1)]
+    #[case::three_commits_ahead(3)]
+    fn test_inclusive_distance_is_one_more(#[case] commits: u32) {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::with_distance("v1.0.0", commits)
+            .expect("Failed to create git repository");
+
+        let exclusive = distance(&fixture, "");
+        let inclusive = distance(&fixture, "--distance-inclusive");
+        assert_eq!(exclusive, u64::from(commits));
+        assert_eq!(inclusive, exclusive + 1);
+    }
+
+    #[test]
+    fn test_inclusive_distance_on_tag_counts_the_tag_commit() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        assert_eq!(distance(&fixture, ""), 0);
+        assert_eq!(distance(&fixture, "--distance-inclusive"), 1);
+    }
+}
+
+mod fail_if_untagged {
+    use super::*;
+
+    #[test]
+    fn test_fail_if_untagged_errors_without_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::empty().expect("Failed to create git repository");
+        TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --fail-if-untagged --first-tag 0.1.0")
+            .assert_failure()
+            .assert_stderr_contains("--fail-if-untagged is set");
+    }
+
+    #[test]
+    fn test_fail_if_untagged_passes_with_tag() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::tagged("v1.0.0").expect("Failed to create git repository");
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str("version --source git --fail-if-untagged --output-format semver")
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "1.0.0");
+    }
+
+    #[test]
+    fn test_fail_if_untagged_satisfied_by_tag_version() {
+        if !should_run_docker_tests() {
+            return;
+        }
+
+        let fixture = GitRepoFixture::without_commits().expect("Failed to create git repository");
+        let output = TestCommand::new()
+            .current_dir(fixture.path())
+            .args_from_str(
+                "version --source git --fail-if-untagged --tag-version 2.0.0 --output-format semver",
+            )
+            .assert_success();
+        assert_eq!(output.stdout().trim(), "2.0.0");
+    }
+}
@@ -69,11 +69,29 @@ fn test_none_source_with_all_overrides() {
 }
 
 #[test]
-fn test_none_source_without_tag_version_defaults_to_zero() {
-    // --source none without --tag-version defaults to 0.0.0
+fn test_none_source_without_base_version_fails() {
+    // --source none has nothing to build a version from without a base version override
+    TestCommand::new()
+        .args_from_str("version --source none --distance 2 --output-format semver")
+        .assert_failure()
+        .assert_stderr_contains("--source none needs a base version");
+}
+
+#[test]
+fn test_none_source_with_zero_distance() {
+    let output = TestCommand::new()
+        .args_from_str("version --source none --tag-version 1.2.3 --distance 0")
+        .assert_success();
+
+    assert_eq!(output.stdout().trim(), "1.2.3");
+}
+
+#[test]
+fn test_none_source_with_component_overrides() {
+    // Component overrides alone are enough; unset components default to 0
     let output = TestCommand::new()
-        .args_from_str("version --source none --output-format semver")
+        .args_from_str("version --source none --major 2 --minor 1")
         .assert_success();
 
-    assert_eq!(output.stdout().trim(), "0.0.0");
+    assert_eq!(output.stdout().trim(), "2.1.0");
 }
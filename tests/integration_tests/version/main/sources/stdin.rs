@@ -120,3 +120,38 @@ fn test_stdin_without_input_returns_error() {
         stderr
     );
 }
+
+/// Schema with components in a deliberately unusual order
+const UNUSUAL_ORDER_SCHEMA: &str = r#"(
+    core: [var(Major), var(Minor), var(Patch)],
+    extra_core: [var(Dev), str("zz"), var(Post), var(PreRelease)],
+    build: [uint(7), var(BumpedBranch), str("b"), var(Distance)],
+)"#;
+
+#[rstest]
+#[case::semver("semver", "1.2.3-dev.3.zz.post.2.alpha.1+7.main.b.4")]
+#[case::pep440("pep440", "1.2.3a1.post2.dev3+zz.7.main.b.4")]
+fn test_stdin_pipe_preserves_component_order(#[case] format: &str, #[case] expected: &str) {
+    let source_args = format!(
+        "version --source none --tag-version 1.2.3-alpha.1 --post 2 --dev 3 --distance 4 --bumped-branch main --schema-ron '{UNUSUAL_ORDER_SCHEMA}'"
+    );
+    let zerv_ron = TestCommand::run(&format!("{source_args} --output-format zerv"));
+    let original: Zerv = ron::from_str(&zerv_ron).expect("Failed to parse output as Zerv RON");
+
+    let piped = TestCommand::run_with_stdin(
+        "version --source stdin --output-format zerv",
+        zerv_ron.clone(),
+    );
+    let piped: Zerv = ron::from_str(&piped).expect("Failed to parse output as Zerv RON");
+    assert_eq!(piped.schema.extra_core(), original.schema.extra_core());
+    assert_eq!(piped.schema.build(), original.schema.build());
+    assert_eq!(piped, original);
+
+    let direct = TestCommand::run(&format!("{source_args} --output-format {format}"));
+    let rendered = TestCommand::run_with_stdin(
+        &format!("version --source stdin --output-format {format}"),
+        zerv_ron,
+    );
+    assert_eq!(direct.trim(), expected);
+    assert_eq!(rendered.trim(), expected);
+}
@@ -0,0 +1,76 @@
+use rstest::rstest;
+
+use crate::util::TestCommand;
+
+const BASE_ARGS: &str =
+    "version --source none --tag-version 1.2.3 --distance 5 --bumped-branch main";
+
+#[rstest]
+#[case::clean("", "1.2.3+main.5")]
+#[case::dirty_allowed("--dirty --no-fail-on-dirty", "1.2.3+main.5")]
+#[case::pep440("--output-format pep440", "1.2.3+main.5")]
+#[case::lenient_pep440(
+    "--output-format pep440 --context-separator - --no-strict-pep440",
+    "1.2.3+main-5"
+)]
+#[case::lenient_template("--output-template '{{ major }}{{ nope }}' --no-template-strict", "1")]
+fn test_strict_passes(#[case] extra_args: &str, #[case] expected: &str) {
+    let output = TestCommand::new()
+        .args_from_str(format!("{BASE_ARGS} --strict {extra_args}"))
+        .assert_success();
+    assert_eq!(output.stdout().trim(), expected);
+}
+
+#[rstest]
+#[case::dirty("--dirty", "Working tree is dirty (--fail-on-dirty)")]
+#[case::non_canonical_pep440(
+    "--output-format pep440 --context-separator -",
+    "--context-separator '-' produces non-canonical PEP440 local versions"
+)]
+#[case::undefined_template_variable(
+    "--output-template '{{ major }}{{ nope }}'",
+    "Variable `nope` not found"
+)]
+fn test_strict_fails(#[case] extra_args: &str, #[case] expected_error: &str) {
+    TestCommand::new()
+        .args_from_str(format!("{BASE_ARGS} --strict {extra_args}"))
+        .assert_failure()
+        .assert_stderr_contains(expected_error);
+
+    // Without --strict the same command succeeds
+    TestCommand::new()
+        .args_from_str(format!("{BASE_ARGS} {extra_args}"))
+        .assert_success();
+}
+
+#[test]
+fn test_fail_on_dirty_without_strict() {
+    TestCommand::new()
+        .args_from_str(format!("{BASE_ARGS} --dirty --fail-on-dirty"))
+        .assert_failure()
+        .assert_stderr_contains("--fail-on-dirty");
+}
+
+#[rstest]
+#[case::fail_on_dirty("--fail-on-dirty --no-fail-on-dirty", "cannot be used with")]
+#[case::strict_pep440(
+    "--output-format pep440 --strict-pep440 --no-strict-pep440",
+    "Cannot use --strict-pep440 with --no-strict-pep440"
+)]
+#[case::strict_pep440_context_separator(
+    "--output-format pep440 --strict-pep440 --context-separator _",
+    "--context-separator '_' produces non-canonical PEP440 local versions"
+)]
+#[case::template_strict(
+    "--output-template '{{ major }}' --template-strict --no-template-strict",
+    "Cannot use --template-strict with --no-template-strict"
+)]
+fn test_strict_negations_conflict_with_their_flags(
+    #[case] extra_args: &str,
+    #[case] expected_error: &str,
+) {
+    TestCommand::new()
+        .args_from_str(format!("{BASE_ARGS} {extra_args}"))
+        .assert_failure()
+        .assert_stderr_contains(expected_error);
+}
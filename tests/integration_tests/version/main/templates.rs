@@ -181,6 +181,8 @@ mod template_helpers_hash {
     #[case::default("{{ hash(value=bumped_branch) }}", "c7dedb4")]
     #[case::custom_length("{{ hash(value=bumped_branch, length=10) }}", "c7dedb4632")]
     #[case::hash_int("{{ hash_int(value=bumped_branch) }}", "1440218")]
+    #[case::short_hash("{{ short_hash(value=bumped_branch) }}", "f9e6e6ef")]
+    #[case::short_hash_full("{{ short_hash(value=bumped_branch, length=16) }}", "f9e6e6ef197c2b25")]
     fn test_hash(#[case] template: &str, #[case] expected: &str) {
         let fixture = ZervFixture::new().with_version(1, 0, 0).with_vcs_data(
             None,
@@ -433,4 +435,63 @@ mod template_validation_errors {
             "Error message should suggest adding prefix in template. Got result: {result}"
         );
     }
+
+    #[test]
+    fn test_template_strict_names_undefined_variable() {
+        let fixture = ZervFixture::new().with_version(1, 2, 3);
+        let zerv_ron = fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --output-template '{{ nonexistent }}' --template-strict",
+            zerv_ron,
+        );
+        assert!(
+            result.contains("Variable `nonexistent` not found"),
+            "Error should name the undefined variable. Got result: {result}"
+        );
+    }
+
+    #[rstest]
+    #[case::variable_block("v{{ major }}{{ nonexistent }}", "v1")]
+    #[case::concat("v{{ nonexistent ~ major }}", "v1")]
+    #[case::function_arg(
+        "v{{ major }}{{ ts_format(value=nonexistent, pattern=\"YYYY\") }}",
+        "v1"
+    )]
+    fn test_template_undefined_variable_renders_empty_without_strict(
+        #[case] template: &str,
+        #[case] expected: &str,
+    ) {
+        let fixture = ZervFixture::new().with_version(1, 2, 3);
+        assert_eq!(run_template(template, fixture).trim(), expected);
+    }
+
+    #[rstest]
+    #[case::concat("v{{ nonexistent ~ major }}")]
+    #[case::function_arg("{{ ts_format(value=nonexistent, pattern=\"YYYY\") }}")]
+    fn test_template_strict_fails_on_undefined_variable_in_expressions(#[case] template: &str) {
+        let fixture = ZervFixture::new().with_version(1, 2, 3);
+        let zerv_ron = fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            &format!("version --source stdin --output-template '{template}' --template-strict"),
+            zerv_ron,
+        );
+        assert!(
+            result.contains("Variable `nonexistent` not found"),
+            "{result}"
+        );
+    }
+
+    #[test]
+    fn test_template_strict_requires_output_template() {
+        let fixture = ZervFixture::new().with_version(1, 2, 3);
+        let zerv_ron = fixture.build().to_string();
+
+        let result = TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --template-strict",
+            zerv_ron,
+        );
+        assert!(result.contains("--output-template"), "{result}");
+    }
 }
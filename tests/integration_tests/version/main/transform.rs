@@ -0,0 +1,51 @@
+#![cfg(unix)]
+
+use zerv::test_utils::ZervFixture;
+
+use crate::util::TestCommand;
+
+#[test]
+fn test_transform_command_identity() {
+    let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+    let output = TestCommand::run_with_stdin(
+        "version --source stdin --transform-command cat --output-format semver",
+        zerv_ron,
+    );
+    assert_eq!(output, "1.2.3");
+}
+
+#[test]
+fn test_transform_command_sed_mutation() {
+    let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+    let output = TestCommand::run_with_stdin(
+        r#"version --source stdin --transform-command "sed 's/patch: Some(3)/patch: Some(9)/'" --output-format semver"#,
+        zerv_ron,
+    );
+    assert_eq!(output, "1.2.9");
+}
+
+#[test]
+fn test_transform_command_malformed_output() {
+    let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+    let stderr = TestCommand::run_with_stdin_expect_fail(
+        "version --source stdin --transform-command 'echo garbage'",
+        zerv_ron,
+    );
+    assert!(
+        stderr.contains("returned invalid Zerv RON"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+#[test]
+fn test_transform_command_invalid_schema() {
+    let zerv_ron = ZervFixture::new().with_version(1, 2, 3).build().to_string();
+    let stderr = TestCommand::run_with_stdin_expect_fail(
+        r#"version --source stdin --transform-command "sed 's/var(Minor),/var(Epoch),/'""#,
+        zerv_ron,
+    );
+    assert!(
+        stderr.contains("returned an invalid Zerv schema") && !stderr.contains("Stdin error"),
+        "unexpected stderr: {stderr}"
+    );
+}
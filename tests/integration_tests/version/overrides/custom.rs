@@ -335,6 +335,38 @@ mod template_helpers {
 
         assert_eq!(output, "abc");
     }
+
+    #[rstest]
+    fn test_custom_each_custom_array() {
+        let zerv_ron = ZervFixture::new().with_version(1, 0, 0).build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            concat!(
+                "version --source stdin ",
+                r#"--custom '{"contributors":["alice","bob"]}' "#,
+                r#"--output-template "{{major}}.{{minor}}.{{patch}}+{% for name in custom | each_custom(path='contributors') %}{{ name }}{% if not loop.last %}.{% endif %}{% endfor %}""#
+            ),
+            zerv_ron,
+        );
+
+        assert_eq!(output, "1.0.0+alice.bob");
+    }
+
+    #[rstest]
+    fn test_custom_each_custom_object() {
+        let zerv_ron = ZervFixture::new().with_version(1, 0, 0).build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            concat!(
+                "version --source stdin ",
+                r#"--custom '{"build":{"env":{"arch":"x86","os":"linux"}}}' "#,
+                r#"--output-template "{% for entry in custom | each_custom(path='build.env') %}{{ entry.key }}-{{ entry.value }};{% endfor %}""#
+            ),
+            zerv_ron,
+        );
+
+        assert_eq!(output, "arch-x86;os-linux;");
+    }
 }
 
 mod real_world_scenarios {
@@ -0,0 +1,76 @@
+use std::fs;
+
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::util::TestCommand;
+
+fn run_with_overrides_file(file_name: &str, content: &str, args: &str) -> String {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(file_name), content).unwrap();
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str(format!("{args} --overrides-file {file_name}"))
+        .assert_success()
+        .stdout()
+        .trim()
+        .to_string()
+}
+
+#[rstest]
+#[case::json(
+    "overrides.json",
+    r#"{"tag_version": "1.2.3", "distance": 4, "bumped_branch": "main"}"#
+)]
+#[case::ron(
+    "overrides.ron",
+    r#"(tag_version: "1.2.3", distance: 4, bumped_branch: "main")"#
+)]
+fn test_overrides_file_populates_overrides(#[case] file_name: &str, #[case] content: &str) {
+    let output = run_with_overrides_file(
+        file_name,
+        content,
+        "version --source none --schema standard-base-context --output-format semver",
+    );
+    assert_eq!(output, "1.2.3+main.4");
+}
+
+#[rstest]
+#[case::tag_version("--tag-version 9.9.9", "9.9.9+main.4")]
+#[case::distance("--distance 7", "1.2.3+main.7")]
+#[case::clean_over_file_distance("--clean", "1.2.3+main")]
+fn test_overrides_file_flag_wins(#[case] flag: &str, #[case] expected: &str) {
+    let output = run_with_overrides_file(
+        "overrides.json",
+        r#"{"tag_version": "1.2.3", "distance": 4, "bumped_branch": "main"}"#,
+        &format!(
+            "version --source none --schema standard-base-context --output-format semver {flag}"
+        ),
+    );
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_overrides_file_missing() {
+    TestCommand::new()
+        .args_from_str("version --source none --overrides-file does-not-exist.json")
+        .assert_failure()
+        .assert_stderr_contains("Failed to read overrides file 'does-not-exist.json'");
+}
+
+#[rstest]
+#[case::json("overrides.json", r#"{"tag_version": "1.2.3", "distanc": 9}"#)]
+#[case::ron("overrides.ron", r#"(tag_version: "1.2.3", distanc: 9)"#)]
+fn test_overrides_file_rejects_unknown_key(#[case] file_name: &str, #[case] content: &str) {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(file_name), content).unwrap();
+
+    TestCommand::new()
+        .current_dir(dir.path())
+        .args_from_str(format!(
+            "version --source none --overrides-file {file_name}"
+        ))
+        .assert_failure()
+        .assert_stderr_contains("unknown override 'distanc'");
+}
@@ -1,5 +1,6 @@
 pub mod combinations;
 pub mod custom;
+pub mod file;
 pub mod primary;
 pub mod schema;
 pub mod secondary;
@@ -310,7 +310,7 @@ mod component_with_prerelease {
         let zerv_ron = prerelease_fixture.build().to_string();
 
         let output = TestCommand::run_with_stdin(
-            "version --source stdin --major 5 --output-format semver",
+            "version --source stdin --major 5 --keep-prerelease --output-format semver",
             zerv_ron,
         );
 
@@ -322,7 +322,7 @@ mod component_with_prerelease {
         let zerv_ron = prerelease_fixture.build().to_string();
 
         let output = TestCommand::run_with_stdin(
-            "version --source stdin --minor 10 --output-format semver",
+            "version --source stdin --minor 10 --keep-prerelease --output-format semver",
             zerv_ron,
         );
 
@@ -334,7 +334,7 @@ mod component_with_prerelease {
         let zerv_ron = prerelease_fixture.build().to_string();
 
         let output = TestCommand::run_with_stdin(
-            "version --source stdin --patch 15 --output-format semver",
+            "version --source stdin --patch 15 --keep-prerelease --output-format semver",
             zerv_ron,
         );
 
@@ -346,12 +346,33 @@ mod component_with_prerelease {
         let zerv_ron = prerelease_fixture.build().to_string();
 
         let output = TestCommand::run_with_stdin(
-            "version --source stdin --major 2 --minor 3 --patch 4 --output-format semver",
+            "version --source stdin --major 2 --minor 3 --patch 4 --keep-prerelease --output-format semver",
             zerv_ron,
         );
 
         assert_eq!(output, "2.3.4-alpha.1");
     }
+
+    #[rstest]
+    #[case::new_minor("--minor 3 --patch 0", "1.3.0")]
+    #[case::new_major("--major 2", "2.2.3")]
+    #[case::new_prerelease("--minor 3 --patch 0 --pre-release-num 2", "1.3.0-alpha.2")]
+    #[case::lower_base("--minor 1", "1.1.3-alpha.1")]
+    #[case::same_base("--patch 3", "1.2.3-alpha.1")]
+    fn test_base_increase_resets_prerelease(
+        prerelease_fixture: ZervFixture,
+        #[case] overrides: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = prerelease_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin {overrides} --output-format semver"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
 }
 
 mod component_with_vcs_data {
@@ -145,6 +145,7 @@ mod pre_release_label_override {
     #[case::alpha("alpha", "1.2.3-alpha.0")]
     #[case::beta("beta", "1.2.3-beta.0")]
     #[case::rc("rc", "1.2.3-rc.0")]
+    #[case::numeric("numeric", "1.2.3-0")]
     fn test_pre_release_label_override_semver(
         base_fixture: ZervFixture,
         #[case] label: &str,
@@ -212,6 +213,41 @@ mod pre_release_label_override {
             "Label should replace existing, preserving number"
         );
     }
+
+    #[rstest]
+    #[case::numeric_keeps_number("--pre-release-label numeric", "1.2.3-5")]
+    #[case::numeric_with_number("--pre-release-label numeric --pre-release-num 9", "1.2.3-9")]
+    #[case::bump_to_numeric("--bump-pre-release-label numeric", "1.2.3-0")]
+    fn test_numeric_pre_release_replaces_label(
+        base_fixture: ZervFixture,
+        #[case] args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = base_fixture
+            .with_pre_release(PreReleaseLabel::Alpha, Some(5))
+            .build()
+            .to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin {args} --output-format semver"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    #[case::bump_num("--bump-pre-release-num", "1.0.0-2")]
+    #[case::override_num("--pre-release-num 7", "1.0.0-7")]
+    #[case::label_replaces_numeric("--pre-release-label beta", "1.0.0-beta.1")]
+    fn test_numeric_pre_release_from_tag(#[case] args: &str, #[case] expected: &str) {
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source none --tag-version 1.0.0-1 {args} --output-format semver"),
+            String::new(),
+        );
+
+        assert_eq!(output, expected);
+    }
 }
 
 mod pre_release_num_override {
@@ -275,6 +311,48 @@ mod pre_release_num_override {
             "Number should replace existing, preserving label"
         );
     }
+
+    // 2023-12-21 00:00:00 UTC
+    #[rstest]
+    #[case::semver("semver", "1.2.3-rc.20231221")]
+    #[case::pep440("pep440", "1.2.3rc20231221")]
+    fn test_prerelease_date(
+        base_fixture: ZervFixture,
+        #[case] format: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = base_fixture
+            .with_pre_release(PreReleaseLabel::Rc, Some(5))
+            .with_bumped_timestamp(1_703_116_800)
+            .build()
+            .to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --prerelease-date --output-format {format}"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_prerelease_date_conflicts_with_pre_release_num() {
+        let output = TestCommand::run_expect_fail(
+            "version --source none --tag-version 1.2.3 --prerelease-date --pre-release-num 3",
+        );
+        assert!(output.contains("cannot be used with"), "{output}");
+    }
+
+    #[test]
+    fn test_prerelease_date_requires_timestamp() {
+        let output = TestCommand::run_expect_fail(
+            "version --source none --tag-version 1.2.3 --prerelease-date",
+        );
+        assert!(
+            output.contains("--prerelease-date requires a commit timestamp"),
+            "{output}"
+        );
+    }
 }
 
 mod secondary_component_combinations {
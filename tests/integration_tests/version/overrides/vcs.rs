@@ -65,6 +65,11 @@ mod tag_version_override {
     #[rstest]
     #[case::semver_basic("1.0.0", "semver", "1.0.0")]
     #[case::semver_prerelease("2.0.0-beta.1", "semver", "2.0.0-beta.1")]
+    #[case::semver_multi_identifier("2.0.0-alpha.beta", "semver", "2.0.0-alpha.beta")]
+    #[case::semver_multi_identifier_num("2.0.0-rc.1.hotfix.2", "semver", "2.0.0-rc.1.hotfix.2")]
+    #[case::semver_label_only("1.0.0-beta", "semver", "1.0.0-beta")]
+    #[case::semver_raw_identifiers("1.0.0-x.7.z.92", "semver", "1.0.0-x.7.z.92")]
+    #[case::semver_raw_hyphenated("1.0.0-x-y.01a", "semver", "1.0.0-x-y.01a")]
     #[case::pep440_basic("3.1.4", "pep440", "3.1.4")]
     #[case::pep440_prerelease("1.0.0a1", "pep440", "1.0.0-alpha.1")]
     fn test_tag_version_override(
@@ -90,6 +95,38 @@ mod tag_version_override {
         );
     }
 
+    #[rstest]
+    #[case::implicit_v("", Some("1.2.3"))]
+    #[case::literal_v("--literal-v", None)]
+    #[case::literal_v_with_input_prefix("--literal-v --input-prefix v", Some("1.2.3"))]
+    #[case::custom_prefix("--input-prefix release- --tag-version release-1.2.3", Some("1.2.3"))]
+    fn test_tag_version_prefix_handling(
+        clean_fixture: ZervFixture,
+        #[case] flags: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let zerv_ron = clean_fixture.build().to_string();
+        let args = if flags.contains("--tag-version") {
+            format!("version --source stdin --input-format semver {flags}")
+        } else {
+            format!("version --source stdin --input-format semver --tag-version v1.2.3 {flags}")
+        };
+
+        match expected {
+            Some(expected) => {
+                let output = TestCommand::run_with_stdin(&args, zerv_ron);
+                assert_eq!(output.trim(), expected);
+            }
+            None => {
+                let stderr = TestCommand::run_with_stdin_expect_fail(&args, zerv_ron);
+                assert!(
+                    stderr.contains("--literal-v"),
+                    "Expected literal-v error, got: {stderr}"
+                );
+            }
+        }
+    }
+
     #[rstest]
     fn test_tag_version_replaces_stdin_version(clean_fixture: ZervFixture) {
         let zerv_ron = clean_fixture.build().to_string();
@@ -231,6 +268,168 @@ mod dirty_override {
     }
 }
 
+mod dirty_marker {
+    use super::*;
+
+    #[rstest]
+    #[case::semver_default_marker("semver", "--dirty-marker", "1.0.0+dirty")]
+    #[case::semver_custom_marker("semver", "--dirty-marker wip", "1.0.0+wip")]
+    #[case::pep440_default_marker("pep440", "--dirty-marker", "1.0.0+dirty")]
+    #[case::no_marker("semver", "", "1.0.0")]
+    fn test_dirty_marker_on_dirty_build(
+        dirty_fixture: ZervFixture,
+        #[case] format: &str,
+        #[case] marker_args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = dirty_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin {marker_args} --output-format {format}"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    fn test_dirty_marker_omitted_on_clean_build(clean_fixture: ZervFixture) {
+        let zerv_ron = clean_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --dirty-marker --output-format semver",
+            zerv_ron,
+        );
+
+        assert_eq!(output, "1.0.0");
+    }
+
+    #[rstest]
+    fn test_dirty_marker_follows_dirty_override(clean_fixture: ZervFixture) {
+        let zerv_ron = clean_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --dirty --dirty-marker --output-format semver",
+            zerv_ron,
+        );
+
+        assert_eq!(output, "1.0.0+dirty");
+    }
+}
+
+mod dirty_on_tag {
+    use zerv::version::PreReleaseLabel;
+
+    use super::*;
+
+    #[rstest]
+    #[case::post("post", "semver", "1.0.0-post.1")]
+    #[case::dev("dev", "semver", "1.0.0-dev.1")]
+    #[case::prerelease("prerelease", "semver", "1.0.1-alpha.1")]
+    #[case::post_pep440("post", "pep440", "1.0.0.post1")]
+    #[case::dev_pep440("dev", "pep440", "1.0.0.dev1")]
+    #[case::prerelease_pep440("prerelease", "pep440", "1.0.1a1")]
+    fn test_dirty_on_tag_modes(
+        dirty_fixture: ZervFixture,
+        #[case] mode: &str,
+        #[case] format: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = dirty_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!("version --source stdin --dirty-on-tag {mode} --output-format {format}"),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    fn test_dirty_on_tag_bumps_existing_prerelease() {
+        let zerv_ron = ZervFixture::new()
+            .with_version(1, 0, 0)
+            .with_pre_release(PreReleaseLabel::Rc, Some(2))
+            .with_vcs_data(Some(0), Some(true), None, None, None, None, None)
+            .build()
+            .to_string();
+
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --dirty-on-tag prerelease --output-format semver",
+            zerv_ron,
+        );
+
+        assert_eq!(output, "1.0.0-rc.3");
+    }
+
+    #[rstest]
+    #[case::clean(clean_fixture(), "1.0.0")]
+    #[case::off_tag(fixture_with_distance(), "1.0.0")]
+    fn test_dirty_on_tag_ignored_unless_dirty_on_tag(
+        #[case] fixture: ZervFixture,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            "version --source stdin --dirty-on-tag post --output-format semver",
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+}
+
+mod build_number {
+    use super::*;
+
+    #[rstest]
+    #[case::custom_modulus("--build-number-formula 1000", "3123")]
+    #[case::default_modulus("--build-number-formula", "300123")]
+    #[case::not_requested("", "")]
+    fn test_build_number_formula(
+        clean_fixture: ZervFixture,
+        #[case] formula_args: &str,
+        #[case] expected: &str,
+    ) {
+        let zerv_ron = clean_fixture.build().to_string();
+
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --distance 3 --bumped-timestamp 1700000123 {formula_args} --output-template '{{{{ build_number | default(value=\"\") }}}}'"
+            ),
+            zerv_ron,
+        );
+
+        assert_eq!(output, expected);
+    }
+
+    #[rstest]
+    fn test_build_number_in_schema(clean_fixture: ZervFixture) {
+        let zerv_ron = clean_fixture.build().to_string();
+        let schema_ron = "(core: [var(Major), var(Minor), var(Patch)], extra_core: [], build: [var(BuildNumber)])";
+
+        let output = TestCommand::run_with_stdin(
+            &format!(
+                "version --source stdin --distance 2 --bumped-timestamp 1700000045 --build-number-formula 100 --schema-ron '{schema_ron}' --output-format semver"
+            ),
+            zerv_ron,
+        );
+
+        assert_eq!(output, "1.0.0+245");
+    }
+
+    #[rstest]
+    fn test_build_number_formula_rejects_zero(clean_fixture: ZervFixture) {
+        let zerv_ron = clean_fixture.build().to_string();
+
+        TestCommand::run_with_stdin_expect_fail(
+            "version --source stdin --build-number-formula 0",
+            zerv_ron,
+        );
+    }
+}
+
 mod clean_override {
     use super::*;
 